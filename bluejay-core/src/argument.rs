@@ -1,10 +1,14 @@
-use crate::{AsIter, Value};
+use crate::{AsIter, Location, Value};
 
 pub trait Argument<const CONST: bool> {
     type Value: Value<CONST>;
 
     fn name(&self) -> &str;
     fn value(&self) -> &Self::Value;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
 
 pub trait ConstArgument: Argument<true> {}
@@ -1,5 +1,5 @@
 use crate::definition::ArgumentsDefinition;
-use crate::AsIter;
+use crate::{AsIter, Location};
 use strum::{AsRefStr, Display, EnumIter, EnumString, VariantNames};
 
 #[derive(
@@ -56,4 +56,8 @@ pub trait DirectiveDefinition {
     fn is_repeatable(&self) -> bool;
     fn locations(&self) -> &Self::DirectiveLocations;
     fn is_builtin(&self) -> bool;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
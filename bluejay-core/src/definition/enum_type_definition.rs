@@ -1,4 +1,5 @@
 use crate::definition::{EnumValueDefinitions, HasDirectives};
+use crate::Location;
 
 pub trait EnumTypeDefinition: HasDirectives {
     type EnumValueDefinitions: EnumValueDefinitions;
@@ -7,4 +8,8 @@ pub trait EnumTypeDefinition: HasDirectives {
     fn name(&self) -> &str;
     fn enum_value_definitions(&self) -> &Self::EnumValueDefinitions;
     fn is_builtin(&self) -> bool;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
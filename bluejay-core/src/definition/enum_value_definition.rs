@@ -1,6 +1,11 @@
 use crate::definition::HasDirectives;
+use crate::Location;
 
 pub trait EnumValueDefinition: HasDirectives {
     fn description(&self) -> Option<&str>;
     fn name(&self) -> &str;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
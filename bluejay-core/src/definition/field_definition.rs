@@ -1,4 +1,5 @@
 use crate::definition::{ArgumentsDefinition, HasDirectives, OutputType};
+use crate::Location;
 
 pub trait FieldDefinition: HasDirectives {
     type ArgumentsDefinition: ArgumentsDefinition;
@@ -9,4 +10,8 @@ pub trait FieldDefinition: HasDirectives {
     fn arguments_definition(&self) -> Option<&Self::ArgumentsDefinition>;
     fn r#type(&self) -> &Self::OutputType;
     fn is_builtin(&self) -> bool;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
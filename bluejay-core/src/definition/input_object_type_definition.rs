@@ -1,4 +1,5 @@
 use crate::definition::{HasDirectives, InputFieldsDefinition};
+use crate::Location;
 
 pub trait InputObjectTypeDefinition: HasDirectives {
     type InputFieldsDefinition: InputFieldsDefinition;
@@ -6,4 +7,8 @@ pub trait InputObjectTypeDefinition: HasDirectives {
     fn description(&self) -> Option<&str>;
     fn name(&self) -> &str;
     fn input_field_definitions(&self) -> &Self::InputFieldsDefinition;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
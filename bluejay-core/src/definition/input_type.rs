@@ -126,8 +126,10 @@ impl<I: InputType> std::fmt::Display for ShallowInputTypeReference<'_, I> {
     }
 }
 
-impl<I: InputType> PartialEq for ShallowInputTypeReference<'_, I> {
-    fn eq(&self, other: &Self) -> bool {
+impl<'b, I: InputType, I2: InputType> PartialEq<ShallowInputTypeReference<'b, I2>>
+    for ShallowInputTypeReference<'_, I>
+{
+    fn eq(&self, other: &ShallowInputTypeReference<'b, I2>) -> bool {
         match (self, other) {
             (
                 ShallowInputTypeReference::Base(name1, required1),
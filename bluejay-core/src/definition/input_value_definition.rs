@@ -1,5 +1,6 @@
 use crate::definition::{HasDirectives, InputType};
 use crate::ConstValue;
+use crate::Location;
 
 pub trait InputValueDefinition: HasDirectives {
     type InputType: InputType;
@@ -13,4 +14,8 @@ pub trait InputValueDefinition: HasDirectives {
     fn is_required(&self) -> bool {
         self.default_value().is_none() && self.r#type().is_required()
     }
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
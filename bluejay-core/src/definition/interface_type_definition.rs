@@ -1,4 +1,5 @@
 use crate::definition::{FieldsDefinition, HasDirectives, InterfaceImplementations};
+use crate::Location;
 
 pub trait InterfaceTypeDefinition: HasDirectives {
     type FieldsDefinition: FieldsDefinition;
@@ -8,4 +9,8 @@ pub trait InterfaceTypeDefinition: HasDirectives {
     fn name(&self) -> &str;
     fn interface_implementations(&self) -> Option<&Self::InterfaceImplementations>;
     fn fields_definition(&self) -> &Self::FieldsDefinition;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
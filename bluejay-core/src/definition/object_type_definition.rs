@@ -1,4 +1,5 @@
 use crate::definition::{FieldsDefinition, HasDirectives, InterfaceImplementations};
+use crate::Location;
 
 pub trait ObjectTypeDefinition: HasDirectives {
     type FieldsDefinition: FieldsDefinition;
@@ -9,4 +10,8 @@ pub trait ObjectTypeDefinition: HasDirectives {
     fn interface_implementations(&self) -> Option<&Self::InterfaceImplementations>;
     fn fields_definition(&self) -> &Self::FieldsDefinition;
     fn is_builtin(&self) -> bool;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
@@ -108,8 +108,10 @@ impl<'a, O: OutputType> ShallowOutputTypeReference<'a, O> {
     }
 }
 
-impl<O: OutputType> PartialEq for ShallowOutputTypeReference<'_, O> {
-    fn eq(&self, other: &Self) -> bool {
+impl<'b, O: OutputType, O2: OutputType> PartialEq<ShallowOutputTypeReference<'b, O2>>
+    for ShallowOutputTypeReference<'_, O>
+{
+    fn eq(&self, other: &ShallowOutputTypeReference<'b, O2>) -> bool {
         match (self, other) {
             (
                 ShallowOutputTypeReference::Base(name1, required1),
@@ -1,4 +1,5 @@
 use crate::definition::HasDirectives;
+use crate::Location;
 use crate::Value;
 use std::borrow::Cow;
 
@@ -12,4 +13,8 @@ pub trait ScalarTypeDefinition: HasDirectives {
     ) -> Result<(), Cow<'static, str>> {
         Ok(())
     }
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
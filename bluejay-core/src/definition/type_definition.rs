@@ -3,7 +3,7 @@ use crate::definition::{
     InputObjectTypeDefinition, InputType, InterfaceTypeDefinition, ObjectTypeDefinition,
     OutputType, ScalarTypeDefinition, UnionTypeDefinition,
 };
-use crate::BuiltinScalarDefinition;
+use crate::{BuiltinScalarDefinition, Location};
 use enum_as_inner::EnumAsInner;
 
 #[derive(Debug, EnumAsInner)]
@@ -117,6 +117,18 @@ impl<'a, T: TypeDefinition> TypeDefinitionReference<'a, T> {
         }
     }
 
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::BuiltinScalar(_) => None,
+            Self::CustomScalar(cstd) => cstd.location(),
+            Self::Object(otd) => otd.location(),
+            Self::InputObject(iotd) => iotd.location(),
+            Self::Enum(etd) => etd.location(),
+            Self::Union(utd) => utd.location(),
+            Self::Interface(itd) => itd.location(),
+        }
+    }
+
     pub fn kind(&self) -> &'static str {
         match self {
             TypeDefinitionReference::BuiltinScalar(_) => "SCALAR",
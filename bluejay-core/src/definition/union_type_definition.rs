@@ -1,4 +1,5 @@
 use crate::definition::{FieldsDefinition, HasDirectives, UnionMemberTypes};
+use crate::Location;
 
 pub trait UnionTypeDefinition: HasDirectives {
     type UnionMemberTypes: UnionMemberTypes;
@@ -9,4 +10,8 @@ pub trait UnionTypeDefinition: HasDirectives {
     fn union_member_types(&self) -> &Self::UnionMemberTypes;
     /// Should only contain the builtin `__typename` field definition
     fn fields_definition(&self) -> &Self::FieldsDefinition;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
@@ -1,10 +1,14 @@
-use crate::{Arguments, AsIter};
+use crate::{Arguments, AsIter, Location};
 
 pub trait Directive<const CONST: bool> {
     type Arguments: Arguments<CONST>;
 
     fn name(&self) -> &str;
     fn arguments(&self) -> Option<&Self::Arguments>;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
 
 pub trait ConstDirective: Directive<true> {}
@@ -1,5 +1,5 @@
 use crate::executable::SelectionSet;
-use crate::{VariableArguments, VariableDirectives};
+use crate::{Location, VariableArguments, VariableDirectives};
 
 pub trait Field {
     type Arguments: VariableArguments;
@@ -15,4 +15,8 @@ pub trait Field {
     fn response_name(&self) -> &str {
         self.alias().unwrap_or_else(|| self.name())
     }
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
@@ -1,5 +1,5 @@
 use crate::executable::SelectionSet;
-use crate::{Indexable, VariableDirectives};
+use crate::{Indexable, Location, VariableDirectives};
 
 pub trait FragmentDefinition: Indexable {
     type Directives: VariableDirectives;
@@ -10,4 +10,8 @@ pub trait FragmentDefinition: Indexable {
     fn type_condition(&self) -> &str;
     fn directives(&self) -> Option<&Self::Directives>;
     fn selection_set(&self) -> &Self::SelectionSet;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
@@ -1,8 +1,12 @@
-use crate::VariableDirectives;
+use crate::{Location, VariableDirectives};
 
 pub trait FragmentSpread {
     type Directives: VariableDirectives;
 
     fn name(&self) -> &str;
     fn directives(&self) -> Option<&Self::Directives>;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
@@ -1,5 +1,5 @@
 use crate::executable::SelectionSet;
-use crate::VariableDirectives;
+use crate::{Location, VariableDirectives};
 
 pub trait InlineFragment {
     type Directives: VariableDirectives;
@@ -8,4 +8,8 @@ pub trait InlineFragment {
     fn type_condition(&self) -> Option<&str>;
     fn directives(&self) -> Option<&Self::Directives>;
     fn selection_set(&self) -> &Self::SelectionSet;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
@@ -1,5 +1,5 @@
 use crate::executable::{SelectionSet, VariableDefinitions};
-use crate::{Indexable, OperationType, VariableDirectives};
+use crate::{Indexable, Location, OperationType, VariableDirectives};
 
 #[derive(Debug)]
 pub enum OperationDefinitionReference<'a, O: OperationDefinition> {
@@ -49,6 +49,13 @@ impl<'a, O: OperationDefinition> OperationDefinitionReference<'a, O> {
             Self::Implicit(_) => None,
         }
     }
+
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::Explicit(eod) => eod.location(),
+            Self::Implicit(iod) => iod.location(),
+        }
+    }
 }
 
 pub trait OperationDefinition: Sized + Indexable {
@@ -56,6 +63,10 @@ pub trait OperationDefinition: Sized + Indexable {
     type ImplicitOperationDefinition: ImplicitOperationDefinition<SelectionSet=<Self::ExplicitOperationDefinition as ExplicitOperationDefinition>::SelectionSet>;
 
     fn as_ref(&self) -> OperationDefinitionReference<'_, Self>;
+
+    fn location(&self) -> Option<Location> {
+        self.as_ref().location()
+    }
 }
 
 pub trait ExplicitOperationDefinition {
@@ -69,10 +80,18 @@ pub trait ExplicitOperationDefinition {
     fn variable_definitions(&self) -> Option<&Self::VariableDefinitions>;
     fn directives(&self) -> Option<&Self::Directives>;
     fn selection_set(&self) -> &Self::SelectionSet;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
 
 pub trait ImplicitOperationDefinition {
     type SelectionSet: SelectionSet;
 
     fn selection_set(&self) -> &Self::SelectionSet;
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
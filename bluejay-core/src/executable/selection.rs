@@ -1,6 +1,7 @@
 use crate::{
     definition::DirectiveLocation,
     executable::{Field, FragmentSpread, InlineFragment},
+    Location,
 };
 
 #[derive(Debug)]
@@ -34,4 +35,12 @@ impl<'a, S: Selection> SelectionReference<'a, S> {
             Self::InlineFragment(_) => DirectiveLocation::InlineFragment,
         }
     }
+
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::Field(f) => f.location(),
+            Self::FragmentSpread(fs) => fs.location(),
+            Self::InlineFragment(i) => i.location(),
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::executable::VariableType;
-use crate::{AsIter, ConstDirectives, ConstValue};
+use crate::{AsIter, ConstDirectives, ConstValue, Location};
 
 pub trait VariableDefinition {
     type VariableType: VariableType;
@@ -15,6 +15,10 @@ pub trait VariableDefinition {
     fn is_required(&self) -> bool {
         self.default_value().is_none() && self.r#type().as_ref().is_required()
     }
+
+    fn location(&self) -> Option<Location> {
+        None
+    }
 }
 
 pub trait VariableDefinitions: AsIter<Item = Self::VariableDefinition> {
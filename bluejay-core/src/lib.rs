@@ -5,6 +5,7 @@ pub mod definition;
 mod directive;
 pub mod executable;
 mod indexable;
+mod location;
 mod operation_type;
 mod value;
 
@@ -17,6 +18,7 @@ pub use directive::{
     ConstDirective, ConstDirectives, Directive, Directives, VariableDirective, VariableDirectives,
 };
 pub use indexable::{Indexable, Indexed};
+pub use location::Location;
 pub use operation_type::OperationType;
 pub use strum::IntoEnumIterator;
 pub use value::{
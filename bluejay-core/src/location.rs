@@ -0,0 +1,44 @@
+use std::ops::Range;
+
+/// The location of an element within the source text it was parsed from, as a range of byte
+/// offsets.
+///
+/// The core traits expose this through a provided `location` method that returns `None`, so
+/// only implementations backed by source text need to override it. The location is that of the
+/// element's name, which is what diagnostics point at: the field name rather than its alias,
+/// the variable of a variable definition, and the name of a definition rather than its
+/// description or body. Elements without a name use their first token instead, i.e. the
+/// operation type of an anonymous operation, the `{` of a shorthand query and the `...` of an
+/// inline fragment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    start: usize,
+    end: usize,
+}
+
+impl Location {
+    pub fn new(byte_range: Range<usize>) -> Self {
+        Self {
+            start: byte_range.start,
+            end: byte_range.end,
+        }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn byte_range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl From<Range<usize>> for Location {
+    fn from(value: Range<usize>) -> Self {
+        Self::new(value)
+    }
+}
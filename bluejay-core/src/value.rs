@@ -75,7 +75,7 @@ impl<T: Value<false>> VariableValue for T {}
 #[strum(serialize_all = "lowercase")]
 pub enum ValueReference<'a, const CONST: bool, V: Value<CONST>> {
     Variable(&'a V::Variable),
-    Integer(i32),
+    Integer(i64),
     Float(f64),
     String(&'a str),
     Boolean(bool),
@@ -99,33 +99,35 @@ impl<const CONST: bool, V: Value<CONST>> Clone for ValueReference<'_, CONST, V>
 
 impl<const CONST: bool, V: Value<CONST>> Copy for ValueReference<'_, CONST, V> {}
 
-impl<const CONST: bool, V: Value<CONST>> std::cmp::PartialEq for ValueReference<'_, CONST, V> {
-    fn eq(&self, other: &Self) -> bool {
+impl<'b, const CONST: bool, V: Value<CONST>, W: Value<CONST>>
+    std::cmp::PartialEq<ValueReference<'b, CONST, W>> for ValueReference<'_, CONST, V>
+{
+    fn eq(&self, other: &ValueReference<'b, CONST, W>) -> bool {
         match self {
             Self::Variable(v) => {
-                matches!(other, Self::Variable(other_v) if v.name() == other_v.name())
+                matches!(other, ValueReference::Variable(other_v) if v.name() == other_v.name())
             }
             Self::Integer(i) => {
-                matches!(other, Self::Integer(other_i) if i == other_i)
+                matches!(other, ValueReference::Integer(other_i) if i == other_i)
             }
             Self::Float(f) => {
-                matches!(other, Self::Float(other_f) if f == other_f)
+                matches!(other, ValueReference::Float(other_f) if f == other_f)
             }
             Self::String(s) => {
-                matches!(other, Self::String(other_s) if s == other_s)
+                matches!(other, ValueReference::String(other_s) if s == other_s)
             }
             Self::Boolean(b) => {
-                matches!(other, Self::Boolean(other_b) if b == other_b)
+                matches!(other, ValueReference::Boolean(other_b) if b == other_b)
             }
-            Self::Null => matches!(other, Self::Null),
-            Self::Enum(e) => matches!(other, Self::Enum(other_e) if e == other_e),
+            Self::Null => matches!(other, ValueReference::Null),
+            Self::Enum(e) => matches!(other, ValueReference::Enum(other_e) if e == other_e),
             Self::List(l) => {
-                matches!(other, Self::List(other_l) if itertools::equal(l.iter().map(Value::as_ref), other_l.iter().map(Value::as_ref)))
+                matches!(other, ValueReference::List(other_l) if itertools::equal(l.iter().map(Value::as_ref), other_l.iter().map(Value::as_ref)))
             }
-            Self::Object(o) => matches!(other, Self::Object(other_o) if {
+            Self::Object(o) => matches!(other, ValueReference::Object(other_o) if {
                 let lhs: HashMap<&str, _> = HashMap::from_iter(o.iter().map(|(k, v)| (k.as_ref(), v.as_ref())));
                 let rhs: HashMap<&str, _> = HashMap::from_iter(other_o.iter().map(|(k, v)| (k.as_ref(), v.as_ref())));
-                lhs == rhs
+                lhs.len() == rhs.len() && lhs.iter().all(|(k, v)| rhs.get(k).is_some_and(|other_v| v == other_v))
             }),
         }
     }
@@ -29,7 +29,7 @@ impl<const CONST: bool> Value<CONST> for JsonValue {
             Self::Null => ValueReference::Null,
             Self::Bool(b) => ValueReference::Boolean(*b),
             Self::Number(n) => {
-                if let Some(i) = n.as_i64().and_then(|i| i32::try_from(i).ok()) {
+                if let Some(i) = n.as_i64() {
                     ValueReference::Integer(i)
                 } else {
                     ValueReference::Float(n.as_f64().expect("Json numeric values must be finite"))
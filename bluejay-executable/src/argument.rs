@@ -0,0 +1,57 @@
+use crate::Value;
+use bluejay_core::{Argument as CoreArgument, Arguments as CoreArguments, AsIter, Location};
+
+#[derive(Debug, Clone)]
+pub struct Argument<const CONST: bool> {
+    name: String,
+    value: Value<CONST>,
+    location: Option<Location>,
+}
+
+impl<const CONST: bool> Argument<CONST> {
+    pub(crate) fn new(name: String, value: Value<CONST>, location: Option<Location>) -> Self {
+        Self {
+            name,
+            value,
+            location,
+        }
+    }
+}
+
+impl<const CONST: bool> CoreArgument<CONST> for Argument<CONST> {
+    type Value = Value<CONST>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Arguments<const CONST: bool>(Vec<Argument<CONST>>);
+
+impl<const CONST: bool> Arguments<CONST> {
+    pub(crate) fn new(items: Vec<Argument<CONST>>) -> Self {
+        Self(items)
+    }
+}
+
+impl<const CONST: bool> AsIter for Arguments<CONST> {
+    type Item = Argument<CONST>;
+    type Iterator<'a> = std::slice::Iter<'a, Argument<CONST>>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl<const CONST: bool> CoreArguments<CONST> for Arguments<CONST> {
+    type Argument = Argument<CONST>;
+}
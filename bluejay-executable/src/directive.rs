@@ -0,0 +1,61 @@
+use crate::Arguments;
+use bluejay_core::{AsIter, Directive as CoreDirective, Directives as CoreDirectives, Location};
+
+#[derive(Debug, Clone)]
+pub struct Directive<const CONST: bool> {
+    name: String,
+    arguments: Option<Arguments<CONST>>,
+    location: Option<Location>,
+}
+
+impl<const CONST: bool> Directive<CONST> {
+    pub(crate) fn new(
+        name: String,
+        arguments: Option<Arguments<CONST>>,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            name,
+            arguments,
+            location,
+        }
+    }
+}
+
+impl<const CONST: bool> CoreDirective<CONST> for Directive<CONST> {
+    type Arguments = Arguments<CONST>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn arguments(&self) -> Option<&Self::Arguments> {
+        self.arguments.as_ref()
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Directives<const CONST: bool>(Vec<Directive<CONST>>);
+
+impl<const CONST: bool> Directives<CONST> {
+    pub(crate) fn new(items: Vec<Directive<CONST>>) -> Self {
+        Self(items)
+    }
+}
+
+impl<const CONST: bool> AsIter for Directives<CONST> {
+    type Item = Directive<CONST>;
+    type Iterator<'a> = std::slice::Iter<'a, Directive<CONST>>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl<const CONST: bool> CoreDirectives<CONST> for Directives<CONST> {
+    type Directive = Directive<CONST>;
+}
@@ -0,0 +1,65 @@
+use crate::{
+    Argument, Arguments, Directive, Directives, ExplicitOperationDefinition, Field,
+    FragmentDefinition, FragmentSpread, ImplicitOperationDefinition, InlineFragment,
+    OperationDefinition, Selection, SelectionSet, Value, VariableDefinition, VariableDefinitions,
+    VariableType,
+};
+use bluejay_core::executable::ExecutableDocument as CoreExecutableDocument;
+
+/// An executable document that owns all of its contents, so it does not borrow the source
+/// text it was parsed from and can be shared across threads
+#[derive(Debug, Clone)]
+pub struct ExecutableDocument {
+    operation_definitions: Vec<OperationDefinition>,
+    fragment_definitions: Vec<FragmentDefinition>,
+}
+
+impl ExecutableDocument {
+    pub(crate) fn new(
+        operation_definitions: Vec<OperationDefinition>,
+        fragment_definitions: Vec<FragmentDefinition>,
+    ) -> Self {
+        Self {
+            operation_definitions,
+            fragment_definitions,
+        }
+    }
+
+    pub fn operation_definitions(&self) -> &[OperationDefinition] {
+        &self.operation_definitions
+    }
+
+    pub fn fragment_definitions(&self) -> &[FragmentDefinition] {
+        &self.fragment_definitions
+    }
+}
+
+impl CoreExecutableDocument for ExecutableDocument {
+    type Value<const CONST: bool> = Value<CONST>;
+    type VariableType = VariableType;
+    type Argument<const CONST: bool> = Argument<CONST>;
+    type Arguments<const CONST: bool> = Arguments<CONST>;
+    type Directive<const CONST: bool> = Directive<CONST>;
+    type Directives<const CONST: bool> = Directives<CONST>;
+    type FragmentSpread = FragmentSpread;
+    type Field = Field;
+    type Selection = Selection;
+    type SelectionSet = SelectionSet;
+    type InlineFragment = InlineFragment;
+    type VariableDefinition = VariableDefinition;
+    type VariableDefinitions = VariableDefinitions;
+    type ExplicitOperationDefinition = ExplicitOperationDefinition;
+    type ImplicitOperationDefinition = ImplicitOperationDefinition;
+    type OperationDefinition = OperationDefinition;
+    type FragmentDefinition = FragmentDefinition;
+    type OperationDefinitions<'a> = std::slice::Iter<'a, OperationDefinition>;
+    type FragmentDefinitions<'a> = std::slice::Iter<'a, FragmentDefinition>;
+
+    fn operation_definitions(&self) -> Self::OperationDefinitions<'_> {
+        self.operation_definitions.iter()
+    }
+
+    fn fragment_definitions(&self) -> Self::FragmentDefinitions<'_> {
+        self.fragment_definitions.iter()
+    }
+}
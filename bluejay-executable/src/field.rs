@@ -0,0 +1,63 @@
+use crate::{Arguments, Directives, SelectionSet};
+use bluejay_core::executable::Field as CoreField;
+use bluejay_core::Location;
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    alias: Option<String>,
+    name: String,
+    arguments: Option<Arguments<false>>,
+    directives: Option<Directives<false>>,
+    selection_set: Option<SelectionSet>,
+    location: Option<Location>,
+}
+
+impl Field {
+    pub(crate) fn new(
+        alias: Option<String>,
+        name: String,
+        arguments: Option<Arguments<false>>,
+        directives: Option<Directives<false>>,
+        selection_set: Option<SelectionSet>,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            alias,
+            name,
+            arguments,
+            directives,
+            selection_set,
+            location,
+        }
+    }
+}
+
+impl CoreField for Field {
+    type Arguments = Arguments<false>;
+    type Directives = Directives<false>;
+    type SelectionSet = SelectionSet;
+
+    fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn arguments(&self) -> Option<&Self::Arguments> {
+        self.arguments.as_ref()
+    }
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+
+    fn selection_set(&self) -> Option<&Self::SelectionSet> {
+        self.selection_set.as_ref()
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Directives, SelectionSet};
+use bluejay_core::executable::FragmentDefinition as CoreFragmentDefinition;
+use bluejay_core::{Indexable, Location};
+
+#[derive(Debug, Clone)]
+pub struct FragmentDefinition {
+    description: Option<String>,
+    name: String,
+    type_condition: String,
+    directives: Option<Directives<false>>,
+    selection_set: SelectionSet,
+    location: Option<Location>,
+}
+
+impl FragmentDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        type_condition: String,
+        directives: Option<Directives<false>>,
+        selection_set: SelectionSet,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            type_condition,
+            directives,
+            selection_set,
+            location,
+        }
+    }
+}
+
+impl Indexable for FragmentDefinition {
+    type Id = usize;
+
+    /// Every fragment has its own selection set, so its identifier is reused
+    fn id(&self) -> &Self::Id {
+        self.selection_set.id()
+    }
+}
+
+impl CoreFragmentDefinition for FragmentDefinition {
+    type Directives = Directives<false>;
+    type SelectionSet = SelectionSet;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn type_condition(&self) -> &str {
+        &self.type_condition
+    }
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+
+    fn selection_set(&self) -> &Self::SelectionSet {
+        &self.selection_set
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
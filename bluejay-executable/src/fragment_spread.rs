@@ -0,0 +1,40 @@
+use crate::Directives;
+use bluejay_core::executable::FragmentSpread as CoreFragmentSpread;
+use bluejay_core::Location;
+
+#[derive(Debug, Clone)]
+pub struct FragmentSpread {
+    name: String,
+    directives: Option<Directives<false>>,
+    location: Option<Location>,
+}
+
+impl FragmentSpread {
+    pub(crate) fn new(
+        name: String,
+        directives: Option<Directives<false>>,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            name,
+            directives,
+            location,
+        }
+    }
+}
+
+impl CoreFragmentSpread for FragmentSpread {
+    type Directives = Directives<false>;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    Argument, Arguments, Directive, Directives, ExecutableDocument, ExplicitOperationDefinition,
+    Field, FragmentDefinition, FragmentSpread, ImplicitOperationDefinition, InlineFragment,
+    OperationDefinition, Selection, SelectionSet, Value, VariableDefinition, VariableDefinitions,
+    VariableType,
+};
+use bluejay_core::executable::{
+    ExecutableDocument as CoreExecutableDocument, ExplicitOperationDefinition as _, Field as _,
+    FragmentDefinition as _, FragmentSpread as _, ImplicitOperationDefinition as _,
+    InlineFragment as _, OperationDefinition as _, OperationDefinitionReference, Selection as _,
+    SelectionReference, VariableDefinition as _, VariableType as _, VariableTypeReference,
+};
+use bluejay_core::{
+    Argument as _, Arguments as CoreArguments, AsIter, Directive as _, Directives as CoreDirectives,
+};
+
+impl ExecutableDocument {
+    /// Copies any implementation of [`bluejay_core::executable::ExecutableDocument`] into an
+    /// owned executable document
+    pub fn from_executable_document<E: CoreExecutableDocument>(executable_document: &E) -> Self {
+        let mut converter = Converter { next_id: 0 };
+        let operation_definitions = executable_document
+            .operation_definitions()
+            .map(|operation_definition| converter.operation_definition::<E>(operation_definition))
+            .collect();
+        let fragment_definitions = executable_document
+            .fragment_definitions()
+            .map(|fragment_definition| converter.fragment_definition::<E>(fragment_definition))
+            .collect();
+        Self::new(operation_definitions, fragment_definitions)
+    }
+}
+
+#[cfg(feature = "parser")]
+impl ExecutableDocument {
+    /// Parses an executable document. The result does not borrow `s`, unlike
+    /// [`bluejay_parser::ast::executable::ExecutableDocument`].
+    pub fn parse(s: &str) -> Result<Self, Vec<bluejay_parser::Error>> {
+        use bluejay_parser::ast::{
+            executable::ExecutableDocument as ParserExecutableDocument, Parse,
+        };
+
+        let executable_document = ParserExecutableDocument::parse(s).result?;
+        Ok(Self::from_executable_document(&executable_document))
+    }
+}
+
+/// Assigns the identifiers used by [`bluejay_core::Indexable`], which are unique within a document
+struct Converter {
+    next_id: usize,
+}
+
+impl Converter {
+    fn next_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn operation_definition<E: CoreExecutableDocument>(
+        &mut self,
+        operation_definition: &E::OperationDefinition,
+    ) -> OperationDefinition {
+        match operation_definition.as_ref() {
+            OperationDefinitionReference::Explicit(eod) => {
+                OperationDefinition::Explicit(ExplicitOperationDefinition::new(
+                    eod.description().map(ToOwned::to_owned),
+                    eod.operation_type(),
+                    eod.name().map(ToOwned::to_owned),
+                    eod.variable_definitions().map(|variable_definitions| {
+                        VariableDefinitions::new(
+                            variable_definitions
+                                .iter()
+                                .map(|vd| self.variable_definition::<E>(vd))
+                                .collect(),
+                        )
+                    }),
+                    directives(eod.directives()),
+                    self.selection_set::<E>(eod.selection_set()),
+                    eod.location(),
+                ))
+            }
+            OperationDefinitionReference::Implicit(iod) => {
+                OperationDefinition::Implicit(ImplicitOperationDefinition::new(
+                    self.selection_set::<E>(iod.selection_set()),
+                    iod.location(),
+                ))
+            }
+        }
+    }
+
+    fn fragment_definition<E: CoreExecutableDocument>(
+        &mut self,
+        fragment_definition: &E::FragmentDefinition,
+    ) -> FragmentDefinition {
+        FragmentDefinition::new(
+            fragment_definition.description().map(ToOwned::to_owned),
+            fragment_definition.name().to_owned(),
+            fragment_definition.type_condition().to_owned(),
+            directives(fragment_definition.directives()),
+            self.selection_set::<E>(fragment_definition.selection_set()),
+            fragment_definition.location(),
+        )
+    }
+
+    fn variable_definition<E: CoreExecutableDocument>(
+        &mut self,
+        variable_definition: &E::VariableDefinition,
+    ) -> VariableDefinition {
+        VariableDefinition::new(
+            variable_definition.description().map(ToOwned::to_owned),
+            variable_definition.variable().to_owned(),
+            self.variable_type::<E>(variable_definition.r#type()),
+            directives(variable_definition.directives()),
+            variable_definition.default_value().map(Value::from),
+            variable_definition.location(),
+        )
+    }
+
+    fn variable_type<E: CoreExecutableDocument>(
+        &mut self,
+        variable_type: &E::VariableType,
+    ) -> VariableType {
+        let id = self.next_id();
+        match variable_type.as_ref() {
+            VariableTypeReference::Named(name, is_required) => VariableType::Named {
+                name: name.to_owned(),
+                is_required,
+                id,
+            },
+            VariableTypeReference::List(inner, is_required) => VariableType::List {
+                inner: Box::new(self.variable_type::<E>(inner)),
+                is_required,
+                id,
+            },
+        }
+    }
+
+    fn selection_set<E: CoreExecutableDocument>(
+        &mut self,
+        selection_set: &E::SelectionSet,
+    ) -> SelectionSet {
+        let id = self.next_id();
+        let selections = selection_set
+            .iter()
+            .map(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => Selection::Field(self.field::<E>(field)),
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    Selection::FragmentSpread(FragmentSpread::new(
+                        fragment_spread.name().to_owned(),
+                        directives(fragment_spread.directives()),
+                        fragment_spread.location(),
+                    ))
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    Selection::InlineFragment(InlineFragment::new(
+                        inline_fragment.type_condition().map(ToOwned::to_owned),
+                        directives(inline_fragment.directives()),
+                        self.selection_set::<E>(inline_fragment.selection_set()),
+                        inline_fragment.location(),
+                    ))
+                }
+            })
+            .collect();
+        SelectionSet::new(selections, id)
+    }
+
+    fn field<E: CoreExecutableDocument>(&mut self, field: &E::Field) -> Field {
+        Field::new(
+            field.alias().map(ToOwned::to_owned),
+            field.name().to_owned(),
+            arguments(field.arguments()),
+            directives(field.directives()),
+            field
+                .selection_set()
+                .map(|selection_set| self.selection_set::<E>(selection_set)),
+            field.location(),
+        )
+    }
+}
+
+fn arguments<const CONST: bool, A: CoreArguments<CONST>>(
+    arguments: Option<&A>,
+) -> Option<Arguments<CONST>> {
+    arguments.map(|arguments| {
+        Arguments::new(
+            arguments
+                .iter()
+                .map(|argument| {
+                    Argument::new(
+                        argument.name().to_owned(),
+                        Value::from(argument.value()),
+                        argument.location(),
+                    )
+                })
+                .collect(),
+        )
+    })
+}
+
+fn directives<const CONST: bool, D: CoreDirectives<CONST>>(
+    directives: Option<&D>,
+) -> Option<Directives<CONST>> {
+    directives.map(|directives| {
+        Directives::new(
+            directives
+                .iter()
+                .map(|directive| {
+                    Directive::new(
+                        directive.name().to_owned(),
+                        arguments(directive.arguments()),
+                        directive.location(),
+                    )
+                })
+                .collect(),
+        )
+    })
+}
@@ -0,0 +1,48 @@
+use crate::{Directives, SelectionSet};
+use bluejay_core::executable::InlineFragment as CoreInlineFragment;
+use bluejay_core::Location;
+
+#[derive(Debug, Clone)]
+pub struct InlineFragment {
+    type_condition: Option<String>,
+    directives: Option<Directives<false>>,
+    selection_set: SelectionSet,
+    location: Option<Location>,
+}
+
+impl InlineFragment {
+    pub(crate) fn new(
+        type_condition: Option<String>,
+        directives: Option<Directives<false>>,
+        selection_set: SelectionSet,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            type_condition,
+            directives,
+            selection_set,
+            location,
+        }
+    }
+}
+
+impl CoreInlineFragment for InlineFragment {
+    type Directives = Directives<false>;
+    type SelectionSet = SelectionSet;
+
+    fn type_condition(&self) -> Option<&str> {
+        self.type_condition.as_deref()
+    }
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+
+    fn selection_set(&self) -> &Self::SelectionSet {
+        &self.selection_set
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
@@ -0,0 +1,30 @@
+mod argument;
+mod directive;
+mod executable_document;
+mod field;
+mod fragment_definition;
+mod fragment_spread;
+mod from_executable_document;
+mod inline_fragment;
+mod operation_definition;
+mod selection;
+mod selection_set;
+mod value;
+mod variable_definition;
+mod variable_type;
+
+pub use argument::{Argument, Arguments};
+pub use directive::{Directive, Directives};
+pub use executable_document::ExecutableDocument;
+pub use field::Field;
+pub use fragment_definition::FragmentDefinition;
+pub use fragment_spread::FragmentSpread;
+pub use inline_fragment::InlineFragment;
+pub use operation_definition::{
+    ExplicitOperationDefinition, ImplicitOperationDefinition, OperationDefinition,
+};
+pub use selection::Selection;
+pub use selection_set::SelectionSet;
+pub use value::Value;
+pub use variable_definition::{VariableDefinition, VariableDefinitions};
+pub use variable_type::VariableType;
@@ -0,0 +1,137 @@
+use crate::{Directives, SelectionSet, VariableDefinitions};
+use bluejay_core::executable::{
+    ExplicitOperationDefinition as CoreExplicitOperationDefinition,
+    ImplicitOperationDefinition as CoreImplicitOperationDefinition,
+    OperationDefinition as CoreOperationDefinition, OperationDefinitionReference,
+};
+use bluejay_core::{Indexable, Location, OperationType};
+
+#[derive(Debug, Clone)]
+pub enum OperationDefinition {
+    Explicit(ExplicitOperationDefinition),
+    Implicit(ImplicitOperationDefinition),
+}
+
+impl OperationDefinition {
+    pub fn selection_set(&self) -> &SelectionSet {
+        match self {
+            Self::Explicit(e) => &e.selection_set,
+            Self::Implicit(i) => &i.selection_set,
+        }
+    }
+}
+
+impl Indexable for OperationDefinition {
+    type Id = usize;
+
+    /// Every operation has its own selection set, so its identifier is reused
+    fn id(&self) -> &Self::Id {
+        self.selection_set().id()
+    }
+}
+
+impl CoreOperationDefinition for OperationDefinition {
+    type ExplicitOperationDefinition = ExplicitOperationDefinition;
+    type ImplicitOperationDefinition = ImplicitOperationDefinition;
+
+    fn as_ref(&self) -> OperationDefinitionReference<'_, Self> {
+        match self {
+            Self::Explicit(e) => OperationDefinitionReference::Explicit(e),
+            Self::Implicit(i) => OperationDefinitionReference::Implicit(i),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplicitOperationDefinition {
+    description: Option<String>,
+    operation_type: OperationType,
+    name: Option<String>,
+    variable_definitions: Option<VariableDefinitions>,
+    directives: Option<Directives<false>>,
+    selection_set: SelectionSet,
+    location: Option<Location>,
+}
+
+impl ExplicitOperationDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        operation_type: OperationType,
+        name: Option<String>,
+        variable_definitions: Option<VariableDefinitions>,
+        directives: Option<Directives<false>>,
+        selection_set: SelectionSet,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            description,
+            operation_type,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+            location,
+        }
+    }
+}
+
+impl CoreExplicitOperationDefinition for ExplicitOperationDefinition {
+    type VariableDefinitions = VariableDefinitions;
+    type Directives = Directives<false>;
+    type SelectionSet = SelectionSet;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn operation_type(&self) -> OperationType {
+        self.operation_type
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    fn variable_definitions(&self) -> Option<&Self::VariableDefinitions> {
+        self.variable_definitions.as_ref()
+    }
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+
+    fn selection_set(&self) -> &Self::SelectionSet {
+        &self.selection_set
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImplicitOperationDefinition {
+    selection_set: SelectionSet,
+    location: Option<Location>,
+}
+
+impl ImplicitOperationDefinition {
+    pub(crate) fn new(selection_set: SelectionSet, location: Option<Location>) -> Self {
+        Self {
+            selection_set,
+            location,
+        }
+    }
+}
+
+impl CoreImplicitOperationDefinition for ImplicitOperationDefinition {
+    type SelectionSet = SelectionSet;
+
+    fn selection_set(&self) -> &Self::SelectionSet {
+        &self.selection_set
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
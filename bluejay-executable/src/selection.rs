@@ -0,0 +1,23 @@
+use crate::{Field, FragmentSpread, InlineFragment};
+use bluejay_core::executable::{Selection as CoreSelection, SelectionReference};
+
+#[derive(Debug, Clone)]
+pub enum Selection {
+    Field(Field),
+    FragmentSpread(FragmentSpread),
+    InlineFragment(InlineFragment),
+}
+
+impl CoreSelection for Selection {
+    type Field = Field;
+    type FragmentSpread = FragmentSpread;
+    type InlineFragment = InlineFragment;
+
+    fn as_ref(&self) -> SelectionReference<'_, Self> {
+        match self {
+            Self::Field(f) => SelectionReference::Field(f),
+            Self::FragmentSpread(fs) => SelectionReference::FragmentSpread(fs),
+            Self::InlineFragment(i) => SelectionReference::InlineFragment(i),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::Selection;
+use bluejay_core::executable::SelectionSet as CoreSelectionSet;
+use bluejay_core::{AsIter, Indexable};
+
+#[derive(Debug, Clone)]
+pub struct SelectionSet {
+    selections: Vec<Selection>,
+    id: usize,
+}
+
+impl SelectionSet {
+    pub(crate) fn new(selections: Vec<Selection>, id: usize) -> Self {
+        Self { selections, id }
+    }
+}
+
+impl Indexable for SelectionSet {
+    type Id = usize;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl AsIter for SelectionSet {
+    type Item = Selection;
+    type Iterator<'a> = std::slice::Iter<'a, Selection>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.selections.iter()
+    }
+}
+
+impl CoreSelectionSet for SelectionSet {
+    type Selection = Selection;
+}
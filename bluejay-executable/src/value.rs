@@ -0,0 +1,55 @@
+use bluejay_core::{AsIter, ObjectValue, Value as CoreValue, ValueReference, Variable as _};
+
+/// An owned GraphQL value. Variables can only occur when `CONST` is `false`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<const CONST: bool> {
+    Variable(String),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Enum(String),
+    List(Vec<Value<CONST>>),
+    Object(Vec<(String, Value<CONST>)>),
+}
+
+impl<const CONST: bool> CoreValue<CONST> for Value<CONST> {
+    type List = Vec<Value<CONST>>;
+    type Object = Vec<(String, Value<CONST>)>;
+    type Variable = String;
+
+    fn as_ref(&self) -> ValueReference<'_, CONST, Self> {
+        match self {
+            Self::Variable(v) => ValueReference::Variable(v),
+            Self::Integer(i) => ValueReference::Integer(*i),
+            Self::Float(f) => ValueReference::Float(*f),
+            Self::String(s) => ValueReference::String(s),
+            Self::Boolean(b) => ValueReference::Boolean(*b),
+            Self::Null => ValueReference::Null,
+            Self::Enum(e) => ValueReference::Enum(e),
+            Self::List(l) => ValueReference::List(l),
+            Self::Object(o) => ValueReference::Object(o),
+        }
+    }
+}
+
+impl<const CONST: bool, V: CoreValue<CONST>> From<&V> for Value<CONST> {
+    fn from(value: &V) -> Self {
+        match value.as_ref() {
+            ValueReference::Variable(v) => Self::Variable(v.name().to_owned()),
+            ValueReference::Integer(i) => Self::Integer(i),
+            ValueReference::Float(f) => Self::Float(f),
+            ValueReference::String(s) => Self::String(s.to_owned()),
+            ValueReference::Boolean(b) => Self::Boolean(b),
+            ValueReference::Null => Self::Null,
+            ValueReference::Enum(e) => Self::Enum(e.to_owned()),
+            ValueReference::List(l) => Self::List(l.iter().map(Self::from).collect()),
+            ValueReference::Object(o) => Self::Object(
+                o.iter()
+                    .map(|(key, value)| (key.as_ref().to_owned(), Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
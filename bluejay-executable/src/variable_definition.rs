@@ -0,0 +1,88 @@
+use crate::{Directives, Value, VariableType};
+use bluejay_core::executable::{
+    VariableDefinition as CoreVariableDefinition, VariableDefinitions as CoreVariableDefinitions,
+};
+use bluejay_core::AsIter;
+use bluejay_core::Location;
+
+#[derive(Debug, Clone)]
+pub struct VariableDefinition {
+    description: Option<String>,
+    variable: String,
+    r#type: VariableType,
+    directives: Option<Directives<true>>,
+    default_value: Option<Value<true>>,
+    location: Option<Location>,
+}
+
+impl VariableDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        variable: String,
+        r#type: VariableType,
+        directives: Option<Directives<true>>,
+        default_value: Option<Value<true>>,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            description,
+            variable,
+            r#type,
+            directives,
+            default_value,
+            location,
+        }
+    }
+}
+
+impl CoreVariableDefinition for VariableDefinition {
+    type VariableType = VariableType;
+    type Directives = Directives<true>;
+    type Value = Value<true>;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn variable(&self) -> &str {
+        &self.variable
+    }
+
+    fn r#type(&self) -> &Self::VariableType {
+        &self.r#type
+    }
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+
+    fn default_value(&self) -> Option<&Self::Value> {
+        self.default_value.as_ref()
+    }
+
+    fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VariableDefinitions(Vec<VariableDefinition>);
+
+impl VariableDefinitions {
+    pub(crate) fn new(items: Vec<VariableDefinition>) -> Self {
+        Self(items)
+    }
+}
+
+impl AsIter for VariableDefinitions {
+    type Item = VariableDefinition;
+    type Iterator<'a> = std::slice::Iter<'a, VariableDefinition>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl CoreVariableDefinitions for VariableDefinitions {
+    type VariableDefinition = VariableDefinition;
+}
@@ -0,0 +1,39 @@
+use bluejay_core::executable::{VariableType as CoreVariableType, VariableTypeReference};
+use bluejay_core::Indexable;
+
+#[derive(Debug, Clone)]
+pub enum VariableType {
+    Named {
+        name: String,
+        is_required: bool,
+        id: usize,
+    },
+    List {
+        inner: Box<Self>,
+        is_required: bool,
+        id: usize,
+    },
+}
+
+impl Indexable for VariableType {
+    type Id = usize;
+
+    fn id(&self) -> &Self::Id {
+        match self {
+            Self::Named { id, .. } | Self::List { id, .. } => id,
+        }
+    }
+}
+
+impl CoreVariableType for VariableType {
+    fn as_ref(&self) -> VariableTypeReference<'_, Self> {
+        match self {
+            Self::Named {
+                name, is_required, ..
+            } => VariableTypeReference::Named(name, *is_required),
+            Self::List {
+                inner, is_required, ..
+            } => VariableTypeReference::List(inner, *is_required),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+#![cfg(feature = "parser")]
+
+use bluejay_executable::ExecutableDocument;
+use bluejay_parser::ast::{executable::ExecutableDocument as ParserExecutableDocument, Parse};
+use bluejay_printer::executable::ExecutableDocumentPrinter;
+use bluejay_schema::SchemaDefinition;
+use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache};
+use std::sync::Arc;
+
+const SCHEMA: &str = r#"
+directive @tag(name: String!) on FIELD | VARIABLE_DEFINITION
+
+enum Species {
+  DOG
+  CAT
+}
+
+input PetFilter {
+  species: [Species!]
+  minAge: Int = 0
+}
+
+interface Pet {
+  name: String!
+}
+
+type Dog implements Pet {
+  name: String!
+  barkVolume: Int
+}
+
+type Cat implements Pet {
+  name: String!
+  meowVolume: Int
+}
+
+type Query {
+  pets(filter: PetFilter, first: Int = 10): [Pet!]!
+  pet(name: String!): Pet
+}
+"#;
+
+const QUERY: &str = r#"
+"A described query"
+query Pets($filter: PetFilter = { species: [DOG], minAge: 2 }, $first: Int @tag(name: "first")) {
+  pets(filter: $filter, first: $first) {
+    ...PetName
+    ... on Dog @skip(if: false) {
+      barkVolume
+    }
+  }
+  fido: pet(name: "Fido") @tag(name: "fido") {
+    name
+  }
+}
+
+fragment PetName on Pet {
+  name
+}
+
+{
+  pet(name: "Mittens") {
+    ... on Cat {
+      meowVolume
+    }
+  }
+}
+"#;
+
+fn schema_definition() -> SchemaDefinition {
+    SchemaDefinition::parse(SCHEMA).expect("Schema had errors")
+}
+
+fn validation_error_count(
+    executable_document: &impl bluejay_core::executable::ExecutableDocument,
+    schema_definition: &SchemaDefinition,
+) -> usize {
+    let cache = Cache::new(executable_document, schema_definition);
+    BuiltinRulesValidator::validate(executable_document, schema_definition, &cache).count()
+}
+
+#[test]
+fn test_matches_parser_executable_document() {
+    let parser_executable_document = ParserExecutableDocument::parse(QUERY)
+        .result
+        .expect("Document had parse errors");
+    let executable_document = ExecutableDocument::parse(QUERY).unwrap();
+
+    assert_eq!(
+        ExecutableDocumentPrinter::to_string(&parser_executable_document),
+        ExecutableDocumentPrinter::to_string(&executable_document),
+    );
+    assert_eq!(2, executable_document.operation_definitions().len());
+    assert_eq!(1, executable_document.fragment_definitions().len());
+    assert_eq!(
+        bluejay_operation_normalizer::signature(&parser_executable_document, Some("Pets")),
+        bluejay_operation_normalizer::signature(&executable_document, Some("Pets")),
+    );
+}
+
+#[test]
+fn test_validation() {
+    let schema_definition = schema_definition();
+
+    let executable_document = ExecutableDocument::parse(QUERY).unwrap();
+    // the document has an anonymous operation alongside a named one
+    assert_eq!(
+        1,
+        validation_error_count(&executable_document, &schema_definition)
+    );
+
+    let invalid = "query($unused: Int) { pet(name: 1) { nickname ...Missing } }";
+    let parser_executable_document = ParserExecutableDocument::parse(invalid)
+        .result
+        .expect("Document had parse errors");
+    let executable_document = ExecutableDocument::parse(invalid).unwrap();
+    // unused variable, wrong argument type, unknown field and undefined fragment
+    assert_eq!(
+        4,
+        validation_error_count(&parser_executable_document, &schema_definition)
+    );
+    assert_eq!(
+        4,
+        validation_error_count(&executable_document, &schema_definition)
+    );
+}
+
+#[test]
+fn test_outlives_source_and_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<ExecutableDocument>();
+
+    let executable_document = {
+        let source = String::from("query Named { pets { name } }");
+        Arc::new(ExecutableDocument::parse(&source).unwrap())
+    };
+    let schema_definition = Arc::new(schema_definition());
+
+    let error_count = std::thread::spawn({
+        let executable_document = Arc::clone(&executable_document);
+        let schema_definition = Arc::clone(&schema_definition);
+        move || validation_error_count(executable_document.as_ref(), schema_definition.as_ref())
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(0, error_count);
+}
+
+#[test]
+fn test_copies_locations() {
+    use bluejay_core::executable::{
+        FragmentDefinition as _, OperationDefinition as _, Selection as _,
+    };
+    use bluejay_core::AsIter;
+
+    let executable_document = ExecutableDocument::parse(QUERY).unwrap();
+    let operation_definition = &executable_document.operation_definitions()[0];
+    let location = operation_definition.location().unwrap();
+    assert_eq!("Pets", &QUERY[location.byte_range()]);
+
+    let pets = operation_definition
+        .as_ref()
+        .selection_set()
+        .iter()
+        .next()
+        .unwrap();
+    assert_eq!(
+        Some(QUERY.find("pets(").unwrap()),
+        pets.as_ref().location().map(|location| location.start()),
+    );
+
+    let fragment_definition = &executable_document.fragment_definitions()[0];
+    assert_eq!(
+        Some(QUERY.find("PetName on").unwrap()),
+        fragment_definition
+            .location()
+            .map(|location| location.start()),
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(ExecutableDocument::parse("query { pets { name }").is_err());
+}
@@ -0,0 +1,30 @@
+use bluejay_core::OperationType;
+use bluejay_validator::executable::operation::OperationResolutionError;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub enum Error<'a> {
+    OperationResolution(OperationResolutionError<'a>),
+    UnsupportedOperationType { operation_type: OperationType },
+    NonIntrospectionField { name: &'a str },
+}
+
+impl Error<'_> {
+    pub fn message(&self) -> Cow<'static, str> {
+        match self {
+            Self::OperationResolution(error) => error.message(),
+            Self::UnsupportedOperationType { operation_type } => {
+                format!("Introspection is not supported for {operation_type} operations").into()
+            }
+            Self::NonIntrospectionField { name } => {
+                format!("Field `{name}` is not an introspection field").into()
+            }
+        }
+    }
+}
+
+impl<'a> From<OperationResolutionError<'a>> for Error<'a> {
+    fn from(value: OperationResolutionError<'a>) -> Self {
+        Self::OperationResolution(value)
+    }
+}
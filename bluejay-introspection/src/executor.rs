@@ -0,0 +1,719 @@
+use crate::Error;
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition, HasDirectives,
+    InputObjectTypeDefinition, InputType, InputValueDefinition, InterfaceImplementation,
+    InterfaceTypeDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
+    ShallowInputTypeReference, ShallowOutputTypeReference, TypeDefinitionReference,
+    UnionMemberType, UnionTypeDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference, VariableDefinition,
+};
+use bluejay_core::{Argument, AsIter, Directive, OperationType, Value, ValueReference, Variable};
+use bluejay_printer::value::ValuePrinter;
+use bluejay_validator::executable::operation::{OperationResolutionError, VariableValues};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::HashSet;
+
+type GroupedFields<'a, E> = Vec<(&'a str, Vec<&'a <E as ExecutableDocument>::Field>)>;
+
+/// Executes the introspection fields of an operation against a schema definition,
+/// returning the `data` portion of the response.
+pub struct Executor<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    executable_document: &'a E,
+    schema_definition: &'a S,
+    operation_definition: &'a E::OperationDefinition,
+    variable_values: &'a VV,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Executor<'a, E, S, VV> {
+    pub fn execute(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_name: Option<&'a str>,
+        variable_values: &'a VV,
+    ) -> Result<Map<String, JsonValue>, Error<'a>> {
+        let operation_definition = match operation_name {
+            Some(operation_name) => executable_document
+                .operation_definitions()
+                .find(|operation_definition| {
+                    operation_definition.as_ref().name() == Some(operation_name)
+                })
+                .ok_or(OperationResolutionError::NoOperationWithName {
+                    name: operation_name,
+                })?,
+            None => {
+                let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
+                    .operation_definitions()
+                    .collect::<Vec<_>>()
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
+                operation_definition
+            }
+        };
+
+        let operation_type = operation_definition.as_ref().operation_type();
+        if operation_type != OperationType::Query {
+            return Err(Error::UnsupportedOperationType { operation_type });
+        }
+
+        let instance = Self {
+            executable_document,
+            schema_definition,
+            operation_definition,
+            variable_values,
+        };
+
+        let grouped_fields = instance.collect_fields(
+            schema_definition.query().name(),
+            std::iter::once(operation_definition.as_ref().selection_set()),
+        );
+
+        if let Some(field) = grouped_fields
+            .iter()
+            .map(|(_, fields)| fields[0])
+            .find(|field| !matches!(field.name(), "__schema" | "__type" | "__typename"))
+        {
+            return Err(Error::NonIntrospectionField { name: field.name() });
+        }
+
+        Ok(instance.execute_grouped_fields(&Object::Query, grouped_fields))
+    }
+
+    fn execute_grouped_fields(
+        &self,
+        object: &Object<'a, S>,
+        grouped_fields: GroupedFields<'a, E>,
+    ) -> Map<String, JsonValue> {
+        grouped_fields
+            .into_iter()
+            .map(|(response_key, fields)| {
+                let value = self.complete_value(self.resolve_field(object, fields[0]), &fields);
+                (response_key.to_owned(), value)
+            })
+            .collect()
+    }
+
+    fn complete_value(&self, resolved: Resolved<'a, S>, fields: &[&'a E::Field]) -> JsonValue {
+        match resolved {
+            Resolved::Leaf(value) => value,
+            Resolved::Object(object) => JsonValue::Object(self.execute_object(&object, fields)),
+            Resolved::List(objects) => JsonValue::Array(
+                objects
+                    .iter()
+                    .map(|object| JsonValue::Object(self.execute_object(object, fields)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn execute_object(
+        &self,
+        object: &Object<'a, S>,
+        fields: &[&'a E::Field],
+    ) -> Map<String, JsonValue> {
+        let grouped_fields = self.collect_fields(
+            object.typename(self.schema_definition),
+            fields.iter().filter_map(|field| field.selection_set()),
+        );
+        self.execute_grouped_fields(object, grouped_fields)
+    }
+
+    fn collect_fields(
+        &self,
+        object_type_name: &str,
+        selection_sets: impl Iterator<Item = &'a E::SelectionSet>,
+    ) -> GroupedFields<'a, E> {
+        let mut grouped_fields = Vec::new();
+        let mut visited_fragments = HashSet::new();
+        selection_sets.for_each(|selection_set| {
+            self.collect_fields_into(
+                object_type_name,
+                selection_set,
+                &mut grouped_fields,
+                &mut visited_fragments,
+            )
+        });
+        grouped_fields
+    }
+
+    fn collect_fields_into(
+        &self,
+        object_type_name: &str,
+        selection_set: &'a E::SelectionSet,
+        grouped_fields: &mut GroupedFields<'a, E>,
+        visited_fragments: &mut HashSet<&'a str>,
+    ) {
+        selection_set.iter().for_each(|selection| {
+            let selection = selection.as_ref();
+            if !self.should_include(selection.directives()) {
+                return;
+            }
+            match selection {
+                SelectionReference::Field(field) => {
+                    let response_key = field.response_name();
+                    match grouped_fields
+                        .iter_mut()
+                        .find(|(key, _)| *key == response_key)
+                    {
+                        Some((_, fields)) => fields.push(field),
+                        None => grouped_fields.push((response_key, vec![field])),
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    let fragment_name = fragment_spread.name();
+                    if !visited_fragments.insert(fragment_name) {
+                        return;
+                    }
+                    let Some(fragment_definition) = self
+                        .executable_document
+                        .fragment_definitions()
+                        .find(|fragment_definition| fragment_definition.name() == fragment_name)
+                    else {
+                        return;
+                    };
+                    if self.does_fragment_type_apply(
+                        object_type_name,
+                        fragment_definition.type_condition(),
+                    ) {
+                        self.collect_fields_into(
+                            object_type_name,
+                            fragment_definition.selection_set(),
+                            grouped_fields,
+                            visited_fragments,
+                        );
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    if inline_fragment
+                        .type_condition()
+                        .is_none_or(|type_condition| {
+                            self.does_fragment_type_apply(object_type_name, type_condition)
+                        })
+                    {
+                        self.collect_fields_into(
+                            object_type_name,
+                            inline_fragment.selection_set(),
+                            grouped_fields,
+                            visited_fragments,
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    fn does_fragment_type_apply(&self, object_type_name: &str, type_condition: &str) -> bool {
+        if object_type_name == type_condition {
+            return true;
+        }
+        match self.schema_definition.get_type_definition(type_condition) {
+            Some(TypeDefinitionReference::Union(utd)) => utd
+                .union_member_types()
+                .iter()
+                .any(|member_type| member_type.name() == object_type_name),
+            Some(TypeDefinitionReference::Interface(_)) => matches!(
+                self.schema_definition.get_type_definition(object_type_name),
+                Some(TypeDefinitionReference::Object(otd)) if otd
+                    .interface_implementations()
+                    .is_some_and(|interface_implementations| interface_implementations
+                        .iter()
+                        .any(|ii| ii.name() == type_condition))
+            ),
+            _ => false,
+        }
+    }
+
+    fn should_include(&self, directives: Option<&'a E::Directives<false>>) -> bool {
+        directives
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .all(|directive| match directive.name() {
+                "skip" => self.boolean_argument(directive.arguments(), "if") != Some(true),
+                "include" => self.boolean_argument(directive.arguments(), "if") != Some(false),
+                _ => true,
+            })
+    }
+
+    fn argument_value(
+        &self,
+        arguments: Option<&'a E::Arguments<false>>,
+        name: &str,
+    ) -> Option<&'a E::Value<false>> {
+        arguments
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .find(|argument| argument.name() == name)
+            .map(Argument::value)
+    }
+
+    fn variable_default_value(&self, variable: &str) -> Option<&'a E::Value<true>> {
+        self.operation_definition
+            .as_ref()
+            .variable_definitions()?
+            .iter()
+            .find(|variable_definition| variable_definition.variable() == variable)?
+            .default_value()
+    }
+
+    fn boolean_argument(
+        &self,
+        arguments: Option<&'a E::Arguments<false>>,
+        name: &str,
+    ) -> Option<bool> {
+        match self.argument_value(arguments, name)?.as_ref() {
+            ValueReference::Boolean(value) => Some(value),
+            ValueReference::Variable(variable) => match self.variable_values.get(variable.name()) {
+                Some(value) => value.as_ref().as_boolean().copied(),
+                None => self
+                    .variable_default_value(variable.name())
+                    .and_then(|value| value.as_ref().as_boolean().copied()),
+            },
+            _ => None,
+        }
+    }
+
+    fn string_argument(
+        &self,
+        arguments: Option<&'a E::Arguments<false>>,
+        name: &str,
+    ) -> Option<String> {
+        match self.argument_value(arguments, name)?.as_ref() {
+            ValueReference::String(value) => Some(value.to_owned()),
+            ValueReference::Variable(variable) => match self.variable_values.get(variable.name()) {
+                Some(value) => value.as_ref().as_string().map(|value| value.to_string()),
+                None => self
+                    .variable_default_value(variable.name())
+                    .and_then(|value| value.as_ref().as_string().map(|value| value.to_string())),
+            },
+            _ => None,
+        }
+    }
+
+    fn include_deprecated(&self, field: &'a E::Field) -> bool {
+        self.boolean_argument(field.arguments(), "includeDeprecated")
+            .unwrap_or(false)
+    }
+
+    /// Returns `None` if the element is not deprecated, otherwise the deprecation reason,
+    /// falling back to the default value of the `reason` argument of `@deprecated`
+    fn deprecation(
+        &self,
+        directives: Option<&'a <S as SchemaDefinition>::Directives>,
+    ) -> Option<Option<String>> {
+        let directive = directives?
+            .iter()
+            .find(|directive| directive.name() == "deprecated")?;
+        let reason = directive
+            .arguments()
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .find(|argument| argument.name() == "reason")
+            .and_then(|argument| argument.value().as_ref().as_string().map(|s| s.to_string()))
+            .or_else(|| {
+                self.schema_definition
+                    .get_directive_definition("deprecated")?
+                    .arguments_definition()?
+                    .iter()
+                    .find(|argument_definition| argument_definition.name() == "reason")?
+                    .default_value()?
+                    .as_ref()
+                    .as_string()
+                    .map(|s| s.to_string())
+            });
+        Some(reason)
+    }
+
+    fn is_visible(
+        &self,
+        include_deprecated: bool,
+        directives: Option<&'a <S as SchemaDefinition>::Directives>,
+    ) -> bool {
+        include_deprecated || self.deprecation(directives).is_none()
+    }
+
+    fn arguments(
+        &self,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+        field: &'a E::Field,
+    ) -> Resolved<'a, S> {
+        let include_deprecated = self.include_deprecated(field);
+        Resolved::List(
+            arguments_definition
+                .into_iter()
+                .flat_map(AsIter::iter)
+                .filter(|ivd| self.is_visible(include_deprecated, ivd.directives()))
+                .map(Object::InputValue)
+                .collect(),
+        )
+    }
+
+    fn resolve_field(&self, object: &Object<'a, S>, field: &'a E::Field) -> Resolved<'a, S> {
+        if field.name() == "__typename" {
+            return Resolved::Leaf(object.typename(self.schema_definition).into());
+        }
+
+        match object {
+            Object::Query => match field.name() {
+                "__schema" => Resolved::Object(Object::Schema),
+                "__type" => Resolved::optional_object(
+                    self.string_argument(field.arguments(), "name")
+                        .and_then(|name| self.schema_definition.get_type_definition(&name))
+                        .map(|tdr| Object::Type(TypeRef::Named(tdr))),
+                ),
+                _ => Resolved::null(),
+            },
+            Object::Schema => self.resolve_schema_field(field),
+            Object::Type(type_ref) => self.resolve_type_field(type_ref, field),
+            Object::Field(fd) => match field.name() {
+                "name" => Resolved::Leaf(fd.name().into()),
+                "description" => Resolved::Leaf(fd.description().into()),
+                "args" => self.arguments(fd.arguments_definition(), field),
+                "type" => Resolved::Object(Object::Type(TypeRef::Output {
+                    r#type: fd.r#type(),
+                    nullable: false,
+                })),
+                "isDeprecated" => {
+                    Resolved::Leaf(self.deprecation(fd.directives()).is_some().into())
+                }
+                "deprecationReason" => {
+                    Resolved::Leaf(self.deprecation(fd.directives()).flatten().into())
+                }
+                _ => Resolved::null(),
+            },
+            Object::InputValue(ivd) => match field.name() {
+                "name" => Resolved::Leaf(ivd.name().into()),
+                "description" => Resolved::Leaf(ivd.description().into()),
+                "type" => Resolved::Object(Object::Type(TypeRef::Input {
+                    r#type: ivd.r#type(),
+                    nullable: false,
+                })),
+                "defaultValue" => {
+                    Resolved::Leaf(ivd.default_value().map(ValuePrinter::to_string).into())
+                }
+                "isDeprecated" => {
+                    Resolved::Leaf(self.deprecation(ivd.directives()).is_some().into())
+                }
+                "deprecationReason" => {
+                    Resolved::Leaf(self.deprecation(ivd.directives()).flatten().into())
+                }
+                _ => Resolved::null(),
+            },
+            Object::EnumValue(evd) => match field.name() {
+                "name" => Resolved::Leaf(evd.name().into()),
+                "description" => Resolved::Leaf(evd.description().into()),
+                "isDeprecated" => {
+                    Resolved::Leaf(self.deprecation(evd.directives()).is_some().into())
+                }
+                "deprecationReason" => {
+                    Resolved::Leaf(self.deprecation(evd.directives()).flatten().into())
+                }
+                _ => Resolved::null(),
+            },
+            Object::Directive(dd) => match field.name() {
+                "name" => Resolved::Leaf(dd.name().into()),
+                "description" => Resolved::Leaf(dd.description().into()),
+                "locations" => Resolved::Leaf(
+                    dd.locations()
+                        .iter()
+                        .map(|location| JsonValue::from(location.as_ref()))
+                        .collect(),
+                ),
+                "args" => self.arguments(dd.arguments_definition(), field),
+                "isRepeatable" => Resolved::Leaf(dd.is_repeatable().into()),
+                _ => Resolved::null(),
+            },
+        }
+    }
+
+    fn resolve_schema_field(&self, field: &'a E::Field) -> Resolved<'a, S> {
+        let schema_definition = self.schema_definition;
+        match field.name() {
+            "description" => Resolved::Leaf(schema_definition.description().into()),
+            "types" => Resolved::List(
+                schema_definition
+                    .type_definitions()
+                    .map(|tdr| Object::Type(TypeRef::Named(tdr)))
+                    .collect(),
+            ),
+            "queryType" => Resolved::Object(Object::Type(TypeRef::Named(
+                TypeDefinitionReference::Object(schema_definition.query()),
+            ))),
+            "mutationType" => Resolved::optional_object(
+                schema_definition
+                    .mutation()
+                    .map(|otd| Object::Type(TypeRef::Named(TypeDefinitionReference::Object(otd)))),
+            ),
+            "subscriptionType" => Resolved::optional_object(
+                schema_definition
+                    .subscription()
+                    .map(|otd| Object::Type(TypeRef::Named(TypeDefinitionReference::Object(otd)))),
+            ),
+            "directives" => Resolved::List(
+                schema_definition
+                    .directive_definitions()
+                    .map(Object::Directive)
+                    .collect(),
+            ),
+            _ => Resolved::null(),
+        }
+    }
+
+    fn resolve_type_field(
+        &self,
+        type_ref: &TypeRef<'a, S>,
+        field: &'a E::Field,
+    ) -> Resolved<'a, S> {
+        let Some(wrapped_type) = type_ref.unwrap_one(self.schema_definition) else {
+            return Resolved::null();
+        };
+
+        let (kind, inner) = match wrapped_type {
+            WrappedType::Named(tdr) => return self.resolve_named_type_field(tdr, field),
+            WrappedType::List(inner) => ("LIST", inner),
+            WrappedType::NonNull(inner) => ("NON_NULL", inner),
+        };
+
+        match field.name() {
+            "kind" => Resolved::Leaf(kind.into()),
+            "ofType" => Resolved::Object(Object::Type(inner)),
+            _ => Resolved::null(),
+        }
+    }
+
+    fn resolve_named_type_field(
+        &self,
+        tdr: TypeDefinitionReference<'a, S::TypeDefinition>,
+        field: &'a E::Field,
+    ) -> Resolved<'a, S> {
+        match field.name() {
+            "kind" => Resolved::Leaf(tdr.kind().into()),
+            "name" => Resolved::Leaf(tdr.name().into()),
+            "description" => Resolved::Leaf(tdr.description().into()),
+            "fields" => match tdr {
+                TypeDefinitionReference::Object(_) | TypeDefinitionReference::Interface(_) => {
+                    let include_deprecated = self.include_deprecated(field);
+                    Resolved::List(
+                        tdr.fields_definition()
+                            .into_iter()
+                            .flat_map(AsIter::iter)
+                            .filter(|fd| {
+                                !fd.is_builtin()
+                                    && self.is_visible(include_deprecated, fd.directives())
+                            })
+                            .map(Object::Field)
+                            .collect(),
+                    )
+                }
+                _ => Resolved::null(),
+            },
+            "interfaces" => {
+                let interface_implementations = match tdr {
+                    TypeDefinitionReference::Object(otd) => otd.interface_implementations(),
+                    TypeDefinitionReference::Interface(itd) => itd.interface_implementations(),
+                    _ => return Resolved::null(),
+                };
+                Resolved::List(
+                    interface_implementations
+                        .into_iter()
+                        .flat_map(AsIter::iter)
+                        .map(|ii| {
+                            Object::Type(TypeRef::Named(TypeDefinitionReference::Interface(
+                                ii.interface(self.schema_definition),
+                            )))
+                        })
+                        .collect(),
+                )
+            }
+            "possibleTypes" => match tdr {
+                TypeDefinitionReference::Union(utd) => Resolved::List(
+                    utd.union_member_types()
+                        .iter()
+                        .map(|member_type| {
+                            Object::Type(TypeRef::Named(TypeDefinitionReference::Object(
+                                member_type.member_type(self.schema_definition),
+                            )))
+                        })
+                        .collect(),
+                ),
+                TypeDefinitionReference::Interface(itd) => Resolved::List(
+                    self.schema_definition
+                        .get_interface_implementors(itd)
+                        .map(|otd| {
+                            Object::Type(TypeRef::Named(TypeDefinitionReference::Object(otd)))
+                        })
+                        .collect(),
+                ),
+                _ => Resolved::null(),
+            },
+            "enumValues" => match tdr {
+                TypeDefinitionReference::Enum(etd) => {
+                    let include_deprecated = self.include_deprecated(field);
+                    Resolved::List(
+                        etd.enum_value_definitions()
+                            .iter()
+                            .filter(|evd| self.is_visible(include_deprecated, evd.directives()))
+                            .map(Object::EnumValue)
+                            .collect(),
+                    )
+                }
+                _ => Resolved::null(),
+            },
+            "inputFields" => match tdr {
+                TypeDefinitionReference::InputObject(iotd) => {
+                    let include_deprecated = self.include_deprecated(field);
+                    Resolved::List(
+                        iotd.input_field_definitions()
+                            .iter()
+                            .filter(|ivd| self.is_visible(include_deprecated, ivd.directives()))
+                            .map(Object::InputValue)
+                            .collect(),
+                    )
+                }
+                _ => Resolved::null(),
+            },
+            "specifiedByURL" => match tdr {
+                TypeDefinitionReference::CustomScalar(cstd) => Resolved::Leaf(
+                    cstd.directives()
+                        .into_iter()
+                        .flat_map(AsIter::iter)
+                        .find(|directive| directive.name() == "specifiedBy")
+                        .and_then(|directive| {
+                            directive
+                                .arguments()
+                                .into_iter()
+                                .flat_map(AsIter::iter)
+                                .find(|argument| argument.name() == "url")
+                        })
+                        .and_then(|argument| {
+                            argument.value().as_ref().as_string().map(|s| s.to_string())
+                        })
+                        .into(),
+                ),
+                _ => Resolved::null(),
+            },
+            "isOneOf" => match tdr {
+                TypeDefinitionReference::InputObject(iotd) => Resolved::Leaf(
+                    iotd.directives()
+                        .into_iter()
+                        .flat_map(AsIter::iter)
+                        .any(|directive| directive.name() == "oneOf")
+                        .into(),
+                ),
+                _ => Resolved::null(),
+            },
+            _ => Resolved::null(),
+        }
+    }
+}
+
+enum Object<'a, S: SchemaDefinition> {
+    Query,
+    Schema,
+    Type(TypeRef<'a, S>),
+    Field(&'a S::FieldDefinition),
+    InputValue(&'a S::InputValueDefinition),
+    EnumValue(&'a S::EnumValueDefinition),
+    Directive(&'a S::DirectiveDefinition),
+}
+
+impl<'a, S: SchemaDefinition> Object<'a, S> {
+    fn typename(&self, schema_definition: &'a S) -> &'a str {
+        match self {
+            Self::Query => schema_definition.query().name(),
+            Self::Schema => "__Schema",
+            Self::Type(_) => "__Type",
+            Self::Field(_) => "__Field",
+            Self::InputValue(_) => "__InputValue",
+            Self::EnumValue(_) => "__EnumValue",
+            Self::Directive(_) => "__Directive",
+        }
+    }
+}
+
+enum Resolved<'a, S: SchemaDefinition> {
+    Leaf(JsonValue),
+    Object(Object<'a, S>),
+    List(Vec<Object<'a, S>>),
+}
+
+impl<'a, S: SchemaDefinition> Resolved<'a, S> {
+    fn null() -> Self {
+        Self::Leaf(JsonValue::Null)
+    }
+
+    fn optional_object(object: Option<Object<'a, S>>) -> Self {
+        object.map_or_else(Self::null, Self::Object)
+    }
+}
+
+/// A reference to a type as seen by `__Type`. Wrapping types are represented by
+/// the input or output type they originate from, with `nullable` set once the
+/// outermost `NON_NULL` wrapper has been peeled off.
+enum TypeRef<'a, S: SchemaDefinition> {
+    Named(TypeDefinitionReference<'a, S::TypeDefinition>),
+    Output {
+        r#type: &'a S::OutputType,
+        nullable: bool,
+    },
+    Input {
+        r#type: &'a S::InputType,
+        nullable: bool,
+    },
+}
+
+enum WrappedType<'a, S: SchemaDefinition> {
+    Named(TypeDefinitionReference<'a, S::TypeDefinition>),
+    List(TypeRef<'a, S>),
+    NonNull(TypeRef<'a, S>),
+}
+
+impl<'a, S: SchemaDefinition> TypeRef<'a, S> {
+    fn unwrap_one(&self, schema_definition: &'a S) -> Option<WrappedType<'a, S>> {
+        match *self {
+            Self::Named(tdr) => Some(WrappedType::Named(tdr)),
+            Self::Output { r#type, nullable } => {
+                let shallow = r#type.as_shallow_ref();
+                if shallow.is_required() && !nullable {
+                    return Some(WrappedType::NonNull(Self::Output {
+                        r#type,
+                        nullable: true,
+                    }));
+                }
+                match shallow {
+                    ShallowOutputTypeReference::Base(name, _) => schema_definition
+                        .get_type_definition(name)
+                        .map(WrappedType::Named),
+                    ShallowOutputTypeReference::List(inner, _) => {
+                        Some(WrappedType::List(Self::Output {
+                            r#type: inner,
+                            nullable: false,
+                        }))
+                    }
+                }
+            }
+            Self::Input { r#type, nullable } => {
+                let shallow = r#type.as_shallow_ref();
+                if shallow.is_required() && !nullable {
+                    return Some(WrappedType::NonNull(Self::Input {
+                        r#type,
+                        nullable: true,
+                    }));
+                }
+                match shallow {
+                    ShallowInputTypeReference::Base(name, _) => schema_definition
+                        .get_type_definition(name)
+                        .map(WrappedType::Named),
+                    ShallowInputTypeReference::List(inner, _) => {
+                        Some(WrappedType::List(Self::Input {
+                            r#type: inner,
+                            nullable: false,
+                        }))
+                    }
+                }
+            }
+        }
+    }
+}
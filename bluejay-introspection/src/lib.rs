@@ -0,0 +1,5 @@
+mod error;
+mod executor;
+
+pub use error::Error;
+pub use executor::Executor;
@@ -0,0 +1,131 @@
+use bluejay_introspection::{Error, Executor};
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use serde_json::{json, Map, Value};
+
+fn with_schema(f: impl FnOnce(SchemaDefinition)) {
+    let s = std::fs::read_to_string("tests/test_data/schema.graphql").unwrap();
+    let definition_document = DefinitionDocument::parse(s.as_str())
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    f(schema_definition)
+}
+
+fn execute(
+    schema_definition: &SchemaDefinition,
+    query: &str,
+    variables: Value,
+) -> Result<Map<String, Value>, String> {
+    let executable_document = ExecutableDocument::parse(query)
+        .result
+        .unwrap_or_else(|_| panic!("Document had parse errors: {query}"));
+    let variables = variables.as_object().unwrap().clone();
+    Executor::execute(&executable_document, schema_definition, None, &variables)
+        .map_err(|error: Error| error.message().into_owned())
+}
+
+#[test]
+fn test_full_introspection_query() {
+    with_schema(|schema_definition| {
+        let query = std::fs::read_to_string("tests/test_data/introspection_query.graphql").unwrap();
+        let result = execute(&schema_definition, &query, json!({})).unwrap();
+        insta::assert_snapshot!(serde_json::to_string_pretty(&result).unwrap());
+    });
+}
+
+#[test]
+fn test_type_with_variable_and_aliases() {
+    with_schema(|schema_definition| {
+        let result = execute(
+            &schema_definition,
+            r#"
+            query($name: String!, $includeDeprecated: Boolean = false) {
+              __typename
+              t: __type(name: $name) {
+                name
+                kind
+                all: fields(includeDeprecated: true) { name isDeprecated deprecationReason }
+                current: fields(includeDeprecated: $includeDeprecated) { name }
+              }
+              missing: __type(name: "Missing") { name }
+            }
+            "#,
+            json!({ "name": "Person" }),
+        )
+        .unwrap();
+        assert_eq!(
+            json!({
+                "__typename": "Query",
+                "t": {
+                    "name": "Person",
+                    "kind": "OBJECT",
+                    "all": [
+                        { "name": "id", "isDeprecated": false, "deprecationReason": null },
+                        { "name": "name", "isDeprecated": false, "deprecationReason": null },
+                        { "name": "nickname", "isDeprecated": true, "deprecationReason": "Use `name`" },
+                        { "name": "friends", "isDeprecated": false, "deprecationReason": null },
+                        { "name": "birthday", "isDeprecated": false, "deprecationReason": null },
+                    ],
+                    "current": [
+                        { "name": "id" },
+                        { "name": "name" },
+                        { "name": "friends" },
+                        { "name": "birthday" },
+                    ],
+                },
+                "missing": null,
+            }),
+            Value::Object(result),
+        );
+    });
+}
+
+#[test]
+fn test_is_one_of_and_skip_include() {
+    with_schema(|schema_definition| {
+        let result = execute(
+            &schema_definition,
+            r#"
+            query($skip: Boolean!) {
+              petFilter: __type(name: "PetFilter") { isOneOf __typename @skip(if: $skip) }
+              personFilter: __type(name: "PersonFilter") {
+                isOneOf
+                inputFields { name }
+              }
+              person: __type(name: "Person") {
+                isOneOf
+                ... on __Type @include(if: false) { name }
+              }
+            }
+            "#,
+            json!({ "skip": true }),
+        )
+        .unwrap();
+        assert_eq!(
+            json!({
+                "petFilter": { "isOneOf": true },
+                "personFilter": { "isOneOf": false, "inputFields": [{ "name": "name" }] },
+                "person": { "isOneOf": null },
+            }),
+            Value::Object(result),
+        );
+    });
+}
+
+#[test]
+fn test_non_introspection_field() {
+    with_schema(|schema_definition| {
+        let error = execute(
+            &schema_definition,
+            "{ __typename search(term: \"x\") { __typename } }",
+            json!({}),
+        )
+        .unwrap_err();
+        assert_eq!("Field `search` is not an introspection field", error);
+    });
+}
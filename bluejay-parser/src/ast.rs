@@ -22,7 +22,7 @@ pub use directives::{ConstDirectives, Directives, VariableDirectives};
 use from_tokens::FromTokens;
 use is_match::IsMatch;
 use operation_type::OperationType;
-pub use parse::{Parse, ParseDetails, ParseOptions};
+pub use parse::{Parse, ParseDetails, ParseOptions, ParseResilient, ResilientParseDetails};
 use parse_error::ParseError;
 use tokens::{LexerTokens, Tokens};
 use try_from_tokens::TryFromTokens;
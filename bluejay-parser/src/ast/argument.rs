@@ -1,6 +1,7 @@
 use crate::ast::{DepthLimiter, FromTokens, ParseError, Tokens, Value};
 use crate::lexical_token::{Name, PunctuatorType};
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct Argument<'a, const CONST: bool> {
@@ -29,13 +30,31 @@ impl<'a, const CONST: bool> FromTokens<'a> for Argument<'a, CONST> {
         depth_limiter: DepthLimiter,
     ) -> Result<Self, ParseError> {
         let name = tokens.expect_name()?;
-        tokens.expect_punctuator(PunctuatorType::Colon)?;
-        let value = Value::from_tokens(tokens, depth_limiter.bump()?)?;
+        let colon_span = tokens.expect_punctuator(PunctuatorType::Colon)?;
+        let value = if tokens.is_recovering() && Self::is_missing_value(tokens) {
+            let span = colon_span.empty_at_end();
+            tokens.record_error(ParseError::ExpectedValue { span });
+            Value::Missing(span)
+        } else {
+            Value::from_tokens(tokens, depth_limiter.bump()?)?
+        };
         let span = name.span().merge(value.span());
         Ok(Self { name, value, span })
     }
 }
 
+impl<'a, const CONST: bool> Argument<'a, CONST> {
+    /// Whether the next token ends the arguments or starts the next argument, rather than
+    /// being the value of the current argument
+    fn is_missing_value(tokens: &mut impl Tokens<'a>) -> bool {
+        tokens.is_at_eof()
+            || tokens.peek_punctuator_matches(0, PunctuatorType::CloseRoundBracket)
+            || tokens.peek_punctuator_matches(0, PunctuatorType::CloseBrace)
+            || (tokens.peek_name(0).is_some()
+                && tokens.peek_punctuator_matches(1, PunctuatorType::Colon))
+    }
+}
+
 impl<'a, const CONST: bool> bluejay_core::Argument<CONST> for Argument<'a, CONST> {
     type Value = Value<'a, CONST>;
 
@@ -46,6 +65,10 @@ impl<'a, const CONST: bool> bluejay_core::Argument<CONST> for Argument<'a, CONST
     fn value(&self) -> &Value<'a, CONST> {
         &self.value
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl<const CONST: bool> HasSpan for Argument<'_, CONST> {
@@ -20,6 +20,23 @@ impl<'a, const CONST: bool> FromTokens<'a> for Arguments<'a, CONST> {
         let open_span = tokens.expect_punctuator(PunctuatorType::OpenRoundBracket)?;
         let mut arguments: Vec<Argument<CONST>> = Vec::new();
         let close_span = loop {
+            if tokens.is_recovering() && arguments.is_empty() {
+                if let Some(close_span) =
+                    tokens.next_if_punctuator(PunctuatorType::CloseRoundBracket)
+                {
+                    tokens.record_error(ParseError::ExpectedName { span: close_span });
+                    break close_span;
+                }
+            }
+            if tokens.is_recovering() && tokens.peek_name(0).is_none() && !arguments.is_empty() {
+                // the arguments are not terminated, so end them after the last argument
+                let last_span = *arguments.last().unwrap().span();
+                tokens.record_error(ParseError::ExpectedIdentifier {
+                    span: last_span.empty_at_end(),
+                    value: PunctuatorType::CloseRoundBracket.to_string(),
+                });
+                break last_span;
+            }
             arguments.push(Argument::from_tokens(tokens, depth_limiter.bump()?)?);
             if let Some(close_span) = tokens.next_if_punctuator(PunctuatorType::CloseRoundBracket) {
                 break close_span;
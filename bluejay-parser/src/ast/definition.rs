@@ -5,6 +5,7 @@ mod definition_document;
 mod directive;
 mod directive_definition;
 mod enum_type_definition;
+mod enum_type_extension;
 mod enum_value_definition;
 mod enum_value_definitions;
 mod explicit_schema_definition;
@@ -12,18 +13,25 @@ mod field_definition;
 mod fields_definition;
 mod input_fields_definition;
 mod input_object_type_definition;
+mod input_object_type_extension;
 mod input_type;
 mod input_value_definition;
 mod interface_implementation;
 mod interface_implementations;
 mod interface_type_definition;
+mod interface_type_extension;
 mod object_type_definition;
+mod object_type_extension;
 mod output_type;
+mod scalar_type_extension;
 mod schema_definition;
+mod schema_extension;
 mod type_definition;
+mod type_extension;
 mod union_member_type;
 mod union_member_types;
 mod union_type_definition;
+mod union_type_extension;
 
 pub use arguments_definition::ArgumentsDefinition;
 pub use context::{Context, DefaultContext};
@@ -32,6 +40,7 @@ pub use definition_document::DefinitionDocument;
 pub use directive::{Directive, Directives};
 pub use directive_definition::DirectiveDefinition;
 pub use enum_type_definition::EnumTypeDefinition;
+pub use enum_type_extension::EnumTypeExtension;
 pub use enum_value_definition::EnumValueDefinition;
 pub use enum_value_definitions::EnumValueDefinitions;
 pub use explicit_schema_definition::{ExplicitSchemaDefinition, RootOperationTypeDefinition};
@@ -39,15 +48,22 @@ pub use field_definition::FieldDefinition;
 pub use fields_definition::FieldsDefinition;
 pub use input_fields_definition::InputFieldsDefinition;
 pub use input_object_type_definition::InputObjectTypeDefinition;
+pub use input_object_type_extension::InputObjectTypeExtension;
 pub use input_type::{BaseInputType, InputType};
 pub use input_value_definition::InputValueDefinition;
 pub use interface_implementation::InterfaceImplementation;
 pub use interface_implementations::InterfaceImplementations;
 pub use interface_type_definition::InterfaceTypeDefinition;
+pub use interface_type_extension::InterfaceTypeExtension;
 pub use object_type_definition::ObjectTypeDefinition;
+pub use object_type_extension::ObjectTypeExtension;
 pub use output_type::{BaseOutputType, OutputType};
+pub use scalar_type_extension::ScalarTypeExtension;
 pub use schema_definition::SchemaDefinition;
+pub use schema_extension::SchemaExtension;
 pub use type_definition::TypeDefinition;
+pub use type_extension::TypeExtension;
 pub use union_member_type::UnionMemberType;
 pub use union_member_types::UnionMemberTypes;
 pub use union_type_definition::UnionTypeDefinition;
+pub use union_type_extension::UnionTypeExtension;
@@ -56,3 +56,11 @@ impl<'a, C: Context> IsMatch<'a> for ArgumentsDefinition<'a, C> {
         tokens.peek_punctuator_matches(0, PunctuatorType::OpenRoundBracket)
     }
 }
+
+impl<C: Context> ArgumentsDefinition<'_, C> {
+    pub(crate) fn clear_locations(&mut self) {
+        self.argument_definitions
+            .iter_mut()
+            .for_each(|definition| definition.clear_location());
+    }
+}
@@ -1,10 +1,11 @@
 use crate::ast::{
-    definition::{Context, Directives},
+    definition::{Context, Directives, ScalarTypeExtension},
     ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens,
 };
 use crate::lexical_token::{Name, StringValue};
-use crate::Span;
+use crate::{HasSpan, Span};
 use bluejay_core::definition::{HasDirectives, ScalarTypeDefinition as CoreScalarTypeDefinition};
+use bluejay_core::Location;
 use bluejay_core::Value;
 use std::borrow::Cow;
 use std::marker::PhantomData;
@@ -22,6 +23,14 @@ impl<'a, C: Context> CustomScalarTypeDefinition<'a, C> {
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
+
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    pub(crate) fn extend(&mut self, extension: ScalarTypeExtension<'a, C>) {
+        Directives::merge(&mut self.directives, Some(extension.into_directives()));
+    }
 }
 
 impl<C: Context> CoreScalarTypeDefinition for CustomScalarTypeDefinition<'_, C> {
@@ -39,6 +48,10 @@ impl<C: Context> CoreScalarTypeDefinition for CustomScalarTypeDefinition<'_, C>
     ) -> Result<(), Cow<'static, str>> {
         C::coerce_custom_scalar_input(self, value)
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl<C: Context> CustomScalarTypeDefinition<'_, C> {
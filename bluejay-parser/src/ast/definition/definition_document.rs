@@ -3,9 +3,12 @@ use crate::ast::definition::{
     CustomScalarTypeDefinition, DefaultContext, DirectiveDefinition, Directives,
     EnumTypeDefinition, ExplicitSchemaDefinition, FieldsDefinition, InputObjectTypeDefinition,
     InputValueDefinition, InterfaceImplementations, InterfaceTypeDefinition, ObjectTypeDefinition,
-    SchemaDefinition, TypeDefinition, UnionTypeDefinition,
+    SchemaDefinition, SchemaExtension, TypeDefinition, TypeExtension, UnionTypeDefinition,
+};
+use crate::ast::{
+    DepthLimiter, FromTokens, Parse, ParseDetails, ParseError, ParseResilient,
+    ResilientParseDetails, Tokens,
 };
-use crate::ast::{DepthLimiter, FromTokens, Parse, ParseDetails, ParseError, Tokens};
 use bluejay_core::definition::{prelude::*, HasDirectives};
 use bluejay_core::{
     AsIter, BuiltinScalarDefinition, Directive as _, IntoEnumIterator, OperationType,
@@ -21,6 +24,10 @@ pub struct DefinitionDocument<'a, C: Context = DefaultContext> {
     schema_definitions: Vec<ExplicitSchemaDefinition<'a, C>>,
     directive_definitions: Vec<DirectiveDefinition<'a, C>>,
     type_definitions: Vec<TypeDefinition<'a, C>>,
+    /// Extensions are merged into their definitions after parsing, leaving behind
+    /// only the extensions (or parts thereof) that could not be applied
+    schema_extensions: Vec<SchemaExtension<'a, C>>,
+    type_extensions: Vec<TypeExtension<'a, C>>,
 }
 
 #[derive(Debug)]
@@ -39,16 +46,94 @@ type ExplicitSchemaDefinitionWithRootTypes<'a, C> = (
 
 impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
     fn parse_from_tokens(mut tokens: impl Tokens<'a>, max_depth: usize) -> ParseDetails<Self> {
+        let (mut instance, errors) = Self::parse_definitions(&mut tokens, max_depth);
+
+        let token_count = tokens.token_count();
+        let lex_errors = tokens.into_errors();
+
+        let errors = if lex_errors.is_empty() {
+            if errors.is_empty() && instance.is_empty() {
+                vec![ParseError::EmptyDocument.into()]
+            } else {
+                errors.into_iter().map(Into::into).collect()
+            }
+        } else {
+            lex_errors.into_iter().map(Into::into).collect()
+        };
+
+        let result = if errors.is_empty() {
+            instance.complete();
+            Ok(instance)
+        } else {
+            Err(errors)
+        };
+
+        ParseDetails::new(result, token_count)
+    }
+}
+
+impl<'a, C: Context> ParseResilient<'a> for DefinitionDocument<'a, C> {
+    fn parse_resilient_from_tokens(
+        mut tokens: impl Tokens<'a>,
+        max_depth: usize,
+    ) -> ResilientParseDetails<Self> {
+        let (mut instance, mut errors) = Self::parse_definitions(&mut tokens, max_depth);
+
+        if errors.is_empty() && instance.is_empty() {
+            errors.push(ParseError::EmptyDocument);
+        }
+        instance.complete();
+
+        let token_count = tokens.token_count();
+        let errors = tokens
+            .into_errors()
+            .into_iter()
+            .map(Into::into)
+            .chain(errors.into_iter().map(Into::into))
+            .collect();
+
+        ResilientParseDetails::new(instance, errors, token_count)
+    }
+}
+
+impl<'a, C: Context> DefinitionDocument<'a, C> {
+    fn new() -> Self {
+        let mut type_definitions = Vec::with_capacity(64);
+        type_definitions.extend([
+            ObjectTypeDefinition::__schema().into(),
+            ObjectTypeDefinition::__type().into(),
+            ObjectTypeDefinition::__field().into(),
+            ObjectTypeDefinition::__input_value().into(),
+            ObjectTypeDefinition::__enum_value().into(),
+            ObjectTypeDefinition::__directive().into(),
+            EnumTypeDefinition::__type_kind().into(),
+            EnumTypeDefinition::__directive_location().into(),
+        ]);
+        Self {
+            schema_definitions: Vec::new(),
+            directive_definitions: Vec::with_capacity(8),
+            type_definitions,
+            schema_extensions: Vec::new(),
+            type_extensions: Vec::new(),
+        }
+    }
+
+    /// Parses definitions until the end of the document, skipping the tokens following a
+    /// definition that could not be parsed until the next definition keyword
+    fn parse_definitions(
+        tokens: &mut impl Tokens<'a>,
+        max_depth: usize,
+    ) -> (Self, Vec<ParseError>) {
         let mut instance: Self = Self::new();
         let mut errors = Vec::new();
         let mut last_pass_had_error = false;
 
         loop {
-            match Self::next_definition_identifier(&mut tokens) {
+            match Self::next_definition_identifier(tokens) {
                 Some(CustomScalarTypeDefinition::<C>::SCALAR_IDENTIFIER) => {
                     Self::parse_definition::<_, CustomScalarTypeDefinition<C>>(
                         &mut instance.type_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -57,7 +142,7 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(ObjectTypeDefinition::<C>::TYPE_IDENTIFIER) => {
                     Self::parse_definition::<_, ObjectTypeDefinition<C>>(
                         &mut instance.type_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -66,7 +151,7 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(InputObjectTypeDefinition::<C>::INPUT_IDENTIFIER) => {
                     Self::parse_definition::<_, InputObjectTypeDefinition<C>>(
                         &mut instance.type_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -75,7 +160,7 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(EnumTypeDefinition::<C>::ENUM_IDENTIFIER) => {
                     Self::parse_definition::<_, EnumTypeDefinition<C>>(
                         &mut instance.type_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -84,7 +169,7 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(UnionTypeDefinition::<C>::UNION_IDENTIFIER) => {
                     Self::parse_definition::<_, UnionTypeDefinition<C>>(
                         &mut instance.type_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -93,7 +178,7 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(InterfaceTypeDefinition::<C>::INTERFACE_IDENTIFIER) => {
                     Self::parse_definition::<_, InterfaceTypeDefinition<C>>(
                         &mut instance.type_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -102,7 +187,7 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(ExplicitSchemaDefinition::<C>::SCHEMA_IDENTIFIER) => {
                     Self::parse_definition::<_, ExplicitSchemaDefinition<C>>(
                         &mut instance.schema_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
@@ -111,12 +196,31 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
                 Some(DirectiveDefinition::<C>::DIRECTIVE_IDENTIFIER) => {
                     Self::parse_definition::<_, DirectiveDefinition<C>>(
                         &mut instance.directive_definitions,
-                        &mut tokens,
+                        tokens,
                         &mut errors,
                         &mut last_pass_had_error,
                         max_depth,
                     )
                 }
+                Some(TypeExtension::<C>::EXTEND_IDENTIFIER) => {
+                    if tokens.peek_name_matches(1, SchemaExtension::<C>::SCHEMA_IDENTIFIER) {
+                        Self::parse_definition::<_, SchemaExtension<C>>(
+                            &mut instance.schema_extensions,
+                            tokens,
+                            &mut errors,
+                            &mut last_pass_had_error,
+                            max_depth,
+                        )
+                    } else {
+                        Self::parse_definition::<_, TypeExtension<C>>(
+                            &mut instance.type_extensions,
+                            tokens,
+                            &mut errors,
+                            &mut last_pass_had_error,
+                            max_depth,
+                        )
+                    }
+                }
                 _ => {
                     if let Some(token) = tokens.next() {
                         if !last_pass_had_error {
@@ -130,50 +234,16 @@ impl<'a, C: Context> Parse<'a> for DefinitionDocument<'a, C> {
             }
         }
 
-        let token_count = tokens.token_count();
-        let lex_errors = tokens.into_errors();
-
-        let errors = if lex_errors.is_empty() {
-            if errors.is_empty() && instance.is_empty() {
-                vec![ParseError::EmptyDocument.into()]
-            } else {
-                errors.into_iter().map(Into::into).collect()
-            }
-        } else {
-            lex_errors.into_iter().map(Into::into).collect()
-        };
-
-        let result = if errors.is_empty() {
-            instance.insert_builtin_scalar_definitions();
-            instance.insert_builtin_directive_definitions();
-            instance.add_query_root_fields();
-            Ok(instance)
-        } else {
-            Err(errors)
-        };
-
-        ParseDetails::new(result, token_count)
+        (instance, errors)
     }
-}
 
-impl<'a, C: Context> DefinitionDocument<'a, C> {
-    fn new() -> Self {
-        let mut type_definitions = Vec::with_capacity(64);
-        type_definitions.extend([
-            ObjectTypeDefinition::__schema().into(),
-            ObjectTypeDefinition::__type().into(),
-            ObjectTypeDefinition::__field().into(),
-            ObjectTypeDefinition::__input_value().into(),
-            ObjectTypeDefinition::__enum_value().into(),
-            ObjectTypeDefinition::__directive().into(),
-            EnumTypeDefinition::__type_kind().into(),
-            EnumTypeDefinition::__directive_location().into(),
-        ]);
-        Self {
-            schema_definitions: Vec::new(),
-            directive_definitions: Vec::with_capacity(8),
-            type_definitions,
-        }
+    /// Adds the builtin definitions and applies extensions
+    fn complete(&mut self) {
+        self.insert_builtin_scalar_definitions();
+        self.insert_builtin_directive_definitions();
+        self.apply_schema_extensions();
+        self.apply_type_extensions();
+        self.add_query_root_fields();
     }
 
     fn parse_definition<'b, S, T: FromTokens<'b> + Into<S>>(
@@ -183,7 +253,9 @@ impl<'a, C: Context> DefinitionDocument<'a, C> {
         last_pass_had_error: &mut bool,
         max_depth: usize,
     ) {
-        match T::from_tokens(tokens, DepthLimiter::new(max_depth)) {
+        let definition = T::from_tokens(tokens, DepthLimiter::new(max_depth));
+        errors.extend(tokens.take_recorded_errors());
+        match definition {
             Ok(definition) => {
                 definitions.push(definition.into());
                 *last_pass_had_error = false;
@@ -234,6 +306,46 @@ impl<'a, C: Context> DefinitionDocument<'a, C> {
         );
     }
 
+    /// Merges schema extensions into the explicit schema definition. Extensions are left
+    /// unapplied when there is not exactly one explicit schema definition.
+    fn apply_schema_extensions(&mut self) {
+        if let [schema_definition] = self.schema_definitions.as_mut_slice() {
+            std::mem::take(&mut self.schema_extensions)
+                .into_iter()
+                .for_each(|schema_extension| schema_definition.extend(schema_extension));
+        }
+    }
+
+    /// Merges type extensions into the type definitions they extend, keeping any extensions
+    /// that could not be fully applied so that they can be reported as errors when
+    /// building the schema definition
+    fn apply_type_extensions(&mut self) {
+        let type_extensions = std::mem::take(&mut self.type_extensions);
+
+        let type_definition_indices: Vec<Option<usize>> = {
+            let mut indexed: HashMap<&str, usize> = HashMap::new();
+            self.type_definitions
+                .iter()
+                .enumerate()
+                .for_each(|(idx, td)| {
+                    indexed.entry(td.name()).or_insert(idx);
+                });
+            type_extensions
+                .iter()
+                .map(|type_extension| indexed.get(type_extension.name().as_ref()).copied())
+                .collect()
+        };
+
+        self.type_extensions = type_extensions
+            .into_iter()
+            .zip(type_definition_indices)
+            .filter_map(|(type_extension, idx)| match idx {
+                Some(idx) => self.type_definitions[idx].extend(type_extension),
+                None => Some(type_extension),
+            })
+            .collect();
+    }
+
     fn add_query_root_fields(&mut self) {
         let explicit_query_roots: HashSet<&str> = HashSet::from_iter(
             self.schema_definitions
@@ -261,6 +373,8 @@ impl<'a, C: Context> DefinitionDocument<'a, C> {
 
     fn is_empty(&self) -> bool {
         self.definition_count() == 0
+            && self.schema_extensions.is_empty()
+            && self.type_extensions.is_empty()
     }
 
     fn next_definition_identifier(tokens: &mut impl Tokens<'a>) -> Option<&str> {
@@ -343,6 +457,116 @@ impl<'a, C: Context> DefinitionDocument<'a, C> {
         indexed
     }
 
+    fn unapplied_extension_errors(
+        &'a self,
+        indexed_type_definitions: &BTreeMap<&str, &'a TypeDefinition<'a, C>>,
+        errors: &mut Vec<DefinitionDocumentError<'a, C>>,
+    ) {
+        if self.schema_definitions.is_empty() {
+            errors.extend(self.schema_extensions.iter().map(|extension| {
+                DefinitionDocumentError::SchemaExtensionWithoutSchemaDefinition { extension }
+            }));
+        }
+
+        self.type_extensions.iter().for_each(|extension| {
+            let name = extension.name();
+            match indexed_type_definitions.get(name.as_ref()) {
+                None => errors.push(DefinitionDocumentError::ExtendedTypeDoesNotExist { name }),
+                Some(definition) if definition.as_ref().is_builtin() => {
+                    errors.push(DefinitionDocumentError::CannotExtendBuiltinType { name })
+                }
+                Some(definition) if !definition.matches_extension_kind(extension) => {
+                    errors.push(DefinitionDocumentError::ExtendedTypeKindMismatch {
+                        extension,
+                        definition,
+                    })
+                }
+                Some(_) => Self::duplicate_extension_definition_errors(extension, errors),
+            }
+        });
+    }
+
+    fn duplicate_extension_definition_errors(
+        extension: &'a TypeExtension<'a, C>,
+        errors: &mut Vec<DefinitionDocumentError<'a, C>>,
+    ) {
+        let type_name = extension.name();
+        let (interface_implementations, fields_definition) = match extension {
+            TypeExtension::Object(ote) => {
+                (ote.interface_implementations(), ote.fields_definition())
+            }
+            TypeExtension::Interface(ite) => {
+                (ite.interface_implementations(), ite.fields_definition())
+            }
+            TypeExtension::Enum(ete) => {
+                errors.extend(ete.enum_value_definitions().into_iter().flat_map(
+                    |enum_value_definitions| {
+                        enum_value_definitions.iter().map(|enum_value_definition| {
+                            DefinitionDocumentError::DuplicateEnumValueDefinitionFromExtension {
+                                type_name,
+                                name: enum_value_definition.name_token(),
+                            }
+                        })
+                    },
+                ));
+                (None, None)
+            }
+            TypeExtension::InputObject(iote) => {
+                errors.extend(iote.input_fields_definition().into_iter().flat_map(
+                    |input_fields_definition| {
+                        input_fields_definition.iter().map(|input_value_definition| {
+                            DefinitionDocumentError::DuplicateInputFieldDefinitionFromExtension {
+                                type_name,
+                                name: input_value_definition.name_token(),
+                            }
+                        })
+                    },
+                ));
+                (None, None)
+            }
+            TypeExtension::Union(ute) => {
+                errors.extend(ute.union_member_types().into_iter().flat_map(
+                    |union_member_types| {
+                        union_member_types.iter().map(|union_member_type| {
+                            DefinitionDocumentError::DuplicateUnionMemberTypeFromExtension {
+                                type_name,
+                                name: union_member_type.name(),
+                            }
+                        })
+                    },
+                ));
+                (None, None)
+            }
+            TypeExtension::Scalar(_) => (None, None),
+        };
+
+        errors.extend(interface_implementations.into_iter().flat_map(
+            |interface_implementations| {
+                interface_implementations
+                    .iter()
+                    .map(|interface_implementation| {
+                        DefinitionDocumentError::DuplicateInterfaceImplementationFromExtension {
+                            type_name,
+                            name: interface_implementation.interface_name(),
+                        }
+                    })
+            },
+        ));
+
+        errors.extend(
+            fields_definition
+                .into_iter()
+                .flat_map(|fields_definition| fields_definition.iter())
+                .filter(|field_definition| !field_definition.is_builtin())
+                .map(|field_definition| {
+                    DefinitionDocumentError::DuplicateFieldDefinitionFromExtension {
+                        type_name,
+                        name: field_definition.name_token(),
+                    }
+                }),
+        );
+    }
+
     fn implicit_schema_definition(
         indexed_type_definitions: &BTreeMap<&str, &'a TypeDefinition<'a, C>>,
     ) -> Result<Option<ImplicitSchemaDefinition<'a, C>>, Vec<DefinitionDocumentError<'a, C>>> {
@@ -723,6 +947,8 @@ impl<'a, C: Context> TryFrom<&'a DefinitionDocument<'a, C>> for SchemaDefinition
         let indexed_directive_definitions =
             definition_document.index_directive_definitions(&mut errors);
 
+        definition_document.unapplied_extension_errors(&indexed_type_definitions, &mut errors);
+
         DefinitionDocument::resolve_type_and_directive_definitions(
             &indexed_type_definitions,
             &indexed_directive_definitions,
@@ -768,11 +994,11 @@ mod tests {
 
     use bluejay_core::{
         definition::{
-            FieldDefinition as CoreFieldDefinition,
+            prelude::*, FieldDefinition as CoreFieldDefinition, HasDirectives,
             ObjectTypeDefinition as CoreObjectTypeDefinition,
             SchemaDefinition as CoreSchemaDefinition,
         },
-        AsIter,
+        AsIter, Directive as _,
     };
 
     use super::{DefinitionDocument, Parse, SchemaDefinition};
@@ -916,4 +1142,104 @@ mod tests {
             builtin_types,
         );
     }
+
+    #[test]
+    fn type_system_extensions_test() {
+        let s = r#"
+        directive @tag(name: String!) repeatable on SCHEMA | OBJECT | UNION | ENUM
+
+        schema { query: Query }
+
+        extend schema @tag(name: "schema") { mutation: Mutation }
+
+        extend type Query implements Node @tag(name: "extension") {
+            id: ID!
+        }
+
+        type Query @tag(name: "definition") {
+            foo: String!
+        }
+
+        type Mutation {
+            bar: String!
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        union Result = Query
+
+        extend union Result = Mutation
+
+        enum Sort { ASC }
+
+        extend enum Sort { DESC }
+        "#;
+
+        let document: DefinitionDocument = DefinitionDocument::parse(s)
+            .result
+            .expect("Document had parse errors");
+
+        let schema_definition = SchemaDefinition::try_from(&document)
+            .expect("Could not convert document to schema definition");
+
+        let query = schema_definition.query();
+
+        let query_fields: HashSet<&str> = query
+            .fields_definition()
+            .iter()
+            .filter_map(|fd| (!fd.is_builtin()).then_some(fd.name()))
+            .collect();
+
+        assert_eq!(HashSet::from(["foo", "id"]), query_fields);
+        assert_eq!(
+            vec!["Node"],
+            query
+                .interface_implementations()
+                .unwrap()
+                .iter()
+                .map(|ii| ii.name())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            vec!["tag", "tag"],
+            query
+                .directives()
+                .unwrap()
+                .iter()
+                .map(|directive| directive.name())
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(
+            Some("Mutation"),
+            schema_definition
+                .mutation()
+                .map(CoreObjectTypeDefinition::name),
+        );
+        assert_eq!(1, schema_definition.directives().unwrap().len());
+
+        let union_type_definition = schema_definition.get_type_definition("Result").unwrap();
+        let union_member_types: Vec<&str> = union_type_definition
+            .as_union()
+            .unwrap()
+            .union_member_types()
+            .iter()
+            .map(|member_type| member_type.name().as_str())
+            .collect();
+
+        assert_eq!(vec!["Query", "Mutation"], union_member_types);
+
+        let enum_type_definition = schema_definition.get_type_definition("Sort").unwrap();
+        let enum_values: Vec<&str> = enum_type_definition
+            .as_enum()
+            .unwrap()
+            .enum_value_definitions()
+            .iter()
+            .map(|evd| evd.name())
+            .collect();
+
+        assert_eq!(vec!["ASC", "DESC"], enum_values);
+    }
 }
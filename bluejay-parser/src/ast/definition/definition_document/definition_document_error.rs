@@ -1,6 +1,6 @@
 use crate::ast::definition::{
     Context, Directive, DirectiveDefinition, ExplicitSchemaDefinition, RootOperationTypeDefinition,
-    TypeDefinition,
+    SchemaExtension, TypeDefinition, TypeExtension,
 };
 use crate::error::{Annotation, Error};
 use crate::lexical_token::Name;
@@ -59,11 +59,106 @@ pub enum DefinitionDocumentError<'a, C: Context> {
     ReferencedDirectiveDoesNotExist {
         directive: &'a Directive<'a, C>,
     },
+    SchemaExtensionWithoutSchemaDefinition {
+        extension: &'a SchemaExtension<'a, C>,
+    },
+    ExtendedTypeDoesNotExist {
+        name: &'a Name<'a>,
+    },
+    CannotExtendBuiltinType {
+        name: &'a Name<'a>,
+    },
+    ExtendedTypeKindMismatch {
+        extension: &'a TypeExtension<'a, C>,
+        definition: &'a TypeDefinition<'a, C>,
+    },
+    DuplicateFieldDefinitionFromExtension {
+        type_name: &'a Name<'a>,
+        name: &'a Name<'a>,
+    },
+    DuplicateInputFieldDefinitionFromExtension {
+        type_name: &'a Name<'a>,
+        name: &'a Name<'a>,
+    },
+    DuplicateEnumValueDefinitionFromExtension {
+        type_name: &'a Name<'a>,
+        name: &'a Name<'a>,
+    },
+    DuplicateUnionMemberTypeFromExtension {
+        type_name: &'a Name<'a>,
+        name: &'a Name<'a>,
+    },
+    DuplicateInterfaceImplementationFromExtension {
+        type_name: &'a Name<'a>,
+        name: &'a Name<'a>,
+    },
+}
+
+impl<C: Context> DefinitionDocumentError<'_, C> {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DuplicateDirectiveDefinitions { .. } => "DUPLICATE_DIRECTIVE_DEFINITIONS",
+            Self::DuplicateTypeDefinitions { .. } => "DUPLICATE_TYPE_DEFINITIONS",
+            Self::ImplicitRootOperationTypeNotAnObject { .. } => {
+                "IMPLICIT_ROOT_OPERATION_TYPE_NOT_AN_OBJECT"
+            }
+            Self::ExplicitRootOperationTypeNotAnObject { .. } => {
+                "EXPLICIT_ROOT_OPERATION_TYPE_NOT_AN_OBJECT"
+            }
+            Self::ImplicitSchemaDefinitionMissingQuery => {
+                "IMPLICIT_SCHEMA_DEFINITION_MISSING_QUERY"
+            }
+            Self::ExplicitSchemaDefinitionMissingQuery { .. } => {
+                "EXPLICIT_SCHEMA_DEFINITION_MISSING_QUERY"
+            }
+            Self::DuplicateExplicitSchemaDefinitions { .. } => {
+                "DUPLICATE_EXPLICIT_SCHEMA_DEFINITIONS"
+            }
+            Self::DuplicateExplicitRootOperationDefinitions { .. } => {
+                "DUPLICATE_EXPLICIT_ROOT_OPERATION_DEFINITIONS"
+            }
+            Self::ExplicitRootOperationTypeDoesNotExist { .. } => {
+                "EXPLICIT_ROOT_OPERATION_TYPE_DOES_NOT_EXIST"
+            }
+            Self::NoSchemaDefinition => "NO_SCHEMA_DEFINITION",
+            Self::ReferencedTypeDoesNotExist { .. } => "REFERENCED_TYPE_DOES_NOT_EXIST",
+            Self::ReferencedTypeIsNotAnOutputType { .. } => "REFERENCED_TYPE_IS_NOT_AN_OUTPUT_TYPE",
+            Self::ReferencedTypeIsNotAnInputType { .. } => "REFERENCED_TYPE_IS_NOT_AN_INPUT_TYPE",
+            Self::ReferencedUnionMemberTypeIsNotAnObject { .. } => {
+                "REFERENCED_UNION_MEMBER_TYPE_IS_NOT_AN_OBJECT"
+            }
+            Self::ReferencedTypeIsNotAnInterface { .. } => "REFERENCED_TYPE_IS_NOT_AN_INTERFACE",
+            Self::ReferencedDirectiveDoesNotExist { .. } => "REFERENCED_DIRECTIVE_DOES_NOT_EXIST",
+            Self::SchemaExtensionWithoutSchemaDefinition { .. } => {
+                "SCHEMA_EXTENSION_WITHOUT_SCHEMA_DEFINITION"
+            }
+            Self::ExtendedTypeDoesNotExist { .. } => "EXTENDED_TYPE_DOES_NOT_EXIST",
+            Self::CannotExtendBuiltinType { .. } => "CANNOT_EXTEND_BUILTIN_TYPE",
+            Self::ExtendedTypeKindMismatch { .. } => "EXTENDED_TYPE_KIND_MISMATCH",
+            Self::DuplicateFieldDefinitionFromExtension { .. } => {
+                "DUPLICATE_FIELD_DEFINITION_FROM_EXTENSION"
+            }
+            Self::DuplicateInputFieldDefinitionFromExtension { .. } => {
+                "DUPLICATE_INPUT_FIELD_DEFINITION_FROM_EXTENSION"
+            }
+            Self::DuplicateEnumValueDefinitionFromExtension { .. } => {
+                "DUPLICATE_ENUM_VALUE_DEFINITION_FROM_EXTENSION"
+            }
+            Self::DuplicateUnionMemberTypeFromExtension { .. } => {
+                "DUPLICATE_UNION_MEMBER_TYPE_FROM_EXTENSION"
+            }
+            Self::DuplicateInterfaceImplementationFromExtension { .. } => {
+                "DUPLICATE_INTERFACE_IMPLEMENTATION_FROM_EXTENSION"
+            }
+        }
+    }
 }
 
 impl<C: Context> From<DefinitionDocumentError<'_, C>> for Error {
     fn from(value: DefinitionDocumentError<C>) -> Self {
-        match value {
+        let code = value.code();
+        let error = match value {
             DefinitionDocumentError::DuplicateDirectiveDefinitions { name, definitions } => {
                 let message = if definitions
                     .iter()
@@ -235,6 +330,116 @@ impl<C: Context> From<DefinitionDocumentError<'_, C>> for Error {
                 )),
                 Vec::new(),
             ),
-        }
+            DefinitionDocumentError::SchemaExtensionWithoutSchemaDefinition { extension } => {
+                Error::new(
+                    "Schema extension without a schema definition",
+                    Some(Annotation::new(
+                        "No schema definition to extend",
+                        *extension.schema_identifier_span(),
+                    )),
+                    Vec::new(),
+                )
+            }
+            DefinitionDocumentError::ExtendedTypeDoesNotExist { name } => Error::new(
+                format!("Extended type `{}` does not exist", name.as_ref()),
+                Some(Annotation::new(
+                    "No definition for extended type",
+                    *name.span(),
+                )),
+                Vec::new(),
+            ),
+            DefinitionDocumentError::CannotExtendBuiltinType { name } => Error::new(
+                format!("Cannot extend builtin type {}", name.as_ref()),
+                Some(Annotation::new("Builtin type", *name.span())),
+                Vec::new(),
+            ),
+            DefinitionDocumentError::ExtendedTypeKindMismatch {
+                extension,
+                definition,
+            } => Error::new(
+                format!(
+                    "Type `{}` cannot be extended with `extend {}`",
+                    extension.name().as_ref(),
+                    extension.kind(),
+                ),
+                Some(Annotation::new(
+                    "Extension does not match the kind of the type definition",
+                    *extension.name().span(),
+                )),
+                definition
+                    .name_token()
+                    .map(|name| Annotation::new("Type definition", *name.span()))
+                    .into_iter()
+                    .collect(),
+            ),
+            DefinitionDocumentError::DuplicateFieldDefinitionFromExtension { type_name, name } => {
+                Error::new(
+                    format!(
+                        "Field `{}` is already defined on type `{}`",
+                        name.as_ref(),
+                        type_name.as_ref(),
+                    ),
+                    Some(Annotation::new("Duplicate field definition", *name.span())),
+                    Vec::new(),
+                )
+            }
+            DefinitionDocumentError::DuplicateInputFieldDefinitionFromExtension {
+                type_name,
+                name,
+            } => Error::new(
+                format!(
+                    "Input field `{}` is already defined on type `{}`",
+                    name.as_ref(),
+                    type_name.as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Duplicate input field definition",
+                    *name.span(),
+                )),
+                Vec::new(),
+            ),
+            DefinitionDocumentError::DuplicateEnumValueDefinitionFromExtension {
+                type_name,
+                name,
+            } => Error::new(
+                format!(
+                    "Enum value `{}` is already defined on type `{}`",
+                    name.as_ref(),
+                    type_name.as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Duplicate enum value definition",
+                    *name.span(),
+                )),
+                Vec::new(),
+            ),
+            DefinitionDocumentError::DuplicateUnionMemberTypeFromExtension { type_name, name } => {
+                Error::new(
+                    format!(
+                        "Type `{}` is already a member of union `{}`",
+                        name.as_ref(),
+                        type_name.as_ref(),
+                    ),
+                    Some(Annotation::new("Duplicate union member type", *name.span())),
+                    Vec::new(),
+                )
+            }
+            DefinitionDocumentError::DuplicateInterfaceImplementationFromExtension {
+                type_name,
+                name,
+            } => Error::new(
+                format!(
+                    "Type `{}` already implements interface `{}`",
+                    type_name.as_ref(),
+                    name.as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Duplicate interface implementation",
+                    *name.span(),
+                )),
+                Vec::new(),
+            ),
+        };
+        error.with_code(code)
     }
 }
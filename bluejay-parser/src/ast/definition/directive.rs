@@ -4,6 +4,7 @@ use crate::ast::{
 };
 use crate::{HasSpan, Span};
 use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::Location;
 use std::marker::PhantomData;
 
 #[derive(Debug)]
@@ -22,6 +23,10 @@ impl<'a, C: Context> bluejay_core::Directive<true> for Directive<'a, C> {
     fn arguments(&self) -> Option<&Self::Arguments> {
         self.inner.arguments()
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.inner.name().span()).into())
+    }
 }
 
 impl<'a, C: Context> bluejay_core::definition::Directive for Directive<'a, C> {
@@ -77,6 +82,17 @@ impl<'a, C: Context> bluejay_core::definition::Directives for Directives<'a, C>
     type Directive = Directive<'a, C>;
 }
 
+impl<'a, C: Context> Directives<'a, C> {
+    /// Appends the directives of `other` to `directives`, or takes `other` if `directives` is empty
+    pub(crate) fn merge(directives: &mut Option<Self>, other: Option<Self>) {
+        match (directives, other) {
+            (Some(directives), Some(other)) => directives.directives.extend(other.directives),
+            (directives @ None, other) => *directives = other,
+            (Some(_), None) => {}
+        }
+    }
+}
+
 impl<'a, C: Context> From<ast::Directives<'a, true>> for Directives<'a, C> {
     fn from(value: ast::Directives<'a, true>) -> Self {
         Self {
@@ -1,11 +1,11 @@
 use crate::ast::definition::{ArgumentsDefinition, Context};
 use crate::ast::{DepthLimiter, FromTokens, Parse, ParseError, Tokens, TryFromTokens};
 use crate::lexical_token::{Name, PunctuatorType, StringValue};
-use crate::Span;
+use crate::{HasSpan, Span};
 use bluejay_core::definition::{
     DirectiveDefinition as CoreDirectiveDefinition, DirectiveLocation as CoreDirectiveLocation,
 };
-use bluejay_core::AsIter;
+use bluejay_core::{AsIter, Location};
 use std::str::FromStr;
 use strum::{EnumIter, IntoStaticStr};
 
@@ -46,6 +46,9 @@ impl<C: Context> From<BuiltinDirectiveDefinition> for DirectiveDefinition<'_, C>
             .unwrap();
 
         definition.is_builtin = true;
+        if let Some(arguments_definition) = &mut definition.arguments_definition {
+            arguments_definition.clear_locations();
+        }
         definition
     }
 }
@@ -87,6 +90,10 @@ impl<'a, C: Context> CoreDirectiveDefinition for DirectiveDefinition<'a, C> {
     fn is_builtin(&self) -> bool {
         self.is_builtin
     }
+
+    fn location(&self) -> Option<Location> {
+        (!self.is_builtin).then(|| (*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> DirectiveDefinition<'a, C> {
@@ -94,7 +101,7 @@ impl<'a, C: Context> DirectiveDefinition<'a, C> {
     const REPEATABLE_IDENTIFIER: &'static str = "repeatable";
     const ON_IDENTIFIER: &'static str = "on";
 
-    pub(crate) fn name_token(&self) -> &Name<'a> {
+    pub fn name_token(&self) -> &Name<'a> {
         &self.name
     }
 
@@ -1,9 +1,11 @@
-use crate::ast::definition::{Context, Directives, EnumValueDefinitions};
+use crate::ast::definition::{Context, Directives, EnumTypeExtension, EnumValueDefinitions};
 use crate::ast::{
     ConstDirectives, DepthLimiter, FromTokens, Parse, ParseError, Tokens, TryFromTokens,
 };
 use crate::lexical_token::{Name, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{EnumTypeDefinition as CoreEnumTypeDefinition, HasDirectives};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct EnumTypeDefinition<'a, C: Context> {
@@ -32,6 +34,10 @@ impl<'a, C: Context> CoreEnumTypeDefinition for EnumTypeDefinition<'a, C> {
     fn is_builtin(&self) -> bool {
         self.is_builtin
     }
+
+    fn location(&self) -> Option<Location> {
+        (!self.is_builtin).then(|| (*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> EnumTypeDefinition<'a, C> {
@@ -72,9 +78,14 @@ impl<'a, C: Context> EnumTypeDefinition<'a, C> {
         &self.name
     }
 
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     fn builtin(s: &'static str) -> Self {
         let mut definition = Self::parse(s).result.unwrap();
         definition.is_builtin = true;
+        definition.enum_value_definitions.clear_locations();
         definition
     }
 
@@ -85,6 +96,22 @@ impl<'a, C: Context> EnumTypeDefinition<'a, C> {
     pub(crate) fn __directive_location() -> Self {
         Self::builtin(Self::__DIRECTIVE_LOCATION_DEFINITION)
     }
+
+    /// Merges the extension into the definition, returning the parts of the extension
+    /// that conflict with the definition, if any
+    pub(crate) fn extend(
+        &mut self,
+        extension: EnumTypeExtension<'a, C>,
+    ) -> Option<EnumTypeExtension<'a, C>> {
+        let name = extension.name().clone();
+        let (directives, enum_value_definitions) = extension.into_parts();
+        Directives::merge(&mut self.directives, directives);
+        let duplicate_enum_value_definitions =
+            enum_value_definitions.and_then(|enum_value_definitions| {
+                self.enum_value_definitions.merge(enum_value_definitions)
+            });
+        EnumTypeExtension::remainder(name, duplicate_enum_value_definitions)
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for EnumTypeDefinition<'a, C> {
@@ -0,0 +1,69 @@
+use crate::ast::definition::{
+    Context, Directives, EnumTypeDefinition, EnumValueDefinitions, TypeExtension,
+};
+use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
+use crate::lexical_token::Name;
+use crate::HasSpan;
+
+#[derive(Debug)]
+pub struct EnumTypeExtension<'a, C: Context> {
+    name: Name<'a>,
+    directives: Option<Directives<'a, C>>,
+    enum_value_definitions: Option<EnumValueDefinitions<'a, C>>,
+}
+
+impl<'a, C: Context> EnumTypeExtension<'a, C> {
+    pub fn name(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    pub fn directives(&self) -> Option<&Directives<'a, C>> {
+        self.directives.as_ref()
+    }
+
+    pub fn enum_value_definitions(&self) -> Option<&EnumValueDefinitions<'a, C>> {
+        self.enum_value_definitions.as_ref()
+    }
+
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        Option<Directives<'a, C>>,
+        Option<EnumValueDefinitions<'a, C>>,
+    ) {
+        (self.directives, self.enum_value_definitions)
+    }
+
+    pub(crate) fn remainder(
+        name: Name<'a>,
+        enum_value_definitions: Option<EnumValueDefinitions<'a, C>>,
+    ) -> Option<Self> {
+        enum_value_definitions.map(|enum_value_definitions| Self {
+            name,
+            directives: None,
+            enum_value_definitions: Some(enum_value_definitions),
+        })
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for EnumTypeExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        tokens.expect_name_value(TypeExtension::<C>::EXTEND_IDENTIFIER)?;
+        tokens.expect_name_value(EnumTypeDefinition::<C>::ENUM_IDENTIFIER)?;
+        let name = tokens.expect_name()?;
+        let directives = ConstDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        let enum_value_definitions =
+            EnumValueDefinitions::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        if directives.is_none() && enum_value_definitions.is_none() {
+            return Err(ParseError::EmptyExtension { span: *name.span() });
+        }
+        Ok(Self {
+            name,
+            directives: directives.map(Directives::from),
+            enum_value_definitions,
+        })
+    }
+}
@@ -8,18 +8,25 @@ use crate::{
     HasSpan,
 };
 use bluejay_core::definition::{EnumValueDefinition as CoreEnumValueDefinition, HasDirectives};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct EnumValueDefinition<'a, C: Context> {
     description: Option<StringValue<'a>>,
     name: Name<'a>,
     directives: Option<Directives<'a, C>>,
+    has_location: bool,
 }
 
 impl<'a, C: Context> EnumValueDefinition<'a, C> {
     pub fn name_token(&self) -> &Name<'a> {
         &self.name
     }
+
+    /// Used for definitions parsed from builtin source rather than the document
+    pub(crate) fn clear_location(&mut self) {
+        self.has_location = false;
+    }
 }
 
 impl<C: Context> CoreEnumValueDefinition for EnumValueDefinition<'_, C> {
@@ -30,6 +37,10 @@ impl<C: Context> CoreEnumValueDefinition for EnumValueDefinition<'_, C> {
     fn name(&self) -> &str {
         self.name.as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.has_location.then(|| (*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for EnumValueDefinition<'a, C> {
@@ -51,6 +62,7 @@ impl<'a, C: Context> FromTokens<'a> for EnumValueDefinition<'a, C> {
             description,
             name,
             directives: directives.map(Directives::from),
+            has_location: true,
         })
     }
 }
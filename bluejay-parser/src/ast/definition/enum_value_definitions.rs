@@ -1,8 +1,11 @@
 use crate::ast::definition::{Context, EnumValueDefinition};
-use crate::ast::{DepthLimiter, FromTokens, ParseError, Tokens};
+use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens};
 use crate::lexical_token::PunctuatorType;
 use crate::Span;
-use bluejay_core::definition::EnumValueDefinitions as CoreEnumValueDefinitions;
+use bluejay_core::definition::{
+    EnumValueDefinition as CoreEnumValueDefinition,
+    EnumValueDefinitions as CoreEnumValueDefinitions,
+};
 use bluejay_core::AsIter;
 
 #[derive(Debug)]
@@ -50,3 +53,37 @@ impl<'a, C: Context> FromTokens<'a> for EnumValueDefinitions<'a, C> {
         })
     }
 }
+
+impl<'a, C: Context> IsMatch<'a> for EnumValueDefinitions<'a, C> {
+    fn is_match(tokens: &mut impl Tokens<'a>) -> bool {
+        tokens.peek_punctuator_matches(0, PunctuatorType::OpenBrace)
+    }
+}
+
+impl<C: Context> EnumValueDefinitions<'_, C> {
+    /// Appends the enum value definitions of `other` to `self`, returning the
+    /// enum value definitions whose names are already defined on `self`, if any
+    pub(crate) fn merge(&mut self, other: Self) -> Option<Self> {
+        let (duplicates, additions): (Vec<_>, Vec<_>) = other
+            .enum_value_definitions
+            .into_iter()
+            .partition(|enum_value_definition| {
+                self.enum_value_definitions
+                    .iter()
+                    .any(|existing| existing.name() == enum_value_definition.name())
+            });
+
+        self.enum_value_definitions.extend(additions);
+
+        (!duplicates.is_empty()).then_some(Self {
+            enum_value_definitions: duplicates,
+            _span: other._span,
+        })
+    }
+
+    pub(crate) fn clear_locations(&mut self) {
+        self.enum_value_definitions
+            .iter_mut()
+            .for_each(|definition| definition.clear_location());
+    }
+}
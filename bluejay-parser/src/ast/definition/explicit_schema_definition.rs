@@ -1,5 +1,5 @@
 use crate::ast::{
-    definition::{Context, Directives},
+    definition::{Context, Directives, SchemaExtension},
     ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens,
 };
 use crate::lexical_token::{Name, PunctuatorType, StringValue};
@@ -38,6 +38,15 @@ impl<'a, C: Context> ExplicitSchemaDefinition<'a, C> {
     pub(crate) fn root_operation_type_definitions_span(&self) -> &Span {
         &self.root_operation_type_definitions_span
     }
+
+    /// Merges the extension into the definition. Conflicting root operation type definitions
+    /// are kept, to be reported as duplicates when building the schema definition.
+    pub(crate) fn extend(&mut self, extension: SchemaExtension<'a, C>) {
+        let (directives, root_operation_type_definitions) = extension.into_parts();
+        Directives::merge(&mut self.directives, directives);
+        self.root_operation_type_definitions
+            .extend(root_operation_type_definitions);
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for ExplicitSchemaDefinition<'a, C> {
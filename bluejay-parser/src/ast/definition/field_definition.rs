@@ -3,7 +3,9 @@ use crate::ast::{
     ConstDirectives, DepthLimiter, FromTokens, Parse, ParseError, Tokens, TryFromTokens,
 };
 use crate::lexical_token::{Name, PunctuatorType, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{FieldDefinition as CoreFieldDefinition, HasDirectives};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct FieldDefinition<'a, C: Context> {
@@ -13,6 +15,7 @@ pub struct FieldDefinition<'a, C: Context> {
     r#type: OutputType<'a, C>,
     directives: Option<Directives<'a, C>>,
     is_builtin: bool,
+    has_location: bool,
 }
 
 impl<C: Context> FieldDefinition<'_, C> {
@@ -23,6 +26,7 @@ impl<C: Context> FieldDefinition<'_, C> {
     fn builtin(s: &'static str) -> Self {
         let mut definition = Self::parse(s).result.unwrap();
         definition.is_builtin = true;
+        definition.clear_location();
         definition
     }
 
@@ -39,6 +43,20 @@ impl<C: Context> FieldDefinition<'_, C> {
     }
 }
 
+impl<'a, C: Context> FieldDefinition<'a, C> {
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    /// Used for definitions parsed from builtin source rather than the document
+    pub(crate) fn clear_location(&mut self) {
+        self.has_location = false;
+        if let Some(arguments_definition) = &mut self.arguments_definition {
+            arguments_definition.clear_locations();
+        }
+    }
+}
+
 impl<'a, C: Context> CoreFieldDefinition for FieldDefinition<'a, C> {
     type ArgumentsDefinition = ArgumentsDefinition<'a, C>;
     type OutputType = OutputType<'a, C>;
@@ -62,6 +80,10 @@ impl<'a, C: Context> CoreFieldDefinition for FieldDefinition<'a, C> {
     fn is_builtin(&self) -> bool {
         self.is_builtin
     }
+
+    fn location(&self) -> Option<Location> {
+        self.has_location.then(|| (*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for FieldDefinition<'a, C> {
@@ -83,6 +105,7 @@ impl<'a, C: Context> FromTokens<'a> for FieldDefinition<'a, C> {
             r#type,
             directives: directives.map(Directives::from),
             is_builtin: false,
+            has_location: true,
         })
     }
 }
@@ -1,8 +1,10 @@
 use crate::ast::definition::{Context, FieldDefinition};
-use crate::ast::{DepthLimiter, FromTokens, ParseError, Tokens};
+use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens};
 use crate::lexical_token::PunctuatorType;
 use crate::Span;
-use bluejay_core::definition::FieldsDefinition as CoreFieldsDefinition;
+use bluejay_core::definition::{
+    FieldDefinition as CoreFieldDefinition, FieldsDefinition as CoreFieldsDefinition,
+};
 use bluejay_core::AsIter;
 
 #[derive(Debug)]
@@ -49,7 +51,34 @@ impl<'a, C: Context> FromTokens<'a> for FieldsDefinition<'a, C> {
     }
 }
 
-impl<C: Context> FieldsDefinition<'_, C> {
+impl<'a, C: Context> IsMatch<'a> for FieldsDefinition<'a, C> {
+    fn is_match(tokens: &mut impl Tokens<'a>) -> bool {
+        tokens.peek_punctuator_matches(0, PunctuatorType::OpenBrace)
+    }
+}
+
+impl<'a, C: Context> FieldsDefinition<'a, C> {
+    /// Appends the non-builtin field definitions of `other` to `self`, returning the
+    /// field definitions whose names are already defined on `self`, if any
+    pub(crate) fn merge(&mut self, other: Self) -> Option<Self> {
+        let (duplicates, additions): (Vec<_>, Vec<_>) = other
+            .field_definitions
+            .into_iter()
+            .filter(|field_definition| !field_definition.is_builtin())
+            .partition(|field_definition| {
+                self.field_definitions
+                    .iter()
+                    .any(|existing| existing.name() == field_definition.name())
+            });
+
+        self.field_definitions.extend(additions);
+
+        (!duplicates.is_empty()).then_some(Self {
+            field_definitions: duplicates,
+            _span: other._span,
+        })
+    }
+
     pub(crate) fn add_query_root_fields(&mut self) {
         self.field_definitions.push(FieldDefinition::__schema());
         self.field_definitions.push(FieldDefinition::__type());
@@ -61,4 +90,10 @@ impl<C: Context> FieldsDefinition<'_, C> {
             _span: Span::new(0..0),
         }
     }
+
+    pub(crate) fn clear_locations(&mut self) {
+        self.field_definitions
+            .iter_mut()
+            .for_each(|definition| definition.clear_location());
+    }
 }
@@ -1,8 +1,11 @@
 use crate::ast::definition::{Context, InputValueDefinition};
-use crate::ast::{DepthLimiter, FromTokens, ParseError, Tokens};
+use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens};
 use crate::lexical_token::PunctuatorType;
 use crate::Span;
-use bluejay_core::definition::InputFieldsDefinition as CoreInputFieldsDefinition;
+use bluejay_core::definition::{
+    InputFieldsDefinition as CoreInputFieldsDefinition,
+    InputValueDefinition as CoreInputValueDefinition,
+};
 use bluejay_core::AsIter;
 
 #[derive(Debug)]
@@ -50,3 +53,31 @@ impl<'a, C: Context> FromTokens<'a> for InputFieldsDefinition<'a, C> {
         })
     }
 }
+
+impl<'a, C: Context> IsMatch<'a> for InputFieldsDefinition<'a, C> {
+    fn is_match(tokens: &mut impl Tokens<'a>) -> bool {
+        tokens.peek_punctuator_matches(0, PunctuatorType::OpenBrace)
+    }
+}
+
+impl<C: Context> InputFieldsDefinition<'_, C> {
+    /// Appends the input field definitions of `other` to `self`, returning the
+    /// input field definitions whose names are already defined on `self`, if any
+    pub(crate) fn merge(&mut self, other: Self) -> Option<Self> {
+        let (duplicates, additions): (Vec<_>, Vec<_>) = other
+            .input_field_definitions
+            .into_iter()
+            .partition(|input_field_definition| {
+                self.input_field_definitions
+                    .iter()
+                    .any(|existing| existing.name() == input_field_definition.name())
+            });
+
+        self.input_field_definitions.extend(additions);
+
+        (!duplicates.is_empty()).then_some(Self {
+            input_field_definitions: duplicates,
+            _span: other._span,
+        })
+    }
+}
@@ -1,9 +1,13 @@
-use crate::ast::definition::{Context, Directives, InputFieldsDefinition};
+use crate::ast::definition::{
+    Context, Directives, InputFieldsDefinition, InputObjectTypeExtension,
+};
 use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
 use crate::lexical_token::{Name, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{
     HasDirectives, InputObjectTypeDefinition as CoreInputObjectTypeDefinition,
 };
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct InputObjectTypeDefinition<'a, C: Context> {
@@ -27,6 +31,10 @@ impl<'a, C: Context> CoreInputObjectTypeDefinition for InputObjectTypeDefinition
     fn input_field_definitions(&self) -> &Self::InputFieldsDefinition {
         &self.input_fields_definition
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> InputObjectTypeDefinition<'a, C> {
@@ -35,6 +43,22 @@ impl<'a, C: Context> InputObjectTypeDefinition<'a, C> {
     pub fn name_token(&self) -> &Name<'a> {
         &self.name
     }
+
+    /// Merges the extension into the definition, returning the parts of the extension
+    /// that conflict with the definition, if any
+    pub(crate) fn extend(
+        &mut self,
+        extension: InputObjectTypeExtension<'a, C>,
+    ) -> Option<InputObjectTypeExtension<'a, C>> {
+        let name = extension.name().clone();
+        let (directives, input_fields_definition) = extension.into_parts();
+        Directives::merge(&mut self.directives, directives);
+        let duplicate_input_fields_definition =
+            input_fields_definition.and_then(|input_fields_definition| {
+                self.input_fields_definition.merge(input_fields_definition)
+            });
+        InputObjectTypeExtension::remainder(name, duplicate_input_fields_definition)
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for InputObjectTypeDefinition<'a, C> {
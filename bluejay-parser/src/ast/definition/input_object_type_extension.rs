@@ -0,0 +1,69 @@
+use crate::ast::definition::{
+    Context, Directives, InputFieldsDefinition, InputObjectTypeDefinition, TypeExtension,
+};
+use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
+use crate::lexical_token::Name;
+use crate::HasSpan;
+
+#[derive(Debug)]
+pub struct InputObjectTypeExtension<'a, C: Context> {
+    name: Name<'a>,
+    directives: Option<Directives<'a, C>>,
+    input_fields_definition: Option<InputFieldsDefinition<'a, C>>,
+}
+
+impl<'a, C: Context> InputObjectTypeExtension<'a, C> {
+    pub fn name(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    pub fn directives(&self) -> Option<&Directives<'a, C>> {
+        self.directives.as_ref()
+    }
+
+    pub fn input_fields_definition(&self) -> Option<&InputFieldsDefinition<'a, C>> {
+        self.input_fields_definition.as_ref()
+    }
+
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        Option<Directives<'a, C>>,
+        Option<InputFieldsDefinition<'a, C>>,
+    ) {
+        (self.directives, self.input_fields_definition)
+    }
+
+    pub(crate) fn remainder(
+        name: Name<'a>,
+        input_fields_definition: Option<InputFieldsDefinition<'a, C>>,
+    ) -> Option<Self> {
+        input_fields_definition.map(|input_fields_definition| Self {
+            name,
+            directives: None,
+            input_fields_definition: Some(input_fields_definition),
+        })
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for InputObjectTypeExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        tokens.expect_name_value(TypeExtension::<C>::EXTEND_IDENTIFIER)?;
+        tokens.expect_name_value(InputObjectTypeDefinition::<C>::INPUT_IDENTIFIER)?;
+        let name = tokens.expect_name()?;
+        let directives = ConstDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        let input_fields_definition =
+            InputFieldsDefinition::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        if directives.is_none() && input_fields_definition.is_none() {
+            return Err(ParseError::EmptyExtension { span: *name.span() });
+        }
+        Ok(Self {
+            name,
+            directives: directives.map(Directives::from),
+            input_fields_definition,
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use crate::ast::definition::{Context, Directives, InputType};
 use crate::ast::{ConstDirectives, ConstValue, DepthLimiter, FromTokens, ParseError, Tokens};
 use crate::lexical_token::{Name, PunctuatorType, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{HasDirectives, InputValueDefinition as CoreInputValueDefinition};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct InputValueDefinition<'a, C: Context> {
@@ -10,12 +12,18 @@ pub struct InputValueDefinition<'a, C: Context> {
     r#type: InputType<'a, C>,
     default_value: Option<ConstValue<'a>>,
     directives: Option<Directives<'a, C>>,
+    has_location: bool,
 }
 
 impl<'a, C: Context> InputValueDefinition<'a, C> {
     pub fn name_token(&self) -> &Name<'a> {
         &self.name
     }
+
+    /// Used for definitions parsed from builtin source rather than the document
+    pub(crate) fn clear_location(&mut self) {
+        self.has_location = false;
+    }
 }
 
 impl<'a, C: Context> CoreInputValueDefinition for InputValueDefinition<'a, C> {
@@ -37,6 +45,10 @@ impl<'a, C: Context> CoreInputValueDefinition for InputValueDefinition<'a, C> {
     fn default_value(&self) -> Option<&Self::Value> {
         self.default_value.as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.has_location.then(|| (*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for InputValueDefinition<'a, C> {
@@ -61,6 +73,7 @@ impl<'a, C: Context> FromTokens<'a> for InputValueDefinition<'a, C> {
             r#type,
             default_value,
             directives: directives.map(Directives::from),
+            has_location: true,
         })
     }
 }
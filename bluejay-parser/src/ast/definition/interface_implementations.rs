@@ -1,7 +1,10 @@
 use crate::ast::definition::{Context, InterfaceImplementation};
 use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens};
 use crate::lexical_token::PunctuatorType;
-use bluejay_core::definition::InterfaceImplementations as CoreInterfaceImplementations;
+use bluejay_core::definition::{
+    InterfaceImplementation as CoreInterfaceImplementation,
+    InterfaceImplementations as CoreInterfaceImplementations,
+};
 use bluejay_core::AsIter;
 
 #[derive(Debug)]
@@ -27,6 +30,38 @@ impl<'a, C: Context + 'a> CoreInterfaceImplementations for InterfaceImplementati
 
 impl<'a, C: Context + 'a> InterfaceImplementations<'a, C> {
     const IMPLEMENTS_IDENTIFIER: &'static str = "implements";
+
+    /// Appends the interface implementations of `other` to `interface_implementations`, returning
+    /// the interface implementations that are already present, if any
+    pub(crate) fn merge(
+        interface_implementations: &mut Option<Self>,
+        other: Option<Self>,
+    ) -> Option<Self> {
+        match (interface_implementations, other) {
+            (Some(existing), Some(other)) => {
+                let (duplicates, additions): (Vec<_>, Vec<_>) = other
+                    .interface_implementations
+                    .into_iter()
+                    .partition(|interface_implementation| {
+                        existing
+                            .interface_implementations
+                            .iter()
+                            .any(|existing| existing.name() == interface_implementation.name())
+                    });
+
+                existing.interface_implementations.extend(additions);
+
+                (!duplicates.is_empty()).then_some(Self {
+                    interface_implementations: duplicates,
+                })
+            }
+            (interface_implementations @ None, other) => {
+                *interface_implementations = other;
+                None
+            }
+            (Some(_), None) => None,
+        }
+    }
 }
 
 impl<'a, C: Context + 'a> FromTokens<'a> for InterfaceImplementations<'a, C> {
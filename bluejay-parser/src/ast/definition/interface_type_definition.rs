@@ -1,9 +1,13 @@
-use crate::ast::definition::{Context, Directives, FieldsDefinition, InterfaceImplementations};
+use crate::ast::definition::{
+    Context, Directives, FieldsDefinition, InterfaceImplementations, InterfaceTypeExtension,
+};
 use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
 use crate::lexical_token::{Name, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{
     HasDirectives, InterfaceTypeDefinition as CoreInterfaceTypeDefinition,
 };
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct InterfaceTypeDefinition<'a, C: Context> {
@@ -33,6 +37,10 @@ impl<'a, C: Context> CoreInterfaceTypeDefinition for InterfaceTypeDefinition<'a,
     fn fields_definition(&self) -> &Self::FieldsDefinition {
         &self.fields_definition
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> InterfaceTypeDefinition<'a, C> {
@@ -41,6 +49,32 @@ impl<'a, C: Context> InterfaceTypeDefinition<'a, C> {
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
+
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    /// Merges the extension into the definition, returning the parts of the extension
+    /// that conflict with the definition, if any
+    pub(crate) fn extend(
+        &mut self,
+        extension: InterfaceTypeExtension<'a, C>,
+    ) -> Option<InterfaceTypeExtension<'a, C>> {
+        let name = extension.name().clone();
+        let (interface_implementations, directives, fields_definition) = extension.into_parts();
+        let duplicate_interface_implementations = InterfaceImplementations::merge(
+            &mut self.interface_implementations,
+            interface_implementations,
+        );
+        Directives::merge(&mut self.directives, directives);
+        let duplicate_fields_definition = fields_definition
+            .and_then(|fields_definition| self.fields_definition.merge(fields_definition));
+        InterfaceTypeExtension::remainder(
+            name,
+            duplicate_interface_implementations,
+            duplicate_fields_definition,
+        )
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for InterfaceTypeDefinition<'a, C> {
@@ -0,0 +1,87 @@
+use crate::ast::definition::{
+    Context, Directives, FieldsDefinition, InterfaceImplementations, InterfaceTypeDefinition,
+    TypeExtension,
+};
+use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
+use crate::lexical_token::Name;
+use crate::HasSpan;
+
+type InterfaceTypeExtensionParts<'a, C> = (
+    Option<InterfaceImplementations<'a, C>>,
+    Option<Directives<'a, C>>,
+    Option<FieldsDefinition<'a, C>>,
+);
+
+#[derive(Debug)]
+pub struct InterfaceTypeExtension<'a, C: Context> {
+    name: Name<'a>,
+    interface_implementations: Option<InterfaceImplementations<'a, C>>,
+    directives: Option<Directives<'a, C>>,
+    fields_definition: Option<FieldsDefinition<'a, C>>,
+}
+
+impl<'a, C: Context> InterfaceTypeExtension<'a, C> {
+    pub fn name(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    pub fn interface_implementations(&self) -> Option<&InterfaceImplementations<'a, C>> {
+        self.interface_implementations.as_ref()
+    }
+
+    pub fn directives(&self) -> Option<&Directives<'a, C>> {
+        self.directives.as_ref()
+    }
+
+    pub fn fields_definition(&self) -> Option<&FieldsDefinition<'a, C>> {
+        self.fields_definition.as_ref()
+    }
+
+    pub(crate) fn into_parts(self) -> InterfaceTypeExtensionParts<'a, C> {
+        (
+            self.interface_implementations,
+            self.directives,
+            self.fields_definition,
+        )
+    }
+
+    pub(crate) fn remainder(
+        name: Name<'a>,
+        interface_implementations: Option<InterfaceImplementations<'a, C>>,
+        fields_definition: Option<FieldsDefinition<'a, C>>,
+    ) -> Option<Self> {
+        (interface_implementations.is_some() || fields_definition.is_some()).then_some(Self {
+            name,
+            interface_implementations,
+            directives: None,
+            fields_definition,
+        })
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for InterfaceTypeExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        tokens.expect_name_value(TypeExtension::<C>::EXTEND_IDENTIFIER)?;
+        tokens.expect_name_value(InterfaceTypeDefinition::<C>::INTERFACE_IDENTIFIER)?;
+        let name = tokens.expect_name()?;
+        let interface_implementations =
+            InterfaceImplementations::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        let directives = ConstDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        let fields_definition = FieldsDefinition::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        if interface_implementations.is_none()
+            && directives.is_none()
+            && fields_definition.is_none()
+        {
+            return Err(ParseError::EmptyExtension { span: *name.span() });
+        }
+        Ok(Self {
+            name,
+            interface_implementations,
+            directives: directives.map(Directives::from),
+            fields_definition,
+        })
+    }
+}
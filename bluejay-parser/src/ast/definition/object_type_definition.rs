@@ -1,9 +1,13 @@
-use crate::ast::definition::{Context, Directives, FieldsDefinition, InterfaceImplementations};
+use crate::ast::definition::{
+    Context, Directives, FieldsDefinition, InterfaceImplementations, ObjectTypeExtension,
+};
 use crate::ast::{
     ConstDirectives, DepthLimiter, FromTokens, Parse, ParseError, Tokens, TryFromTokens,
 };
 use crate::lexical_token::{Name, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{HasDirectives, ObjectTypeDefinition as CoreObjectTypeDefinition};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct ObjectTypeDefinition<'a, C: Context> {
@@ -38,6 +42,10 @@ impl<'a, C: Context> CoreObjectTypeDefinition for ObjectTypeDefinition<'a, C> {
     fn is_builtin(&self) -> bool {
         self.is_builtin
     }
+
+    fn location(&self) -> Option<Location> {
+        (!self.is_builtin).then(|| (*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> ObjectTypeDefinition<'a, C> {
@@ -68,6 +76,8 @@ impl<'a, C: Context> ObjectTypeDefinition<'a, C> {
         ofType: __Type
         # may be non-null for custom SCALAR, otherwise null.
         specifiedByURL: String
+        # must be non-null for INPUT_OBJECT, otherwise null.
+        isOneOf: Boolean
     }";
     const __FIELD_DEFINITION: &'static str = "type __Field {
         name: String!
@@ -82,6 +92,8 @@ impl<'a, C: Context> ObjectTypeDefinition<'a, C> {
         description: String
         type: __Type!
         defaultValue: String
+        isDeprecated: Boolean!
+        deprecationReason: String
     }";
     const __ENUM_VALUE_DEFINITION: &'static str = "type __EnumValue {
         name: String!
@@ -101,9 +113,14 @@ impl<'a, C: Context> ObjectTypeDefinition<'a, C> {
         &self.name
     }
 
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     fn builtin(s: &'static str) -> Self {
         let mut definition = Self::parse(s).result.unwrap();
         definition.is_builtin = true;
+        definition.fields_definition.clear_locations();
         definition
     }
 
@@ -134,6 +151,28 @@ impl<'a, C: Context> ObjectTypeDefinition<'a, C> {
     pub(crate) fn add_query_root_fields(&mut self) {
         self.fields_definition.add_query_root_fields();
     }
+
+    /// Merges the extension into the definition, returning the parts of the extension
+    /// that conflict with the definition, if any
+    pub(crate) fn extend(
+        &mut self,
+        extension: ObjectTypeExtension<'a, C>,
+    ) -> Option<ObjectTypeExtension<'a, C>> {
+        let name = extension.name().clone();
+        let (interface_implementations, directives, fields_definition) = extension.into_parts();
+        let duplicate_interface_implementations = InterfaceImplementations::merge(
+            &mut self.interface_implementations,
+            interface_implementations,
+        );
+        Directives::merge(&mut self.directives, directives);
+        let duplicate_fields_definition = fields_definition
+            .and_then(|fields_definition| self.fields_definition.merge(fields_definition));
+        ObjectTypeExtension::remainder(
+            name,
+            duplicate_interface_implementations,
+            duplicate_fields_definition,
+        )
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for ObjectTypeDefinition<'a, C> {
@@ -0,0 +1,44 @@
+use crate::ast::definition::{Context, CustomScalarTypeDefinition, Directives, TypeExtension};
+use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens};
+use crate::lexical_token::Name;
+use crate::HasSpan;
+use bluejay_core::AsIter;
+
+#[derive(Debug)]
+pub struct ScalarTypeExtension<'a, C: Context> {
+    name: Name<'a>,
+    directives: Directives<'a, C>,
+}
+
+impl<'a, C: Context> ScalarTypeExtension<'a, C> {
+    pub fn name(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    pub fn directives(&self) -> &Directives<'a, C> {
+        &self.directives
+    }
+
+    pub(crate) fn into_directives(self) -> Directives<'a, C> {
+        self.directives
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for ScalarTypeExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        tokens.expect_name_value(TypeExtension::<C>::EXTEND_IDENTIFIER)?;
+        tokens.expect_name_value(CustomScalarTypeDefinition::<C>::SCALAR_IDENTIFIER)?;
+        let name = tokens.expect_name()?;
+        let directives = ConstDirectives::from_tokens(tokens, depth_limiter.bump()?)?;
+        if directives.is_empty() {
+            return Err(ParseError::EmptyExtension { span: *name.span() });
+        }
+        Ok(Self {
+            name,
+            directives: directives.into(),
+        })
+    }
+}
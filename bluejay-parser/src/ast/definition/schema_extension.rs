@@ -0,0 +1,74 @@
+use crate::ast::definition::{Context, Directives, RootOperationTypeDefinition, TypeExtension};
+use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
+use crate::lexical_token::PunctuatorType;
+use crate::Span;
+
+#[derive(Debug)]
+pub struct SchemaExtension<'a, C: Context> {
+    schema_identifier_span: Span,
+    directives: Option<Directives<'a, C>>,
+    root_operation_type_definitions: Vec<RootOperationTypeDefinition<'a>>,
+}
+
+impl<'a, C: Context> SchemaExtension<'a, C> {
+    pub(crate) const SCHEMA_IDENTIFIER: &'static str = "schema";
+
+    pub fn directives(&self) -> Option<&Directives<'a, C>> {
+        self.directives.as_ref()
+    }
+
+    pub fn root_operation_type_definitions(&self) -> &[RootOperationTypeDefinition<'a>] {
+        &self.root_operation_type_definitions
+    }
+
+    pub(crate) fn schema_identifier_span(&self) -> &Span {
+        &self.schema_identifier_span
+    }
+
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        Option<Directives<'a, C>>,
+        Vec<RootOperationTypeDefinition<'a>>,
+    ) {
+        (self.directives, self.root_operation_type_definitions)
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for SchemaExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        tokens.expect_name_value(TypeExtension::<C>::EXTEND_IDENTIFIER)?;
+        let schema_identifier_span = tokens.expect_name_value(Self::SCHEMA_IDENTIFIER)?;
+        let directives = ConstDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        let mut root_operation_type_definitions = Vec::new();
+        if tokens
+            .next_if_punctuator(PunctuatorType::OpenBrace)
+            .is_some()
+        {
+            loop {
+                root_operation_type_definitions.push(RootOperationTypeDefinition::from_tokens(
+                    tokens,
+                    depth_limiter.bump()?,
+                )?);
+                if tokens
+                    .next_if_punctuator(PunctuatorType::CloseBrace)
+                    .is_some()
+                {
+                    break;
+                }
+            }
+        } else if directives.is_none() {
+            return Err(ParseError::EmptyExtension {
+                span: schema_identifier_span,
+            });
+        }
+        Ok(Self {
+            schema_identifier_span,
+            directives: directives.map(Directives::from),
+            root_operation_type_definitions,
+        })
+    }
+}
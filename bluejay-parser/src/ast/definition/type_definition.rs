@@ -1,6 +1,6 @@
 use crate::ast::definition::{
     Context, CustomScalarTypeDefinition, EnumTypeDefinition, InputObjectTypeDefinition,
-    InterfaceTypeDefinition, ObjectTypeDefinition, UnionTypeDefinition,
+    InterfaceTypeDefinition, ObjectTypeDefinition, TypeExtension, UnionTypeDefinition,
 };
 use crate::lexical_token::Name;
 use bluejay_core::definition::{TypeDefinition as CoreTypeDefinition, TypeDefinitionReference};
@@ -43,6 +43,49 @@ impl<C: Context> TypeDefinition<'_, C> {
     }
 }
 
+impl<'a, C: Context> TypeDefinition<'a, C> {
+    /// Merges the extension into the definition, returning the parts of the extension
+    /// that could not be applied, if any. If the definition is builtin or of a different kind
+    /// than the extension, the extension is returned unchanged.
+    pub(crate) fn extend(
+        &mut self,
+        extension: TypeExtension<'a, C>,
+    ) -> Option<TypeExtension<'a, C>> {
+        if self.as_ref().is_builtin() {
+            return Some(extension);
+        }
+        match (self, extension) {
+            (Self::CustomScalar(cstd), TypeExtension::Scalar(ste)) => {
+                cstd.extend(ste);
+                None
+            }
+            (Self::Object(otd), TypeExtension::Object(ote)) => otd.extend(ote).map(Into::into),
+            (Self::InputObject(iotd), TypeExtension::InputObject(iote)) => {
+                iotd.extend(iote).map(Into::into)
+            }
+            (Self::Enum(etd), TypeExtension::Enum(ete)) => etd.extend(ete).map(Into::into),
+            (Self::Union(utd), TypeExtension::Union(ute)) => utd.extend(ute).map(Into::into),
+            (Self::Interface(itd), TypeExtension::Interface(ite)) => {
+                itd.extend(ite).map(Into::into)
+            }
+            (_, extension) => Some(extension),
+        }
+    }
+
+    /// Whether the extension is of the same kind as the definition
+    pub(crate) fn matches_extension_kind(&self, extension: &TypeExtension<'a, C>) -> bool {
+        matches!(
+            (self, extension),
+            (Self::CustomScalar(_), TypeExtension::Scalar(_))
+                | (Self::Object(_), TypeExtension::Object(_))
+                | (Self::InputObject(_), TypeExtension::InputObject(_))
+                | (Self::Enum(_), TypeExtension::Enum(_))
+                | (Self::Union(_), TypeExtension::Union(_))
+                | (Self::Interface(_), TypeExtension::Interface(_))
+        )
+    }
+}
+
 impl<'a, C: Context> CoreTypeDefinition for TypeDefinition<'a, C> {
     type CustomScalarTypeDefinition = CustomScalarTypeDefinition<'a, C>;
     type ObjectTypeDefinition = ObjectTypeDefinition<'a, C>;
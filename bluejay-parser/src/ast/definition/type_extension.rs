@@ -0,0 +1,127 @@
+use crate::ast::definition::{
+    Context, CustomScalarTypeDefinition, EnumTypeDefinition, EnumTypeExtension,
+    InputObjectTypeDefinition, InputObjectTypeExtension, InterfaceTypeDefinition,
+    InterfaceTypeExtension, ObjectTypeDefinition, ObjectTypeExtension, ScalarTypeExtension,
+    UnionTypeDefinition, UnionTypeExtension,
+};
+use crate::ast::{DepthLimiter, FromTokens, ParseError, Tokens};
+use crate::lexical_token::Name;
+
+#[derive(Debug)]
+pub enum TypeExtension<'a, C: Context> {
+    Scalar(ScalarTypeExtension<'a, C>),
+    Object(ObjectTypeExtension<'a, C>),
+    InputObject(InputObjectTypeExtension<'a, C>),
+    Enum(EnumTypeExtension<'a, C>),
+    Union(UnionTypeExtension<'a, C>),
+    Interface(InterfaceTypeExtension<'a, C>),
+}
+
+impl<'a, C: Context> TypeExtension<'a, C> {
+    pub(crate) const EXTEND_IDENTIFIER: &'static str = "extend";
+    const POSSIBLE_KINDS: &'static [&'static str] = &[
+        CustomScalarTypeDefinition::<C>::SCALAR_IDENTIFIER,
+        ObjectTypeDefinition::<C>::TYPE_IDENTIFIER,
+        InputObjectTypeDefinition::<C>::INPUT_IDENTIFIER,
+        EnumTypeDefinition::<C>::ENUM_IDENTIFIER,
+        UnionTypeDefinition::<C>::UNION_IDENTIFIER,
+        InterfaceTypeDefinition::<C>::INTERFACE_IDENTIFIER,
+    ];
+
+    pub fn name(&self) -> &Name<'a> {
+        match self {
+            Self::Scalar(ste) => ste.name(),
+            Self::Object(ote) => ote.name(),
+            Self::InputObject(iote) => iote.name(),
+            Self::Enum(ete) => ete.name(),
+            Self::Union(ute) => ute.name(),
+            Self::Interface(ite) => ite.name(),
+        }
+    }
+
+    /// The keyword following `extend` that identifies the kind of type being extended
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::Scalar(_) => CustomScalarTypeDefinition::<C>::SCALAR_IDENTIFIER,
+            Self::Object(_) => ObjectTypeDefinition::<C>::TYPE_IDENTIFIER,
+            Self::InputObject(_) => InputObjectTypeDefinition::<C>::INPUT_IDENTIFIER,
+            Self::Enum(_) => EnumTypeDefinition::<C>::ENUM_IDENTIFIER,
+            Self::Union(_) => UnionTypeDefinition::<C>::UNION_IDENTIFIER,
+            Self::Interface(_) => InterfaceTypeDefinition::<C>::INTERFACE_IDENTIFIER,
+        }
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for TypeExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        match tokens.peek_name(1).map(AsRef::as_ref) {
+            Some(CustomScalarTypeDefinition::<C>::SCALAR_IDENTIFIER) => {
+                ScalarTypeExtension::from_tokens(tokens, depth_limiter).map(Self::Scalar)
+            }
+            Some(ObjectTypeDefinition::<C>::TYPE_IDENTIFIER) => {
+                ObjectTypeExtension::from_tokens(tokens, depth_limiter).map(Self::Object)
+            }
+            Some(InputObjectTypeDefinition::<C>::INPUT_IDENTIFIER) => {
+                InputObjectTypeExtension::from_tokens(tokens, depth_limiter).map(Self::InputObject)
+            }
+            Some(EnumTypeDefinition::<C>::ENUM_IDENTIFIER) => {
+                EnumTypeExtension::from_tokens(tokens, depth_limiter).map(Self::Enum)
+            }
+            Some(UnionTypeDefinition::<C>::UNION_IDENTIFIER) => {
+                UnionTypeExtension::from_tokens(tokens, depth_limiter).map(Self::Union)
+            }
+            Some(InterfaceTypeDefinition::<C>::INTERFACE_IDENTIFIER) => {
+                InterfaceTypeExtension::from_tokens(tokens, depth_limiter).map(Self::Interface)
+            }
+            _ => {
+                tokens.expect_name_value(Self::EXTEND_IDENTIFIER)?;
+                Err(match tokens.next() {
+                    Some(token) => ParseError::ExpectedOneOf {
+                        span: token.into(),
+                        values: Self::POSSIBLE_KINDS,
+                    },
+                    None => tokens.unexpected_eof(),
+                })
+            }
+        }
+    }
+}
+
+impl<'a, C: Context> From<ScalarTypeExtension<'a, C>> for TypeExtension<'a, C> {
+    fn from(value: ScalarTypeExtension<'a, C>) -> Self {
+        Self::Scalar(value)
+    }
+}
+
+impl<'a, C: Context> From<ObjectTypeExtension<'a, C>> for TypeExtension<'a, C> {
+    fn from(value: ObjectTypeExtension<'a, C>) -> Self {
+        Self::Object(value)
+    }
+}
+
+impl<'a, C: Context> From<InputObjectTypeExtension<'a, C>> for TypeExtension<'a, C> {
+    fn from(value: InputObjectTypeExtension<'a, C>) -> Self {
+        Self::InputObject(value)
+    }
+}
+
+impl<'a, C: Context> From<EnumTypeExtension<'a, C>> for TypeExtension<'a, C> {
+    fn from(value: EnumTypeExtension<'a, C>) -> Self {
+        Self::Enum(value)
+    }
+}
+
+impl<'a, C: Context> From<UnionTypeExtension<'a, C>> for TypeExtension<'a, C> {
+    fn from(value: UnionTypeExtension<'a, C>) -> Self {
+        Self::Union(value)
+    }
+}
+
+impl<'a, C: Context> From<InterfaceTypeExtension<'a, C>> for TypeExtension<'a, C> {
+    fn from(value: InterfaceTypeExtension<'a, C>) -> Self {
+        Self::Interface(value)
+    }
+}
@@ -39,3 +39,24 @@ impl<'a, C: Context> FromTokens<'a> for UnionMemberTypes<'a, C> {
         Ok(Self { union_member_types })
     }
 }
+
+impl<C: Context> UnionMemberTypes<'_, C> {
+    /// Appends the member types of `other` to `self`, returning the
+    /// member types that are already members of `self`, if any
+    pub(crate) fn merge(&mut self, other: Self) -> Option<Self> {
+        let (duplicates, additions): (Vec<_>, Vec<_>) = other
+            .union_member_types
+            .into_iter()
+            .partition(|member_type| {
+                self.union_member_types
+                    .iter()
+                    .any(|existing| existing.name().as_str() == member_type.name().as_str())
+            });
+
+        self.union_member_types.extend(additions);
+
+        (!duplicates.is_empty()).then_some(Self {
+            union_member_types: duplicates,
+        })
+    }
+}
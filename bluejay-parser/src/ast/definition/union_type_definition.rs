@@ -1,7 +1,11 @@
-use crate::ast::definition::{Context, Directives, FieldsDefinition, UnionMemberTypes};
+use crate::ast::definition::{
+    Context, Directives, FieldsDefinition, UnionMemberTypes, UnionTypeExtension,
+};
 use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
 use crate::lexical_token::{Name, PunctuatorType, StringValue};
+use crate::HasSpan;
 use bluejay_core::definition::{HasDirectives, UnionTypeDefinition as CoreUnionTypeDefinition};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct UnionTypeDefinition<'a, C: Context> {
@@ -31,6 +35,10 @@ impl<'a, C: Context> CoreUnionTypeDefinition for UnionTypeDefinition<'a, C> {
     fn fields_definition(&self) -> &Self::FieldsDefinition {
         &self.fields_definition
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl<'a, C: Context> UnionTypeDefinition<'a, C> {
@@ -39,6 +47,24 @@ impl<'a, C: Context> UnionTypeDefinition<'a, C> {
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
+
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    /// Merges the extension into the definition, returning the parts of the extension
+    /// that conflict with the definition, if any
+    pub(crate) fn extend(
+        &mut self,
+        extension: UnionTypeExtension<'a, C>,
+    ) -> Option<UnionTypeExtension<'a, C>> {
+        let name = extension.name().clone();
+        let (directives, member_types) = extension.into_parts();
+        Directives::merge(&mut self.directives, directives);
+        let duplicate_member_types =
+            member_types.and_then(|member_types| self.member_types.merge(member_types));
+        UnionTypeExtension::remainder(name, duplicate_member_types)
+    }
 }
 
 impl<'a, C: Context> FromTokens<'a> for UnionTypeDefinition<'a, C> {
@@ -0,0 +1,70 @@
+use crate::ast::definition::{
+    Context, Directives, TypeExtension, UnionMemberTypes, UnionTypeDefinition,
+};
+use crate::ast::{ConstDirectives, DepthLimiter, FromTokens, ParseError, Tokens, TryFromTokens};
+use crate::lexical_token::{Name, PunctuatorType};
+use crate::HasSpan;
+
+#[derive(Debug)]
+pub struct UnionTypeExtension<'a, C: Context> {
+    name: Name<'a>,
+    directives: Option<Directives<'a, C>>,
+    member_types: Option<UnionMemberTypes<'a, C>>,
+}
+
+impl<'a, C: Context> UnionTypeExtension<'a, C> {
+    pub fn name(&self) -> &Name<'a> {
+        &self.name
+    }
+
+    pub fn directives(&self) -> Option<&Directives<'a, C>> {
+        self.directives.as_ref()
+    }
+
+    pub fn union_member_types(&self) -> Option<&UnionMemberTypes<'a, C>> {
+        self.member_types.as_ref()
+    }
+
+    pub(crate) fn into_parts(self) -> (Option<Directives<'a, C>>, Option<UnionMemberTypes<'a, C>>) {
+        (self.directives, self.member_types)
+    }
+
+    pub(crate) fn remainder(
+        name: Name<'a>,
+        member_types: Option<UnionMemberTypes<'a, C>>,
+    ) -> Option<Self> {
+        member_types.map(|member_types| Self {
+            name,
+            directives: None,
+            member_types: Some(member_types),
+        })
+    }
+}
+
+impl<'a, C: Context> FromTokens<'a> for UnionTypeExtension<'a, C> {
+    fn from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+    ) -> Result<Self, ParseError> {
+        tokens.expect_name_value(TypeExtension::<C>::EXTEND_IDENTIFIER)?;
+        tokens.expect_name_value(UnionTypeDefinition::<C>::UNION_IDENTIFIER)?;
+        let name = tokens.expect_name()?;
+        let directives = ConstDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
+        let member_types = if tokens.next_if_punctuator(PunctuatorType::Equals).is_some() {
+            Some(UnionMemberTypes::from_tokens(
+                tokens,
+                depth_limiter.bump()?,
+            )?)
+        } else {
+            None
+        };
+        if directives.is_none() && member_types.is_none() {
+            return Err(ParseError::EmptyExtension { span: *name.span() });
+        }
+        Ok(Self {
+            name,
+            directives: directives.map(Directives::from),
+            member_types,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use crate::ast::{Arguments, DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, TryFromTokens};
 use crate::lexical_token::{Name, PunctuatorType};
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct Directive<'a, const CONST: bool> {
@@ -51,6 +52,10 @@ impl<'a, const CONST: bool> bluejay_core::Directive<CONST> for Directive<'a, CON
     fn arguments(&self) -> Option<&Self::Arguments> {
         self.arguments.as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl<const CONST: bool> HasSpan for Directive<'_, CONST> {
@@ -5,7 +5,7 @@ use crate::ast::executable::{
 };
 use crate::ast::{
     Argument, Arguments, DepthLimiter, Directive, Directives, Parse, ParseDetails, ParseError,
-    Tokens, TryFromTokens, Value,
+    ParseResilient, ResilientParseDetails, Tokens, TryFromTokens, Value,
 };
 
 #[derive(Debug)]
@@ -42,15 +42,70 @@ impl<'a> ExecutableDocument<'a> {
 impl<'a> Parse<'a> for ExecutableDocument<'a> {
     #[inline]
     fn parse_from_tokens(mut tokens: impl Tokens<'a>, max_depth: usize) -> ParseDetails<Self> {
+        let (instance, errors) = Self::parse_definitions(&mut tokens, max_depth);
+
+        let token_count = tokens.token_count();
+        let lex_errors = tokens.into_errors();
+
+        let errors = if lex_errors.is_empty() {
+            if errors.is_empty() && instance.is_empty() {
+                vec![ParseError::EmptyDocument.into()]
+            } else {
+                errors.into_iter().map(Into::into).collect()
+            }
+        } else {
+            lex_errors.into_iter().map(Into::into).collect()
+        };
+
+        let result = if errors.is_empty() {
+            Ok(instance)
+        } else {
+            Err(errors)
+        };
+
+        ParseDetails::new(result, token_count)
+    }
+}
+
+impl<'a> ParseResilient<'a> for ExecutableDocument<'a> {
+    fn parse_resilient_from_tokens(
+        mut tokens: impl Tokens<'a>,
+        max_depth: usize,
+    ) -> ResilientParseDetails<Self> {
+        let (instance, mut errors) = Self::parse_definitions(&mut tokens, max_depth);
+
+        if errors.is_empty() && instance.is_empty() {
+            errors.push(ParseError::EmptyDocument);
+        }
+
+        let token_count = tokens.token_count();
+        let errors = tokens
+            .into_errors()
+            .into_iter()
+            .map(Into::into)
+            .chain(errors.into_iter().map(Into::into))
+            .collect();
+
+        ResilientParseDetails::new(instance, errors, token_count)
+    }
+}
+
+impl<'a> ExecutableDocument<'a> {
+    /// Parses definitions until the end of the document, skipping the tokens following a
+    /// definition that could not be parsed until the next definition can be
+    fn parse_definitions(
+        tokens: &mut impl Tokens<'a>,
+        max_depth: usize,
+    ) -> (Self, Vec<ParseError>) {
         let mut instance: Self = Self::new(Vec::new(), Vec::new());
         let mut errors = Vec::new();
         let mut last_pass_had_error = false;
 
         loop {
-            last_pass_had_error = match ExecutableDefinition::try_from_tokens(
-                &mut tokens,
-                DepthLimiter::new(max_depth),
-            ) {
+            let definition =
+                ExecutableDefinition::try_from_tokens(tokens, DepthLimiter::new(max_depth));
+            errors.extend(tokens.take_recorded_errors());
+            last_pass_had_error = match definition {
                 Ok(Some(ExecutableDefinition::Operation(operation_definition))) => {
                     instance.operation_definitions.push(operation_definition);
                     false
@@ -83,26 +138,7 @@ impl<'a> Parse<'a> for ExecutableDocument<'a> {
             }
         }
 
-        let token_count = tokens.token_count();
-        let lex_errors = tokens.into_errors();
-
-        let errors = if lex_errors.is_empty() {
-            if errors.is_empty() && instance.is_empty() {
-                vec![ParseError::EmptyDocument.into()]
-            } else {
-                errors.into_iter().map(Into::into).collect()
-            }
-        } else {
-            lex_errors.into_iter().map(Into::into).collect()
-        };
-
-        let result = if errors.is_empty() {
-            Ok(instance)
-        } else {
-            Err(errors)
-        };
-
-        ParseDetails::new(result, token_count)
+        (instance, errors)
     }
 }
 
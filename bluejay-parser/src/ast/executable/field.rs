@@ -4,6 +4,7 @@ use crate::ast::{
 };
 use crate::lexical_token::{Name, PunctuatorType};
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 use std::cmp::{Eq, PartialEq};
 use std::hash::{Hash, Hasher};
 
@@ -129,6 +130,10 @@ impl<'a> bluejay_core::executable::Field for Field<'a> {
     fn selection_set(&self) -> Option<&Self::SelectionSet> {
         self.selection_set.as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl HasSpan for Field<'_> {
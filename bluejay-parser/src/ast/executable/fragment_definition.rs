@@ -3,6 +3,7 @@ use crate::ast::try_from_tokens::TryFromTokens;
 use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, VariableDirectives};
 use crate::lexical_token::{Name, StringValue};
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct FragmentDefinition<'a> {
@@ -99,6 +100,10 @@ impl<'a> bluejay_core::executable::FragmentDefinition for FragmentDefinition<'a>
     fn selection_set(&self) -> &Self::SelectionSet {
         &self.selection_set
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl HasSpan for FragmentDefinition<'_> {
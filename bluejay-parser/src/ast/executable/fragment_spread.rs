@@ -3,6 +3,7 @@ use crate::ast::try_from_tokens::TryFromTokens;
 use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, VariableDirectives};
 use crate::lexical_token::{Name, PunctuatorType};
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct FragmentSpread<'a> {
@@ -57,6 +58,10 @@ impl<'a> bluejay_core::executable::FragmentSpread for FragmentSpread<'a> {
     fn directives(&self) -> Option<&Self::Directives> {
         self.directives.as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.name.span()).into())
+    }
 }
 
 impl HasSpan for FragmentSpread<'_> {
@@ -2,6 +2,7 @@ use crate::ast::executable::{SelectionSet, TypeCondition};
 use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, VariableDirectives};
 use crate::lexical_token::PunctuatorType;
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct InlineFragment<'a> {
@@ -80,6 +81,12 @@ impl<'a> bluejay_core::executable::InlineFragment for InlineFragment<'a> {
     fn selection_set(&self) -> &Self::SelectionSet {
         &self.selection_set
     }
+
+    fn location(&self) -> Option<Location> {
+        // the `...` that starts the inline fragment
+        let start = self.span.byte_range().start;
+        Some(Location::new(start..start + 3))
+    }
 }
 
 impl HasSpan for InlineFragment<'_> {
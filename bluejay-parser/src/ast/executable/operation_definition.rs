@@ -5,6 +5,7 @@ use crate::ast::{
 };
 use crate::lexical_token::{Name, StringValue};
 use crate::{HasSpan, Span};
+use bluejay_core::Location;
 use bluejay_core::{
     executable::{OperationDefinition as CoreOperationDefinition, OperationDefinitionReference},
     Indexable,
@@ -149,6 +150,14 @@ impl<'a> bluejay_core::executable::ExplicitOperationDefinition for ExplicitOpera
     fn selection_set(&self) -> &Self::SelectionSet {
         &self.selection_set
     }
+
+    fn location(&self) -> Option<Location> {
+        let span = match &self.name {
+            Some(name) => name.span(),
+            None => self.operation_type.span(),
+        };
+        Some((*span).into())
+    }
 }
 
 impl<'a> ExplicitOperationDefinition<'a> {
@@ -178,6 +187,12 @@ impl<'a> bluejay_core::executable::ImplicitOperationDefinition for ImplicitOpera
     fn selection_set(&self) -> &Self::SelectionSet {
         &self.selection_set
     }
+
+    fn location(&self) -> Option<Location> {
+        // the `{` that starts the shorthand query
+        let start = self.selection_set.span().byte_range().start;
+        Some(Location::new(start..start + 1))
+    }
 }
 
 impl HasSpan for ImplicitOperationDefinition<'_> {
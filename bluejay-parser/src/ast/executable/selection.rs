@@ -1,6 +1,7 @@
 use crate::ast::executable::{Field, FragmentSpread, InlineFragment};
 use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens};
 use crate::lexical_token::PunctuatorType;
+use crate::{HasSpan, Span};
 use bluejay_core::executable::{Selection as CoreSelection, SelectionReference};
 
 #[derive(Debug)]
@@ -49,3 +50,13 @@ impl<'a> IsMatch<'a> for Selection<'a> {
         Field::is_match(tokens) || tokens.peek_punctuator_matches(0, PunctuatorType::Ellipse)
     }
 }
+
+impl HasSpan for Selection<'_> {
+    fn span(&self) -> &Span {
+        match self {
+            Self::Field(f) => f.span(),
+            Self::FragmentSpread(fs) => fs.span(),
+            Self::InlineFragment(i) => i.span(),
+        }
+    }
+}
@@ -18,6 +18,9 @@ impl<'a> FromTokens<'a> for SelectionSet<'a> {
     ) -> Result<Self, ParseError> {
         let open_span = tokens.expect_punctuator(PunctuatorType::OpenBrace)?;
         let mut selections: Vec<Selection> = Vec::new();
+        if tokens.is_recovering() {
+            return Self::recover_from_tokens(tokens, depth_limiter, open_span, selections);
+        }
         let close_span = loop {
             selections.push(Selection::from_tokens(tokens, depth_limiter.bump()?)?);
             if let Some(close_span) = tokens.next_if_punctuator(PunctuatorType::CloseBrace) {
@@ -29,6 +32,77 @@ impl<'a> FromTokens<'a> for SelectionSet<'a> {
     }
 }
 
+impl<'a> SelectionSet<'a> {
+    /// Parses the selections after the opening brace, recording errors and skipping tokens that
+    /// cannot start a selection instead of failing. A selection set that is not terminated before
+    /// the end of the document ends after its last selection, or before what looks like the start
+    /// of another definition, so that the rest of the document can still be parsed.
+    fn recover_from_tokens(
+        tokens: &mut impl Tokens<'a>,
+        depth_limiter: DepthLimiter,
+        open_span: Span,
+        mut selections: Vec<Selection<'a>>,
+    ) -> Result<Self, ParseError> {
+        // whether a closing brace for this selection set follows, computed on first use
+        let mut is_terminated: Option<bool> = None;
+        let close_span = loop {
+            if let Some(close_span) = tokens.next_if_punctuator(PunctuatorType::CloseBrace) {
+                if selections.is_empty() {
+                    tokens.record_error(ParseError::ExpectedName { span: close_span });
+                }
+                break close_span;
+            } else if tokens.peek_punctuator_matches(0, PunctuatorType::OpenBrace)
+                || (Self::is_at_definition_keyword(tokens)
+                    && !*is_terminated.get_or_insert_with(|| {
+                        tokens.peek_block_is_closed(
+                            PunctuatorType::OpenBrace,
+                            PunctuatorType::CloseBrace,
+                        )
+                    }))
+            {
+                let last_span = selections
+                    .last()
+                    .map_or(open_span, |selection| *selection.span());
+                tokens.record_error(ParseError::ExpectedIdentifier {
+                    span: last_span.empty_at_end(),
+                    value: PunctuatorType::CloseBrace.to_string(),
+                });
+                break last_span;
+            } else if Selection::is_match(tokens) {
+                match Selection::from_tokens(tokens, depth_limiter.bump()?) {
+                    Ok(selection) => selections.push(selection),
+                    Err(ParseError::MaxDepthExceeded) => return Err(ParseError::MaxDepthExceeded),
+                    Err(error) => tokens.record_error(error),
+                }
+            } else if tokens.is_at_eof() {
+                tokens.record_error(tokens.unexpected_eof());
+                break selections
+                    .last()
+                    .map_or(open_span, |selection| *selection.span());
+            } else {
+                let error = tokens.unexpected_token();
+                tokens.record_error(error);
+            }
+        };
+        let span = open_span.merge(&close_span);
+        Ok(Self { selections, span })
+    }
+
+    /// Whether the next tokens look like the start of an operation or fragment definition rather
+    /// than a selection. This also matches a field named like a keyword, e.g. `query { ... }`,
+    /// so it is only used once the selection set is known to be unterminated.
+    fn is_at_definition_keyword(tokens: &mut impl Tokens<'a>) -> bool {
+        let is_operation_keyword = bluejay_core::OperationType::POSSIBLE_VALUES
+            .iter()
+            .any(|value| tokens.peek_name_matches(0, value));
+        (is_operation_keyword
+            && (tokens.peek_name(1).is_some()
+                || tokens.peek_punctuator_matches(1, PunctuatorType::OpenBrace)
+                || tokens.peek_punctuator_matches(1, PunctuatorType::OpenRoundBracket)))
+            || (tokens.peek_name_matches(0, "fragment") && tokens.peek_name(1).is_some())
+    }
+}
+
 impl<'a> IsMatch<'a> for SelectionSet<'a> {
     #[inline]
     fn is_match(tokens: &mut impl Tokens<'a>) -> bool {
@@ -4,6 +4,8 @@ use crate::ast::{
     executable::VariableType, ConstDirectives, ConstValue, FromTokens, ParseError, Tokens,
 };
 use crate::lexical_token::{PunctuatorType, StringValue, Variable};
+use crate::HasSpan;
+use bluejay_core::Location;
 
 #[derive(Debug)]
 pub struct VariableDefinition<'a> {
@@ -79,4 +81,8 @@ impl<'a> bluejay_core::executable::VariableDefinition for VariableDefinition<'a>
     fn default_value(&self) -> Option<&Self::Value> {
         self.default_value.as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        Some((*self.variable.span()).into())
+    }
 }
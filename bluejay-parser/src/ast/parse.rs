@@ -18,6 +18,28 @@ impl<T> ParseDetails<T> {
     }
 }
 
+/// The result of parsing with error recovery, see [ParseResilient]
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct ResilientParseDetails<T> {
+    /// The document, containing every definition that could be parsed. Nodes missing from the
+    /// source are filled in with placeholders, such as [Value::Missing](crate::ast::Value::Missing).
+    pub document: T,
+    /// The lexing and parsing errors, empty if the document was parsed without errors
+    pub errors: Vec<Error>,
+    pub token_count: usize,
+}
+
+impl<T> ResilientParseDetails<T> {
+    pub fn new(document: T, errors: Vec<Error>, token_count: usize) -> Self {
+        Self {
+            document,
+            errors,
+            token_count,
+        }
+    }
+}
+
 pub struct ParseOptions {
     pub graphql_ruby_compatibility: bool,
     pub max_depth: usize,
@@ -53,6 +75,36 @@ pub trait Parse<'a>: Sized {
     fn parse_from_tokens(tokens: impl Tokens<'a>, max_depth: usize) -> ParseDetails<Self>;
 }
 
+/// Parsing that recovers from syntax errors, for tooling that needs a usable document while it is
+/// being edited. Errors are recorded instead of discarding the definition they occur in, and
+/// unterminated selection sets and arguments, as well as missing argument values, are completed
+/// with placeholders.
+pub trait ParseResilient<'a>: Sized {
+    #[inline]
+    fn parse_resilient(s: &'a str) -> ResilientParseDetails<Self> {
+        Self::parse_resilient_with_options(s, Default::default())
+    }
+
+    #[inline]
+    fn parse_resilient_with_options(
+        s: &'a str,
+        options: ParseOptions,
+    ) -> ResilientParseDetails<Self> {
+        let lexer = LogosLexer::new(s)
+            .with_graphql_ruby_compatibility(options.graphql_ruby_compatibility)
+            .with_max_tokens(options.max_tokens);
+        let tokens = LexerTokens::new(lexer).with_error_recovery();
+
+        Self::parse_resilient_from_tokens(tokens, options.max_depth)
+    }
+
+    /// Errors are only recovered from within definitions when `tokens` has error recovery enabled
+    fn parse_resilient_from_tokens(
+        tokens: impl Tokens<'a>,
+        max_depth: usize,
+    ) -> ResilientParseDetails<Self>;
+}
+
 impl<'a, T: FromTokens<'a>> Parse<'a> for T {
     #[inline]
     fn parse_from_tokens(mut tokens: impl Tokens<'a>, max_depth: usize) -> ParseDetails<Self> {
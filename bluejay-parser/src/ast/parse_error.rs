@@ -18,6 +18,9 @@ pub enum ParseError {
     ExpectedName {
         span: Span,
     },
+    ExpectedValue {
+        span: Span,
+    },
     UnexpectedEOF {
         span: Span,
     },
@@ -25,6 +28,9 @@ pub enum ParseError {
         span: Span,
     },
     EmptyDocument,
+    EmptyExtension {
+        span: Span,
+    },
     MaxDepthExceeded,
     MaxTokensExceeded {
         span: Span,
@@ -32,9 +38,29 @@ pub enum ParseError {
     },
 }
 
+impl ParseError {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidEnumValue { .. } => "INVALID_ENUM_VALUE",
+            Self::ExpectedOneOf { .. } => "EXPECTED_ONE_OF",
+            Self::ExpectedIdentifier { .. } => "EXPECTED_IDENTIFIER",
+            Self::ExpectedName { .. } => "EXPECTED_NAME",
+            Self::ExpectedValue { .. } => "EXPECTED_VALUE",
+            Self::UnexpectedEOF { .. } => "UNEXPECTED_EOF",
+            Self::UnexpectedToken { .. } => "UNEXPECTED_TOKEN",
+            Self::EmptyDocument => "EMPTY_DOCUMENT",
+            Self::EmptyExtension { .. } => "EMPTY_EXTENSION",
+            Self::MaxDepthExceeded => "MAX_DEPTH_EXCEEDED",
+            Self::MaxTokensExceeded { .. } => "MAX_TOKENS_EXCEEDED",
+        }
+    }
+}
+
 impl From<ParseError> for Error {
     fn from(val: ParseError) -> Self {
-        match val {
+        let code = val.code();
+        let error = match val {
             ParseError::InvalidEnumValue { span, value } => Self::new(
                 "Parse error",
                 Some(Annotation::new(
@@ -61,6 +87,11 @@ impl From<ParseError> for Error {
                 Some(Annotation::new("Expected a name", span)),
                 Vec::new(),
             ),
+            ParseError::ExpectedValue { span } => Self::new(
+                "Parse error",
+                Some(Annotation::new("Expected a value", span)),
+                Vec::new(),
+            ),
             ParseError::UnexpectedEOF { span } => Self::new(
                 "Parse error",
                 Some(Annotation::new("Unexpected EOF", span)),
@@ -76,6 +107,14 @@ impl From<ParseError> for Error {
                 None,
                 Vec::new(),
             ),
+            ParseError::EmptyExtension { span } => Self::new(
+                "Parse error",
+                Some(Annotation::new(
+                    "Extension must contain at least one directive or definition",
+                    span,
+                )),
+                Vec::new(),
+            ),
             ParseError::MaxDepthExceeded => Self::new("Max depth exceeded", None, Vec::new()),
             ParseError::MaxTokensExceeded { span, limit } => Self::new(
                 "Max tokens exceeded",
@@ -85,6 +124,7 @@ impl From<ParseError> for Error {
                 )),
                 Vec::new(),
             ),
-        }
+        };
+        error.with_code(code)
     }
 }
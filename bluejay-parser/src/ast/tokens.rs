@@ -24,6 +24,15 @@ pub trait Tokens<'a>: Iterator<Item = LexicalToken<'a>> {
     fn peek_name_matches(&mut self, n: usize, name: &str) -> bool;
     fn peek_string_value(&mut self, n: usize) -> bool;
     fn peek_punctuator_matches(&mut self, n: usize, punctuator_type: PunctuatorType) -> bool;
+    fn is_at_eof(&mut self) -> bool;
+    /// Whether a `close` punctuator ending the currently open block, i.e. one not matching an
+    /// `open` punctuator among the upcoming tokens, appears before the end of the document
+    fn peek_block_is_closed(&mut self, open: PunctuatorType, close: PunctuatorType) -> bool;
+    /// Whether parse errors should be recorded with [Tokens::record_error] so that parsing can
+    /// continue with placeholder nodes, instead of being returned
+    fn is_recovering(&self) -> bool;
+    fn record_error(&mut self, error: ParseError);
+    fn take_recorded_errors(&mut self) -> Vec<ParseError>;
     fn into_errors(self) -> Vec<(LexError, Span)>;
     fn token_count(&self) -> usize;
 }
@@ -32,6 +41,7 @@ pub struct LexerTokens<'a, T: Lexer<'a>> {
     lexer: T,
     errors: Vec<(LexError, Span)>,
     buffer: VecDeque<LexicalToken<'a>>,
+    recorded_errors: Option<Vec<ParseError>>,
 }
 
 impl<'a, T: Lexer<'a>> LexerTokens<'a, T> {
@@ -41,9 +51,17 @@ impl<'a, T: Lexer<'a>> LexerTokens<'a, T> {
             lexer,
             errors: Vec::new(),
             buffer: VecDeque::new(),
+            recorded_errors: None,
         }
     }
 
+    /// Enables error recovery, see [Tokens::is_recovering]
+    #[inline]
+    pub fn with_error_recovery(mut self) -> Self {
+        self.recorded_errors = Some(Vec::new());
+        self
+    }
+
     #[inline]
     pub fn token_count(&self) -> usize {
         self.lexer.token_count()
@@ -224,6 +242,59 @@ impl<'a, T: Lexer<'a>> LexerTokens<'a, T> {
     pub fn peek_punctuator_matches(&mut self, n: usize, punctuator_type: PunctuatorType) -> bool {
         matches!(self.peek(n), Some(LexicalToken::Punctuator(p)) if p.r#type() == punctuator_type)
     }
+
+    #[inline]
+    pub fn is_at_eof(&mut self) -> bool {
+        self.peek(0).is_none()
+    }
+
+    pub fn peek_block_is_closed(&mut self, open: PunctuatorType, close: PunctuatorType) -> bool {
+        let mut depth = 0usize;
+        let mut idx = 0;
+        while let Some(token) = self.peek(idx) {
+            if let LexicalToken::Punctuator(punctuator) = token {
+                if punctuator.r#type() == open {
+                    depth += 1;
+                } else if punctuator.r#type() == close {
+                    if depth == 0 {
+                        return true;
+                    }
+                    depth -= 1;
+                }
+            }
+            idx += 1;
+        }
+        false
+    }
+
+    #[inline]
+    pub fn is_recovering(&self) -> bool {
+        self.recorded_errors.is_some()
+    }
+
+    pub fn record_error(&mut self, error: ParseError) {
+        if let Some(recorded_errors) = &mut self.recorded_errors {
+            // every unterminated node reports reaching the end of the document, only keep one
+            let is_repeated_eof = matches!(
+                (recorded_errors.last(), &error),
+                (
+                    Some(ParseError::UnexpectedEOF { .. }),
+                    ParseError::UnexpectedEOF { .. }
+                )
+            );
+            if !is_repeated_eof {
+                recorded_errors.push(error);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn take_recorded_errors(&mut self) -> Vec<ParseError> {
+        self.recorded_errors
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
 }
 
 impl<'a, T: Lexer<'a>> Iterator for LexerTokens<'a, T> {
@@ -328,6 +399,31 @@ impl<'a, T: Lexer<'a>> Tokens<'a> for LexerTokens<'a, T> {
         self.peek_punctuator_matches(n, punctuator_type)
     }
 
+    #[inline]
+    fn is_at_eof(&mut self) -> bool {
+        self.is_at_eof()
+    }
+
+    #[inline]
+    fn peek_block_is_closed(&mut self, open: PunctuatorType, close: PunctuatorType) -> bool {
+        self.peek_block_is_closed(open, close)
+    }
+
+    #[inline]
+    fn is_recovering(&self) -> bool {
+        self.is_recovering()
+    }
+
+    #[inline]
+    fn record_error(&mut self, error: ParseError) {
+        self.record_error(error)
+    }
+
+    #[inline]
+    fn take_recorded_errors(&mut self) -> Vec<ParseError> {
+        self.take_recorded_errors()
+    }
+
     #[inline]
     fn into_errors(self) -> Vec<(LexError, Span)> {
         self.errors
@@ -19,6 +19,9 @@ pub enum Value<'a, const CONST: bool> {
     Enum(Name<'a>),
     List(ListValue<'a, CONST>),
     Object(ObjectValue<'a, CONST>),
+    /// A placeholder for a value missing from the document, only produced when parsing with
+    /// error recovery. It is treated as `null`.
+    Missing(Span),
 }
 
 impl<'a, const CONST: bool> CoreValue<CONST> for Value<'a, CONST> {
@@ -37,6 +40,7 @@ impl<'a, const CONST: bool> CoreValue<CONST> for Value<'a, CONST> {
             Self::Enum(e) => ValueReference::Enum(e.as_ref()),
             Self::List(l) => ValueReference::List(l),
             Self::Object(o) => ValueReference::Object(o),
+            Self::Missing(_) => ValueReference::Null,
         }
     }
 }
@@ -126,6 +130,7 @@ impl<const CONST: bool> HasSpan for Value<'_, CONST> {
             Self::Object(o) => &o.span,
             Self::String(s) => s.span(),
             Self::Variable(v) => v.span(),
+            Self::Missing(span) => span,
         }
     }
 }
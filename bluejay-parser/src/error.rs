@@ -16,6 +16,7 @@ pub struct Error {
     message: Cow<'static, str>,
     primary_annotation: Option<Annotation>,
     secondary_annotations: Vec<Annotation>,
+    code: Option<Cow<'static, str>>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -31,6 +32,32 @@ pub struct Location {
 pub struct GraphQLError {
     pub message: Cow<'static, str>,
     pub locations: Vec<Location>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub path: Option<Vec<PathSegment>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub extensions: Option<GraphQLErrorExtensions>,
+}
+
+/// A segment of the `path` of a [GraphQLError], either a response key or a list index
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(untagged))]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// The `extensions` of a [GraphQLError]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct GraphQLErrorExtensions {
+    /// A stable, machine-readable code identifying the kind of error, e.g. `UNEXPECTED_TOKEN`
+    pub code: Cow<'static, str>,
 }
 
 impl Error {
@@ -43,9 +70,17 @@ impl Error {
             message: message.into(),
             primary_annotation,
             secondary_annotations,
+            code: None,
         }
     }
 
+    /// Sets the machine-readable code of the error, which is exposed as `extensions.code` on the
+    /// errors returned by [Error::into_graphql_errors]
+    pub fn with_code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
     pub fn into_graphql_errors<E: Into<Error>>(
         document: &str,
         errors: impl IntoIterator<Item = E>,
@@ -55,6 +90,7 @@ impl Error {
             .into_iter()
             .flat_map(|err| {
                 let err: Error = err.into();
+                let extensions = err.code.map(|code| GraphQLErrorExtensions { code });
                 if let Some(primary_annotation) = err.primary_annotation {
                     let (line, col) = converter
                         .borrow_mut()
@@ -63,10 +99,13 @@ impl Error {
                     Either::Left(std::iter::once(GraphQLError {
                         message: primary_annotation.message,
                         locations: vec![Location { line, col }],
+                        path: None,
+                        extensions,
                     }))
                 } else if !err.secondary_annotations.is_empty() {
+                    let converter = &converter;
                     Either::Right(err.secondary_annotations.into_iter().map(
-                        |secondary_annotation| {
+                        move |secondary_annotation| {
                             let (line, col) = converter
                                 .borrow_mut()
                                 .convert(secondary_annotation.span())
@@ -74,6 +113,8 @@ impl Error {
                             GraphQLError {
                                 message: secondary_annotation.message,
                                 locations: vec![Location { line, col }],
+                                path: None,
+                                extensions: extensions.clone(),
                             }
                         },
                     ))
@@ -81,6 +122,8 @@ impl Error {
                     Either::Left(std::iter::once(GraphQLError {
                         message: err.message,
                         locations: vec![],
+                        path: None,
+                        extensions,
                     }))
                 }
             })
@@ -148,4 +191,8 @@ impl Error {
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
 }
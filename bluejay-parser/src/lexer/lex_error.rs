@@ -25,16 +25,30 @@ pub enum StringValueLexError {
     InvalidCharacters(Span),
 }
 
+impl LexError {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnrecognizedToken => "UNRECOGNIZED_TOKEN",
+            Self::IntegerValueTooLarge => "INTEGER_VALUE_TOO_LARGE",
+            Self::FloatValueTooLarge => "FLOAT_VALUE_TOO_LARGE",
+            Self::StringValueInvalid(_) => "STRING_VALUE_INVALID",
+            Self::MaxTokensExceeded { .. } => "MAX_TOKENS_EXCEEDED",
+        }
+    }
+}
+
 impl From<(LexError, Span)> for Error {
     fn from((error, span): (LexError, Span)) -> Self {
-        match error {
+        let code = error.code();
+        let error = match error {
             LexError::UnrecognizedToken => Self::new(
                 "Unrecognized token",
                 Some(Annotation::new("Unable to parse", span)),
                 Vec::new(),
             ),
             LexError::IntegerValueTooLarge => Self::new(
-                "Value too large to fit in a 32-bit signed integer",
+                "Value too large to fit in a 64-bit signed integer",
                 Some(Annotation::new("Integer too large", span)),
                 Vec::new(),
             ),
@@ -69,6 +83,7 @@ impl From<(LexError, Span)> for Error {
                 )),
                 Vec::new(),
             ),
-        }
+        };
+        error.with_code(code)
     }
 }
@@ -2,6 +2,7 @@ use crate::lexer::{LexError, Lexer};
 use crate::lexical_token::{
     FloatValue, IntValue, LexicalToken, Name, Punctuator, PunctuatorType, StringValue, Variable,
 };
+use crate::trivia::{Trivia, TriviaKind};
 use crate::Span;
 use logos::Logos;
 use std::borrow::Cow;
@@ -65,7 +66,7 @@ pub(crate) enum Token<'a> {
 
     // IntValue
     #[regex(r"(?&intpart)", parse_integer)]
-    IntValue(i32),
+    IntValue(i64),
 
     // FloatValue
     #[regex(
@@ -106,7 +107,7 @@ fn validate_number_no_trailing_name_start<'a>(
 }
 
 #[inline]
-fn parse_integer<'a>(lexer: &mut logos::Lexer<'a, Token<'a>>) -> Result<i32, LexError> {
+fn parse_integer<'a>(lexer: &mut logos::Lexer<'a, Token<'a>>) -> Result<i64, LexError> {
     validate_number_no_trailing_name_start(lexer).and_then(|_| {
         lexer
             .slice()
@@ -130,6 +131,52 @@ pub struct LogosLexer<'a> {
     token_count: usize,
     max_tokens: Option<usize>,
     exceeded_max_tokens: bool,
+    trivia: Option<TriviaCollector<'a>>,
+}
+
+/// Records the ignored tokens in the gaps between the tokens and errors yielded by the lexer
+struct TriviaCollector<'a> {
+    trivia: Vec<Trivia<'a>>,
+    end_of_previous: usize,
+}
+
+impl<'a> TriviaCollector<'a> {
+    fn collect_until(&mut self, source: &'a str, end: usize) {
+        let mut start = self.end_of_previous;
+        while start < end {
+            let gap = &source[start..end];
+            let first = gap.chars().next().unwrap();
+            let (kind, len) = match first {
+                '#' => (
+                    TriviaKind::Comment,
+                    gap.find(['\n', '\r']).unwrap_or(gap.len()),
+                ),
+                ',' => (TriviaKind::Comma, run_length(gap, |c| c == ',')),
+                '\u{FEFF}' => (
+                    TriviaKind::ByteOrderMark,
+                    run_length(gap, |c| c == '\u{FEFF}'),
+                ),
+                _ => (
+                    TriviaKind::Whitespace,
+                    run_length(gap, |c| matches!(c, '\t' | ' ' | '\n' | '\r')),
+                ),
+            };
+            // the lexer only skips the characters matched above, but never loop forever
+            let len = len.max(first.len_utf8());
+            self.trivia.push(Trivia::new(
+                kind,
+                &gap[..len],
+                Span::new(start..start + len),
+            ));
+            start += len;
+        }
+        self.end_of_previous = self.end_of_previous.max(end);
+    }
+}
+
+#[inline]
+fn run_length(s: &str, f: impl Fn(char) -> bool) -> usize {
+    s.find(|c| !f(c)).unwrap_or(s.len())
 }
 
 impl<'a> Iterator for LogosLexer<'a> {
@@ -141,7 +188,20 @@ impl<'a> Iterator for LogosLexer<'a> {
             return None;
         }
 
-        match self.inner.next() {
+        let next = self.inner.next();
+
+        if let Some(collector) = &mut self.trivia {
+            let source = self.inner.source();
+            let end = if next.is_some() {
+                self.inner.span().start
+            } else {
+                source.len()
+            };
+            collector.collect_until(source, end);
+            collector.end_of_previous = self.inner.span().end.max(collector.end_of_previous);
+        }
+
+        match next {
             Some(Ok(token)) => {
                 self.token_count += 1;
                 let span = Span::new(self.inner.span());
@@ -211,9 +271,26 @@ impl<'a> LogosLexer<'a> {
             token_count: 0,
             max_tokens: None,
             exceeded_max_tokens: false,
+            trivia: None,
         }
     }
 
+    /// Records comments, commas, whitespace and byte order marks instead of discarding them,
+    /// so that they can be retrieved with [Self::into_trivia] once the lexer is exhausted
+    pub fn with_trivia(mut self) -> Self {
+        self.trivia = Some(TriviaCollector {
+            trivia: Vec::new(),
+            end_of_previous: 0,
+        });
+        self
+    }
+
+    pub fn into_trivia(self) -> Vec<Trivia<'a>> {
+        self.trivia
+            .map(|collector| collector.trivia)
+            .unwrap_or_default()
+    }
+
     pub fn with_graphql_ruby_compatibility(mut self, enabled: bool) -> Self {
         self.inner.extras.graphql_ruby_compatibility = enabled;
         self
@@ -360,12 +437,16 @@ mod tests {
         );
         assert_eq!(Some(Ok(Token::IntValue(0))), Token::lexer("0").next());
         assert_eq!(Some(Ok(Token::IntValue(0))), Token::lexer("-0").next());
-        let int_too_positive = (i64::from(i32::MAX) + 1).to_string();
+        assert_eq!(
+            Some(Ok(Token::IntValue(9007199254740993))),
+            Token::lexer("9007199254740993").next()
+        );
+        let int_too_positive = (i128::from(i64::MAX) + 1).to_string();
         assert_eq!(
             Token::lexer(&int_too_positive).next(),
             Some(Err(LexError::IntegerValueTooLarge))
         );
-        let int_too_negative = (i64::from(i32::MIN) - 1).to_string();
+        let int_too_negative = (i128::from(i64::MIN) - 1).to_string();
         assert_eq!(
             Token::lexer(&int_too_negative).next(),
             Some(Err(LexError::IntegerValueTooLarge))
@@ -733,14 +814,14 @@ mod tests {
 
     #[test]
     fn number_edge_cases_test() {
-        // Boundary values for 32-bit signed integers
+        // Boundary values for 64-bit signed integers
         assert_eq!(
-            Some(Ok(Token::IntValue(i32::MAX))),
-            Token::lexer("2147483647").next(),
+            Some(Ok(Token::IntValue(i64::MAX))),
+            Token::lexer("9223372036854775807").next(),
         );
         assert_eq!(
-            Some(Ok(Token::IntValue(i32::MIN))),
-            Token::lexer("-2147483648").next(),
+            Some(Ok(Token::IntValue(i64::MIN))),
+            Token::lexer("-9223372036854775808").next(),
         );
         // A minus sign alone is not a valid token
         assert_eq!(
@@ -3,7 +3,7 @@ use crate::Span;
 
 #[derive(PartialEq, Debug)]
 pub struct IntValue {
-    value: i32,
+    value: i64,
     span: Span,
 }
 
@@ -19,24 +19,24 @@ impl From<IntValue> for Span {
     }
 }
 
-impl From<IntValue> for i32 {
+impl From<IntValue> for i64 {
     fn from(val: IntValue) -> Self {
         val.value
     }
 }
 
 impl IntValue {
-    pub(crate) fn value(&self) -> i32 {
+    pub(crate) fn value(&self) -> i64 {
         self.value
     }
 
-    pub(crate) fn new(value: i32, span: Span) -> Self {
+    pub(crate) fn new(value: i64, span: Span) -> Self {
         Self { value, span }
     }
 }
 
-impl AsRef<i32> for IntValue {
-    fn as_ref(&self) -> &i32 {
+impl AsRef<i64> for IntValue {
+    fn as_ref(&self) -> &i64 {
         &self.value
     }
 }
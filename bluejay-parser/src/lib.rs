@@ -3,6 +3,7 @@ pub mod error;
 mod lexer;
 mod lexical_token;
 mod span;
+pub mod trivia;
 
 pub use ast::ParseDetails;
 pub use error::Error;
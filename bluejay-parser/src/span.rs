@@ -22,6 +22,15 @@ impl Span {
         self.start as usize..(self.start + self.len) as usize
     }
 
+    /// A zero-width span at the end of this span
+    #[inline]
+    pub(crate) fn empty_at_end(&self) -> Self {
+        Self {
+            start: self.start + self.len,
+            len: 0,
+        }
+    }
+
     #[inline]
     pub fn merge(&self, other: &Self) -> Self {
         let start = min(self.start, other.start);
@@ -39,6 +48,12 @@ impl From<Span> for Range<usize> {
     }
 }
 
+impl From<Span> for bluejay_core::Location {
+    fn from(val: Span) -> Self {
+        Self::new(val.byte_range())
+    }
+}
+
 impl From<logos::Span> for Span {
     #[inline]
     fn from(value: logos::Span) -> Self {
@@ -0,0 +1,216 @@
+//! Lossless access to the parts of a document that the parser ignores.
+//!
+//! The AST only retains the significant tokens of a document, with comments, commas and
+//! whitespace discarded by the lexer. [DocumentTrivia] keeps these ignored tokens along with
+//! their spans, so that the original source can be reproduced from the spans of the AST and the
+//! trivia, e.g. to keep user comments when formatting or rewriting a document.
+use crate::lexer::LogosLexer;
+use crate::{HasSpan, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    /// A `#` comment, excluding the line terminator that ends it
+    Comment,
+    /// One or more consecutive commas
+    Comma,
+    /// One or more consecutive spaces, tabs or line terminators
+    Whitespace,
+    /// One or more consecutive Unicode byte order marks
+    ByteOrderMark,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia<'a> {
+    kind: TriviaKind,
+    text: &'a str,
+    span: Span,
+}
+
+impl<'a> Trivia<'a> {
+    pub(crate) fn new(kind: TriviaKind, text: &'a str, span: Span) -> Self {
+        Self { kind, text, span }
+    }
+
+    pub fn kind(&self) -> TriviaKind {
+        self.kind
+    }
+
+    /// The source text of the trivia, including the leading `#` of a comment
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TriviaKind::Comment)
+    }
+
+    fn contains_line_terminator(&self) -> bool {
+        matches!(self.kind, TriviaKind::Whitespace) && self.text.contains(['\n', '\r'])
+    }
+}
+
+impl HasSpan for Trivia<'_> {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+/// All of the trivia of a document, in source order.
+///
+/// Trivia between two tokens is split between them: the trivia up to the first line terminator
+/// trails the preceding token, e.g. a comment on the same line, and the remainder leads the
+/// following token, e.g. comments on the lines above it.
+#[derive(Debug, Clone)]
+pub struct DocumentTrivia<'a> {
+    trivia: Vec<Trivia<'a>>,
+}
+
+impl<'a> DocumentTrivia<'a> {
+    /// Lexes `source` in lossless mode. This is independent of parsing, so the spans of the
+    /// trivia line up with the spans of an AST parsed from the same `source`.
+    pub fn new(source: &'a str) -> Self {
+        let mut lexer = LogosLexer::new(source).with_trivia();
+        lexer.by_ref().for_each(drop);
+        Self {
+            trivia: lexer.into_trivia(),
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Trivia<'a>> {
+        self.trivia.iter()
+    }
+
+    pub fn comments(&self) -> impl Iterator<Item = &Trivia<'a>> {
+        self.trivia.iter().filter(|trivia| trivia.is_comment())
+    }
+
+    /// The trivia preceding `span` that belongs to it, typically the comments on the lines above
+    /// a definition or selection
+    pub fn leading(&self, span: &Span) -> &[Trivia<'a>] {
+        let start = span.byte_range().start;
+        let end_index = self
+            .trivia
+            .partition_point(|trivia| trivia.span.byte_range().end <= start);
+        let mut start_index = end_index;
+        let mut cursor = start;
+        while start_index > 0 && self.trivia[start_index - 1].span.byte_range().end == cursor {
+            start_index -= 1;
+            cursor = self.trivia[start_index].span.byte_range().start;
+        }
+        let gap = &self.trivia[start_index..end_index];
+        if cursor == 0 {
+            // nothing precedes the gap, so all of it leads `span`
+            return gap;
+        }
+        match gap
+            .iter()
+            .position(|trivia| trivia.contains_line_terminator())
+        {
+            Some(split) => &gap[split..],
+            None => &[],
+        }
+    }
+
+    /// The trivia following `span` on the same line, typically a comment after a field
+    pub fn trailing(&self, span: &Span) -> &[Trivia<'a>] {
+        let end = span.byte_range().end;
+        let start_index = self
+            .trivia
+            .partition_point(|trivia| trivia.span.byte_range().start < end);
+        let mut end_index = start_index;
+        let mut cursor = end;
+        while end_index < self.trivia.len() {
+            let trivia = &self.trivia[end_index];
+            if trivia.span.byte_range().start != cursor || trivia.contains_line_terminator() {
+                break;
+            }
+            cursor = trivia.span.byte_range().end;
+            end_index += 1;
+        }
+        &self.trivia[start_index..end_index]
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b DocumentTrivia<'a> {
+    type Item = &'b Trivia<'a>;
+    type IntoIter = std::slice::Iter<'b, Trivia<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocumentTrivia, Trivia, TriviaKind};
+    use crate::ast::executable::ExecutableDocument;
+    use crate::ast::Parse;
+    use crate::lexer::LogosLexer;
+    use crate::HasSpan;
+    use bluejay_core::executable::{OperationDefinition, Selection, SelectionReference};
+    use bluejay_core::AsIter;
+
+    #[test]
+    fn test_source_is_reproducible() {
+        let source = "\u{FEFF}# leading\nquery($a: Int = 1,, $b: [String]) {\r\n\tdog(a: $a, b: \"#not a comment\") { name } # trailing\n}\n# end";
+        let mut lexer = LogosLexer::new(source).with_trivia();
+        let mut spans: Vec<_> = lexer
+            .by_ref()
+            .map(|token| token.unwrap().span().byte_range())
+            .collect();
+        let trivia = lexer.into_trivia();
+        spans.extend(trivia.iter().map(|trivia| trivia.span().byte_range()));
+        spans.sort_by_key(|range| range.start);
+
+        let reproduced: String = spans.into_iter().map(|range| &source[range]).collect();
+        assert_eq!(source, reproduced);
+
+        let comments: Vec<_> = trivia
+            .iter()
+            .filter(|trivia| trivia.is_comment())
+            .map(|trivia| trivia.text())
+            .collect();
+        assert_eq!(vec!["# leading", "# trailing", "# end"], comments);
+        assert_eq!(TriviaKind::ByteOrderMark, trivia[0].kind());
+        assert!(trivia
+            .iter()
+            .any(|trivia| trivia.kind() == TriviaKind::Comma && trivia.text() == ",,"));
+    }
+
+    fn comments<'a>(trivia: &[Trivia<'a>]) -> Vec<&'a str> {
+        trivia
+            .iter()
+            .filter(|trivia| trivia.is_comment())
+            .map(|trivia| trivia.text())
+            .collect()
+    }
+
+    #[test]
+    fn test_leading_and_trailing() {
+        let source = "# operation\nquery {\n  # first line\n  # second line\n  dog # the dog\n  cat, # the cat\n}";
+        let document = ExecutableDocument::parse(source).result.unwrap();
+        let trivia = DocumentTrivia::new(source);
+        let operation_definition = document.operation_definitions()[0].as_ref();
+        let selections: Vec<_> = operation_definition.selection_set().iter().collect();
+
+        let field_span = |index: usize| match selections[index].as_ref() {
+            SelectionReference::Field(field) => field.span(),
+            _ => panic!("Expected a field"),
+        };
+
+        let dog = field_span(0);
+        assert_eq!(
+            vec!["# first line", "# second line"],
+            comments(trivia.leading(dog))
+        );
+        assert_eq!(vec!["# the dog"], comments(trivia.trailing(dog)));
+
+        let cat = field_span(1);
+        assert!(comments(trivia.leading(cat)).is_empty());
+        assert_eq!(vec!["# the cat"], comments(trivia.trailing(cat)));
+
+        let query = document.operation_definitions()[0].span();
+        assert_eq!(vec!["# operation"], comments(trivia.leading(query)));
+        assert_eq!(5, trivia.comments().count());
+    }
+}
@@ -1,8 +1,12 @@
 use std::borrow::Cow;
 
 use bluejay_parser::{
-    ast::{executable::ExecutableDocument, Parse},
-    error::{GraphQLError, Location},
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    },
+    error::{GraphQLError, GraphQLErrorExtensions, Location},
     Error,
 };
 
@@ -19,6 +23,43 @@ fn test_parser_errors() {
     let expected: Vec<GraphQLError> = vec![GraphQLError {
         message: Cow::from("Expected a name"),
         locations: vec![Location { line: 2, col: 15 }],
+        path: None,
+        extensions: Some(GraphQLErrorExtensions {
+            code: Cow::from("EXPECTED_NAME"),
+        }),
     }];
     assert_eq!(expected, graphql_errors);
 }
+
+#[test]
+fn test_lexer_error_code() {
+    let source = "{ field(arg: 99999999999999999999) }";
+    let document = ExecutableDocument::parse(source);
+
+    let document_errors = document.result.unwrap_err();
+    let graphql_errors = Error::into_graphql_errors(source, document_errors);
+    let codes: Vec<_> = graphql_errors
+        .iter()
+        .filter_map(|error| error.extensions.as_ref())
+        .map(|extensions| extensions.code.as_ref())
+        .collect();
+    assert_eq!(vec!["INTEGER_VALUE_TOO_LARGE"], codes);
+}
+
+#[test]
+fn test_definition_document_error_code() {
+    let source = "type Query { field: Missing }";
+    let document: DefinitionDocument = DefinitionDocument::parse(source).result.unwrap();
+
+    let document_errors = SchemaDefinition::try_from(&document)
+        .unwrap_err()
+        .into_iter()
+        .map(Error::from);
+    let graphql_errors = Error::into_graphql_errors(source, document_errors);
+    let codes: Vec<_> = graphql_errors
+        .iter()
+        .filter_map(|error| error.extensions.as_ref())
+        .map(|extensions| extensions.code.as_ref())
+        .collect();
+    assert_eq!(vec!["REFERENCED_TYPE_DOES_NOT_EXIST"], codes);
+}
@@ -0,0 +1,210 @@
+use bluejay_core::definition::{
+    prelude::*, SchemaDefinition as CoreSchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    ExplicitOperationDefinition as _, Field as _, FragmentDefinition as _,
+    ImplicitOperationDefinition as _, InlineFragment as _, OperationDefinition as _,
+    OperationDefinitionReference, Selection as _, SelectionReference, VariableDefinition as _,
+};
+use bluejay_core::{Argument as _, AsIter, Directive as _, Location};
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+
+fn source_text(source: &str, location: Option<Location>) -> Option<&str> {
+    location.map(|location| &source[location.byte_range()])
+}
+
+#[test]
+fn test_executable_document_locations() {
+    let source = "query Dog($id: ID!) { dog(id: $id) @include(if: true) { ...DogFields } }\nfragment DogFields on Dog { name }";
+    let document = ExecutableDocument::parse(source)
+        .result
+        .expect("Document had parse errors");
+
+    let operation_definition = &document.operation_definitions()[0];
+    assert_eq!(
+        Some("Dog"),
+        source_text(source, operation_definition.location()),
+    );
+    let operation_definition = operation_definition.as_ref();
+    assert_eq!(
+        Some("$id"),
+        source_text(
+            source,
+            operation_definition
+                .variable_definitions()
+                .and_then(|variable_definitions| variable_definitions.iter().next())
+                .and_then(|variable_definition| variable_definition.location()),
+        ),
+    );
+
+    let dog = match operation_definition
+        .selection_set()
+        .iter()
+        .next()
+        .unwrap()
+        .as_ref()
+    {
+        SelectionReference::Field(field) => field,
+        _ => panic!("Expected a field"),
+    };
+    assert_eq!(Some("dog"), source_text(source, dog.location()),);
+    let argument = dog.arguments().unwrap().iter().next().unwrap();
+    assert_eq!("id", argument.name().as_ref());
+    assert_eq!(Some("id"), source_text(source, argument.location()));
+    let directive = dog.directives().unwrap().iter().next().unwrap();
+    assert_eq!(Some("include"), source_text(source, directive.location()),);
+
+    let fragment_spread = dog.selection_set().unwrap().iter().next().unwrap().as_ref();
+    assert_eq!(
+        Some(source.find("DogFields }").unwrap()),
+        fragment_spread.location().map(|location| location.start()),
+    );
+
+    assert_eq!(
+        Some(source.find("DogFields on").unwrap()),
+        document.fragment_definitions()[0]
+            .location()
+            .map(|location| location.start()),
+    );
+}
+
+#[test]
+fn test_unnamed_executable_locations() {
+    let source = "{ dog { ... on Dog { name } } }\nquery { dog { name } }";
+    let document = ExecutableDocument::parse(source)
+        .result
+        .expect("Document had parse errors");
+
+    let implicit = &document.operation_definitions()[0];
+    assert_eq!(
+        Some(0..1),
+        implicit.location().map(|location| location.byte_range()),
+    );
+    let OperationDefinitionReference::Implicit(implicit) = implicit.as_ref() else {
+        panic!("Expected an implicit operation");
+    };
+    let SelectionReference::Field(dog) = implicit.selection_set().iter().next().unwrap().as_ref()
+    else {
+        panic!("Expected a field");
+    };
+    let SelectionReference::InlineFragment(inline_fragment) =
+        dog.selection_set().unwrap().iter().next().unwrap().as_ref()
+    else {
+        panic!("Expected an inline fragment");
+    };
+    assert_eq!(Some("..."), source_text(source, inline_fragment.location()),);
+
+    let OperationDefinitionReference::Explicit(explicit) =
+        document.operation_definitions()[1].as_ref()
+    else {
+        panic!("Expected an explicit operation");
+    };
+    assert_eq!(Some("query"), source_text(source, explicit.location()));
+}
+
+#[test]
+fn test_definition_locations() {
+    let source = "type Query {\n  dog(name: String): Dog\n}\n\ntype Dog {\n  name: String\n}\n\nenum Size { SMALL LARGE }";
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(source)
+        .result
+        .expect("Document had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+
+    let query = schema_definition.query();
+    assert_eq!(Some("Query"), source_text(source, query.location()));
+    let dog = query.fields_definition().get("dog").unwrap();
+    assert_eq!(Some("dog"), source_text(source, dog.location()));
+    assert_eq!(
+        Some("name"),
+        source_text(
+            source,
+            dog.arguments_definition()
+                .and_then(|arguments_definition| arguments_definition.get("name"))
+                .and_then(|input_value_definition| input_value_definition.location()),
+        ),
+    );
+    assert_eq!(
+        Some(source.find("Dog {").unwrap()),
+        schema_definition
+            .get_type_definition("Dog")
+            .and_then(|type_definition| type_definition.location())
+            .map(|location| location.start()),
+    );
+
+    let Some(TypeDefinitionReference::Enum(size)) = schema_definition.get_type_definition("Size")
+    else {
+        panic!("Expected an enum");
+    };
+    assert_eq!(
+        Some("LARGE"),
+        source_text(
+            source,
+            size.enum_value_definitions()
+                .iter()
+                .nth(1)
+                .and_then(|enum_value_definition| enum_value_definition.location()),
+        ),
+    );
+}
+
+#[test]
+fn test_builtin_definitions_have_no_location() {
+    let source = "type Query { name: String }";
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(source)
+        .result
+        .expect("Document had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+
+    let query = schema_definition.query();
+    for field_name in ["__typename", "__schema", "__type"] {
+        let field_definition = query.fields_definition().get(field_name).unwrap();
+        assert_eq!(None, field_definition.location(), "{field_name}");
+    }
+    assert_eq!(
+        None,
+        query
+            .fields_definition()
+            .get("__type")
+            .and_then(|field_definition| field_definition.arguments_definition())
+            .and_then(|arguments_definition| arguments_definition.get("name"))
+            .and_then(|input_value_definition| input_value_definition.location()),
+    );
+
+    let Some(TypeDefinitionReference::Object(schema)) =
+        schema_definition.get_type_definition("__Schema")
+    else {
+        panic!("Expected an object");
+    };
+    assert_eq!(None, schema.location());
+    assert!(schema
+        .fields_definition()
+        .iter()
+        .all(|field_definition| field_definition.location().is_none()));
+
+    let Some(TypeDefinitionReference::Enum(type_kind)) =
+        schema_definition.get_type_definition("__TypeKind")
+    else {
+        panic!("Expected an enum");
+    };
+    assert_eq!(None, type_kind.location());
+    assert!(type_kind
+        .enum_value_definitions()
+        .iter()
+        .all(|enum_value_definition| enum_value_definition.location().is_none()));
+
+    let deprecated = schema_definition
+        .get_directive_definition("deprecated")
+        .unwrap();
+    assert_eq!(None, deprecated.location());
+    assert!(deprecated
+        .arguments_definition()
+        .unwrap()
+        .iter()
+        .all(|argument_definition| argument_definition.location().is_none()));
+}
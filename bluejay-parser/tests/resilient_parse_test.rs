@@ -0,0 +1,204 @@
+use bluejay_core::definition::SchemaDefinition as _;
+use bluejay_core::executable::{OperationDefinition as _, Selection as _, SelectionReference};
+use bluejay_core::{AsIter, ValueReference};
+use bluejay_parser::{
+    ast::{
+        definition::{DefaultContext, DefinitionDocument, SchemaDefinition},
+        executable::{ExecutableDocument, Field, SelectionSet},
+        Parse, ParseResilient, ResilientParseDetails, Value,
+    },
+    Error,
+};
+
+fn error_messages(source: &str, errors: Vec<Error>) -> Vec<String> {
+    Error::into_graphql_errors(source, errors)
+        .into_iter()
+        .map(|error| error.message.into_owned())
+        .collect()
+}
+
+fn field_names<'a>(selection_set: &'a SelectionSet<'a>) -> Vec<&'a str> {
+    selection_set
+        .iter()
+        .filter_map(|selection| match selection.as_ref() {
+            SelectionReference::Field(field) => Some(field.name().as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn root_field<'a>(document: &'a ExecutableDocument<'a>) -> &'a Field<'a> {
+    let operation_definition = document
+        .operation_definitions()
+        .first()
+        .expect("Document had no operation definitions");
+    match operation_definition
+        .as_ref()
+        .selection_set()
+        .iter()
+        .next()
+        .map(|selection| selection.as_ref())
+    {
+        Some(SelectionReference::Field(field)) => field,
+        _ => panic!("Operation did not select a field"),
+    }
+}
+
+#[test]
+fn test_unterminated_selection_sets() {
+    let source = "query { dog { name owner { name";
+    let parsed = ExecutableDocument::parse_resilient(source);
+
+    assert_eq!(
+        vec!["Unexpected EOF"],
+        error_messages(source, parsed.errors)
+    );
+    let dog = root_field(&parsed.document);
+    assert_eq!(
+        vec!["name", "owner"],
+        field_names(dog.selection_set().unwrap())
+    );
+    assert!(ExecutableDocument::parse(source).result.is_err());
+}
+
+#[test]
+fn test_unterminated_selection_set_before_definitions() {
+    let source =
+        "query A { dog { name }\nquery B { cat { name } }\nfragment F on Dog { name }\n{ owner }";
+    let parsed = ExecutableDocument::parse_resilient(source);
+
+    assert_eq!(
+        vec!["Expected to find: }"],
+        error_messages(source, parsed.errors)
+    );
+    let operation_names: Vec<_> = parsed
+        .document
+        .operation_definitions()
+        .iter()
+        .map(|operation_definition| operation_definition.as_ref().name())
+        .collect();
+    assert_eq!(vec![Some("A"), Some("B"), None], operation_names);
+    assert_eq!(vec!["dog"], {
+        let operation_definition = parsed.document.operation_definitions()[0].as_ref();
+        field_names(operation_definition.selection_set())
+    });
+    assert_eq!(1, parsed.document.fragment_definitions().len());
+}
+
+#[test]
+fn test_fields_named_like_keywords() {
+    for source in [
+        "{ search { query { id } } }",
+        "{ fragment name }",
+        "{ a { query(first: 1) { id } } b }",
+        "query A { mutation subscription { id } }",
+    ] {
+        let parsed = ExecutableDocument::parse_resilient(source);
+        let strict = ExecutableDocument::parse(source)
+            .result
+            .expect("Document had parse errors");
+
+        assert!(parsed.errors.is_empty(), "{source}");
+        assert_eq!(
+            format!("{strict:?}"),
+            format!("{:?}", parsed.document),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn test_missing_argument_values() {
+    let source = "{ dog(name: , age: 1, nickname: ) { name } }";
+    let parsed = ExecutableDocument::parse_resilient(source);
+
+    assert_eq!(
+        vec!["Expected a value", "Expected a value"],
+        error_messages(source, parsed.errors),
+    );
+    let dog = root_field(&parsed.document);
+    let arguments: Vec<_> = dog
+        .arguments()
+        .unwrap()
+        .iter()
+        .map(|argument| (argument.name().as_ref(), argument.value()))
+        .collect();
+    assert_eq!(3, arguments.len());
+    assert!(matches!(arguments[0], ("name", Value::Missing(_))));
+    assert!(matches!(
+        bluejay_core::Value::as_ref(arguments[1].1),
+        ValueReference::Integer(1)
+    ));
+    assert!(matches!(arguments[2], ("nickname", Value::Missing(_))));
+    assert_eq!(vec!["name"], field_names(dog.selection_set().unwrap()));
+}
+
+#[test]
+fn test_unterminated_arguments() {
+    let source = "{ dog(name: \"Fido\" { name } }";
+    let parsed = ExecutableDocument::parse_resilient(source);
+
+    assert_eq!(
+        vec!["Expected to find: )"],
+        error_messages(source, parsed.errors)
+    );
+    let dog = root_field(&parsed.document);
+    assert_eq!(1, dog.arguments().unwrap().len());
+    assert_eq!(vec!["name"], field_names(dog.selection_set().unwrap()));
+}
+
+#[test]
+fn test_skips_invalid_selections() {
+    let source = "{ dog { name ! owner { } barkVolume } } fragment F on Dog { name }";
+    let parsed = ExecutableDocument::parse_resilient(source);
+
+    assert_eq!(
+        vec!["Unexpected token", "Expected a name"],
+        error_messages(source, parsed.errors),
+    );
+    let dog = root_field(&parsed.document);
+    assert_eq!(
+        vec!["name", "owner", "barkVolume"],
+        field_names(dog.selection_set().unwrap())
+    );
+    assert_eq!(1, parsed.document.fragment_definitions().len());
+}
+
+#[test]
+fn test_valid_document() {
+    let source = "query($id: ID!) { dog(id: $id) { name } }";
+    let parsed = ExecutableDocument::parse_resilient(source);
+
+    assert!(parsed.errors.is_empty());
+    assert_eq!(1, parsed.document.operation_definitions().len());
+}
+
+#[test]
+fn test_error_documents() {
+    insta::glob!("test_data/executable_document/error/*.graphql", |path| {
+        let input = std::fs::read_to_string(path).unwrap();
+        let parsed = ExecutableDocument::parse_resilient(input.as_str());
+        assert!(
+            !parsed.errors.is_empty(),
+            "Document `{}` did not have any errors",
+            path.display()
+        );
+    });
+}
+
+#[test]
+fn test_partial_definition_document() {
+    let source = "type Query { a: String } type Broken { b: } type Other { c: Int }";
+    let parsed: ResilientParseDetails<DefinitionDocument> =
+        DefinitionDocument::parse_resilient(source);
+
+    assert_eq!(1, parsed.errors.len());
+    assert_eq!(2, parsed.document.definition_count());
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&parsed.document).expect("Schema had errors");
+    assert!(schema_definition.get_type_definition("Other").is_some());
+    assert!(schema_definition.get_type_definition("Broken").is_none());
+    assert!(DefinitionDocument::<DefaultContext>::parse(source)
+        .result
+        .is_err());
+}
@@ -5,8 +5,10 @@ use bluejay_core::definition::{
     ObjectTypeDefinition, OutputType, SchemaDefinition, ShallowInputTypeReference,
     ShallowOutputTypeReference, TypeDefinitionReference, UnionTypeDefinition,
 };
-use bluejay_core::{Argument, AsIter, Directive, Value};
+use bluejay_core::{Argument, AsIter, Directive, OperationType, Value};
 use bluejay_printer::value::ValuePrinter;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use strum::AsRefStr;
 
@@ -18,7 +20,12 @@ pub enum Criticality {
 }
 
 /// Numeric ordering: Breaking(2) > Dangerous(1) > Safe(0)
-#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "SCREAMING_SNAKE_CASE")
+)]
 pub enum CriticalityLevel {
     Safe = 0,
     Dangerous = 1,
@@ -88,186 +95,199 @@ impl Criticality {
 }
 
 #[derive(AsRefStr)]
-pub enum Change<'a, S: SchemaDefinition> {
+pub enum Change<'a, O: SchemaDefinition, N: SchemaDefinition = O> {
     TypeRemoved {
-        removed_type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+        removed_type_definition: TypeDefinitionReference<'a, O::TypeDefinition>,
     },
     TypeAdded {
-        added_type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+        added_type_definition: TypeDefinitionReference<'a, N::TypeDefinition>,
     },
     TypeKindChanged {
-        old_type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
-        new_type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+        old_type_definition: TypeDefinitionReference<'a, O::TypeDefinition>,
+        new_type_definition: TypeDefinitionReference<'a, N::TypeDefinition>,
     },
     TypeDescriptionChanged {
-        old_type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
-        new_type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+        old_type_definition: TypeDefinitionReference<'a, O::TypeDefinition>,
+        new_type_definition: TypeDefinitionReference<'a, N::TypeDefinition>,
     },
     FieldAdded {
-        added_field_definition: &'a S::FieldDefinition,
+        added_field_definition: &'a N::FieldDefinition,
         type_name: &'a str,
     },
     FieldRemoved {
-        removed_field_definition: &'a S::FieldDefinition,
+        removed_field_definition: &'a O::FieldDefinition,
         type_name: &'a str,
     },
     FieldDescriptionChanged {
         type_name: &'a str,
-        old_field_definition: &'a S::FieldDefinition,
-        new_field_definition: &'a S::FieldDefinition,
+        old_field_definition: &'a O::FieldDefinition,
+        new_field_definition: &'a N::FieldDefinition,
     },
     FieldTypeChanged {
         type_name: &'a str,
-        old_field_definition: &'a S::FieldDefinition,
-        new_field_definition: &'a S::FieldDefinition,
+        old_field_definition: &'a O::FieldDefinition,
+        new_field_definition: &'a N::FieldDefinition,
     },
     FieldArgumentAdded {
         type_name: &'a str,
-        field_definition: &'a S::FieldDefinition,
-        argument_definition: &'a S::InputValueDefinition,
+        field_definition: &'a N::FieldDefinition,
+        argument_definition: &'a N::InputValueDefinition,
     },
     FieldArgumentRemoved {
         type_name: &'a str,
-        field_definition: &'a S::FieldDefinition,
-        argument_definition: &'a S::InputValueDefinition,
+        field_definition: &'a O::FieldDefinition,
+        argument_definition: &'a O::InputValueDefinition,
     },
     FieldArgumentDescriptionChanged {
         type_name: &'a str,
-        field_definition: &'a S::FieldDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        field_definition: &'a O::FieldDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     },
     FieldArgumentDefaultValueChanged {
         type_name: &'a str,
-        field_definition: &'a S::FieldDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        field_definition: &'a O::FieldDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     },
     FieldArgumentTypeChanged {
         type_name: &'a str,
-        field_definition: &'a S::FieldDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        field_definition: &'a O::FieldDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     },
     ObjectInterfaceAddition {
-        object_type_definition: &'a S::ObjectTypeDefinition,
-        interface_implementation: &'a S::InterfaceImplementation,
+        object_type_definition: &'a N::ObjectTypeDefinition,
+        interface_implementation: &'a N::InterfaceImplementation,
     },
     ObjectInterfaceRemoval {
-        object_type_definition: &'a S::ObjectTypeDefinition,
-        interface_implementation: &'a S::InterfaceImplementation,
+        object_type_definition: &'a O::ObjectTypeDefinition,
+        interface_implementation: &'a O::InterfaceImplementation,
     },
     EnumValueAdded {
-        enum_type_definition: &'a S::EnumTypeDefinition,
-        enum_value_definition: &'a S::EnumValueDefinition,
+        enum_type_definition: &'a N::EnumTypeDefinition,
+        enum_value_definition: &'a N::EnumValueDefinition,
     },
     EnumValueRemoved {
-        enum_type_definition: &'a S::EnumTypeDefinition,
-        enum_value_definition: &'a S::EnumValueDefinition,
+        enum_type_definition: &'a O::EnumTypeDefinition,
+        enum_value_definition: &'a O::EnumValueDefinition,
     },
     EnumValueDescriptionChanged {
-        enum_type_definition: &'a S::EnumTypeDefinition,
-        old_enum_value_definition: &'a S::EnumValueDefinition,
-        new_enum_value_definition: &'a S::EnumValueDefinition,
+        enum_type_definition: &'a O::EnumTypeDefinition,
+        old_enum_value_definition: &'a O::EnumValueDefinition,
+        new_enum_value_definition: &'a N::EnumValueDefinition,
     },
     UnionMemberAdded {
-        union_type_definition: &'a S::UnionTypeDefinition,
-        union_member_type: &'a S::UnionMemberType,
+        union_type_definition: &'a N::UnionTypeDefinition,
+        union_member_type: &'a N::UnionMemberType,
     },
     UnionMemberRemoved {
-        union_type_definition: &'a S::UnionTypeDefinition,
-        union_member_type: &'a S::UnionMemberType,
+        union_type_definition: &'a O::UnionTypeDefinition,
+        union_member_type: &'a O::UnionMemberType,
     },
     InputFieldAdded {
-        input_object_type_definition: &'a S::InputObjectTypeDefinition,
-        added_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a N::InputObjectTypeDefinition,
+        added_field_definition: &'a N::InputValueDefinition,
     },
     InputFieldRemoved {
-        input_object_type_definition: &'a S::InputObjectTypeDefinition,
-        removed_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a O::InputObjectTypeDefinition,
+        removed_field_definition: &'a O::InputValueDefinition,
     },
     InputFieldDescriptionChanged {
-        input_object_type_definition: &'a S::InputObjectTypeDefinition,
-        old_field_definition: &'a S::InputValueDefinition,
-        new_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a O::InputObjectTypeDefinition,
+        old_field_definition: &'a O::InputValueDefinition,
+        new_field_definition: &'a N::InputValueDefinition,
     },
     InputFieldTypeChanged {
-        input_object_type_definition: &'a S::InputObjectTypeDefinition,
-        old_field_definition: &'a S::InputValueDefinition,
-        new_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a O::InputObjectTypeDefinition,
+        old_field_definition: &'a O::InputValueDefinition,
+        new_field_definition: &'a N::InputValueDefinition,
     },
     InputFieldDefaultValueChanged {
-        input_object_type_definition: &'a S::InputObjectTypeDefinition,
-        old_field_definition: &'a S::InputValueDefinition,
-        new_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a O::InputObjectTypeDefinition,
+        old_field_definition: &'a O::InputValueDefinition,
+        new_field_definition: &'a N::InputValueDefinition,
     },
     DirectiveDefinitionAdded {
-        directive_definition: &'a S::DirectiveDefinition,
+        directive_definition: &'a N::DirectiveDefinition,
     },
     DirectiveDefinitionRemoved {
-        directive_definition: &'a S::DirectiveDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
     },
     DirectiveDefinitionLocationAdded {
-        directive_definition: &'a S::DirectiveDefinition,
+        directive_definition: &'a N::DirectiveDefinition,
         location: &'a DirectiveLocation,
     },
     DirectiveDefinitionLocationRemoved {
-        directive_definition: &'a S::DirectiveDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
         location: &'a DirectiveLocation,
     },
     DirectiveDefinitionDescriptionChanged {
-        old_directive_definition: &'a S::DirectiveDefinition,
-        new_directive_definition: &'a S::DirectiveDefinition,
+        old_directive_definition: &'a O::DirectiveDefinition,
+        new_directive_definition: &'a N::DirectiveDefinition,
     },
     DirectiveDefinitionArgumentAdded {
-        directive_definition: &'a S::DirectiveDefinition,
-        argument_definition: &'a S::InputValueDefinition,
+        directive_definition: &'a N::DirectiveDefinition,
+        argument_definition: &'a N::InputValueDefinition,
     },
     DirectiveDefinitionArgumentRemoved {
-        directive_definition: &'a S::DirectiveDefinition,
-        argument_definition: &'a S::InputValueDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
+        argument_definition: &'a O::InputValueDefinition,
     },
     DirectiveDefinitionArgumentDescriptionChanged {
-        directive_definition: &'a S::DirectiveDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     },
     DirectiveDefinitionArgumentDefaultValueChanged {
-        directive_definition: &'a S::DirectiveDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     },
     DirectiveDefinitionArgumentTypeChanged {
-        directive_definition: &'a S::DirectiveDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     },
     DirectiveAdded {
         location: DirectiveLocation,
         member_name: &'a str,
-        directive: &'a S::Directive,
+        directive: &'a N::Directive,
     },
     DirectiveRemoved {
         location: DirectiveLocation,
         member_name: &'a str,
-        directive: &'a S::Directive,
+        directive: &'a O::Directive,
     },
     DirectiveArgumentAdded {
-        directive: &'a S::Directive,
-        argument: &'a ArgumentForDirective<S>,
+        directive: &'a N::Directive,
+        argument: &'a ArgumentForDirective<N>,
     },
     DirectiveArgumentRemoved {
-        directive: &'a S::Directive,
-        argument: &'a ArgumentForDirective<S>,
+        directive: &'a O::Directive,
+        argument: &'a ArgumentForDirective<O>,
     },
     DirectiveArgumentValueChanged {
-        directive: &'a S::Directive,
-        old_argument: &'a ArgumentForDirective<S>,
-        new_argument: &'a ArgumentForDirective<S>,
+        directive: &'a O::Directive,
+        old_argument: &'a ArgumentForDirective<O>,
+        new_argument: &'a ArgumentForDirective<N>,
+    },
+    RootOperationTypeAdded {
+        operation_type: OperationType,
+        new_type_definition: &'a N::ObjectTypeDefinition,
+    },
+    RootOperationTypeRemoved {
+        operation_type: OperationType,
+        old_type_definition: &'a O::ObjectTypeDefinition,
+    },
+    RootOperationTypeChanged {
+        operation_type: OperationType,
+        old_type_definition: &'a O::ObjectTypeDefinition,
+        new_type_definition: &'a N::ObjectTypeDefinition,
     },
 }
 
-impl<S: SchemaDefinition> Change<'_, S> {
+impl<O: SchemaDefinition, N: SchemaDefinition> Change<'_, O, N> {
     pub fn breaking(&self) -> bool {
         matches!(self.criticality_level(), CriticalityLevel::Breaking)
     }
@@ -295,7 +315,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 old_field_definition: old_field,
                 new_field_definition: new_field,
             } => {
-                if is_change_safe_for_field::<S>(
+                if is_change_safe_for_field::<O, N>(
                     old_field.r#type().as_shallow_ref(),
                     new_field.r#type().as_shallow_ref(),
                 ) {
@@ -324,7 +344,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 old_argument_definition: old_argument,
                 new_argument_definition: new_argument,
             } => {
-                if is_change_safe_for_input_value::<S>(
+                if is_change_safe_for_input_value::<O, N>(
                     old_argument.r#type().as_shallow_ref(),
                     new_argument.r#type().as_shallow_ref(),
                 ) {
@@ -357,7 +377,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 old_field_definition: old_field,
                 new_field_definition: new_field,
             } => {
-                if is_change_safe_for_input_value::<S>(
+                if is_change_safe_for_input_value::<O, N>(
                     old_field.r#type().as_shallow_ref(),
                     new_field.r#type().as_shallow_ref(),
                 ) {
@@ -391,7 +411,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 old_argument_definition,
                 new_argument_definition,
             } => {
-                if is_change_safe_for_input_value::<S>(
+                if is_change_safe_for_input_value::<O, N>(
                     old_argument_definition.r#type().as_shallow_ref(),
                     new_argument_definition.r#type().as_shallow_ref(),
                 ) {
@@ -408,6 +428,9 @@ impl<S: SchemaDefinition> Change<'_, S> {
             Self::DirectiveArgumentAdded { .. } => CriticalityLevel::Safe,
             Self::DirectiveArgumentRemoved { .. } => CriticalityLevel::Safe,
             Self::DirectiveArgumentValueChanged { .. } => CriticalityLevel::Safe,
+            Self::RootOperationTypeAdded { .. } => CriticalityLevel::Safe,
+            Self::RootOperationTypeRemoved { .. } => CriticalityLevel::Breaking,
+            Self::RootOperationTypeChanged { .. } => CriticalityLevel::Breaking,
         }
     }
 
@@ -429,7 +452,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
             },
             Self::FieldDescriptionChanged { .. } => Criticality::safe(None),
             Self::FieldTypeChanged { type_name: _, old_field_definition: old_field, new_field_definition: new_field } => {
-                if is_change_safe_for_field::<S>(old_field.r#type().as_shallow_ref(), new_field.r#type().as_shallow_ref()) {
+                if is_change_safe_for_field::<O, N>(old_field.r#type().as_shallow_ref(), new_field.r#type().as_shallow_ref()) {
                     Criticality::safe(None)
                 } else {
                     Criticality::breaking(Some(Cow::from("Changing a field's type can cause existing queries that use this field to error.")))
@@ -453,7 +476,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 Criticality::dangerous(Some(Cow::from("Changing the default value for an argument may change the runtime behaviour of a field if it was never provided.")))
             },
             Self::FieldArgumentTypeChanged{ type_name: _, field_definition: _, old_argument_definition: old_argument, new_argument_definition: new_argument } => {
-                if is_change_safe_for_input_value::<S>(old_argument.r#type().as_shallow_ref(), new_argument.r#type().as_shallow_ref()) {
+                if is_change_safe_for_input_value::<O, N>(old_argument.r#type().as_shallow_ref(), new_argument.r#type().as_shallow_ref()) {
                     Criticality::safe(None)
                 } else {
                     Criticality::breaking(Some(Cow::from("Changing the type of a field's argument can cause existing queries that use this argument to error.")))
@@ -491,7 +514,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 Criticality::breaking(Some(Cow::from("Removing an input field will cause existing queries that use this input field to error.")))
             },
             Self::InputFieldTypeChanged { input_object_type_definition: _, old_field_definition: old_field, new_field_definition: new_field } => {
-                if is_change_safe_for_input_value::<S>(old_field.r#type().as_shallow_ref(), new_field.r#type().as_shallow_ref()) {
+                if is_change_safe_for_input_value::<O, N>(old_field.r#type().as_shallow_ref(), new_field.r#type().as_shallow_ref()) {
                     Criticality::safe(Some(Cow::from("Changing an input field from non-null to null is considered non-breaking")))
                 } else {
                     Criticality::breaking(Some(Cow::from("Changing the type of an input field can cause existing queries that use this field to error.")))
@@ -532,7 +555,7 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 Criticality::safe(None)
             },
             Self::DirectiveDefinitionArgumentTypeChanged { directive_definition: _, old_argument_definition, new_argument_definition } => {
-                if is_change_safe_for_input_value::<S>(old_argument_definition.r#type().as_shallow_ref(), new_argument_definition.r#type().as_shallow_ref()) {
+                if is_change_safe_for_input_value::<O, N>(old_argument_definition.r#type().as_shallow_ref(), new_argument_definition.r#type().as_shallow_ref()) {
                     Criticality::safe(Some(Cow::from("Changing an input field from non-null to null is considered non-breaking")))
                 } else {
                     Criticality::breaking(None)
@@ -556,6 +579,15 @@ impl<S: SchemaDefinition> Change<'_, S> {
             Self::DirectiveArgumentValueChanged { .. } => {
                 Criticality::safe(None)
             },
+            Self::RootOperationTypeAdded { .. } => {
+                Criticality::safe(None)
+            },
+            Self::RootOperationTypeRemoved { .. } => {
+                Criticality::breaking(Some(Cow::from("Removing a root operation type is a breaking change. Existing operations of this type will no longer be valid.")))
+            },
+            Self::RootOperationTypeChanged { .. } => {
+                Criticality::breaking(Some(Cow::from("Changing a root operation type is a breaking change. Existing operations of this type that use fragments on the old type or select `__typename` may no longer be valid or return different results.")))
+            },
         }
     }
 
@@ -1061,6 +1093,38 @@ impl<S: SchemaDefinition> Change<'_, S> {
                     ValuePrinter::new(new_argument.value()),
                 )
             }
+            Self::RootOperationTypeAdded {
+                operation_type,
+                new_type_definition,
+            } => {
+                format!(
+                    "Schema {} root `{}` was added",
+                    operation_type,
+                    new_type_definition.name()
+                )
+            }
+            Self::RootOperationTypeRemoved {
+                operation_type,
+                old_type_definition,
+            } => {
+                format!(
+                    "Schema {} root `{}` was removed",
+                    operation_type,
+                    old_type_definition.name()
+                )
+            }
+            Self::RootOperationTypeChanged {
+                operation_type,
+                old_type_definition,
+                new_type_definition,
+            } => {
+                format!(
+                    "Schema {} root changed from `{}` to `{}`",
+                    operation_type,
+                    old_type_definition.name(),
+                    new_type_definition.name()
+                )
+            }
         }
     }
 
@@ -1265,8 +1329,8 @@ impl<S: SchemaDefinition> Change<'_, S> {
                 location: _,
                 member_name: _,
                 directive,
-            }
-            | Self::DirectiveRemoved {
+            } => directive.name().to_string(),
+            Self::DirectiveRemoved {
                 location: _,
                 member_name: _,
                 directive,
@@ -1290,13 +1354,219 @@ impl<S: SchemaDefinition> Change<'_, S> {
             } => {
                 format!("@{}.{}", directive.name(), old_argument.name())
             }
+            Self::RootOperationTypeAdded { operation_type, .. }
+            | Self::RootOperationTypeRemoved { operation_type, .. }
+            | Self::RootOperationTypeChanged { operation_type, .. } => {
+                format!("schema.{}", operation_type)
+            }
+        }
+    }
+
+    /// The [schema coordinate](https://spec.graphql.org/draft/#sec-Schema-Coordinates) of the
+    /// element an operation must use to be affected by this change, for changes that are not
+    /// safe. Returns `None` for safe changes and for changes that can affect operations
+    /// regardless of what they select, such as removing a directive applied in the schema.
+    pub fn usage_coordinate(&self) -> Option<String> {
+        if self.criticality_level() == CriticalityLevel::Safe {
+            return None;
+        }
+        match self {
+            Self::TypeRemoved {
+                removed_type_definition: type_definition,
+            }
+            | Self::TypeKindChanged {
+                old_type_definition: type_definition,
+                ..
+            } => Some(type_definition.name().to_owned()),
+            Self::FieldRemoved {
+                type_name,
+                removed_field_definition: field_definition,
+            }
+            | Self::FieldTypeChanged {
+                type_name,
+                old_field_definition: field_definition,
+                ..
+            }
+            | Self::FieldArgumentDefaultValueChanged {
+                type_name,
+                field_definition,
+                ..
+            } => Some(format!("{}.{}", type_name, field_definition.name())),
+            Self::FieldArgumentAdded {
+                type_name,
+                field_definition,
+                ..
+            } => Some(format!("{}.{}", type_name, field_definition.name())),
+            // a newly required argument breaks uses of the field that omit it,
+            // which are not recorded against the argument's coordinate
+            Self::FieldArgumentTypeChanged {
+                type_name,
+                field_definition,
+                new_argument_definition,
+                ..
+            } if is_required_without_default::<N>(new_argument_definition) => {
+                Some(format!("{}.{}", type_name, field_definition.name()))
+            }
+            Self::FieldArgumentRemoved {
+                type_name,
+                field_definition,
+                argument_definition,
+            }
+            | Self::FieldArgumentTypeChanged {
+                type_name,
+                field_definition,
+                old_argument_definition: argument_definition,
+                ..
+            } => Some(format!(
+                "{}.{}({}:)",
+                type_name,
+                field_definition.name(),
+                argument_definition.name()
+            )),
+            Self::ObjectInterfaceAddition {
+                object_type_definition,
+                ..
+            } => Some(object_type_definition.name().to_owned()),
+            Self::ObjectInterfaceRemoval {
+                object_type_definition,
+                ..
+            } => Some(object_type_definition.name().to_owned()),
+            Self::EnumValueAdded {
+                enum_type_definition,
+                ..
+            } => Some(enum_type_definition.name().to_owned()),
+            Self::EnumValueRemoved {
+                enum_type_definition,
+                enum_value_definition,
+            } => Some(format!(
+                "{}.{}",
+                enum_type_definition.name(),
+                enum_value_definition.name()
+            )),
+            Self::UnionMemberAdded {
+                union_type_definition,
+                ..
+            } => Some(union_type_definition.name().to_owned()),
+            Self::UnionMemberRemoved {
+                union_member_type, ..
+            } => Some(union_member_type.name().to_owned()),
+            Self::InputFieldAdded {
+                input_object_type_definition,
+                ..
+            } => Some(input_object_type_definition.name().to_owned()),
+            Self::InputFieldTypeChanged {
+                input_object_type_definition,
+                new_field_definition,
+                ..
+            } if is_required_without_default::<N>(new_field_definition) => {
+                Some(input_object_type_definition.name().to_owned())
+            }
+            Self::InputFieldRemoved {
+                input_object_type_definition,
+                removed_field_definition: field_definition,
+            }
+            | Self::InputFieldTypeChanged {
+                input_object_type_definition,
+                old_field_definition: field_definition,
+                ..
+            }
+            | Self::InputFieldDefaultValueChanged {
+                input_object_type_definition,
+                old_field_definition: field_definition,
+                ..
+            } => Some(format!(
+                "{}.{}",
+                input_object_type_definition.name(),
+                field_definition.name()
+            )),
+            Self::DirectiveDefinitionRemoved {
+                directive_definition,
+            }
+            | Self::DirectiveDefinitionLocationRemoved {
+                directive_definition,
+                ..
+            } => Some(format!("@{}", directive_definition.name())),
+            Self::DirectiveDefinitionArgumentAdded {
+                directive_definition,
+                ..
+            } => Some(format!("@{}", directive_definition.name())),
+            Self::DirectiveDefinitionArgumentTypeChanged {
+                directive_definition,
+                new_argument_definition,
+                ..
+            } if is_required_without_default::<N>(new_argument_definition) => {
+                Some(format!("@{}", directive_definition.name()))
+            }
+            Self::DirectiveDefinitionArgumentRemoved {
+                directive_definition,
+                argument_definition,
+            }
+            | Self::DirectiveDefinitionArgumentTypeChanged {
+                directive_definition,
+                old_argument_definition: argument_definition,
+                ..
+            }
+            | Self::DirectiveDefinitionArgumentDefaultValueChanged {
+                directive_definition,
+                old_argument_definition: argument_definition,
+                ..
+            } => Some(format!(
+                "@{}({}:)",
+                directive_definition.name(),
+                argument_definition.name()
+            )),
+            Self::RootOperationTypeRemoved {
+                old_type_definition,
+                ..
+            }
+            | Self::RootOperationTypeChanged {
+                old_type_definition,
+                ..
+            } => Some(old_type_definition.name().to_owned()),
+            _ => None,
+        }
+    }
+
+    /// Whether this change removes an element that was deprecated in the old schema
+    pub(crate) fn removes_deprecated_element(&self) -> bool {
+        match self {
+            Self::FieldRemoved {
+                removed_field_definition,
+                ..
+            } => is_deprecated::<O>(removed_field_definition.directives()),
+            Self::FieldArgumentRemoved {
+                argument_definition,
+                ..
+            } => is_deprecated::<O>(argument_definition.directives()),
+            Self::EnumValueRemoved {
+                enum_value_definition,
+                ..
+            } => is_deprecated::<O>(enum_value_definition.directives()),
+            Self::InputFieldRemoved {
+                removed_field_definition,
+                ..
+            } => is_deprecated::<O>(removed_field_definition.directives()),
+            _ => false,
         }
     }
 }
 
-fn is_change_safe_for_field<S: SchemaDefinition>(
-    old_type: ShallowOutputTypeReference<S::OutputType>,
-    new_type: ShallowOutputTypeReference<S::OutputType>,
+fn is_deprecated<S: SchemaDefinition>(
+    directives: Option<&<S as SchemaDefinition>::Directives>,
+) -> bool {
+    directives.is_some_and(|directives| directives.iter().any(|d| d.name() == "deprecated"))
+}
+
+/// Whether existing uses that omit this input value will fail to validate
+fn is_required_without_default<S: SchemaDefinition>(
+    input_value_definition: &S::InputValueDefinition,
+) -> bool {
+    input_value_definition.is_required() && input_value_definition.default_value().is_none()
+}
+
+fn is_change_safe_for_field<O: SchemaDefinition, N: SchemaDefinition>(
+    old_type: ShallowOutputTypeReference<O::OutputType>,
+    new_type: ShallowOutputTypeReference<N::OutputType>,
 ) -> bool {
     match (old_type, new_type) {
         (
@@ -1308,7 +1578,7 @@ fn is_change_safe_for_field<S: SchemaDefinition>(
             ShallowOutputTypeReference::List(new_inner, new_required),
         ) => {
             (!old_required || new_required)
-                && is_change_safe_for_field::<S>(
+                && is_change_safe_for_field::<O, N>(
                     old_inner.as_shallow_ref(),
                     new_inner.as_shallow_ref(),
                 )
@@ -1317,9 +1587,9 @@ fn is_change_safe_for_field<S: SchemaDefinition>(
     }
 }
 
-fn is_change_safe_for_input_value<S: SchemaDefinition>(
-    old_type: ShallowInputTypeReference<S::InputType>,
-    new_type: ShallowInputTypeReference<S::InputType>,
+fn is_change_safe_for_input_value<O: SchemaDefinition, N: SchemaDefinition>(
+    old_type: ShallowInputTypeReference<O::InputType>,
+    new_type: ShallowInputTypeReference<N::InputType>,
 ) -> bool {
     match (old_type, new_type) {
         (
@@ -1331,7 +1601,7 @@ fn is_change_safe_for_input_value<S: SchemaDefinition>(
             ShallowInputTypeReference::List(new_inner, new_required),
         ) => {
             (old_required || !new_required)
-                && is_change_safe_for_input_value::<S>(
+                && is_change_safe_for_input_value::<O, N>(
                     old_inner.as_shallow_ref(),
                     new_inner.as_shallow_ref(),
                 )
@@ -5,19 +5,19 @@ use bluejay_core::definition::{
 };
 use bluejay_core::Value;
 
-pub struct ArgumentDiff<'a, S: SchemaDefinition> {
+pub struct ArgumentDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
     type_name: &'a str,
-    field_definition: &'a S::FieldDefinition,
-    old_argument_definition: &'a S::InputValueDefinition,
-    new_argument_definition: &'a S::InputValueDefinition,
+    field_definition: &'a O::FieldDefinition,
+    old_argument_definition: &'a O::InputValueDefinition,
+    new_argument_definition: &'a N::InputValueDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> ArgumentDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> ArgumentDiff<'a, O, N> {
     pub fn new(
         type_name: &'a str,
-        field_definition: &'a S::FieldDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        field_definition: &'a O::FieldDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     ) -> Self {
         Self {
             type_name,
@@ -28,7 +28,7 @@ impl<'a, S: SchemaDefinition + 'a> ArgumentDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         if self.old_argument_definition.description() != self.new_argument_definition.description()
         {
             changes.push(Change::FieldArgumentDescriptionChanged {
@@ -75,7 +75,7 @@ impl<'a, S: SchemaDefinition + 'a> ArgumentDiff<'a, S> {
             (None, None) => {}
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_argument_definition,
             self.new_argument_definition,
             DirectiveLocation::ArgumentDefinition,
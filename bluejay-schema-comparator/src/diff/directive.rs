@@ -7,16 +7,13 @@ pub type ArgumentForDirective<S> =
         true,
     >>::Argument;
 
-pub struct DirectiveDiff<'a, S: SchemaDefinition> {
-    old_directive: &'a <S as SchemaDefinition>::Directive,
-    new_directive: &'a <S as SchemaDefinition>::Directive,
+pub struct DirectiveDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_directive: &'a O::Directive,
+    new_directive: &'a N::Directive,
 }
 
-impl<'a, S: SchemaDefinition + 'a> DirectiveDiff<'a, S> {
-    pub fn new(
-        old_directive: &'a <S as SchemaDefinition>::Directive,
-        new_directive: &'a <S as SchemaDefinition>::Directive,
-    ) -> Self {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> DirectiveDiff<'a, O, N> {
+    pub fn new(old_directive: &'a O::Directive, new_directive: &'a N::Directive) -> Self {
         Self {
             old_directive,
             new_directive,
@@ -24,7 +21,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         // Argument additions
         changes.extend(
             self.argument_additions()
@@ -66,7 +63,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDiff<'a, S> {
             });
     }
 
-    fn argument_additions(&self) -> impl Iterator<Item = &'a ArgumentForDirective<S>> {
+    fn argument_additions(&self) -> impl Iterator<Item = &'a ArgumentForDirective<N>> {
         self.new_directive
             .arguments()
             .map(|ii| ii.iter())
@@ -85,14 +82,16 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDiff<'a, S> {
 #[inline]
 pub fn diff_directives_into<
     'a,
-    S: SchemaDefinition + 'a,
-    T: HasDirectives<Directives = <S as SchemaDefinition>::Directives>,
+    O: SchemaDefinition + 'a,
+    N: SchemaDefinition + 'a,
+    T: HasDirectives<Directives = <O as SchemaDefinition>::Directives>,
+    U: HasDirectives<Directives = <N as SchemaDefinition>::Directives>,
 >(
     old_member: &'a T,
-    new_member: &'a T,
+    new_member: &'a U,
     location: DirectiveLocation,
     member_name: &'a str,
-    changes: &mut Vec<Change<'a, S>>,
+    changes: &mut Vec<Change<'a, O, N>>,
 ) {
     let old_directives = old_member.directives();
     let new_directives = new_member.directives();
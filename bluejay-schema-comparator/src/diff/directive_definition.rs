@@ -5,15 +5,15 @@ use bluejay_core::definition::{
 };
 use bluejay_core::AsIter;
 
-pub struct DirectiveDefinitionDiff<'a, S: SchemaDefinition> {
-    old_directive_definition: &'a S::DirectiveDefinition,
-    new_directive_definition: &'a S::DirectiveDefinition,
+pub struct DirectiveDefinitionDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_directive_definition: &'a O::DirectiveDefinition,
+    new_directive_definition: &'a N::DirectiveDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> DirectiveDefinitionDiff<'a, O, N> {
     pub fn new(
-        old_directive_definition: &'a S::DirectiveDefinition,
-        new_directive_definition: &'a S::DirectiveDefinition,
+        old_directive_definition: &'a O::DirectiveDefinition,
+        new_directive_definition: &'a N::DirectiveDefinition,
     ) -> Self {
         Self {
             old_directive_definition,
@@ -22,7 +22,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         if self.old_directive_definition.description()
             != self.new_directive_definition.description()
         {
@@ -41,7 +41,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionDiff<'a, S> {
 
         changes.extend(self.location_removals().map(|location| {
             Change::DirectiveDefinitionLocationRemoved {
-                directive_definition: self.new_directive_definition,
+                directive_definition: self.old_directive_definition,
                 location,
             }
         }));
@@ -60,31 +60,29 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionDiff<'a, S> {
             .map(|ii| ii.iter())
             .into_iter()
             .flatten()
-            .for_each(
-                |old_argument: &'a <S as SchemaDefinition>::InputValueDefinition| {
-                    let new_argument: Option<&'a <S as SchemaDefinition>::InputValueDefinition> =
-                        self.new_directive_definition
-                            .arguments_definition()
-                            .map(|ii| ii.iter())
-                            .into_iter()
-                            .flatten()
-                            .find(|new_argument| old_argument.name() == new_argument.name());
+            .for_each(|old_argument: &'a O::InputValueDefinition| {
+                let new_argument: Option<&'a N::InputValueDefinition> = self
+                    .new_directive_definition
+                    .arguments_definition()
+                    .map(|ii| ii.iter())
+                    .into_iter()
+                    .flatten()
+                    .find(|new_argument| old_argument.name() == new_argument.name());
 
-                    if let Some(new_argument) = new_argument {
-                        DirectiveDefinitionArgumentDiff::new(
-                            self.new_directive_definition,
-                            old_argument,
-                            new_argument,
-                        )
-                        .diff_into(changes);
-                    } else {
-                        changes.push(Change::DirectiveDefinitionArgumentRemoved {
-                            directive_definition: self.new_directive_definition,
-                            argument_definition: old_argument,
-                        });
-                    }
-                },
-            );
+                if let Some(new_argument) = new_argument {
+                    DirectiveDefinitionArgumentDiff::new(
+                        self.old_directive_definition,
+                        old_argument,
+                        new_argument,
+                    )
+                    .diff_into(changes);
+                } else {
+                    changes.push(Change::DirectiveDefinitionArgumentRemoved {
+                        directive_definition: self.old_directive_definition,
+                        argument_definition: old_argument,
+                    });
+                }
+            });
     }
 
     fn location_removals(&self) -> impl Iterator<Item = &'a DirectiveLocation> {
@@ -111,7 +109,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionDiff<'a, S> {
             })
     }
 
-    fn argument_additions(&self) -> impl Iterator<Item = &'a S::InputValueDefinition> {
+    fn argument_additions(&self) -> impl Iterator<Item = &'a N::InputValueDefinition> {
         self.new_directive_definition
             .arguments_definition()
             .map(|ii| ii.iter())
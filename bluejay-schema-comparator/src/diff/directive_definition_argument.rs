@@ -5,17 +5,19 @@ use bluejay_core::definition::{
 };
 use bluejay_core::Value;
 
-pub struct DirectiveDefinitionArgumentDiff<'a, S: SchemaDefinition> {
-    directive_definition: &'a S::DirectiveDefinition,
-    old_argument_definition: &'a S::InputValueDefinition,
-    new_argument_definition: &'a S::InputValueDefinition,
+pub struct DirectiveDefinitionArgumentDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    directive_definition: &'a O::DirectiveDefinition,
+    old_argument_definition: &'a O::InputValueDefinition,
+    new_argument_definition: &'a N::InputValueDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionArgumentDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a>
+    DirectiveDefinitionArgumentDiff<'a, O, N>
+{
     pub fn new(
-        directive_definition: &'a S::DirectiveDefinition,
-        old_argument_definition: &'a S::InputValueDefinition,
-        new_argument_definition: &'a S::InputValueDefinition,
+        directive_definition: &'a O::DirectiveDefinition,
+        old_argument_definition: &'a O::InputValueDefinition,
+        new_argument_definition: &'a N::InputValueDefinition,
     ) -> Self {
         Self {
             directive_definition,
@@ -25,7 +27,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionArgumentDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         if self.old_argument_definition.description() != self.new_argument_definition.description()
         {
             changes.push(Change::DirectiveDefinitionArgumentDescriptionChanged {
@@ -68,7 +70,7 @@ impl<'a, S: SchemaDefinition + 'a> DirectiveDefinitionArgumentDiff<'a, S> {
             _ => {}
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_argument_definition,
             self.new_argument_definition,
             DirectiveLocation::ArgumentDefinition,
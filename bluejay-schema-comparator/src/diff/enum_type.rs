@@ -9,15 +9,15 @@ use std::collections::HashMap;
 
 const HASHMAP_THRESHOLD: usize = 64;
 
-pub struct EnumTypeDiff<'a, S: SchemaDefinition> {
-    old_type_definition: &'a S::EnumTypeDefinition,
-    new_type_definition: &'a S::EnumTypeDefinition,
+pub struct EnumTypeDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_type_definition: &'a O::EnumTypeDefinition,
+    new_type_definition: &'a N::EnumTypeDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> EnumTypeDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> EnumTypeDiff<'a, O, N> {
     pub fn new(
-        old_type_definition: &'a S::EnumTypeDefinition,
-        new_type_definition: &'a S::EnumTypeDefinition,
+        old_type_definition: &'a O::EnumTypeDefinition,
+        new_type_definition: &'a N::EnumTypeDefinition,
     ) -> Self {
         Self {
             old_type_definition,
@@ -26,7 +26,7 @@ impl<'a, S: SchemaDefinition + 'a> EnumTypeDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         let old_hint = self
             .old_type_definition
             .enum_value_definitions()
@@ -46,7 +46,7 @@ impl<'a, S: SchemaDefinition + 'a> EnumTypeDiff<'a, S> {
             self.diff_values_linear(changes);
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_type_definition,
             self.new_type_definition,
             DirectiveLocation::Enum,
@@ -57,14 +57,14 @@ impl<'a, S: SchemaDefinition + 'a> EnumTypeDiff<'a, S> {
 
     #[cold]
     #[inline(never)]
-    fn diff_values_hashmap(&self, changes: &mut Vec<Change<'a, S>>) {
-        let old_values: HashMap<&str, &'a S::EnumValueDefinition> = self
+    fn diff_values_hashmap(&self, changes: &mut Vec<Change<'a, O, N>>) {
+        let old_values: HashMap<&str, &'a O::EnumValueDefinition> = self
             .old_type_definition
             .enum_value_definitions()
             .iter()
             .map(|v| (v.name(), v))
             .collect();
-        let new_values: HashMap<&str, &'a S::EnumValueDefinition> = self
+        let new_values: HashMap<&str, &'a N::EnumValueDefinition> = self
             .new_type_definition
             .enum_value_definitions()
             .iter()
@@ -94,7 +94,7 @@ impl<'a, S: SchemaDefinition + 'a> EnumTypeDiff<'a, S> {
     }
 
     #[inline]
-    fn diff_values_linear(&self, changes: &mut Vec<Change<'a, S>>) {
+    fn diff_values_linear(&self, changes: &mut Vec<Change<'a, O, N>>) {
         // Additions
         changes.extend(
             self.new_type_definition
@@ -2,17 +2,17 @@ use crate::changes::Change;
 use crate::diff::directive::diff_directives_into;
 use bluejay_core::definition::{DirectiveLocation, EnumValueDefinition, SchemaDefinition};
 
-pub struct EnumValueDiff<'a, S: SchemaDefinition> {
-    enum_type_definition: &'a S::EnumTypeDefinition,
-    old_value_definition: &'a S::EnumValueDefinition,
-    new_value_definition: &'a S::EnumValueDefinition,
+pub struct EnumValueDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    enum_type_definition: &'a O::EnumTypeDefinition,
+    old_value_definition: &'a O::EnumValueDefinition,
+    new_value_definition: &'a N::EnumValueDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> EnumValueDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> EnumValueDiff<'a, O, N> {
     pub fn new(
-        enum_type_definition: &'a S::EnumTypeDefinition,
-        old_value_definition: &'a S::EnumValueDefinition,
-        new_value_definition: &'a S::EnumValueDefinition,
+        enum_type_definition: &'a O::EnumTypeDefinition,
+        old_value_definition: &'a O::EnumValueDefinition,
+        new_value_definition: &'a N::EnumValueDefinition,
     ) -> Self {
         Self {
             enum_type_definition,
@@ -22,7 +22,7 @@ impl<'a, S: SchemaDefinition + 'a> EnumValueDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         if self.old_value_definition.description() != self.new_value_definition.description() {
             changes.push(Change::EnumValueDescriptionChanged {
                 enum_type_definition: self.enum_type_definition,
@@ -31,7 +31,7 @@ impl<'a, S: SchemaDefinition + 'a> EnumValueDiff<'a, S> {
             });
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_value_definition,
             self.new_value_definition,
             DirectiveLocation::EnumValue,
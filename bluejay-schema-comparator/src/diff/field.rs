@@ -6,17 +6,17 @@ use bluejay_core::definition::{
 };
 use bluejay_core::AsIter;
 
-pub struct FieldDiff<'a, S: SchemaDefinition> {
+pub struct FieldDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
     type_name: &'a str,
-    old_field_definition: &'a S::FieldDefinition,
-    new_field_definition: &'a S::FieldDefinition,
+    old_field_definition: &'a O::FieldDefinition,
+    new_field_definition: &'a N::FieldDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> FieldDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> FieldDiff<'a, O, N> {
     pub fn new(
         type_name: &'a str,
-        old_field_definition: &'a S::FieldDefinition,
-        new_field_definition: &'a S::FieldDefinition,
+        old_field_definition: &'a O::FieldDefinition,
+        new_field_definition: &'a N::FieldDefinition,
     ) -> Self {
         Self {
             type_name,
@@ -26,7 +26,7 @@ impl<'a, S: SchemaDefinition + 'a> FieldDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         if self.old_field_definition.description() != self.new_field_definition.description() {
             changes.push(Change::FieldDescriptionChanged {
                 type_name: self.type_name,
@@ -100,7 +100,7 @@ impl<'a, S: SchemaDefinition + 'a> FieldDiff<'a, S> {
                 });
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_field_definition,
             self.new_field_definition,
             DirectiveLocation::FieldDefinition,
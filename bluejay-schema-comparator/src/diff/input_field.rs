@@ -5,18 +5,18 @@ use bluejay_core::definition::{
 };
 use bluejay_core::Value;
 
-pub struct InputFieldDiff<'a, S: SchemaDefinition> {
-    old_type_definition: &'a S::InputObjectTypeDefinition,
-    old_field_definition: &'a S::InputValueDefinition,
-    new_field_definition: &'a S::InputValueDefinition,
+pub struct InputFieldDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_type_definition: &'a O::InputObjectTypeDefinition,
+    old_field_definition: &'a O::InputValueDefinition,
+    new_field_definition: &'a N::InputValueDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> InputFieldDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> InputFieldDiff<'a, O, N> {
     pub fn new(
-        old_type_definition: &'a S::InputObjectTypeDefinition,
-        _new_type_definition: &'a S::InputObjectTypeDefinition,
-        old_field_definition: &'a S::InputValueDefinition,
-        new_field_definition: &'a S::InputValueDefinition,
+        old_type_definition: &'a O::InputObjectTypeDefinition,
+        _new_type_definition: &'a N::InputObjectTypeDefinition,
+        old_field_definition: &'a O::InputValueDefinition,
+        new_field_definition: &'a N::InputValueDefinition,
     ) -> Self {
         Self {
             old_type_definition,
@@ -26,7 +26,7 @@ impl<'a, S: SchemaDefinition + 'a> InputFieldDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         if self.old_field_definition.description() != self.new_field_definition.description() {
             changes.push(Change::InputFieldDescriptionChanged {
                 input_object_type_definition: self.old_type_definition,
@@ -68,7 +68,7 @@ impl<'a, S: SchemaDefinition + 'a> InputFieldDiff<'a, S> {
             (None, None) => {}
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_field_definition,
             self.new_field_definition,
             DirectiveLocation::InputFieldDefinition,
@@ -10,15 +10,15 @@ use std::collections::HashMap;
 
 const HASHMAP_THRESHOLD: usize = 64;
 
-pub struct InputObjectTypeDiff<'a, S: SchemaDefinition> {
-    old_type_definition: &'a S::InputObjectTypeDefinition,
-    new_type_definition: &'a S::InputObjectTypeDefinition,
+pub struct InputObjectTypeDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_type_definition: &'a O::InputObjectTypeDefinition,
+    new_type_definition: &'a N::InputObjectTypeDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> InputObjectTypeDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> InputObjectTypeDiff<'a, O, N> {
     pub fn new(
-        old_type_definition: &'a S::InputObjectTypeDefinition,
-        new_type_definition: &'a S::InputObjectTypeDefinition,
+        old_type_definition: &'a O::InputObjectTypeDefinition,
+        new_type_definition: &'a N::InputObjectTypeDefinition,
     ) -> Self {
         Self {
             old_type_definition,
@@ -27,7 +27,7 @@ impl<'a, S: SchemaDefinition + 'a> InputObjectTypeDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         let old_hint = self
             .old_type_definition
             .input_field_definitions()
@@ -47,7 +47,7 @@ impl<'a, S: SchemaDefinition + 'a> InputObjectTypeDiff<'a, S> {
             self.diff_fields_linear(changes);
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_type_definition,
             self.new_type_definition,
             DirectiveLocation::InputObject,
@@ -58,14 +58,14 @@ impl<'a, S: SchemaDefinition + 'a> InputObjectTypeDiff<'a, S> {
 
     #[cold]
     #[inline(never)]
-    fn diff_fields_hashmap(&self, changes: &mut Vec<Change<'a, S>>) {
-        let old_fields: HashMap<&str, &'a S::InputValueDefinition> = self
+    fn diff_fields_hashmap(&self, changes: &mut Vec<Change<'a, O, N>>) {
+        let old_fields: HashMap<&str, &'a O::InputValueDefinition> = self
             .old_type_definition
             .input_field_definitions()
             .iter()
             .map(|f| (f.name(), f))
             .collect();
-        let new_fields: HashMap<&str, &'a S::InputValueDefinition> = self
+        let new_fields: HashMap<&str, &'a N::InputValueDefinition> = self
             .new_type_definition
             .input_field_definitions()
             .iter()
@@ -100,7 +100,7 @@ impl<'a, S: SchemaDefinition + 'a> InputObjectTypeDiff<'a, S> {
     }
 
     #[inline]
-    fn diff_fields_linear(&self, changes: &mut Vec<Change<'a, S>>) {
+    fn diff_fields_linear(&self, changes: &mut Vec<Change<'a, O, N>>) {
         // Additions
         changes.extend(
             self.new_type_definition
@@ -122,27 +122,25 @@ impl<'a, S: SchemaDefinition + 'a> InputObjectTypeDiff<'a, S> {
         self.old_type_definition
             .input_field_definitions()
             .iter()
-            .for_each(
-                |old_field: &'a <S as SchemaDefinition>::InputValueDefinition| {
-                    if let Some(new_field) = self
-                        .new_type_definition
-                        .input_field_definitions()
-                        .get(old_field.name())
-                    {
-                        InputFieldDiff::new(
-                            self.old_type_definition,
-                            self.new_type_definition,
-                            old_field,
-                            new_field,
-                        )
-                        .diff_into(changes);
-                    } else {
-                        changes.push(Change::InputFieldRemoved {
-                            removed_field_definition: old_field,
-                            input_object_type_definition: self.old_type_definition,
-                        });
-                    }
-                },
-            );
+            .for_each(|old_field: &'a O::InputValueDefinition| {
+                if let Some(new_field) = self
+                    .new_type_definition
+                    .input_field_definitions()
+                    .get(old_field.name())
+                {
+                    InputFieldDiff::new(
+                        self.old_type_definition,
+                        self.new_type_definition,
+                        old_field,
+                        new_field,
+                    )
+                    .diff_into(changes);
+                } else {
+                    changes.push(Change::InputFieldRemoved {
+                        removed_field_definition: old_field,
+                        input_object_type_definition: self.old_type_definition,
+                    });
+                }
+            });
     }
 }
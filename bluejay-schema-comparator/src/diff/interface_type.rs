@@ -6,15 +6,15 @@ use bluejay_core::definition::{
 };
 use bluejay_core::AsIter;
 
-pub struct InterfaceTypeDiff<'a, S: SchemaDefinition> {
-    old_interface_definition: &'a S::InterfaceTypeDefinition,
-    new_interface_definition: &'a S::InterfaceTypeDefinition,
+pub struct InterfaceTypeDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_interface_definition: &'a O::InterfaceTypeDefinition,
+    new_interface_definition: &'a N::InterfaceTypeDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> InterfaceTypeDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> InterfaceTypeDiff<'a, O, N> {
     pub fn new(
-        old_interface_definition: &'a S::InterfaceTypeDefinition,
-        new_interface_definition: &'a S::InterfaceTypeDefinition,
+        old_interface_definition: &'a O::InterfaceTypeDefinition,
+        new_interface_definition: &'a N::InterfaceTypeDefinition,
     ) -> Self {
         Self {
             old_interface_definition,
@@ -23,7 +23,7 @@ impl<'a, S: SchemaDefinition + 'a> InterfaceTypeDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         // Field additions
         changes.extend(
             self.new_interface_definition
@@ -45,7 +45,7 @@ impl<'a, S: SchemaDefinition + 'a> InterfaceTypeDiff<'a, S> {
         self.old_interface_definition
             .fields_definition()
             .iter()
-            .for_each(|old_field: &'a <S as SchemaDefinition>::FieldDefinition| {
+            .for_each(|old_field: &'a O::FieldDefinition| {
                 if let Some(new_field) = self
                     .new_interface_definition
                     .fields_definition()
@@ -61,7 +61,7 @@ impl<'a, S: SchemaDefinition + 'a> InterfaceTypeDiff<'a, S> {
                 }
             });
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_interface_definition,
             self.new_interface_definition,
             DirectiveLocation::Interface,
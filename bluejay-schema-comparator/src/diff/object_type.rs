@@ -12,15 +12,15 @@ use std::collections::HashMap;
 /// size_hint().0 is O(1) for slice-backed iterators.
 const HASHMAP_THRESHOLD: usize = 64;
 
-pub struct ObjectTypeDiff<'a, S: SchemaDefinition> {
-    old_type_definition: &'a S::ObjectTypeDefinition,
-    new_type_definition: &'a S::ObjectTypeDefinition,
+pub struct ObjectTypeDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_type_definition: &'a O::ObjectTypeDefinition,
+    new_type_definition: &'a N::ObjectTypeDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> ObjectTypeDiff<'a, O, N> {
     pub fn new(
-        old_type_definition: &'a S::ObjectTypeDefinition,
-        new_type_definition: &'a S::ObjectTypeDefinition,
+        old_type_definition: &'a O::ObjectTypeDefinition,
+        new_type_definition: &'a N::ObjectTypeDefinition,
     ) -> Self {
         Self {
             old_type_definition,
@@ -29,10 +29,10 @@ impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         changes.extend(self.interface_additions().map(|interface_implementation| {
             Change::ObjectInterfaceAddition {
-                object_type_definition: self.old_type_definition,
+                object_type_definition: self.new_type_definition,
                 interface_implementation,
             }
         }));
@@ -62,7 +62,7 @@ impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
             self.diff_fields_linear(changes);
         }
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_type_definition,
             self.new_type_definition,
             DirectiveLocation::Object,
@@ -73,14 +73,14 @@ impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
 
     #[cold]
     #[inline(never)]
-    fn diff_fields_hashmap(&self, changes: &mut Vec<Change<'a, S>>) {
-        let old_fields: HashMap<&str, &'a S::FieldDefinition> = self
+    fn diff_fields_hashmap(&self, changes: &mut Vec<Change<'a, O, N>>) {
+        let old_fields: HashMap<&str, &'a O::FieldDefinition> = self
             .old_type_definition
             .fields_definition()
             .iter()
             .map(|f| (f.name(), f))
             .collect();
-        let new_fields: HashMap<&str, &'a S::FieldDefinition> = self
+        let new_fields: HashMap<&str, &'a N::FieldDefinition> = self
             .new_type_definition
             .fields_definition()
             .iter()
@@ -110,7 +110,7 @@ impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
     }
 
     #[inline]
-    fn diff_fields_linear(&self, changes: &mut Vec<Change<'a, S>>) {
+    fn diff_fields_linear(&self, changes: &mut Vec<Change<'a, O, N>>) {
         // Additions
         changes.extend(
             self.new_type_definition
@@ -132,7 +132,7 @@ impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
         self.old_type_definition
             .fields_definition()
             .iter()
-            .for_each(|old_field: &'a <S as SchemaDefinition>::FieldDefinition| {
+            .for_each(|old_field: &'a O::FieldDefinition| {
                 if let Some(new_field) = self
                     .new_type_definition
                     .fields_definition()
@@ -149,41 +149,37 @@ impl<'a, S: SchemaDefinition + 'a> ObjectTypeDiff<'a, S> {
             });
     }
 
-    fn interface_additions(&self) -> impl Iterator<Item = &'a S::InterfaceImplementation> {
+    fn interface_additions(&self) -> impl Iterator<Item = &'a N::InterfaceImplementation> {
         self.new_type_definition
             .interface_implementations()
             .map(|ii| ii.iter())
             .into_iter()
             .flatten()
-            .filter(
-                |new_interface_impl: &&'a <S as SchemaDefinition>::InterfaceImplementation| {
-                    self.old_type_definition
-                        .interface_implementations()
-                        .is_none_or(|interface_implementations| {
-                            !interface_implementations.iter().any(|old_interface_impl| {
-                                old_interface_impl.name() == new_interface_impl.name()
-                            })
+            .filter(|new_interface_impl: &&'a N::InterfaceImplementation| {
+                self.old_type_definition
+                    .interface_implementations()
+                    .is_none_or(|interface_implementations| {
+                        !interface_implementations.iter().any(|old_interface_impl| {
+                            old_interface_impl.name() == new_interface_impl.name()
                         })
-                },
-            )
+                    })
+            })
     }
 
-    fn interface_removals(&self) -> impl Iterator<Item = &'a S::InterfaceImplementation> {
+    fn interface_removals(&self) -> impl Iterator<Item = &'a O::InterfaceImplementation> {
         self.old_type_definition
             .interface_implementations()
             .map(|ii| ii.iter())
             .into_iter()
             .flatten()
-            .filter(
-                |old_interface_impl: &&'a <S as SchemaDefinition>::InterfaceImplementation| {
-                    self.new_type_definition
-                        .interface_implementations()
-                        .is_none_or(|interface_implementations| {
-                            !interface_implementations.iter().any(|new_interface_impl| {
-                                old_interface_impl.name() == new_interface_impl.name()
-                            })
+            .filter(|old_interface_impl: &&'a O::InterfaceImplementation| {
+                self.new_type_definition
+                    .interface_implementations()
+                    .is_none_or(|interface_implementations| {
+                        !interface_implementations.iter().any(|new_interface_impl| {
+                            old_interface_impl.name() == new_interface_impl.name()
                         })
-                },
-            )
+                    })
+            })
     }
 }
@@ -7,24 +7,26 @@ use crate::diff::interface_type::InterfaceTypeDiff;
 use crate::diff::object_type::ObjectTypeDiff;
 use crate::diff::union_type::UnionTypeDiff;
 use bluejay_core::definition::{
-    DirectiveDefinition as _, DirectiveLocation, SchemaDefinition, TypeDefinitionReference,
+    DirectiveDefinition as _, DirectiveLocation, ObjectTypeDefinition as _, SchemaDefinition,
+    TypeDefinitionReference,
 };
+use bluejay_core::OperationType;
 
-pub struct SchemaDiff<'a, S: SchemaDefinition> {
-    old_schema_definition: &'a S,
-    new_schema_definition: &'a S,
+pub struct SchemaDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_schema_definition: &'a O,
+    new_schema_definition: &'a N,
 }
 
-impl<'a, S: SchemaDefinition> SchemaDiff<'a, S> {
-    pub fn new(old_schema: &'a S, new_schema: &'a S) -> Self {
+impl<'a, O: SchemaDefinition, N: SchemaDefinition> SchemaDiff<'a, O, N> {
+    pub fn new(old_schema: &'a O, new_schema: &'a N) -> Self {
         Self {
             old_schema_definition: old_schema,
             new_schema_definition: new_schema,
         }
     }
 
-    pub fn diff(&self) -> Vec<Change<'a, S>> {
-        let mut changes: Vec<Change<'a, S>> = Vec::with_capacity(256);
+    pub fn diff(&self) -> Vec<Change<'a, O, N>> {
+        let mut changes: Vec<Change<'a, O, N>> = Vec::with_capacity(256);
 
         // Type additions
         changes.extend(
@@ -76,7 +78,7 @@ impl<'a, S: SchemaDefinition> SchemaDiff<'a, S> {
             });
 
         // Schema-level directive additions, removals, and changes
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_schema_definition,
             self.new_schema_definition,
             DirectiveLocation::Schema,
@@ -84,14 +86,67 @@ impl<'a, S: SchemaDefinition> SchemaDiff<'a, S> {
             &mut changes,
         );
 
+        // Root operation type additions, removals, and changes
+        self.changes_in_root_operation_types(&mut changes);
+
         changes
     }
 
+    fn changes_in_root_operation_types(&self, changes: &mut Vec<Change<'a, O, N>>) {
+        let root_operation_types = [
+            (
+                OperationType::Query,
+                Some(self.old_schema_definition.query()),
+                Some(self.new_schema_definition.query()),
+            ),
+            (
+                OperationType::Mutation,
+                self.old_schema_definition.mutation(),
+                self.new_schema_definition.mutation(),
+            ),
+            (
+                OperationType::Subscription,
+                self.old_schema_definition.subscription(),
+                self.new_schema_definition.subscription(),
+            ),
+        ];
+
+        root_operation_types.into_iter().for_each(
+            |(operation_type, old_type_definition, new_type_definition)| match (
+                old_type_definition,
+                new_type_definition,
+            ) {
+                (None, Some(new_type_definition)) => {
+                    changes.push(Change::RootOperationTypeAdded {
+                        operation_type,
+                        new_type_definition,
+                    });
+                }
+                (Some(old_type_definition), None) => {
+                    changes.push(Change::RootOperationTypeRemoved {
+                        operation_type,
+                        old_type_definition,
+                    });
+                }
+                (Some(old_type_definition), Some(new_type_definition))
+                    if old_type_definition.name() != new_type_definition.name() =>
+                {
+                    changes.push(Change::RootOperationTypeChanged {
+                        operation_type,
+                        old_type_definition,
+                        new_type_definition,
+                    });
+                }
+                _ => {}
+            },
+        );
+    }
+
     fn changes_in_type(
         &self,
-        old_type: TypeDefinitionReference<'a, S::TypeDefinition>,
-        new_type: TypeDefinitionReference<'a, S::TypeDefinition>,
-        changes: &mut Vec<Change<'a, S>>,
+        old_type: TypeDefinitionReference<'a, O::TypeDefinition>,
+        new_type: TypeDefinitionReference<'a, N::TypeDefinition>,
+        changes: &mut Vec<Change<'a, O, N>>,
     ) {
         match (old_type, new_type) {
             (
@@ -145,7 +200,7 @@ impl<'a, S: SchemaDefinition> SchemaDiff<'a, S> {
         }
     }
 
-    fn added_types(&self) -> impl Iterator<Item = TypeDefinitionReference<'a, S::TypeDefinition>> {
+    fn added_types(&self) -> impl Iterator<Item = TypeDefinitionReference<'a, N::TypeDefinition>> {
         self.new_schema_definition
             .type_definitions()
             .filter(|new_type| {
@@ -155,7 +210,7 @@ impl<'a, S: SchemaDefinition> SchemaDiff<'a, S> {
             })
     }
 
-    fn added_directive_definitions(&self) -> impl Iterator<Item = &'a S::DirectiveDefinition> {
+    fn added_directive_definitions(&self) -> impl Iterator<Item = &'a N::DirectiveDefinition> {
         self.new_schema_definition
             .directive_definitions()
             .filter(|new_directive| {
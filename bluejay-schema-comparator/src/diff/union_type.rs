@@ -5,18 +5,18 @@ use bluejay_core::definition::{
 };
 use bluejay_core::AsIter;
 
-pub struct UnionTypeDiff<'a, S: SchemaDefinition> {
-    old_type: &'a S::UnionTypeDefinition,
-    new_type: &'a S::UnionTypeDefinition,
+pub struct UnionTypeDiff<'a, O: SchemaDefinition, N: SchemaDefinition> {
+    old_type: &'a O::UnionTypeDefinition,
+    new_type: &'a N::UnionTypeDefinition,
 }
 
-impl<'a, S: SchemaDefinition + 'a> UnionTypeDiff<'a, S> {
-    pub fn new(old_type: &'a S::UnionTypeDefinition, new_type: &'a S::UnionTypeDefinition) -> Self {
+impl<'a, O: SchemaDefinition + 'a, N: SchemaDefinition + 'a> UnionTypeDiff<'a, O, N> {
+    pub fn new(old_type: &'a O::UnionTypeDefinition, new_type: &'a N::UnionTypeDefinition) -> Self {
         Self { old_type, new_type }
     }
 
     #[inline]
-    pub fn diff_into(&self, changes: &mut Vec<Change<'a, S>>) {
+    pub fn diff_into(&self, changes: &mut Vec<Change<'a, O, N>>) {
         changes.extend(
             self.member_additions()
                 .map(|union_member_type| Change::UnionMemberAdded {
@@ -27,12 +27,12 @@ impl<'a, S: SchemaDefinition + 'a> UnionTypeDiff<'a, S> {
 
         changes.extend(self.member_removals().map(|union_member_type| {
             Change::UnionMemberRemoved {
-                union_type_definition: self.new_type,
+                union_type_definition: self.old_type,
                 union_member_type,
             }
         }));
 
-        diff_directives_into::<S, _>(
+        diff_directives_into::<O, N, _, _>(
             self.old_type,
             self.new_type,
             DirectiveLocation::Union,
@@ -41,7 +41,7 @@ impl<'a, S: SchemaDefinition + 'a> UnionTypeDiff<'a, S> {
         );
     }
 
-    fn member_removals(&self) -> impl Iterator<Item = &'a S::UnionMemberType> {
+    fn member_removals(&self) -> impl Iterator<Item = &'a O::UnionMemberType> {
         self.old_type
             .union_member_types()
             .iter()
@@ -53,7 +53,7 @@ impl<'a, S: SchemaDefinition + 'a> UnionTypeDiff<'a, S> {
             })
     }
 
-    fn member_additions(&self) -> impl Iterator<Item = &'a S::UnionMemberType> {
+    fn member_additions(&self) -> impl Iterator<Item = &'a N::UnionMemberType> {
         self.new_type
             .union_member_types()
             .iter()
@@ -1,16 +1,34 @@
 mod changes;
 mod diff;
+mod report;
 mod result;
+mod usage;
 
 use bluejay_core::definition::SchemaDefinition;
 pub use changes::{Change, Criticality, CriticalityLevel};
-pub use result::ComparisonResult;
+pub use report::{ChangeReport, ComparisonReport};
+pub use result::{ChangeWithUsage, ComparisonResult, UsageComparisonResult};
+pub use usage::{OperationReference, Usage};
 
-pub fn compare<'a, S: SchemaDefinition>(
-    old_schema: &'a S,
-    new_schema: &'a S,
-) -> ComparisonResult<'a, S> {
+/// Compares two schemas, which may be different implementations of [`SchemaDefinition`],
+/// e.g. a schema parsed from SDL and one built from introspection
+pub fn compare<'a, O: SchemaDefinition, N: SchemaDefinition>(
+    old_schema: &'a O,
+    new_schema: &'a N,
+) -> ComparisonResult<'a, O, N> {
     let schema = diff::SchemaDiff::new(old_schema, new_schema);
 
     ComparisonResult::new(schema.diff())
 }
+
+/// Compares two schemas like [`compare`], classifying each change using the known `usage`
+/// of `old_schema` rather than the schemas alone
+pub fn compare_with_usage<'a, O: SchemaDefinition, N: SchemaDefinition>(
+    old_schema: &'a O,
+    new_schema: &'a N,
+    usage: &'a Usage,
+) -> UsageComparisonResult<'a, O, N> {
+    let schema = diff::SchemaDiff::new(old_schema, new_schema);
+
+    UsageComparisonResult::new(schema.diff(), usage)
+}
@@ -0,0 +1,90 @@
+use crate::{
+    Change, ChangeWithUsage, ComparisonResult, CriticalityLevel, OperationReference,
+    UsageComparisonResult,
+};
+use bluejay_core::definition::SchemaDefinition;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An owned summary of a [`ComparisonResult`] that does not borrow either schema,
+/// suitable for storing or, with the `serde` feature, serializing
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ComparisonReport {
+    pub changes: Vec<ChangeReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ChangeReport {
+    /// The name of the [`Change`] variant, e.g. `FieldRemoved`
+    pub kind: String,
+    pub path: String,
+    pub criticality: CriticalityLevel,
+    pub reason: String,
+    pub message: String,
+    /// The operations known to be affected by the change, when created from a
+    /// [`UsageComparisonResult`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub affected_operations: Vec<OperationReference>,
+}
+
+impl ComparisonReport {
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.criticality == CriticalityLevel::Breaking)
+    }
+}
+
+impl<O: SchemaDefinition, N: SchemaDefinition> From<&ComparisonResult<'_, O, N>>
+    for ComparisonReport
+{
+    fn from(result: &ComparisonResult<'_, O, N>) -> Self {
+        Self {
+            changes: result.changes.iter().map(ChangeReport::from).collect(),
+        }
+    }
+}
+
+impl<O: SchemaDefinition, N: SchemaDefinition> From<&Change<'_, O, N>> for ChangeReport {
+    fn from(change: &Change<'_, O, N>) -> Self {
+        let criticality = change.criticality();
+        Self {
+            kind: change.as_ref().to_owned(),
+            path: change.path(),
+            criticality: criticality.level(),
+            reason: criticality.reason().to_owned(),
+            message: change.message(),
+            affected_operations: Vec::new(),
+        }
+    }
+}
+
+impl<O: SchemaDefinition, N: SchemaDefinition> From<&UsageComparisonResult<'_, O, N>>
+    for ComparisonReport
+{
+    fn from(result: &UsageComparisonResult<'_, O, N>) -> Self {
+        Self {
+            changes: result.changes.iter().map(ChangeReport::from).collect(),
+        }
+    }
+}
+
+impl<O: SchemaDefinition, N: SchemaDefinition> From<&ChangeWithUsage<'_, O, N>> for ChangeReport {
+    fn from(change_with_usage: &ChangeWithUsage<'_, O, N>) -> Self {
+        let change = &change_with_usage.change;
+        let criticality = change_with_usage.criticality();
+        Self {
+            kind: change.as_ref().to_owned(),
+            path: change.path(),
+            criticality: criticality.level(),
+            reason: criticality.reason().to_owned(),
+            message: change.message(),
+            affected_operations: change_with_usage.affected_operations().to_vec(),
+        }
+    }
+}
@@ -1,14 +1,98 @@
-use super::changes::Change;
+use super::changes::{Change, Criticality, CriticalityLevel};
+use crate::{OperationReference, Usage};
 use bluejay_core::definition::SchemaDefinition;
+use std::borrow::Cow;
 
-pub struct ComparisonResult<'a, S: SchemaDefinition> {
-    pub changes: Vec<Change<'a, S>>,
+pub struct ComparisonResult<'a, O: SchemaDefinition, N: SchemaDefinition = O> {
+    pub changes: Vec<Change<'a, O, N>>,
 }
 
-impl<'a, S: SchemaDefinition> ComparisonResult<'a, S> {
-    pub fn new(mut changes: Vec<Change<'a, S>>) -> Self {
+impl<'a, O: SchemaDefinition, N: SchemaDefinition> ComparisonResult<'a, O, N> {
+    pub fn new(mut changes: Vec<Change<'a, O, N>>) -> Self {
         changes.sort_unstable_by_key(|b| std::cmp::Reverse(b.criticality_level()));
 
         Self { changes }
     }
 }
+
+pub struct UsageComparisonResult<'a, O: SchemaDefinition, N: SchemaDefinition = O> {
+    pub changes: Vec<ChangeWithUsage<'a, O, N>>,
+}
+
+impl<'a, O: SchemaDefinition, N: SchemaDefinition> UsageComparisonResult<'a, O, N> {
+    pub fn new(changes: Vec<Change<'a, O, N>>, usage: &'a Usage) -> Self {
+        let mut changes: Vec<_> = changes
+            .into_iter()
+            .map(|change| ChangeWithUsage::new(change, usage))
+            .collect();
+        changes.sort_unstable_by_key(|b| std::cmp::Reverse(b.criticality_level()));
+
+        Self { changes }
+    }
+}
+
+/// A [`Change`] classified using the known usage of the old schema
+pub struct ChangeWithUsage<'a, O: SchemaDefinition, N: SchemaDefinition = O> {
+    pub change: Change<'a, O, N>,
+    unused_coordinate: Option<String>,
+    affected_operations: &'a [OperationReference],
+}
+
+impl<'a, O: SchemaDefinition, N: SchemaDefinition> ChangeWithUsage<'a, O, N> {
+    fn new(change: Change<'a, O, N>, usage: &'a Usage) -> Self {
+        let usage_coordinate = change.usage_coordinate();
+        let affected_operations = usage_coordinate
+            .as_deref()
+            .map_or(&[][..], |coordinate| usage.operations(coordinate));
+        let unused_coordinate =
+            usage_coordinate.filter(|coordinate| !usage.is_used(coordinate.as_str()));
+        Self {
+            change,
+            unused_coordinate,
+            affected_operations,
+        }
+    }
+
+    /// Whether any known operation could be affected by the change. Changes that cannot be
+    /// attributed to a single schema coordinate are always considered to be used.
+    pub fn is_used(&self) -> bool {
+        self.unused_coordinate.is_none()
+    }
+
+    /// The operations known to be affected by the change
+    pub fn affected_operations(&self) -> &'a [OperationReference] {
+        self.affected_operations
+    }
+
+    /// Unused breaking changes are downgraded to safe if they remove an element that was
+    /// deprecated, or dangerous otherwise since the usage may not cover every client.
+    /// Unused dangerous changes are downgraded to safe.
+    pub fn criticality_level(&self) -> CriticalityLevel {
+        let criticality_level = self.change.criticality_level();
+        if self.is_used() {
+            return criticality_level;
+        }
+        match criticality_level {
+            CriticalityLevel::Breaking if !self.change.removes_deprecated_element() => {
+                CriticalityLevel::Dangerous
+            }
+            _ => CriticalityLevel::Safe,
+        }
+    }
+
+    pub fn criticality(&self) -> Criticality {
+        let Some(coordinate) = &self.unused_coordinate else {
+            return self.change.criticality();
+        };
+        match self.criticality_level() {
+            CriticalityLevel::Dangerous => Criticality::Dangerous {
+                reason: Cow::Owned(format!(
+                    "No known operation uses `{coordinate}`, but it was not deprecated so unknown clients may still rely on it."
+                )),
+            },
+            _ => Criticality::Safe {
+                reason: Cow::Owned(format!("No known operation uses `{coordinate}`.")),
+            },
+        }
+    }
+}
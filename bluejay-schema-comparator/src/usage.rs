@@ -0,0 +1,386 @@
+use bluejay_core::definition::{
+    ArgumentsDefinition, DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition,
+    FieldDefinition, FieldsDefinition, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputValueDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
+    ShallowInputTypeReference, TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference, VariableDefinition, VariableType,
+};
+use bluejay_core::{
+    Argument, AsIter, Directive, Directives, ObjectValue, OperationType, Value, ValueReference,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Identifies an operation within a corpus of executable documents
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OperationReference {
+    /// The name the document was added to the [`Usage`] with
+    pub document: String,
+    /// The name of the operation, or `None` for an anonymous operation
+    pub operation: Option<String>,
+}
+
+/// The [schema coordinates](https://spec.graphql.org/draft/#sec-Schema-Coordinates) used by
+/// clients of a schema, for use with [`compare_with_usage`](crate::compare_with_usage).
+///
+/// Coordinates are recorded for every type, field, argument, input field, enum value and
+/// directive an operation references. Where the parts of a value that will be used cannot
+/// be known statically, such as an input object provided through a variable or an enum
+/// returned by a field, every input field or enum value is considered used.
+#[derive(Debug, Clone, Default)]
+pub struct Usage {
+    coordinates: HashMap<String, Vec<OperationReference>>,
+}
+
+impl Usage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a coordinate as used without attributing it to an operation, e.g. when
+    /// the coordinates come from traffic rather than executable documents
+    pub fn add_coordinate(&mut self, coordinate: impl Into<String>) {
+        self.coordinates.entry(coordinate.into()).or_default();
+    }
+
+    /// Records the coordinates used by each operation in `executable_document`, which must be
+    /// valid against `schema_definition`. This should be the schema the clients are currently
+    /// using, i.e. the old schema in a comparison.
+    pub fn add_executable_document<E: ExecutableDocument, S: SchemaDefinition>(
+        &mut self,
+        document_name: &str,
+        executable_document: &E,
+        schema_definition: &S,
+    ) {
+        executable_document
+            .operation_definitions()
+            .for_each(|operation_definition| {
+                let operation = OperationReference {
+                    document: document_name.to_owned(),
+                    operation: operation_definition.as_ref().name().map(ToOwned::to_owned),
+                };
+                UsageCollector::collect(
+                    executable_document,
+                    schema_definition,
+                    operation_definition,
+                )
+                .into_iter()
+                .for_each(|coordinate| {
+                    let operations = self.coordinates.entry(coordinate).or_default();
+                    if !operations.contains(&operation) {
+                        operations.push(operation.clone());
+                    }
+                });
+            });
+    }
+
+    pub fn is_used(&self, coordinate: &str) -> bool {
+        self.coordinates.contains_key(coordinate)
+    }
+
+    /// The operations known to use `coordinate`
+    pub fn operations(&self, coordinate: &str) -> &[OperationReference] {
+        self.coordinates
+            .get(coordinate)
+            .map_or(&[], |operations| operations.as_slice())
+    }
+}
+
+impl<T: Into<String>> FromIterator<T> for Usage {
+    fn from_iter<I: IntoIterator<Item = T>>(coordinates: I) -> Self {
+        let mut usage = Self::new();
+        coordinates
+            .into_iter()
+            .for_each(|coordinate| usage.add_coordinate(coordinate));
+        usage
+    }
+}
+
+struct UsageCollector<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    executable_document: &'a E,
+    schema_definition: &'a S,
+    coordinates: HashSet<String>,
+    visited_fragments: HashSet<&'a str>,
+    visited_input_types: HashSet<&'a str>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> UsageCollector<'a, E, S> {
+    fn collect(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_definition: &'a E::OperationDefinition,
+    ) -> HashSet<String> {
+        let mut collector = Self {
+            executable_document,
+            schema_definition,
+            coordinates: HashSet::new(),
+            visited_fragments: HashSet::new(),
+            visited_input_types: HashSet::new(),
+        };
+        let operation_definition = operation_definition.as_ref();
+
+        if let Some(variable_definitions) = operation_definition.variable_definitions() {
+            variable_definitions.iter().for_each(|variable_definition| {
+                collector.visit_directives(variable_definition.directives());
+                collector.visit_entire_input_type(variable_definition.r#type().as_ref().name());
+            });
+        }
+        collector.visit_directives(operation_definition.directives());
+
+        let root_operation_type = match operation_definition.operation_type() {
+            OperationType::Query => Some(schema_definition.query()),
+            OperationType::Mutation => schema_definition.mutation(),
+            OperationType::Subscription => schema_definition.subscription(),
+        };
+        if let Some(root_operation_type) = root_operation_type {
+            collector
+                .coordinates
+                .insert(root_operation_type.name().to_owned());
+            collector.visit_selection_set(
+                TypeDefinitionReference::Object(root_operation_type),
+                operation_definition.selection_set(),
+            );
+        }
+
+        collector.coordinates
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        scope: TypeDefinitionReference<'a, S::TypeDefinition>,
+        selection_set: &'a E::SelectionSet,
+    ) {
+        selection_set.iter().for_each(|selection| {
+            let selection = selection.as_ref();
+            self.visit_directives(selection.directives());
+            match selection {
+                SelectionReference::Field(field) => self.visit_field(scope, field),
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    let scope = match inline_fragment.type_condition() {
+                        Some(type_condition) => self.visit_type(type_condition),
+                        None => Some(scope),
+                    };
+                    if let Some(scope) = scope {
+                        self.visit_selection_set(scope, inline_fragment.selection_set());
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    if !self.visited_fragments.insert(fragment_spread.name()) {
+                        return;
+                    }
+                    let Some(fragment_definition) = self
+                        .executable_document
+                        .fragment_definitions()
+                        .find(|fragment_definition| {
+                            fragment_definition.name() == fragment_spread.name()
+                        })
+                    else {
+                        return;
+                    };
+                    self.visit_directives(fragment_definition.directives());
+                    if let Some(scope) = self.visit_type(fragment_definition.type_condition()) {
+                        self.visit_selection_set(scope, fragment_definition.selection_set());
+                    }
+                }
+            }
+        });
+    }
+
+    fn visit_field(
+        &mut self,
+        scope: TypeDefinitionReference<'a, S::TypeDefinition>,
+        field: &'a E::Field,
+    ) {
+        let Some(field_definition) = scope
+            .fields_definition()
+            .and_then(|fields_definition| fields_definition.get(field.name()))
+        else {
+            return;
+        };
+
+        // a field selected on an interface is resolved by each of its implementations
+        let mut parent_type_names = vec![scope.name()];
+        if let TypeDefinitionReference::Interface(interface_type_definition) = scope {
+            parent_type_names.extend(
+                self.schema_definition
+                    .get_interface_implementors(interface_type_definition)
+                    .map(|otd| otd.name()),
+            );
+        }
+
+        parent_type_names.iter().for_each(|parent_type_name| {
+            self.coordinates
+                .insert(format!("{}.{}", parent_type_name, field.name()));
+        });
+
+        if let Some(arguments) = field.arguments() {
+            arguments.iter().for_each(|argument| {
+                parent_type_names.iter().for_each(|parent_type_name| {
+                    self.coordinates.insert(format!(
+                        "{}.{}({}:)",
+                        parent_type_name,
+                        field.name(),
+                        argument.name()
+                    ));
+                });
+                if let Some(argument_definition) = field_definition
+                    .arguments_definition()
+                    .and_then(|arguments_definition| arguments_definition.get(argument.name()))
+                {
+                    self.visit_value(argument.value(), argument_definition.r#type());
+                }
+            });
+        }
+
+        let Some(field_type) = self.visit_type(field_definition.r#type().base_name()) else {
+            return;
+        };
+        match field_type {
+            TypeDefinitionReference::Enum(enum_type_definition) => {
+                self.visit_enum_values(enum_type_definition)
+            }
+            _ => {
+                if let Some(selection_set) = field.selection_set() {
+                    self.visit_selection_set(field_type, selection_set);
+                }
+            }
+        }
+    }
+
+    fn visit_type(&mut self, name: &str) -> Option<TypeDefinitionReference<'a, S::TypeDefinition>> {
+        self.coordinates.insert(name.to_owned());
+        self.schema_definition.get_type_definition(name)
+    }
+
+    fn visit_enum_values(&mut self, enum_type_definition: &'a S::EnumTypeDefinition) {
+        enum_type_definition
+            .enum_value_definitions()
+            .iter()
+            .for_each(|enum_value_definition| {
+                self.coordinates.insert(format!(
+                    "{}.{}",
+                    enum_type_definition.name(),
+                    enum_value_definition.name()
+                ));
+            });
+    }
+
+    /// Marks every input field and enum value reachable from the input type named `name` as
+    /// used, for values whose contents are not known statically
+    fn visit_entire_input_type(&mut self, name: &str) {
+        match self.visit_type(name) {
+            Some(TypeDefinitionReference::InputObject(input_object_type_definition)) => {
+                if !self
+                    .visited_input_types
+                    .insert(input_object_type_definition.name())
+                {
+                    return;
+                }
+                input_object_type_definition
+                    .input_field_definitions()
+                    .iter()
+                    .for_each(|input_value_definition| {
+                        self.coordinates.insert(format!(
+                            "{}.{}",
+                            input_object_type_definition.name(),
+                            input_value_definition.name()
+                        ));
+                        self.visit_entire_input_type(input_type_base_name(
+                            input_value_definition.r#type(),
+                        ));
+                    });
+            }
+            Some(TypeDefinitionReference::Enum(enum_type_definition)) => {
+                self.visit_enum_values(enum_type_definition)
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_value<const CONST: bool, V: Value<CONST>>(
+        &mut self,
+        value: &'a V,
+        input_type: &'a S::InputType,
+    ) {
+        match input_type.as_shallow_ref() {
+            ShallowInputTypeReference::List(inner, _) => match value.as_ref() {
+                ValueReference::List(values) => values
+                    .iter()
+                    .for_each(|value| self.visit_value(value, inner)),
+                _ => self.visit_value(value, inner),
+            },
+            ShallowInputTypeReference::Base(name, _) => {
+                match (self.visit_type(name), value.as_ref()) {
+                    (
+                        Some(TypeDefinitionReference::InputObject(input_object_type_definition)),
+                        ValueReference::Object(object),
+                    ) => object.iter().for_each(|(key, value)| {
+                        self.coordinates
+                            .insert(format!("{}.{}", name, key.as_ref()));
+                        if let Some(input_value_definition) = input_object_type_definition
+                            .input_field_definitions()
+                            .get(key.as_ref())
+                        {
+                            self.visit_value(value, input_value_definition.r#type());
+                        }
+                    }),
+                    (
+                        Some(TypeDefinitionReference::Enum(_)),
+                        ValueReference::Enum(enum_value) | ValueReference::String(enum_value),
+                    ) => {
+                        self.coordinates.insert(format!("{name}.{enum_value}"));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn visit_directives<const CONST: bool, D: Directives<CONST>>(
+        &mut self,
+        directives: Option<&'a D>,
+    ) {
+        directives
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+            .for_each(|directive| {
+                self.coordinates.insert(format!("@{}", directive.name()));
+                let directive_definition = self
+                    .schema_definition
+                    .get_directive_definition(directive.name());
+                directive
+                    .arguments()
+                    .into_iter()
+                    .flat_map(|arguments| arguments.iter())
+                    .for_each(|argument| {
+                        self.coordinates.insert(format!(
+                            "@{}({}:)",
+                            directive.name(),
+                            argument.name()
+                        ));
+                        if let Some(argument_definition) = directive_definition
+                            .and_then(|directive_definition| {
+                                directive_definition.arguments_definition()
+                            })
+                            .and_then(|arguments_definition| {
+                                arguments_definition.get(argument.name())
+                            })
+                        {
+                            self.visit_value(argument.value(), argument_definition.r#type());
+                        }
+                    });
+            });
+    }
+}
+
+fn input_type_base_name<I: InputType>(input_type: &I) -> &str {
+    match input_type.as_shallow_ref() {
+        ShallowInputTypeReference::Base(name, _) => name,
+        ShallowInputTypeReference::List(inner, _) => input_type_base_name(inner),
+    }
+}
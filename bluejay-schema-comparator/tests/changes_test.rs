@@ -154,6 +154,97 @@ fn test_safe_input_field_changes() {
     assert!(change_h.breaking());
 }
 
+#[test]
+fn test_root_operation_type_changes() {
+    let old_schema = r#"
+        schema {
+            query: Query
+            subscription: Subscription
+        }
+
+        type Query {
+            foo: String
+        }
+
+        type RootQuery {
+            foo: String
+        }
+
+        type Mutation {
+            bar: String
+        }
+
+        type Subscription {
+            baz: String
+        }
+    "#;
+
+    let new_schema = r#"
+        schema {
+            query: RootQuery
+            mutation: Mutation
+        }
+
+        type Query {
+            foo: String
+        }
+
+        type RootQuery {
+            foo: String
+        }
+
+        type Mutation {
+            bar: String
+        }
+
+        type Subscription {
+            baz: String
+        }
+    "#;
+
+    let document_a: DefinitionDocument = DefinitionDocument::parse(old_schema).result.unwrap();
+    let document_b: DefinitionDocument = DefinitionDocument::parse(new_schema).result.unwrap();
+
+    let schema_definition_a = SchemaDefinition::try_from(&document_a).unwrap();
+    let schema_definition_b = SchemaDefinition::try_from(&document_b).unwrap();
+
+    let result = compare(&schema_definition_a, &schema_definition_b);
+    let changes = result.changes;
+
+    assert_eq!(
+        3,
+        changes
+            .iter()
+            .filter(|change| change.path().starts_with("schema."))
+            .count()
+    );
+
+    let query_change = change_for_path(&changes, "schema.query").unwrap();
+    assert!(query_change.breaking());
+    assert_eq!(
+        "Schema query root changed from `Query` to `RootQuery`",
+        query_change.message()
+    );
+
+    let mutation_change = change_for_path(&changes, "schema.mutation").unwrap();
+    assert!(mutation_change.non_breaking());
+    assert_eq!(
+        "Schema mutation root `Mutation` was added",
+        mutation_change.message()
+    );
+
+    let subscription_change = change_for_path(&changes, "schema.subscription").unwrap();
+    assert!(subscription_change.breaking());
+    assert_eq!(
+        "Schema subscription root `Subscription` was removed",
+        subscription_change.message()
+    );
+    assert_eq!(
+        Some("Subscription".to_string()),
+        subscription_change.usage_coordinate()
+    );
+}
+
 fn change_for_path<'a, S: CoreSchemaDefinition>(
     changes: &'a [Change<'a, S>],
     path: &str,
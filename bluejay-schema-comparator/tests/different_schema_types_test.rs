@@ -0,0 +1,65 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
+    Parse,
+};
+use bluejay_schema_comparator::compare;
+use bluejay_visibility::{Cache, NullWarden, SchemaDefinition as VisibilitySchemaDefinition};
+
+const SCHEMA: &str = r#"
+    type Query {
+        pets(species: Species = DOG): [Pet!]!
+    }
+
+    type Pet {
+        name: String!
+        species: Species!
+    }
+
+    enum Species {
+        CAT
+        DOG
+    }
+"#;
+
+#[test]
+fn test_compare_different_schema_types() {
+    let document: DefinitionDocument = DefinitionDocument::parse(SCHEMA).result.unwrap();
+    let schema_definition = ParserSchemaDefinition::try_from(&document).unwrap();
+    let cache = Cache::new(NullWarden::default(), &schema_definition);
+    let visibility_schema_definition = VisibilitySchemaDefinition::new(&cache).unwrap();
+
+    assert!(compare(&schema_definition, &visibility_schema_definition)
+        .changes
+        .is_empty());
+    assert!(compare(&visibility_schema_definition, &schema_definition)
+        .changes
+        .is_empty());
+
+    let new_schema = r#"
+        type Query {
+            pets(species: Species = CAT): [Pet!]!
+        }
+
+        type Pet {
+            name: String
+            species: Species!
+        }
+
+        enum Species {
+            CAT
+            DOG
+            BIRD
+        }
+    "#;
+    let new_document: DefinitionDocument = DefinitionDocument::parse(new_schema).result.unwrap();
+    let new_schema_definition = ParserSchemaDefinition::try_from(&new_document).unwrap();
+
+    let changes = compare(&visibility_schema_definition, &new_schema_definition).changes;
+    let mut paths: Vec<_> = changes
+        .iter()
+        .map(|change| (change.path(), change.criticality_level()))
+        .collect();
+    paths.sort();
+
+    insta::assert_debug_snapshot!(paths);
+}
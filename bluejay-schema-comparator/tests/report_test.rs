@@ -0,0 +1,42 @@
+#![cfg(feature = "serde")]
+
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    Parse,
+};
+use bluejay_schema_comparator::{compare, ComparisonReport, CriticalityLevel};
+
+#[test]
+fn test_report_serialization() {
+    let old_schema = r#"
+        type Query {
+            a: String!
+            b(arg: Int): String
+            c: String @deprecated
+        }
+    "#;
+
+    let new_schema = r#"
+        type Query {
+            a: String
+            b(arg: Int = 1): String
+            d: String
+        }
+    "#;
+
+    let document_a: DefinitionDocument = DefinitionDocument::parse(old_schema).result.unwrap();
+    let document_b: DefinitionDocument = DefinitionDocument::parse(new_schema).result.unwrap();
+
+    let schema_definition_a = SchemaDefinition::try_from(&document_a).unwrap();
+    let schema_definition_b = SchemaDefinition::try_from(&document_b).unwrap();
+
+    let report = ComparisonReport::from(&compare(&schema_definition_a, &schema_definition_b));
+    assert!(report.has_breaking_changes());
+    assert_eq!(CriticalityLevel::Breaking, report.changes[0].criticality);
+
+    let json = serde_json::to_string_pretty(&report).unwrap();
+    insta::assert_snapshot!(json);
+
+    let deserialized: ComparisonReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(report, deserialized);
+}
@@ -0,0 +1,230 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_schema_comparator::{
+    compare_with_usage, ChangeWithUsage, CriticalityLevel, OperationReference, Usage,
+};
+
+const OLD_SCHEMA: &str = r#"
+    interface Node {
+        id: ID!
+    }
+
+    type Product implements Node {
+        id: ID!
+        title: String!
+        legacyId: Int @deprecated
+        handle: String
+        price(currency: Currency): Float
+        status: Status
+    }
+
+    enum Currency {
+        USD
+        CAD
+        EUR
+    }
+
+    enum Status {
+        ACTIVE
+        DRAFT
+    }
+
+    input ProductFilter {
+        title: String
+        vendor: String
+    }
+
+    type Query {
+        node(id: ID!): Node
+        products(filter: ProductFilter): [Product!]!
+    }
+"#;
+
+const NEW_SCHEMA: &str = r#"
+    interface Node {
+        id: ID!
+    }
+
+    type Product implements Node {
+        id: Int!
+        price(currency: Currency): Float
+        status: Status
+    }
+
+    enum Currency {
+        USD
+        CAD
+    }
+
+    enum Status {
+        ACTIVE
+    }
+
+    input ProductFilter {
+        title: String
+    }
+
+    type Query {
+        node(id: ID!): Node
+        products(filter: ProductFilter): [Product!]!
+    }
+"#;
+
+const DOCUMENT: &str = r#"
+    query Products($filter: ProductFilter) {
+        products(filter: $filter) { title ...ProductPrice }
+    }
+
+    query Node {
+        node(id: "1") { id ... on Product { ...ProductPrice } }
+    }
+
+    fragment ProductPrice on Product {
+        price(currency: CAD)
+    }
+"#;
+
+fn with_schemas(f: impl FnOnce(&SchemaDefinition, &SchemaDefinition)) {
+    let document_a: DefinitionDocument = DefinitionDocument::parse(OLD_SCHEMA).result.unwrap();
+    let document_b: DefinitionDocument = DefinitionDocument::parse(NEW_SCHEMA).result.unwrap();
+
+    let schema_definition_a = SchemaDefinition::try_from(&document_a).unwrap();
+    let schema_definition_b = SchemaDefinition::try_from(&document_b).unwrap();
+
+    f(&schema_definition_a, &schema_definition_b)
+}
+
+fn change_for_path<'a, 'b>(
+    changes: &'b [ChangeWithUsage<'a, SchemaDefinition<'a>>],
+    path: &str,
+) -> &'b ChangeWithUsage<'a, SchemaDefinition<'a>> {
+    changes
+        .iter()
+        .find(|change| change.change.path() == path)
+        .unwrap_or_else(|| panic!("No change for {path}"))
+}
+
+fn operation(name: &str) -> OperationReference {
+    OperationReference {
+        document: "products.graphql".to_owned(),
+        operation: Some(name.to_owned()),
+    }
+}
+
+#[test]
+fn test_compare_with_executable_documents() {
+    with_schemas(|old_schema, new_schema| {
+        let executable_document = ExecutableDocument::parse(DOCUMENT).result.unwrap();
+        let mut usage = Usage::new();
+        usage.add_executable_document("products.graphql", &executable_document, old_schema);
+
+        let result = compare_with_usage(old_schema, new_schema, &usage);
+        let changes = result.changes.as_slice();
+
+        // selected on the `Node` interface, which `Product` implements
+        let id = change_for_path(changes, "Product.id");
+        assert_eq!(CriticalityLevel::Breaking, id.criticality_level());
+        assert_eq!(&[operation("Node")], id.affected_operations());
+
+        let title = change_for_path(changes, "Product.title");
+        assert_eq!(CriticalityLevel::Breaking, title.criticality_level());
+        assert_eq!(&[operation("Products")], title.affected_operations());
+
+        let legacy_id = change_for_path(changes, "Product.legacyId");
+        assert!(!legacy_id.is_used());
+        assert_eq!(CriticalityLevel::Safe, legacy_id.criticality_level());
+        assert_eq!(
+            "No known operation uses `Product.legacyId`.",
+            legacy_id.criticality().reason()
+        );
+
+        let handle = change_for_path(changes, "Product.handle");
+        assert_eq!(CriticalityLevel::Dangerous, handle.criticality_level());
+        assert!(handle.affected_operations().is_empty());
+
+        // only `CAD` is used as a literal
+        let eur = change_for_path(changes, "Currency.EUR");
+        assert_eq!(CriticalityLevel::Dangerous, eur.criticality_level());
+
+        // every value of an enum returned by a field may be received by a client
+        let draft = change_for_path(changes, "Status.DRAFT");
+        assert_eq!(CriticalityLevel::Dangerous, draft.criticality_level());
+
+        // every field of an input object provided through a variable may be used
+        let vendor = change_for_path(changes, "ProductFilter.vendor");
+        assert_eq!(CriticalityLevel::Breaking, vendor.criticality_level());
+        assert_eq!(&[operation("Products")], vendor.affected_operations());
+
+        assert_eq!(
+            CriticalityLevel::Breaking,
+            result.changes[0].criticality_level()
+        );
+    });
+}
+
+#[test]
+fn test_compare_with_coordinates() {
+    with_schemas(|old_schema, new_schema| {
+        let usage = Usage::from_iter(["Query.node", "Product.handle", "Status.DRAFT"]);
+
+        let result = compare_with_usage(old_schema, new_schema, &usage);
+        let changes = result.changes.as_slice();
+
+        let handle = change_for_path(changes, "Product.handle");
+        assert_eq!(CriticalityLevel::Breaking, handle.criticality_level());
+        assert!(handle.affected_operations().is_empty());
+
+        let draft = change_for_path(changes, "Status.DRAFT");
+        assert_eq!(CriticalityLevel::Breaking, draft.criticality_level());
+
+        let title = change_for_path(changes, "Product.title");
+        assert_eq!(CriticalityLevel::Dangerous, title.criticality_level());
+    });
+}
+
+#[test]
+fn test_compare_with_newly_required_input_values() {
+    let document_a: DefinitionDocument = DefinitionDocument::parse(
+        r#"
+        input ProductFilter { title: String vendor: String }
+        type Query { products(first: Int, filter: ProductFilter): [String!]! }
+        "#,
+    )
+    .result
+    .unwrap();
+    let document_b: DefinitionDocument = DefinitionDocument::parse(
+        r#"
+        input ProductFilter { title: String! vendor: String }
+        type Query { products(first: Int!, filter: ProductFilter): [String!]! }
+        "#,
+    )
+    .result
+    .unwrap();
+    let old_schema = SchemaDefinition::try_from(&document_a).unwrap();
+    let new_schema = SchemaDefinition::try_from(&document_b).unwrap();
+
+    let executable_document =
+        ExecutableDocument::parse(r#"query Products { products(filter: { vendor: "x" }) }"#)
+            .result
+            .unwrap();
+    let mut usage = Usage::new();
+    usage.add_executable_document("products.graphql", &executable_document, &old_schema);
+
+    let result = compare_with_usage(&old_schema, &new_schema, &usage);
+    let changes = result.changes.as_slice();
+
+    // the omitted argument is now required, so every use of the field breaks
+    let first = change_for_path(changes, "Query.products.first");
+    assert!(first.is_used());
+    assert_eq!(CriticalityLevel::Breaking, first.criticality_level());
+    assert_eq!(&[operation("Products")], first.affected_operations());
+
+    // likewise for an omitted input field of a used input object
+    let title = change_for_path(changes, "ProductFilter.title");
+    assert!(title.is_used());
+    assert_eq!(CriticalityLevel::Breaking, title.criticality_level());
+    assert_eq!(&[operation("Products")], title.affected_operations());
+}
@@ -0,0 +1,51 @@
+use crate::Value;
+use bluejay_core::{Argument as CoreArgument, Arguments as CoreArguments, AsIter};
+
+#[derive(Debug, Clone)]
+pub struct Argument {
+    name: String,
+    value: Value,
+}
+
+impl Argument {
+    pub fn new(name: impl Into<String>, value: Value) -> Self {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+}
+
+impl CoreArgument<true> for Argument {
+    type Value = Value;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Arguments(Vec<Argument>);
+
+impl AsIter for Arguments {
+    type Item = Argument;
+    type Iterator<'a> = std::slice::Iter<'a, Argument>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl CoreArguments<true> for Arguments {
+    type Argument = Argument;
+}
+
+impl From<Vec<Argument>> for Arguments {
+    fn from(value: Vec<Argument>) -> Self {
+        Self(value)
+    }
+}
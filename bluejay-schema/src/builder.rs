@@ -0,0 +1,51 @@
+//! Builders for constructing a [`SchemaDefinition`](crate::SchemaDefinition) in code rather
+//! than from SDL. Type references are written as they would be in SDL, e.g. `[String!]!`,
+//! and are parsed along with every other reference being checked in [`SchemaBuilder::build`].
+
+mod directive_definition_builder;
+mod enum_type_builder;
+mod enum_value_builder;
+mod field_builder;
+mod input_object_type_builder;
+mod input_value_builder;
+mod interface_type_builder;
+mod object_type_builder;
+mod scalar_type_builder;
+mod schema_builder;
+mod union_type_builder;
+
+pub use directive_definition_builder::DirectiveDefinitionBuilder;
+pub use enum_type_builder::EnumTypeBuilder;
+pub use enum_value_builder::EnumValueBuilder;
+pub use field_builder::FieldBuilder;
+pub use input_object_type_builder::InputObjectTypeBuilder;
+pub use input_value_builder::InputValueBuilder;
+pub use interface_type_builder::InterfaceTypeBuilder;
+pub use object_type_builder::ObjectTypeBuilder;
+pub use scalar_type_builder::ScalarTypeBuilder;
+pub use schema_builder::SchemaBuilder;
+pub use union_type_builder::UnionTypeBuilder;
+
+use crate::{ArgumentsDefinition, Error, InterfaceImplementation, InterfaceImplementations};
+
+fn arguments_definition(
+    arguments: Vec<InputValueBuilder>,
+    errors: &mut Vec<Error>,
+) -> Option<ArgumentsDefinition> {
+    let arguments: Vec<_> = arguments
+        .into_iter()
+        .filter_map(|argument| argument.build(errors))
+        .collect();
+    (!arguments.is_empty()).then(|| ArgumentsDefinition::new(arguments))
+}
+
+fn interface_implementations(interfaces: Vec<String>) -> Option<InterfaceImplementations> {
+    (!interfaces.is_empty()).then(|| {
+        InterfaceImplementations::new(
+            interfaces
+                .into_iter()
+                .map(InterfaceImplementation::new)
+                .collect(),
+        )
+    })
+}
@@ -0,0 +1,55 @@
+use crate::builder::{arguments_definition, InputValueBuilder};
+use crate::{DirectiveDefinition, Error};
+use bluejay_core::definition::DirectiveLocation;
+
+/// Builds a directive definition
+#[derive(Debug, Clone)]
+pub struct DirectiveDefinitionBuilder {
+    description: Option<String>,
+    name: String,
+    arguments: Vec<InputValueBuilder>,
+    is_repeatable: bool,
+    locations: Vec<DirectiveLocation>,
+}
+
+impl DirectiveDefinitionBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            arguments: Vec::new(),
+            is_repeatable: false,
+            locations: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn argument(mut self, argument: InputValueBuilder) -> Self {
+        self.arguments.push(argument);
+        self
+    }
+
+    pub fn repeatable(mut self) -> Self {
+        self.is_repeatable = true;
+        self
+    }
+
+    pub fn location(mut self, location: DirectiveLocation) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    pub(crate) fn build(self, errors: &mut Vec<Error>) -> DirectiveDefinition {
+        DirectiveDefinition::new(
+            self.description,
+            self.name,
+            arguments_definition(self.arguments, errors),
+            self.is_repeatable,
+            self.locations,
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use crate::builder::EnumValueBuilder;
+use crate::{Directive, Directives, EnumTypeDefinition, EnumValueDefinitions};
+
+/// Builds an enum type definition
+#[derive(Debug, Clone)]
+pub struct EnumTypeBuilder {
+    description: Option<String>,
+    name: String,
+    values: Vec<EnumValueBuilder>,
+    directives: Vec<Directive>,
+}
+
+impl EnumTypeBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            values: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn value(mut self, value: EnumValueBuilder) -> Self {
+        self.values.push(value);
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self) -> EnumTypeDefinition {
+        EnumTypeDefinition::new(
+            self.description,
+            self.name,
+            EnumValueDefinitions::new(
+                self.values
+                    .into_iter()
+                    .map(EnumValueBuilder::build)
+                    .collect(),
+            ),
+            Directives::from_vec(self.directives),
+        )
+    }
+}
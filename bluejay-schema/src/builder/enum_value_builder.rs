@@ -0,0 +1,37 @@
+use crate::{Directive, Directives, EnumValueDefinition};
+
+/// Builds a value of an enum type
+#[derive(Debug, Clone)]
+pub struct EnumValueBuilder {
+    description: Option<String>,
+    name: String,
+    directives: Vec<Directive>,
+}
+
+impl EnumValueBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self) -> EnumValueDefinition {
+        EnumValueDefinition::new(
+            self.description,
+            self.name,
+            Directives::from_vec(self.directives),
+        )
+    }
+}
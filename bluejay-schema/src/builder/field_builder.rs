@@ -0,0 +1,55 @@
+use crate::builder::{arguments_definition, InputValueBuilder};
+use crate::{Directive, Directives, Error, FieldDefinition};
+
+/// Builds a field definition of an object or interface type
+#[derive(Debug, Clone)]
+pub struct FieldBuilder {
+    description: Option<String>,
+    name: String,
+    r#type: String,
+    arguments: Vec<InputValueBuilder>,
+    directives: Vec<Directive>,
+}
+
+impl FieldBuilder {
+    pub fn new(name: impl Into<String>, r#type: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            r#type: r#type.into(),
+            arguments: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn argument(mut self, argument: InputValueBuilder) -> Self {
+        self.arguments.push(argument);
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self, errors: &mut Vec<Error>) -> Option<FieldDefinition> {
+        let arguments_definition = arguments_definition(self.arguments, errors);
+        let r#type = self
+            .r#type
+            .parse()
+            .map_err(|error| errors.push(error))
+            .ok()?;
+        Some(FieldDefinition::new(
+            self.description,
+            self.name,
+            arguments_definition,
+            r#type,
+            Directives::from_vec(self.directives),
+        ))
+    }
+}
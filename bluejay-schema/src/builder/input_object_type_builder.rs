@@ -0,0 +1,51 @@
+use crate::builder::InputValueBuilder;
+use crate::{Directive, Directives, Error, InputFieldsDefinition, InputObjectTypeDefinition};
+
+/// Builds an input object type definition
+#[derive(Debug, Clone)]
+pub struct InputObjectTypeBuilder {
+    description: Option<String>,
+    name: String,
+    fields: Vec<InputValueBuilder>,
+    directives: Vec<Directive>,
+}
+
+impl InputObjectTypeBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            fields: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn field(mut self, field: InputValueBuilder) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self, errors: &mut Vec<Error>) -> InputObjectTypeDefinition {
+        InputObjectTypeDefinition::new(
+            self.description,
+            self.name,
+            InputFieldsDefinition::new(
+                self.fields
+                    .into_iter()
+                    .filter_map(|field| field.build(errors))
+                    .collect(),
+            ),
+            Directives::from_vec(self.directives),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{Directive, Directives, Error, InputValueDefinition, Value};
+
+/// Builds an argument or input field definition
+#[derive(Debug, Clone)]
+pub struct InputValueBuilder {
+    description: Option<String>,
+    name: String,
+    r#type: String,
+    default_value: Option<Value>,
+    directives: Vec<Directive>,
+}
+
+impl InputValueBuilder {
+    pub fn new(name: impl Into<String>, r#type: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            r#type: r#type.into(),
+            default_value: None,
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn default_value(mut self, default_value: Value) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self, errors: &mut Vec<Error>) -> Option<InputValueDefinition> {
+        let r#type = self
+            .r#type
+            .parse()
+            .map_err(|error| errors.push(error))
+            .ok()?;
+        Some(InputValueDefinition::new(
+            self.description,
+            self.name,
+            r#type,
+            self.default_value,
+            Directives::from_vec(self.directives),
+        ))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::builder::{interface_implementations, FieldBuilder};
+use crate::{Directive, Directives, Error, FieldsDefinition, ObjectTypeDefinition};
+
+/// Builds an object type definition
+#[derive(Debug, Clone)]
+pub struct ObjectTypeBuilder {
+    description: Option<String>,
+    name: String,
+    interfaces: Vec<String>,
+    fields: Vec<FieldBuilder>,
+    directives: Vec<Directive>,
+}
+
+impl ObjectTypeBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn implements(mut self, interface: impl Into<String>) -> Self {
+        self.interfaces.push(interface.into());
+        self
+    }
+
+    pub fn field(mut self, field: FieldBuilder) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self, errors: &mut Vec<Error>) -> ObjectTypeDefinition {
+        ObjectTypeDefinition::new(
+            self.description,
+            self.name,
+            interface_implementations(self.interfaces),
+            FieldsDefinition::new(
+                self.fields
+                    .into_iter()
+                    .filter_map(|field| field.build(errors))
+                    .collect(),
+            ),
+            Directives::from_vec(self.directives),
+        )
+    }
+}
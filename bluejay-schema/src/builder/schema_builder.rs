@@ -0,0 +1,157 @@
+use crate::builder::{
+    DirectiveDefinitionBuilder, EnumTypeBuilder, InputObjectTypeBuilder, InterfaceTypeBuilder,
+    ObjectTypeBuilder, ScalarTypeBuilder, UnionTypeBuilder,
+};
+use crate::{Directive, DirectiveDefinition, Directives, Error, SchemaDefinition, TypeDefinition};
+
+/// Builds a [`SchemaDefinition`]
+///
+/// ```
+/// use bluejay_schema::{FieldBuilder, InputValueBuilder, ObjectTypeBuilder, SchemaBuilder};
+///
+/// let schema_definition = SchemaBuilder::new("Query")
+///     .object(
+///         ObjectTypeBuilder::new("Query").field(
+///             FieldBuilder::new("greeting", "String!")
+///                 .argument(InputValueBuilder::new("name", "String")),
+///         ),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchemaBuilder {
+    description: Option<String>,
+    query: String,
+    mutation: Option<String>,
+    subscription: Option<String>,
+    objects: Vec<ObjectTypeBuilder>,
+    interfaces: Vec<InterfaceTypeBuilder>,
+    input_objects: Vec<InputObjectTypeBuilder>,
+    type_definitions: Vec<TypeDefinition>,
+    directive_definitions: Vec<DirectiveDefinitionBuilder>,
+    directives: Vec<Directive>,
+}
+
+impl SchemaBuilder {
+    /// `query` is the name of the object type to use as the query root
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            query: query.into(),
+            mutation: None,
+            subscription: None,
+            objects: Vec::new(),
+            interfaces: Vec::new(),
+            input_objects: Vec::new(),
+            type_definitions: Vec::new(),
+            directive_definitions: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn mutation(mut self, mutation: impl Into<String>) -> Self {
+        self.mutation = Some(mutation.into());
+        self
+    }
+
+    pub fn subscription(mut self, subscription: impl Into<String>) -> Self {
+        self.subscription = Some(subscription.into());
+        self
+    }
+
+    pub fn object(mut self, object: ObjectTypeBuilder) -> Self {
+        self.objects.push(object);
+        self
+    }
+
+    pub fn interface(mut self, interface: InterfaceTypeBuilder) -> Self {
+        self.interfaces.push(interface);
+        self
+    }
+
+    pub fn union(mut self, union: UnionTypeBuilder) -> Self {
+        self.type_definitions
+            .push(TypeDefinition::Union(union.build()));
+        self
+    }
+
+    pub fn r#enum(mut self, r#enum: EnumTypeBuilder) -> Self {
+        self.type_definitions
+            .push(TypeDefinition::Enum(r#enum.build()));
+        self
+    }
+
+    pub fn input_object(mut self, input_object: InputObjectTypeBuilder) -> Self {
+        self.input_objects.push(input_object);
+        self
+    }
+
+    pub fn scalar(mut self, scalar: ScalarTypeBuilder) -> Self {
+        self.type_definitions
+            .push(TypeDefinition::CustomScalar(scalar.build()));
+        self
+    }
+
+    pub fn directive_definition(
+        mut self,
+        directive_definition: DirectiveDefinitionBuilder,
+    ) -> Self {
+        self.directive_definitions.push(directive_definition);
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    /// Builds the schema definition, returning every invalid type reference, duplicate
+    /// definition, and reference to a type or directive that does not exist
+    pub fn build(self) -> Result<SchemaDefinition, Vec<Error>> {
+        let mut errors = Vec::new();
+
+        let mut type_definitions = self.type_definitions;
+        type_definitions.extend(
+            self.objects
+                .into_iter()
+                .map(|object| TypeDefinition::Object(object.build(&mut errors))),
+        );
+        type_definitions.extend(
+            self.interfaces
+                .into_iter()
+                .map(|interface| TypeDefinition::Interface(interface.build(&mut errors))),
+        );
+        type_definitions.extend(
+            self.input_objects
+                .into_iter()
+                .map(|input_object| TypeDefinition::InputObject(input_object.build(&mut errors))),
+        );
+        let directive_definitions: Vec<DirectiveDefinition> = self
+            .directive_definitions
+            .into_iter()
+            .map(|directive_definition| directive_definition.build(&mut errors))
+            .collect();
+
+        // invalid type references are reported on their own, since the definitions
+        // containing them are incomplete and would produce misleading errors
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        SchemaDefinition::new(
+            self.description,
+            type_definitions,
+            directive_definitions,
+            self.query,
+            self.mutation,
+            self.subscription,
+            Directives::from_vec(self.directives),
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{Directive, Directives, UnionMemberType, UnionMemberTypes, UnionTypeDefinition};
+
+/// Builds a union type definition
+#[derive(Debug, Clone)]
+pub struct UnionTypeBuilder {
+    description: Option<String>,
+    name: String,
+    members: Vec<String>,
+    directives: Vec<Directive>,
+}
+
+impl UnionTypeBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            description: None,
+            name: name.into(),
+            members: Vec::new(),
+            directives: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn member(mut self, member: impl Into<String>) -> Self {
+        self.members.push(member.into());
+        self
+    }
+
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    pub(crate) fn build(self) -> UnionTypeDefinition {
+        UnionTypeDefinition::new(
+            self.description,
+            self.name,
+            UnionMemberTypes::new(self.members.into_iter().map(UnionMemberType::new).collect()),
+            Directives::from_vec(self.directives),
+        )
+    }
+}
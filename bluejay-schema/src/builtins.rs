@@ -0,0 +1,228 @@
+//! Owned equivalents of the builtin directive definitions, introspection types and
+//! implicit fields that `bluejay-parser` adds to every schema
+
+use crate::{
+    ArgumentsDefinition, DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition,
+    EnumValueDefinitions, FieldDefinition, FieldsDefinition, InputValueDefinition,
+    ObjectTypeDefinition, TypeDefinition, Value,
+};
+use bluejay_core::definition::DirectiveLocation;
+use strum::IntoEnumIterator;
+
+fn input_value(name: &str, r#type: &str, default_value: Option<Value>) -> InputValueDefinition {
+    InputValueDefinition::new(
+        None,
+        name.to_owned(),
+        r#type.parse().unwrap(),
+        default_value,
+        None,
+    )
+}
+
+fn field(name: &str, r#type: &str, arguments: Vec<InputValueDefinition>) -> FieldDefinition {
+    FieldDefinition::new(
+        None,
+        name.to_owned(),
+        (!arguments.is_empty()).then(|| ArgumentsDefinition::new(arguments)),
+        r#type.parse().unwrap(),
+        None,
+    )
+}
+
+fn include_deprecated() -> Vec<InputValueDefinition> {
+    vec![input_value(
+        "includeDeprecated",
+        "Boolean",
+        Some(Value::Boolean(false)),
+    )]
+}
+
+fn object(name: &str, fields: Vec<FieldDefinition>) -> TypeDefinition {
+    TypeDefinition::Object(
+        ObjectTypeDefinition::new(
+            None,
+            name.to_owned(),
+            None,
+            FieldsDefinition::new(fields),
+            None,
+        )
+        .builtin(),
+    )
+}
+
+fn r#enum(name: &str, values: impl Iterator<Item = String>) -> TypeDefinition {
+    TypeDefinition::Enum(
+        EnumTypeDefinition::new(
+            None,
+            name.to_owned(),
+            EnumValueDefinitions::new(
+                values
+                    .map(|value| EnumValueDefinition::new(None, value, None))
+                    .collect(),
+            ),
+            None,
+        )
+        .builtin(),
+    )
+}
+
+pub(crate) fn introspection_type_definitions() -> Vec<TypeDefinition> {
+    vec![
+        object(
+            "__Schema",
+            vec![
+                field("description", "String", vec![]),
+                field("types", "[__Type!]!", vec![]),
+                field("queryType", "__Type!", vec![]),
+                field("mutationType", "__Type", vec![]),
+                field("subscriptionType", "__Type", vec![]),
+                field("directives", "[__Directive!]!", vec![]),
+            ],
+        ),
+        object(
+            "__Type",
+            vec![
+                field("kind", "__TypeKind!", vec![]),
+                field("name", "String", vec![]),
+                field("description", "String", vec![]),
+                field("fields", "[__Field!]", include_deprecated()),
+                field("interfaces", "[__Type!]", vec![]),
+                field("possibleTypes", "[__Type!]", vec![]),
+                field("enumValues", "[__EnumValue!]", include_deprecated()),
+                field("inputFields", "[__InputValue!]", include_deprecated()),
+                field("ofType", "__Type", vec![]),
+                field("specifiedByURL", "String", vec![]),
+                field("isOneOf", "Boolean", vec![]),
+            ],
+        ),
+        object(
+            "__Field",
+            vec![
+                field("name", "String!", vec![]),
+                field("description", "String", vec![]),
+                field("args", "[__InputValue!]!", include_deprecated()),
+                field("type", "__Type!", vec![]),
+                field("isDeprecated", "Boolean!", vec![]),
+                field("deprecationReason", "String", vec![]),
+            ],
+        ),
+        object(
+            "__InputValue",
+            vec![
+                field("name", "String!", vec![]),
+                field("description", "String", vec![]),
+                field("type", "__Type!", vec![]),
+                field("defaultValue", "String", vec![]),
+                field("isDeprecated", "Boolean!", vec![]),
+                field("deprecationReason", "String", vec![]),
+            ],
+        ),
+        object(
+            "__EnumValue",
+            vec![
+                field("name", "String!", vec![]),
+                field("description", "String", vec![]),
+                field("isDeprecated", "Boolean!", vec![]),
+                field("deprecationReason", "String", vec![]),
+            ],
+        ),
+        object(
+            "__Directive",
+            vec![
+                field("name", "String!", vec![]),
+                field("description", "String", vec![]),
+                field("locations", "[__DirectiveLocation!]!", vec![]),
+                field("args", "[__InputValue!]!", include_deprecated()),
+                field("isRepeatable", "Boolean!", vec![]),
+            ],
+        ),
+        r#enum(
+            "__TypeKind",
+            [
+                "SCALAR",
+                "OBJECT",
+                "INTERFACE",
+                "UNION",
+                "ENUM",
+                "INPUT_OBJECT",
+                "LIST",
+                "NON_NULL",
+            ]
+            .into_iter()
+            .map(ToOwned::to_owned),
+        ),
+        r#enum(
+            "__DirectiveLocation",
+            DirectiveLocation::iter().map(|location| location.to_string()),
+        ),
+    ]
+}
+
+pub(crate) fn directive_definitions() -> Vec<DirectiveDefinition> {
+    let directive = |name: &str, arguments: Vec<InputValueDefinition>, locations| {
+        DirectiveDefinition::new(
+            None,
+            name.to_owned(),
+            (!arguments.is_empty()).then(|| ArgumentsDefinition::new(arguments)),
+            false,
+            locations,
+        )
+        .builtin()
+    };
+    let conditional_locations = || {
+        vec![
+            DirectiveLocation::Field,
+            DirectiveLocation::FragmentSpread,
+            DirectiveLocation::InlineFragment,
+        ]
+    };
+
+    vec![
+        directive(
+            "deprecated",
+            vec![input_value(
+                "reason",
+                "String",
+                Some(Value::String("No longer supported".to_owned())),
+            )],
+            vec![
+                DirectiveLocation::FieldDefinition,
+                DirectiveLocation::ArgumentDefinition,
+                DirectiveLocation::InputFieldDefinition,
+                DirectiveLocation::EnumValue,
+            ],
+        ),
+        directive(
+            "include",
+            vec![input_value("if", "Boolean!", None)],
+            conditional_locations(),
+        ),
+        directive("oneOf", vec![], vec![DirectiveLocation::InputObject]),
+        directive(
+            "skip",
+            vec![input_value("if", "Boolean!", None)],
+            conditional_locations(),
+        ),
+        directive(
+            "specifiedBy",
+            vec![input_value("url", "String!", None)],
+            vec![DirectiveLocation::Scalar],
+        ),
+    ]
+}
+
+pub(crate) fn __typename() -> FieldDefinition {
+    field("__typename", "String!", vec![]).builtin()
+}
+
+pub(crate) fn query_root_fields() -> [FieldDefinition; 2] {
+    [
+        field("__schema", "__Schema!", vec![]).builtin(),
+        field(
+            "__type",
+            "__Type",
+            vec![input_value("name", "String!", None)],
+        )
+        .builtin(),
+    ]
+}
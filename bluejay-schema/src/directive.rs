@@ -0,0 +1,44 @@
+use crate::{Argument, Arguments, DirectiveDefinition};
+use bluejay_core::definition::{self, SchemaDefinition};
+use bluejay_core::Directive as CoreDirective;
+
+#[derive(Debug, Clone)]
+pub struct Directive {
+    name: String,
+    arguments: Option<Arguments>,
+}
+
+impl Directive {
+    pub fn new(name: impl Into<String>, arguments: impl IntoIterator<Item = Argument>) -> Self {
+        let arguments: Vec<Argument> = arguments.into_iter().collect();
+        Self {
+            name: name.into(),
+            arguments: (!arguments.is_empty()).then(|| arguments.into()),
+        }
+    }
+}
+
+impl CoreDirective<true> for Directive {
+    type Arguments = Arguments;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn arguments(&self) -> Option<&Self::Arguments> {
+        self.arguments.as_ref()
+    }
+}
+
+impl definition::Directive for Directive {
+    type DirectiveDefinition = DirectiveDefinition;
+
+    fn definition<'a, S: SchemaDefinition<DirectiveDefinition = Self::DirectiveDefinition>>(
+        &'a self,
+        schema_definition: &'a S,
+    ) -> &'a Self::DirectiveDefinition {
+        schema_definition
+            .get_directive_definition(&self.name)
+            .unwrap()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::ArgumentsDefinition;
+use bluejay_core::definition::{self, DirectiveLocation};
+
+#[derive(Debug, Clone)]
+pub struct DirectiveDefinition {
+    description: Option<String>,
+    name: String,
+    arguments_definition: Option<ArgumentsDefinition>,
+    is_repeatable: bool,
+    locations: Vec<DirectiveLocation>,
+    is_builtin: bool,
+}
+
+impl DirectiveDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        arguments_definition: Option<ArgumentsDefinition>,
+        is_repeatable: bool,
+        locations: Vec<DirectiveLocation>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            arguments_definition,
+            is_repeatable,
+            locations,
+            is_builtin: false,
+        }
+    }
+
+    pub(crate) fn builtin(mut self) -> Self {
+        self.is_builtin = true;
+        self
+    }
+}
+
+impl definition::DirectiveDefinition for DirectiveDefinition {
+    type ArgumentsDefinition = ArgumentsDefinition;
+    type DirectiveLocations = Vec<DirectiveLocation>;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn arguments_definition(&self) -> Option<&Self::ArgumentsDefinition> {
+        self.arguments_definition.as_ref()
+    }
+
+    fn is_repeatable(&self) -> bool {
+        self.is_repeatable
+    }
+
+    fn locations(&self) -> &Self::DirectiveLocations {
+        &self.locations
+    }
+
+    fn is_builtin(&self) -> bool {
+        self.is_builtin
+    }
+}
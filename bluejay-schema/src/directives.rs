@@ -0,0 +1,31 @@
+use crate::Directive;
+use bluejay_core::definition;
+use bluejay_core::{AsIter, Directives as CoreDirectives};
+
+#[derive(Debug, Clone, Default)]
+pub struct Directives(Vec<Directive>);
+
+impl Directives {
+    /// Returns `None` when there are no directives, matching how the
+    /// definition traits represent an absent directive list
+    pub(crate) fn from_vec(directives: Vec<Directive>) -> Option<Self> {
+        (!directives.is_empty()).then_some(Self(directives))
+    }
+}
+
+impl AsIter for Directives {
+    type Item = Directive;
+    type Iterator<'a> = std::slice::Iter<'a, Directive>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl CoreDirectives<true> for Directives {
+    type Directive = Directive;
+}
+
+impl definition::Directives for Directives {
+    type Directive = Directive;
+}
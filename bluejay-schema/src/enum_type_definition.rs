@@ -0,0 +1,61 @@
+use crate::{Directives, EnumValueDefinitions};
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct EnumTypeDefinition {
+    description: Option<String>,
+    name: String,
+    enum_value_definitions: EnumValueDefinitions,
+    directives: Option<Directives>,
+    is_builtin: bool,
+}
+
+impl EnumTypeDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        enum_value_definitions: EnumValueDefinitions,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            enum_value_definitions,
+            directives,
+            is_builtin: false,
+        }
+    }
+
+    pub(crate) fn builtin(mut self) -> Self {
+        self.is_builtin = true;
+        self
+    }
+}
+
+impl definition::EnumTypeDefinition for EnumTypeDefinition {
+    type EnumValueDefinitions = EnumValueDefinitions;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn enum_value_definitions(&self) -> &Self::EnumValueDefinitions {
+        &self.enum_value_definitions
+    }
+
+    fn is_builtin(&self) -> bool {
+        self.is_builtin
+    }
+}
+
+impl HasDirectives for EnumTypeDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::Directives;
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct EnumValueDefinition {
+    description: Option<String>,
+    name: String,
+    directives: Option<Directives>,
+}
+
+impl EnumValueDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            directives,
+        }
+    }
+}
+
+impl definition::EnumValueDefinition for EnumValueDefinition {
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl HasDirectives for EnumValueDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
@@ -0,0 +1,25 @@
+use crate::EnumValueDefinition;
+use bluejay_core::definition;
+use bluejay_core::AsIter;
+
+#[derive(Debug, Clone, Default)]
+pub struct EnumValueDefinitions(Vec<EnumValueDefinition>);
+
+impl EnumValueDefinitions {
+    pub(crate) fn new(items: Vec<EnumValueDefinition>) -> Self {
+        Self(items)
+    }
+}
+
+impl AsIter for EnumValueDefinitions {
+    type Item = EnumValueDefinition;
+    type Iterator<'a> = std::slice::Iter<'a, EnumValueDefinition>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl definition::EnumValueDefinitions for EnumValueDefinitions {
+    type EnumValueDefinition = EnumValueDefinition;
+}
@@ -0,0 +1,66 @@
+use bluejay_core::OperationType;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    InvalidTypeReference {
+        type_reference: String,
+    },
+    DuplicateTypeDefinition {
+        name: String,
+    },
+    DuplicateDirectiveDefinition {
+        name: String,
+    },
+    RootOperationTypeNotAnObject {
+        operation_type: OperationType,
+        name: String,
+    },
+    ReferencedTypeDoesNotExist {
+        name: String,
+        referenced_by: String,
+    },
+    ReferencedTypeHasWrongKind {
+        name: String,
+        expected: &'static str,
+        referenced_by: String,
+    },
+    ReferencedDirectiveDoesNotExist {
+        name: String,
+        referenced_by: String,
+    },
+}
+
+impl Error {
+    pub fn message(&self) -> String {
+        match self {
+            Self::InvalidTypeReference { type_reference } => {
+                format!("`{type_reference}` is not a valid type reference")
+            }
+            Self::DuplicateTypeDefinition { name } => {
+                format!("Multiple type definitions with name `{name}`")
+            }
+            Self::DuplicateDirectiveDefinition { name } => {
+                format!("Multiple directive definitions with name `{name}`")
+            }
+            Self::RootOperationTypeNotAnObject {
+                operation_type,
+                name,
+            } => {
+                format!("Root {operation_type} type `{name}` is not a defined object type")
+            }
+            Self::ReferencedTypeDoesNotExist {
+                name,
+                referenced_by,
+            } => format!("Type `{name}` referenced by `{referenced_by}` does not exist"),
+            Self::ReferencedTypeHasWrongKind {
+                name,
+                expected,
+                referenced_by,
+            } => format!("Type `{name}` referenced by `{referenced_by}` is not {expected}"),
+            Self::ReferencedDirectiveDoesNotExist {
+                name,
+                referenced_by,
+            } => format!("Directive `@{name}` used on `{referenced_by}` is not defined"),
+        }
+    }
+}
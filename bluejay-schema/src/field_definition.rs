@@ -0,0 +1,69 @@
+use crate::{ArgumentsDefinition, Directives, OutputType};
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct FieldDefinition {
+    description: Option<String>,
+    name: String,
+    arguments_definition: Option<ArgumentsDefinition>,
+    r#type: OutputType,
+    directives: Option<Directives>,
+    is_builtin: bool,
+}
+
+impl FieldDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        arguments_definition: Option<ArgumentsDefinition>,
+        r#type: OutputType,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            arguments_definition,
+            r#type,
+            directives,
+            is_builtin: false,
+        }
+    }
+
+    pub(crate) fn builtin(mut self) -> Self {
+        self.is_builtin = true;
+        self
+    }
+}
+
+impl definition::FieldDefinition for FieldDefinition {
+    type ArgumentsDefinition = ArgumentsDefinition;
+    type OutputType = OutputType;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn arguments_definition(&self) -> Option<&Self::ArgumentsDefinition> {
+        self.arguments_definition.as_ref()
+    }
+
+    fn r#type(&self) -> &Self::OutputType {
+        &self.r#type
+    }
+
+    fn is_builtin(&self) -> bool {
+        self.is_builtin
+    }
+}
+
+impl HasDirectives for FieldDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
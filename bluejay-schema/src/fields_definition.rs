@@ -0,0 +1,33 @@
+use crate::FieldDefinition;
+use bluejay_core::definition;
+use bluejay_core::AsIter;
+
+#[derive(Debug, Clone, Default)]
+pub struct FieldsDefinition(Vec<FieldDefinition>);
+
+impl FieldsDefinition {
+    pub(crate) fn new(items: Vec<FieldDefinition>) -> Self {
+        Self(items)
+    }
+
+    pub(crate) fn push(&mut self, item: FieldDefinition) {
+        self.0.push(item);
+    }
+
+    pub(crate) fn insert_first(&mut self, item: FieldDefinition) {
+        self.0.insert(0, item);
+    }
+}
+
+impl AsIter for FieldsDefinition {
+    type Item = FieldDefinition;
+    type Iterator<'a> = std::slice::Iter<'a, FieldDefinition>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl definition::FieldsDefinition for FieldsDefinition {
+    type FieldDefinition = FieldDefinition;
+}
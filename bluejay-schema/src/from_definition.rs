@@ -0,0 +1,265 @@
+use crate::{
+    Argument, ArgumentsDefinition, Directive, DirectiveDefinition, Directives, EnumTypeDefinition,
+    EnumValueDefinition, EnumValueDefinitions, Error, FieldDefinition, FieldsDefinition,
+    InputFieldsDefinition, InputObjectTypeDefinition, InputType, InputValueDefinition,
+    InterfaceImplementation, InterfaceImplementations, InterfaceTypeDefinition,
+    ObjectTypeDefinition, OutputType, ScalarTypeDefinition, SchemaDefinition, TypeDefinition,
+    UnionMemberType, UnionMemberTypes, UnionTypeDefinition, Value,
+};
+use bluejay_core::definition::{
+    DirectiveDefinition as _, EnumTypeDefinition as _, EnumValueDefinition as _,
+    FieldDefinition as _, HasDirectives, InputObjectTypeDefinition as _, InputType as _,
+    InputValueDefinition as _, InterfaceImplementation as _, InterfaceTypeDefinition as _,
+    ObjectTypeDefinition as _, OutputType as _, ScalarTypeDefinition as _,
+    SchemaDefinition as CoreSchemaDefinition, ShallowInputTypeReference,
+    ShallowOutputTypeReference, TypeDefinitionReference, UnionMemberType as _,
+    UnionTypeDefinition as _,
+};
+use bluejay_core::{Argument as _, AsIter, Directive as _};
+
+impl SchemaDefinition {
+    /// Copies any implementation of [`bluejay_core::definition::SchemaDefinition`] into an
+    /// owned schema definition. Builtin types, fields and directive definitions are skipped
+    /// and replaced with this crate's equivalents.
+    pub fn from_definition<S: CoreSchemaDefinition>(
+        schema_definition: &S,
+    ) -> Result<Self, Vec<Error>> {
+        let type_definitions = schema_definition
+            .type_definitions()
+            .filter(|tdr| !tdr.is_builtin())
+            .filter_map(type_definition::<S>)
+            .collect();
+        let directive_definitions = schema_definition
+            .directive_definitions()
+            .filter(|dd| !dd.is_builtin())
+            .map(directive_definition::<S>)
+            .collect();
+
+        Self::new(
+            schema_definition.description().map(ToOwned::to_owned),
+            type_definitions,
+            directive_definitions,
+            schema_definition.query().name().to_owned(),
+            schema_definition
+                .mutation()
+                .map(|otd| otd.name().to_owned()),
+            schema_definition
+                .subscription()
+                .map(|otd| otd.name().to_owned()),
+            directives::<S>(HasDirectives::directives(schema_definition)),
+        )
+    }
+}
+
+fn owned(s: Option<&str>) -> Option<String> {
+    s.map(ToOwned::to_owned)
+}
+
+fn directives<S: CoreSchemaDefinition>(
+    directives: Option<&<S as CoreSchemaDefinition>::Directives>,
+) -> Option<Directives> {
+    directives.and_then(|directives| {
+        Directives::from_vec(
+            directives
+                .iter()
+                .map(|directive| {
+                    Directive::new(
+                        directive.name(),
+                        directive.arguments().into_iter().flat_map(|arguments| {
+                            arguments.iter().map(|argument| {
+                                Argument::new(argument.name(), Value::from(argument.value()))
+                            })
+                        }),
+                    )
+                })
+                .collect(),
+        )
+    })
+}
+
+fn type_definition<S: CoreSchemaDefinition>(
+    tdr: TypeDefinitionReference<'_, S::TypeDefinition>,
+) -> Option<TypeDefinition> {
+    let type_definition = match tdr {
+        TypeDefinitionReference::BuiltinScalar(_) => return None,
+        TypeDefinitionReference::CustomScalar(cstd) => {
+            TypeDefinition::CustomScalar(ScalarTypeDefinition::new(
+                owned(cstd.description()),
+                cstd.name().to_owned(),
+                directives::<S>(cstd.directives()),
+            ))
+        }
+        TypeDefinitionReference::Object(otd) => TypeDefinition::Object(ObjectTypeDefinition::new(
+            owned(otd.description()),
+            otd.name().to_owned(),
+            otd.interface_implementations()
+                .map(interface_implementations::<S>),
+            fields_definition::<S>(otd.fields_definition()),
+            directives::<S>(otd.directives()),
+        )),
+        TypeDefinitionReference::Interface(itd) => {
+            TypeDefinition::Interface(InterfaceTypeDefinition::new(
+                owned(itd.description()),
+                itd.name().to_owned(),
+                itd.interface_implementations()
+                    .map(interface_implementations::<S>),
+                fields_definition::<S>(itd.fields_definition()),
+                directives::<S>(itd.directives()),
+            ))
+        }
+        TypeDefinitionReference::Union(utd) => TypeDefinition::Union(UnionTypeDefinition::new(
+            owned(utd.description()),
+            utd.name().to_owned(),
+            UnionMemberTypes::new(
+                utd.union_member_types()
+                    .iter()
+                    .map(|member| UnionMemberType::new(member.name().to_owned()))
+                    .collect(),
+            ),
+            directives::<S>(utd.directives()),
+        )),
+        TypeDefinitionReference::Enum(etd) => TypeDefinition::Enum(EnumTypeDefinition::new(
+            owned(etd.description()),
+            etd.name().to_owned(),
+            EnumValueDefinitions::new(
+                etd.enum_value_definitions()
+                    .iter()
+                    .map(|evd| {
+                        EnumValueDefinition::new(
+                            owned(evd.description()),
+                            evd.name().to_owned(),
+                            directives::<S>(evd.directives()),
+                        )
+                    })
+                    .collect(),
+            ),
+            directives::<S>(etd.directives()),
+        )),
+        TypeDefinitionReference::InputObject(iotd) => {
+            TypeDefinition::InputObject(InputObjectTypeDefinition::new(
+                owned(iotd.description()),
+                iotd.name().to_owned(),
+                InputFieldsDefinition::new(
+                    iotd.input_field_definitions()
+                        .iter()
+                        .map(input_value_definition::<S>)
+                        .collect(),
+                ),
+                directives::<S>(iotd.directives()),
+            ))
+        }
+    };
+    Some(type_definition)
+}
+
+fn interface_implementations<S: CoreSchemaDefinition>(
+    interface_implementations: &S::InterfaceImplementations,
+) -> InterfaceImplementations {
+    InterfaceImplementations::new(
+        interface_implementations
+            .iter()
+            .map(|ii| InterfaceImplementation::new(ii.name().to_owned()))
+            .collect(),
+    )
+}
+
+fn fields_definition<S: CoreSchemaDefinition>(
+    fields_definition: &S::FieldsDefinition,
+) -> FieldsDefinition {
+    FieldsDefinition::new(
+        fields_definition
+            .iter()
+            .filter(|fd| !fd.is_builtin())
+            .map(|fd| {
+                FieldDefinition::new(
+                    owned(fd.description()),
+                    fd.name().to_owned(),
+                    fd.arguments_definition().map(arguments_definition::<S>),
+                    output_type::<S>(fd.r#type()),
+                    directives::<S>(fd.directives()),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn arguments_definition<S: CoreSchemaDefinition>(
+    arguments_definition: &S::ArgumentsDefinition,
+) -> ArgumentsDefinition {
+    ArgumentsDefinition::new(
+        arguments_definition
+            .iter()
+            .map(input_value_definition::<S>)
+            .collect(),
+    )
+}
+
+fn input_value_definition<S: CoreSchemaDefinition>(
+    ivd: &S::InputValueDefinition,
+) -> InputValueDefinition {
+    InputValueDefinition::new(
+        owned(ivd.description()),
+        ivd.name().to_owned(),
+        input_type::<S>(ivd.r#type()),
+        ivd.default_value().map(Value::from),
+        directives::<S>(ivd.directives()),
+    )
+}
+
+fn input_type<S: CoreSchemaDefinition>(r#type: &S::InputType) -> InputType {
+    match r#type.as_shallow_ref() {
+        ShallowInputTypeReference::Base(name, required) => {
+            InputType::Base(name.to_owned(), required)
+        }
+        ShallowInputTypeReference::List(inner, required) => {
+            InputType::List(Box::new(input_type::<S>(inner)), required)
+        }
+    }
+}
+
+fn output_type<S: CoreSchemaDefinition>(r#type: &S::OutputType) -> OutputType {
+    match r#type.as_shallow_ref() {
+        ShallowOutputTypeReference::Base(name, required) => {
+            OutputType::Base(name.to_owned(), required)
+        }
+        ShallowOutputTypeReference::List(inner, required) => {
+            OutputType::List(Box::new(output_type::<S>(inner)), required)
+        }
+    }
+}
+
+fn directive_definition<S: CoreSchemaDefinition>(
+    directive_definition: &S::DirectiveDefinition,
+) -> DirectiveDefinition {
+    DirectiveDefinition::new(
+        owned(directive_definition.description()),
+        directive_definition.name().to_owned(),
+        directive_definition
+            .arguments_definition()
+            .map(arguments_definition::<S>),
+        directive_definition.is_repeatable(),
+        directive_definition.locations().iter().copied().collect(),
+    )
+}
+
+#[cfg(feature = "parser")]
+impl SchemaDefinition {
+    /// Parses a schema from GraphQL SDL. The result does not borrow `s`, unlike
+    /// [`bluejay_parser::ast::definition::SchemaDefinition`].
+    pub fn parse(s: &str) -> Result<Self, Vec<bluejay_parser::Error>> {
+        use bluejay_parser::ast::{
+            definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
+            Parse,
+        };
+
+        let definition_document: DefinitionDocument = DefinitionDocument::parse(s).result?;
+        let schema_definition = ParserSchemaDefinition::try_from(&definition_document)
+            .map_err(|errors| errors.into_iter().map(Into::into).collect::<Vec<_>>())?;
+        Self::from_definition(&schema_definition).map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|error| bluejay_parser::Error::new(error.message(), None, Vec::new()))
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use crate::InputValueDefinition;
+use bluejay_core::definition;
+use bluejay_core::AsIter;
+
+#[derive(Debug, Clone, Default)]
+pub struct InputFieldsDefinition(Vec<InputValueDefinition>);
+
+impl InputFieldsDefinition {
+    pub(crate) fn new(items: Vec<InputValueDefinition>) -> Self {
+        Self(items)
+    }
+}
+
+impl AsIter for InputFieldsDefinition {
+    type Item = InputValueDefinition;
+    type Iterator<'a> = std::slice::Iter<'a, InputValueDefinition>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl definition::InputFieldsDefinition for InputFieldsDefinition {
+    type InputValueDefinition = InputValueDefinition;
+}
@@ -0,0 +1,50 @@
+use crate::{Directives, InputFieldsDefinition};
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct InputObjectTypeDefinition {
+    description: Option<String>,
+    name: String,
+    input_field_definitions: InputFieldsDefinition,
+    directives: Option<Directives>,
+}
+
+impl InputObjectTypeDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        input_field_definitions: InputFieldsDefinition,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            input_field_definitions,
+            directives,
+        }
+    }
+}
+
+impl definition::InputObjectTypeDefinition for InputObjectTypeDefinition {
+    type InputFieldsDefinition = InputFieldsDefinition;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn input_field_definitions(&self) -> &Self::InputFieldsDefinition {
+        &self.input_field_definitions
+    }
+}
+
+impl HasDirectives for InputObjectTypeDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
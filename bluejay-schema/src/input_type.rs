@@ -0,0 +1,67 @@
+use crate::{EnumTypeDefinition, Error, InputObjectTypeDefinition, ScalarTypeDefinition};
+use bluejay_core::definition::{
+    self, InputTypeReference, SchemaDefinition, ShallowInputTypeReference,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputType {
+    Base(String, bool),
+    List(Box<InputType>, bool),
+}
+
+impl InputType {
+    pub(crate) fn base_name(&self) -> &str {
+        match self {
+            Self::Base(name, _) => name,
+            Self::List(inner, _) => inner.base_name(),
+        }
+    }
+}
+
+impl definition::InputType for InputType {
+    type CustomScalarTypeDefinition = ScalarTypeDefinition;
+    type InputObjectTypeDefinition = InputObjectTypeDefinition;
+    type EnumTypeDefinition = EnumTypeDefinition;
+
+    fn as_ref<
+        'a,
+        S: SchemaDefinition<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = Self::InputObjectTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+        >,
+    >(
+        &'a self,
+        schema_definition: &'a S,
+    ) -> InputTypeReference<'a, Self> {
+        match self {
+            Self::Base(name, required) => InputTypeReference::Base(
+                schema_definition
+                    .get_type_definition(name)
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+                *required,
+            ),
+            Self::List(inner, required) => InputTypeReference::List(inner, *required),
+        }
+    }
+
+    fn as_shallow_ref(&self) -> ShallowInputTypeReference<'_, Self> {
+        match self {
+            Self::Base(name, required) => ShallowInputTypeReference::Base(name, *required),
+            Self::List(inner, required) => ShallowInputTypeReference::List(inner, *required),
+        }
+    }
+}
+
+impl FromStr for InputType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::type_reference::parse(s, Self::Base, |inner, required| {
+            Self::List(Box::new(inner), required)
+        })
+    }
+}
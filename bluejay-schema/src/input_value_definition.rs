@@ -0,0 +1,58 @@
+use crate::{Directives, InputType, Value};
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct InputValueDefinition {
+    description: Option<String>,
+    name: String,
+    r#type: InputType,
+    default_value: Option<Value>,
+    directives: Option<Directives>,
+}
+
+impl InputValueDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        r#type: InputType,
+        default_value: Option<Value>,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            r#type,
+            default_value,
+            directives,
+        }
+    }
+}
+
+impl definition::InputValueDefinition for InputValueDefinition {
+    type InputType = InputType;
+    type Value = Value;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn r#type(&self) -> &Self::InputType {
+        &self.r#type
+    }
+
+    fn default_value(&self) -> Option<&Self::Value> {
+        self.default_value.as_ref()
+    }
+}
+
+impl HasDirectives for InputValueDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
@@ -0,0 +1,35 @@
+use crate::InterfaceTypeDefinition;
+use bluejay_core::definition::{self, SchemaDefinition};
+
+#[derive(Debug, Clone)]
+pub struct InterfaceImplementation {
+    name: String,
+}
+
+impl InterfaceImplementation {
+    pub(crate) fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl definition::InterfaceImplementation for InterfaceImplementation {
+    type InterfaceTypeDefinition = InterfaceTypeDefinition;
+
+    fn interface<
+        'a,
+        S: SchemaDefinition<InterfaceTypeDefinition = Self::InterfaceTypeDefinition>,
+    >(
+        &'a self,
+        schema_definition: &'a S,
+    ) -> &'a Self::InterfaceTypeDefinition {
+        schema_definition
+            .get_type_definition(&self.name)
+            .unwrap()
+            .as_interface()
+            .unwrap()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
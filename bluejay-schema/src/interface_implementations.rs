@@ -0,0 +1,25 @@
+use crate::InterfaceImplementation;
+use bluejay_core::definition;
+use bluejay_core::AsIter;
+
+#[derive(Debug, Clone, Default)]
+pub struct InterfaceImplementations(Vec<InterfaceImplementation>);
+
+impl InterfaceImplementations {
+    pub(crate) fn new(items: Vec<InterfaceImplementation>) -> Self {
+        Self(items)
+    }
+}
+
+impl AsIter for InterfaceImplementations {
+    type Item = InterfaceImplementation;
+    type Iterator<'a> = std::slice::Iter<'a, InterfaceImplementation>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl definition::InterfaceImplementations for InterfaceImplementations {
+    type InterfaceImplementation = InterfaceImplementation;
+}
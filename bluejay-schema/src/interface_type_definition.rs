@@ -0,0 +1,62 @@
+use crate::{Directives, FieldsDefinition, InterfaceImplementations};
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct InterfaceTypeDefinition {
+    description: Option<String>,
+    name: String,
+    interface_implementations: Option<InterfaceImplementations>,
+    fields_definition: FieldsDefinition,
+    directives: Option<Directives>,
+}
+
+impl InterfaceTypeDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        interface_implementations: Option<InterfaceImplementations>,
+        fields_definition: FieldsDefinition,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            interface_implementations,
+            fields_definition,
+            directives,
+        }
+    }
+
+    pub(crate) fn fields_definition_mut(&mut self) -> &mut FieldsDefinition {
+        &mut self.fields_definition
+    }
+}
+
+impl definition::InterfaceTypeDefinition for InterfaceTypeDefinition {
+    type FieldsDefinition = FieldsDefinition;
+    type InterfaceImplementations = InterfaceImplementations;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn interface_implementations(&self) -> Option<&Self::InterfaceImplementations> {
+        self.interface_implementations.as_ref()
+    }
+
+    fn fields_definition(&self) -> &Self::FieldsDefinition {
+        &self.fields_definition
+    }
+}
+
+impl HasDirectives for InterfaceTypeDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{
+    type_reference, Argument, ArgumentsDefinition, Directive, DirectiveDefinition, Directives,
+    EnumTypeDefinition, EnumValueDefinition, EnumValueDefinitions, Error, FieldDefinition,
+    FieldsDefinition, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputValueDefinition, InterfaceImplementation, InterfaceImplementations,
+    InterfaceTypeDefinition, ObjectTypeDefinition, OutputType, ScalarTypeDefinition,
+    SchemaDefinition, TypeDefinition, UnionMemberType, UnionMemberTypes, UnionTypeDefinition,
+    Value,
+};
+use bluejay_core::definition::DirectiveLocation;
+use bluejay_core::BuiltinScalarDefinition;
+use bluejay_parser::ast::{ConstValue, Parse};
+use serde_json::{Map, Value as JsonValue};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntrospectionError {
+    MissingSchema,
+    MissingField {
+        path: String,
+        field: &'static str,
+    },
+    InvalidField {
+        path: String,
+        field: &'static str,
+        expected: &'static str,
+    },
+    UnknownTypeKind {
+        path: String,
+        kind: String,
+    },
+    UnknownDirectiveLocation {
+        path: String,
+        location: String,
+    },
+    InvalidDefaultValue {
+        path: String,
+        default_value: String,
+    },
+    InvalidSchema(Vec<Error>),
+}
+
+impl IntrospectionError {
+    pub fn message(&self) -> String {
+        match self {
+            Self::MissingSchema => {
+                "Expected an introspection result containing a `__schema` object".to_owned()
+            }
+            Self::MissingField { path, field } => format!("`{path}` is missing field `{field}`"),
+            Self::InvalidField {
+                path,
+                field,
+                expected,
+            } => format!("Field `{field}` of `{path}` is not {expected}"),
+            Self::UnknownTypeKind { path, kind } => {
+                format!("`{path}` has unknown type kind `{kind}`")
+            }
+            Self::UnknownDirectiveLocation { path, location } => {
+                format!("`{path}` has unknown directive location `{location}`")
+            }
+            Self::InvalidDefaultValue {
+                path,
+                default_value,
+            } => format!("`{path}` has invalid default value `{default_value}`"),
+            Self::InvalidSchema(errors) => errors
+                .iter()
+                .map(Error::message)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+type Result<T> = std::result::Result<T, IntrospectionError>;
+
+impl SchemaDefinition {
+    /// Builds a schema definition from the result of an introspection query. Accepts a full
+    /// response (`{ "data": { "__schema": ... } }`), its `data` member, or the `__schema` object.
+    ///
+    /// Deprecations, `specifiedByURL` and `isOneOf` are represented as applications of the
+    /// `@deprecated`, `@specifiedBy` and `@oneOf` directives respectively. Introspection does
+    /// not expose other applied directives, so they cannot be recovered.
+    pub fn from_introspection(value: &JsonValue) -> Result<Self> {
+        let data = value.get("data").unwrap_or(value);
+        let schema = data
+            .get("__schema")
+            .or_else(|| data.get("queryType").is_some().then_some(data))
+            .and_then(JsonValue::as_object)
+            .ok_or(IntrospectionError::MissingSchema)?;
+        let path = "__schema";
+
+        let root_name = |field: &'static str| -> Result<Option<String>> {
+            optional_object(schema, field, path)?
+                .map(|root| required_str(root, "name", field).map(ToOwned::to_owned))
+                .transpose()
+        };
+        let query = root_name("queryType")?.ok_or(IntrospectionError::MissingField {
+            path: path.to_owned(),
+            field: "queryType",
+        })?;
+        let mutation = root_name("mutationType")?;
+        let subscription = root_name("subscriptionType")?;
+
+        let type_definitions = required_array(schema, "types", path)?
+            .iter()
+            .filter_map(|value| type_definition(value).transpose())
+            .collect::<Result<Vec<_>>>()?;
+
+        let directive_definitions = optional_array(schema, "directives", path)?
+            .into_iter()
+            .flatten()
+            .filter_map(|value| directive_definition(value).transpose())
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::new(
+            optional_str(schema, "description", path)?.map(ToOwned::to_owned),
+            type_definitions,
+            directive_definitions,
+            query,
+            mutation,
+            subscription,
+            None,
+        )
+        .map_err(IntrospectionError::InvalidSchema)
+    }
+}
+
+fn as_object<'a>(
+    value: &'a JsonValue,
+    field: &'static str,
+    path: &str,
+) -> Result<&'a Map<String, JsonValue>> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid(path, field, "a list of objects"))
+}
+
+fn get<'a>(object: &'a Map<String, JsonValue>, field: &'static str) -> Option<&'a JsonValue> {
+    object.get(field).filter(|value| !value.is_null())
+}
+
+fn invalid(path: &str, field: &'static str, expected: &'static str) -> IntrospectionError {
+    IntrospectionError::InvalidField {
+        path: path.to_owned(),
+        field,
+        expected,
+    }
+}
+
+fn missing(path: &str, field: &'static str) -> IntrospectionError {
+    IntrospectionError::MissingField {
+        path: path.to_owned(),
+        field,
+    }
+}
+
+fn optional_str<'a>(
+    object: &'a Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<Option<&'a str>> {
+    get(object, field)
+        .map(|value| {
+            value
+                .as_str()
+                .ok_or_else(|| invalid(path, field, "a string"))
+        })
+        .transpose()
+}
+
+fn required_str<'a>(
+    object: &'a Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<&'a str> {
+    optional_str(object, field, path)?.ok_or_else(|| missing(path, field))
+}
+
+fn optional_bool(
+    object: &Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<Option<bool>> {
+    get(object, field)
+        .map(|value| {
+            value
+                .as_bool()
+                .ok_or_else(|| invalid(path, field, "a boolean"))
+        })
+        .transpose()
+}
+
+fn optional_object<'a>(
+    object: &'a Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<Option<&'a Map<String, JsonValue>>> {
+    get(object, field)
+        .map(|value| {
+            value
+                .as_object()
+                .ok_or_else(|| invalid(path, field, "an object"))
+        })
+        .transpose()
+}
+
+fn optional_array<'a>(
+    object: &'a Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<Option<&'a Vec<JsonValue>>> {
+    get(object, field)
+        .map(|value| {
+            value
+                .as_array()
+                .ok_or_else(|| invalid(path, field, "a list"))
+        })
+        .transpose()
+}
+
+fn required_array<'a>(
+    object: &'a Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<&'a Vec<JsonValue>> {
+    optional_array(object, field, path)?.ok_or_else(|| missing(path, field))
+}
+
+fn names(
+    object: &Map<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<Option<Vec<String>>> {
+    optional_array(object, field, path)?
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| {
+                    required_str(as_object(value, field, path)?, "name", path)
+                        .map(ToOwned::to_owned)
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Returns `None` for builtin scalars and introspection types, which are always
+/// provided by the schema definition itself
+fn type_definition(value: &JsonValue) -> Result<Option<TypeDefinition>> {
+    let object = as_object(value, "types", "__schema")?;
+    let name = required_str(object, "name", "__schema.types")?;
+    if name.starts_with("__") || BuiltinScalarDefinition::from_str(name).is_ok() {
+        return Ok(None);
+    }
+    let description = optional_str(object, "description", name)?.map(ToOwned::to_owned);
+    let kind = required_str(object, "kind", name)?;
+
+    let interface_implementations = || -> Result<Option<InterfaceImplementations>> {
+        Ok(names(object, "interfaces", name)?
+            .filter(|names| !names.is_empty())
+            .map(|names| {
+                InterfaceImplementations::new(
+                    names
+                        .into_iter()
+                        .map(InterfaceImplementation::new)
+                        .collect(),
+                )
+            }))
+    };
+
+    let type_definition = match kind {
+        "SCALAR" => TypeDefinition::CustomScalar(ScalarTypeDefinition::new(
+            description,
+            name.to_owned(),
+            Directives::from_vec(
+                optional_str(object, "specifiedByURL", name)?
+                    .map(|url| {
+                        Directive::new(
+                            "specifiedBy",
+                            [Argument::new("url", Value::String(url.to_owned()))],
+                        )
+                    })
+                    .into_iter()
+                    .collect(),
+            ),
+        )),
+        "OBJECT" => TypeDefinition::Object(ObjectTypeDefinition::new(
+            description,
+            name.to_owned(),
+            interface_implementations()?,
+            fields_definition(object, name)?,
+            None,
+        )),
+        "INTERFACE" => TypeDefinition::Interface(InterfaceTypeDefinition::new(
+            description,
+            name.to_owned(),
+            interface_implementations()?,
+            fields_definition(object, name)?,
+            None,
+        )),
+        "UNION" => TypeDefinition::Union(UnionTypeDefinition::new(
+            description,
+            name.to_owned(),
+            UnionMemberTypes::new(
+                names(object, "possibleTypes", name)?
+                    .ok_or_else(|| missing(name, "possibleTypes"))?
+                    .into_iter()
+                    .map(UnionMemberType::new)
+                    .collect(),
+            ),
+            None,
+        )),
+        "ENUM" => TypeDefinition::Enum(EnumTypeDefinition::new(
+            description,
+            name.to_owned(),
+            EnumValueDefinitions::new(
+                required_array(object, "enumValues", name)?
+                    .iter()
+                    .map(|value| {
+                        let object = as_object(value, "enumValues", name)?;
+                        let value_name = required_str(object, "name", name)?;
+                        let path = format!("{name}.{value_name}");
+                        Ok(EnumValueDefinition::new(
+                            optional_str(object, "description", &path)?.map(ToOwned::to_owned),
+                            value_name.to_owned(),
+                            Directives::from_vec(deprecated(object, &path)?.into_iter().collect()),
+                        ))
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            None,
+        )),
+        "INPUT_OBJECT" => TypeDefinition::InputObject(InputObjectTypeDefinition::new(
+            description,
+            name.to_owned(),
+            InputFieldsDefinition::new(
+                required_array(object, "inputFields", name)?
+                    .iter()
+                    .map(|value| input_value_definition(value, name, "."))
+                    .collect::<Result<_>>()?,
+            ),
+            Directives::from_vec(
+                optional_bool(object, "isOneOf", name)?
+                    .unwrap_or(false)
+                    .then(|| Directive::new("oneOf", []))
+                    .into_iter()
+                    .collect(),
+            ),
+        )),
+        _ => {
+            return Err(IntrospectionError::UnknownTypeKind {
+                path: name.to_owned(),
+                kind: kind.to_owned(),
+            })
+        }
+    };
+
+    Ok(Some(type_definition))
+}
+
+fn fields_definition(object: &Map<String, JsonValue>, type_name: &str) -> Result<FieldsDefinition> {
+    required_array(object, "fields", type_name)?
+        .iter()
+        .map(|value| {
+            let object = as_object(value, "fields", type_name)?;
+            let name = required_str(object, "name", type_name)?;
+            let path = format!("{type_name}.{name}");
+            Ok(FieldDefinition::new(
+                optional_str(object, "description", &path)?.map(ToOwned::to_owned),
+                name.to_owned(),
+                arguments_definition(object, &path)?,
+                type_reference(object, &path, OutputType::Base, |inner, required| {
+                    OutputType::List(Box::new(inner), required)
+                })?,
+                Directives::from_vec(deprecated(object, &path)?.into_iter().collect()),
+            ))
+        })
+        .collect::<Result<_>>()
+        .map(FieldsDefinition::new)
+}
+
+fn arguments_definition(
+    object: &Map<String, JsonValue>,
+    path: &str,
+) -> Result<Option<ArgumentsDefinition>> {
+    let arguments = optional_array(object, "args", path)?
+        .into_iter()
+        .flatten()
+        .map(|value| input_value_definition(value, path, ""))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((!arguments.is_empty()).then(|| ArgumentsDefinition::new(arguments)))
+}
+
+/// `separator` is `.` for input fields and empty for arguments, which are formatted as
+/// `parent(name:)` in paths
+fn input_value_definition(
+    value: &JsonValue,
+    parent_path: &str,
+    separator: &str,
+) -> Result<InputValueDefinition> {
+    let object = as_object(
+        value,
+        if separator.is_empty() {
+            "args"
+        } else {
+            "inputFields"
+        },
+        parent_path,
+    )?;
+    let name = required_str(object, "name", parent_path)?;
+    let path = if separator.is_empty() {
+        format!("{parent_path}({name}:)")
+    } else {
+        format!("{parent_path}{separator}{name}")
+    };
+    let default_value = optional_str(object, "defaultValue", &path)?
+        .map(|default_value| {
+            ConstValue::parse(default_value)
+                .result
+                .map(|value| Value::from(&value))
+                .map_err(|_| IntrospectionError::InvalidDefaultValue {
+                    path: path.clone(),
+                    default_value: default_value.to_owned(),
+                })
+        })
+        .transpose()?;
+    Ok(InputValueDefinition::new(
+        optional_str(object, "description", &path)?.map(ToOwned::to_owned),
+        name.to_owned(),
+        type_reference(object, &path, InputType::Base, |inner, required| {
+            InputType::List(Box::new(inner), required)
+        })?,
+        default_value,
+        Directives::from_vec(deprecated(object, &path)?.into_iter().collect()),
+    ))
+}
+
+fn type_reference<T>(
+    object: &Map<String, JsonValue>,
+    path: &str,
+    base: fn(String, bool) -> T,
+    list: fn(T, bool) -> T,
+) -> Result<T> {
+    fn wrapped<T>(
+        object: &Map<String, JsonValue>,
+        path: &str,
+        base: fn(String, bool) -> T,
+        list: fn(T, bool) -> T,
+    ) -> Result<T> {
+        let (object, required) = match required_str(object, "kind", path)? {
+            "NON_NULL" => (
+                optional_object(object, "ofType", path)?.ok_or_else(|| missing(path, "ofType"))?,
+                true,
+            ),
+            _ => (object, false),
+        };
+        match required_str(object, "kind", path)? {
+            "LIST" => {
+                let inner = optional_object(object, "ofType", path)?
+                    .ok_or_else(|| missing(path, "ofType"))?;
+                Ok(list(wrapped(inner, path, base, list)?, required))
+            }
+            "NON_NULL" => Err(invalid(path, "ofType", "a nullable type")),
+            _ => {
+                let name = required_str(object, "name", path)?;
+                if type_reference::is_valid_name(name) {
+                    Ok(base(name.to_owned(), required))
+                } else {
+                    Err(invalid(path, "name", "a valid name"))
+                }
+            }
+        }
+    }
+
+    let r#type = optional_object(object, "type", path)?.ok_or_else(|| missing(path, "type"))?;
+    wrapped(r#type, path, base, list)
+}
+
+/// Introspection reports the default reason for deprecations that did not specify one, so
+/// the argument is omitted in that case to match the original definition
+fn deprecated(object: &Map<String, JsonValue>, path: &str) -> Result<Option<Directive>> {
+    if !optional_bool(object, "isDeprecated", path)?.unwrap_or(false) {
+        return Ok(None);
+    }
+    Ok(Some(Directive::new(
+        "deprecated",
+        optional_str(object, "deprecationReason", path)?
+            .filter(|reason| *reason != DEFAULT_DEPRECATION_REASON)
+            .map(|reason| Argument::new("reason", Value::String(reason.to_owned()))),
+    )))
+}
+
+/// Returns `None` for builtin directives, which are always provided by the schema
+/// definition itself
+fn directive_definition(value: &JsonValue) -> Result<Option<DirectiveDefinition>> {
+    let object = as_object(value, "directives", "__schema")?;
+    let name = required_str(object, "name", "__schema.directives")?;
+    if matches!(
+        name,
+        "deprecated" | "include" | "oneOf" | "skip" | "specifiedBy"
+    ) {
+        return Ok(None);
+    }
+    let path = format!("@{name}");
+    let locations = required_array(object, "locations", &path)?
+        .iter()
+        .map(|location| {
+            let location = location
+                .as_str()
+                .ok_or_else(|| invalid(&path, "locations", "a list of strings"))?;
+            DirectiveLocation::from_str(location).map_err(|_| {
+                IntrospectionError::UnknownDirectiveLocation {
+                    path: path.clone(),
+                    location: location.to_owned(),
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Some(DirectiveDefinition::new(
+        optional_str(object, "description", &path)?.map(ToOwned::to_owned),
+        name.to_owned(),
+        arguments_definition(object, &path)?,
+        optional_bool(object, "isRepeatable", &path)?.unwrap_or(false),
+        locations,
+    )))
+}
@@ -0,0 +1,68 @@
+mod argument;
+mod arguments_definition;
+mod builder;
+mod builtins;
+mod directive;
+mod directive_definition;
+mod directives;
+mod enum_type_definition;
+mod enum_value_definition;
+mod enum_value_definitions;
+mod error;
+mod field_definition;
+mod fields_definition;
+mod from_definition;
+mod input_fields_definition;
+mod input_object_type_definition;
+mod input_type;
+mod input_value_definition;
+mod interface_implementation;
+mod interface_implementations;
+mod interface_type_definition;
+#[cfg(feature = "serde_json")]
+mod introspection;
+mod object_type_definition;
+mod output_type;
+mod scalar_type_definition;
+mod schema_definition;
+mod type_definition;
+mod type_reference;
+mod union_member_type;
+mod union_member_types;
+mod union_type_definition;
+mod value;
+
+pub use argument::{Argument, Arguments};
+pub use arguments_definition::ArgumentsDefinition;
+pub use builder::{
+    DirectiveDefinitionBuilder, EnumTypeBuilder, EnumValueBuilder, FieldBuilder,
+    InputObjectTypeBuilder, InputValueBuilder, InterfaceTypeBuilder, ObjectTypeBuilder,
+    ScalarTypeBuilder, SchemaBuilder, UnionTypeBuilder,
+};
+pub use directive::Directive;
+pub use directive_definition::DirectiveDefinition;
+pub use directives::Directives;
+pub use enum_type_definition::EnumTypeDefinition;
+pub use enum_value_definition::EnumValueDefinition;
+pub use enum_value_definitions::EnumValueDefinitions;
+pub use error::Error;
+pub use field_definition::FieldDefinition;
+pub use fields_definition::FieldsDefinition;
+pub use input_fields_definition::InputFieldsDefinition;
+pub use input_object_type_definition::InputObjectTypeDefinition;
+pub use input_type::InputType;
+pub use input_value_definition::InputValueDefinition;
+pub use interface_implementation::InterfaceImplementation;
+pub use interface_implementations::InterfaceImplementations;
+pub use interface_type_definition::InterfaceTypeDefinition;
+#[cfg(feature = "serde_json")]
+pub use introspection::IntrospectionError;
+pub use object_type_definition::ObjectTypeDefinition;
+pub use output_type::OutputType;
+pub use scalar_type_definition::ScalarTypeDefinition;
+pub use schema_definition::SchemaDefinition;
+pub use type_definition::TypeDefinition;
+pub use union_member_type::UnionMemberType;
+pub use union_member_types::UnionMemberTypes;
+pub use union_type_definition::UnionTypeDefinition;
+pub use value::Value;
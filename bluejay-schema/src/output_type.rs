@@ -0,0 +1,74 @@
+use crate::{
+    EnumTypeDefinition, Error, InterfaceTypeDefinition, ObjectTypeDefinition, ScalarTypeDefinition,
+    UnionTypeDefinition,
+};
+use bluejay_core::definition::{
+    self, OutputTypeReference, SchemaDefinition, ShallowOutputTypeReference,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputType {
+    Base(String, bool),
+    List(Box<OutputType>, bool),
+}
+
+impl OutputType {
+    pub(crate) fn base_name(&self) -> &str {
+        match self {
+            Self::Base(name, _) => name,
+            Self::List(inner, _) => inner.base_name(),
+        }
+    }
+}
+
+impl definition::OutputType for OutputType {
+    type CustomScalarTypeDefinition = ScalarTypeDefinition;
+    type EnumTypeDefinition = EnumTypeDefinition;
+    type ObjectTypeDefinition = ObjectTypeDefinition;
+    type InterfaceTypeDefinition = InterfaceTypeDefinition;
+    type UnionTypeDefinition = UnionTypeDefinition;
+
+    fn as_ref<
+        'a,
+        S: SchemaDefinition<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+            ObjectTypeDefinition = Self::ObjectTypeDefinition,
+            InterfaceTypeDefinition = Self::InterfaceTypeDefinition,
+            UnionTypeDefinition = Self::UnionTypeDefinition,
+        >,
+    >(
+        &'a self,
+        schema_definition: &'a S,
+    ) -> OutputTypeReference<'a, Self> {
+        match self {
+            Self::Base(name, required) => OutputTypeReference::Base(
+                schema_definition
+                    .get_type_definition(name)
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+                *required,
+            ),
+            Self::List(inner, required) => OutputTypeReference::List(inner, *required),
+        }
+    }
+
+    fn as_shallow_ref(&self) -> ShallowOutputTypeReference<'_, Self> {
+        match self {
+            Self::Base(name, required) => ShallowOutputTypeReference::Base(name, *required),
+            Self::List(inner, required) => ShallowOutputTypeReference::List(inner, *required),
+        }
+    }
+}
+
+impl FromStr for OutputType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::type_reference::parse(s, Self::Base, |inner, required| {
+            Self::List(Box::new(inner), required)
+        })
+    }
+}
@@ -0,0 +1,468 @@
+use crate::{
+    builtins, ArgumentsDefinition, Directive, DirectiveDefinition, Directives, EnumTypeDefinition,
+    EnumValueDefinition, EnumValueDefinitions, Error, FieldDefinition, FieldsDefinition,
+    InputFieldsDefinition, InputObjectTypeDefinition, InputType, InputValueDefinition,
+    InterfaceImplementation, InterfaceImplementations, InterfaceTypeDefinition,
+    ObjectTypeDefinition, OutputType, ScalarTypeDefinition, TypeDefinition, UnionMemberType,
+    UnionMemberTypes, UnionTypeDefinition,
+};
+use bluejay_core::definition::{
+    self, DirectiveDefinition as _, EnumTypeDefinition as _, EnumValueDefinition as _,
+    FieldDefinition as _, HasDirectives, InputObjectTypeDefinition as _, InputValueDefinition as _,
+    InterfaceImplementation as _, InterfaceTypeDefinition as _, ObjectTypeDefinition as _,
+    TypeDefinition as _, TypeDefinitionReference, UnionMemberType as _, UnionTypeDefinition as _,
+};
+use bluejay_core::{AsIter, BuiltinScalarDefinition, Directive as _, OperationType};
+use std::collections::{btree_map, BTreeMap, HashMap};
+use strum::IntoEnumIterator;
+
+/// A schema definition that owns all of its contents, so that it can be cached
+/// and shared across threads without borrowing from the source it was built from
+#[derive(Debug, Clone)]
+pub struct SchemaDefinition {
+    description: Option<String>,
+    type_definitions: BTreeMap<String, TypeDefinition>,
+    directive_definitions: BTreeMap<String, DirectiveDefinition>,
+    query: String,
+    mutation: Option<String>,
+    subscription: Option<String>,
+    directives: Option<Directives>,
+    interface_implementors: HashMap<String, Vec<String>>,
+}
+
+impl SchemaDefinition {
+    /// Builds a schema definition, adding the builtin scalars, directive definitions and
+    /// introspection types that are not already defined, and checking that every type
+    /// and directive referenced by a definition exists
+    pub(crate) fn new(
+        description: Option<String>,
+        type_definitions: Vec<TypeDefinition>,
+        directive_definitions: Vec<DirectiveDefinition>,
+        query: String,
+        mutation: Option<String>,
+        subscription: Option<String>,
+        directives: Option<Directives>,
+    ) -> Result<Self, Vec<Error>> {
+        let mut errors = Vec::new();
+
+        let mut type_definitions_by_name = BTreeMap::new();
+        type_definitions
+            .into_iter()
+            .chain(builtins::introspection_type_definitions())
+            .chain(BuiltinScalarDefinition::iter().map(TypeDefinition::BuiltinScalar))
+            .for_each(|type_definition| {
+                let is_builtin = type_definition.as_ref().is_builtin();
+                match type_definitions_by_name.entry(type_definition.name().to_owned()) {
+                    btree_map::Entry::Vacant(entry) => {
+                        entry.insert(type_definition);
+                    }
+                    btree_map::Entry::Occupied(entry) => {
+                        if !is_builtin {
+                            errors.push(Error::DuplicateTypeDefinition {
+                                name: entry.key().clone(),
+                            });
+                        }
+                    }
+                }
+            });
+
+        let mut directive_definitions_by_name = BTreeMap::new();
+        directive_definitions
+            .into_iter()
+            .chain(builtins::directive_definitions())
+            .for_each(|directive_definition| {
+                let is_builtin = directive_definition.is_builtin();
+                match directive_definitions_by_name.entry(directive_definition.name().to_owned()) {
+                    btree_map::Entry::Vacant(entry) => {
+                        entry.insert(directive_definition);
+                    }
+                    btree_map::Entry::Occupied(entry) => {
+                        if !is_builtin {
+                            errors.push(Error::DuplicateDirectiveDefinition {
+                                name: entry.key().clone(),
+                            });
+                        }
+                    }
+                }
+            });
+
+        type_definitions_by_name
+            .values_mut()
+            .for_each(|type_definition| {
+                let fields_definition = match type_definition {
+                    TypeDefinition::Object(otd) => otd.fields_definition_mut(),
+                    TypeDefinition::Interface(itd) => itd.fields_definition_mut(),
+                    TypeDefinition::Union(utd) => utd.fields_definition_mut(),
+                    _ => return,
+                };
+                fields_definition.insert_first(builtins::__typename());
+            });
+
+        [
+            (OperationType::Query, Some(&query)),
+            (OperationType::Mutation, mutation.as_ref()),
+            (OperationType::Subscription, subscription.as_ref()),
+        ]
+        .into_iter()
+        .for_each(|(operation_type, name)| {
+            if let Some(name) = name {
+                match type_definitions_by_name.get_mut(name) {
+                    Some(TypeDefinition::Object(otd)) => {
+                        if operation_type == OperationType::Query {
+                            builtins::query_root_fields()
+                                .into_iter()
+                                .for_each(|fd| otd.fields_definition_mut().push(fd));
+                        }
+                    }
+                    _ => errors.push(Error::RootOperationTypeNotAnObject {
+                        operation_type,
+                        name: name.clone(),
+                    }),
+                }
+            }
+        });
+
+        let interface_implementors = Self::interface_implementors(&type_definitions_by_name);
+
+        let instance = Self {
+            description,
+            type_definitions: type_definitions_by_name,
+            directive_definitions: directive_definitions_by_name,
+            query,
+            mutation,
+            subscription,
+            directives,
+            interface_implementors,
+        };
+
+        instance.check_references(&mut errors);
+
+        if errors.is_empty() {
+            Ok(instance)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn interface_implementors(
+        type_definitions: &BTreeMap<String, TypeDefinition>,
+    ) -> HashMap<String, Vec<String>> {
+        type_definitions.values().fold(
+            HashMap::new(),
+            |mut interface_implementors, type_definition| {
+                if let TypeDefinition::Object(otd) = type_definition {
+                    otd.interface_implementations()
+                        .into_iter()
+                        .flat_map(AsIter::iter)
+                        .for_each(|interface_implementation| {
+                            interface_implementors
+                                .entry(interface_implementation.name().to_owned())
+                                .or_default()
+                                .push(otd.name().to_owned());
+                        });
+                }
+                interface_implementors
+            },
+        )
+    }
+
+    fn check_references(&self, errors: &mut Vec<Error>) {
+        self.check_directives(self.directives.as_ref(), "schema", errors);
+
+        self.type_definitions.values().for_each(|type_definition| {
+            let type_name = type_definition.name();
+            match type_definition {
+                TypeDefinition::BuiltinScalar(_) => {}
+                TypeDefinition::CustomScalar(cstd) => {
+                    self.check_directives(cstd.directives(), type_name, errors)
+                }
+                TypeDefinition::Object(otd) => {
+                    self.check_directives(otd.directives(), type_name, errors);
+                    self.check_interface_implementations(
+                        otd.interface_implementations(),
+                        type_name,
+                        errors,
+                    );
+                    self.check_fields_definition(otd.fields_definition(), type_name, errors);
+                }
+                TypeDefinition::Interface(itd) => {
+                    self.check_directives(itd.directives(), type_name, errors);
+                    self.check_interface_implementations(
+                        itd.interface_implementations(),
+                        type_name,
+                        errors,
+                    );
+                    self.check_fields_definition(itd.fields_definition(), type_name, errors);
+                }
+                TypeDefinition::Union(utd) => {
+                    self.check_directives(utd.directives(), type_name, errors);
+                    utd.union_member_types().iter().for_each(|member_type| {
+                        self.check_type_reference(
+                            member_type.name(),
+                            type_name,
+                            "an object type",
+                            |tdr| matches!(tdr, TypeDefinitionReference::Object(_)),
+                            errors,
+                        )
+                    });
+                }
+                TypeDefinition::InputObject(iotd) => {
+                    self.check_directives(iotd.directives(), type_name, errors);
+                    iotd.input_field_definitions().iter().for_each(|ivd| {
+                        self.check_input_value_definition(
+                            ivd,
+                            &format!("{type_name}.{}", ivd.name()),
+                            errors,
+                        )
+                    });
+                }
+                TypeDefinition::Enum(etd) => {
+                    self.check_directives(etd.directives(), type_name, errors);
+                    etd.enum_value_definitions().iter().for_each(|evd| {
+                        self.check_directives(
+                            evd.directives(),
+                            &format!("{type_name}.{}", evd.name()),
+                            errors,
+                        )
+                    });
+                }
+            }
+        });
+
+        self.directive_definitions
+            .values()
+            .for_each(|directive_definition| {
+                self.check_arguments_definition(
+                    directive_definition.arguments_definition(),
+                    &format!("@{}", directive_definition.name()),
+                    errors,
+                )
+            });
+    }
+
+    fn check_interface_implementations(
+        &self,
+        interface_implementations: Option<&InterfaceImplementations>,
+        type_name: &str,
+        errors: &mut Vec<Error>,
+    ) {
+        interface_implementations
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|interface_implementation| {
+                self.check_type_reference(
+                    interface_implementation.name(),
+                    type_name,
+                    "an interface type",
+                    |tdr| matches!(tdr, TypeDefinitionReference::Interface(_)),
+                    errors,
+                )
+            });
+    }
+
+    fn check_fields_definition(
+        &self,
+        fields_definition: &FieldsDefinition,
+        type_name: &str,
+        errors: &mut Vec<Error>,
+    ) {
+        fields_definition.iter().for_each(|field_definition| {
+            let referenced_by = format!("{type_name}.{}", field_definition.name());
+            self.check_type_reference(
+                field_definition.r#type().base_name(),
+                &referenced_by,
+                "an output type",
+                |tdr| !matches!(tdr, TypeDefinitionReference::InputObject(_)),
+                errors,
+            );
+            self.check_directives(field_definition.directives(), &referenced_by, errors);
+            self.check_arguments_definition(
+                field_definition.arguments_definition(),
+                &referenced_by,
+                errors,
+            );
+        });
+    }
+
+    fn check_arguments_definition(
+        &self,
+        arguments_definition: Option<&ArgumentsDefinition>,
+        referenced_by: &str,
+        errors: &mut Vec<Error>,
+    ) {
+        arguments_definition
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|ivd| {
+                self.check_input_value_definition(
+                    ivd,
+                    &format!("{referenced_by}({}:)", ivd.name()),
+                    errors,
+                )
+            });
+    }
+
+    fn check_input_value_definition(
+        &self,
+        input_value_definition: &InputValueDefinition,
+        referenced_by: &str,
+        errors: &mut Vec<Error>,
+    ) {
+        self.check_type_reference(
+            input_value_definition.r#type().base_name(),
+            referenced_by,
+            "an input type",
+            |tdr| tdr.is_input(),
+            errors,
+        );
+        self.check_directives(input_value_definition.directives(), referenced_by, errors);
+    }
+
+    fn check_type_reference(
+        &self,
+        name: &str,
+        referenced_by: &str,
+        expected: &'static str,
+        is_expected_kind: impl Fn(TypeDefinitionReference<'_, TypeDefinition>) -> bool,
+        errors: &mut Vec<Error>,
+    ) {
+        match self.type_definitions.get(name) {
+            Some(type_definition) => {
+                if !is_expected_kind(type_definition.as_ref()) {
+                    errors.push(Error::ReferencedTypeHasWrongKind {
+                        name: name.to_owned(),
+                        expected,
+                        referenced_by: referenced_by.to_owned(),
+                    });
+                }
+            }
+            None => errors.push(Error::ReferencedTypeDoesNotExist {
+                name: name.to_owned(),
+                referenced_by: referenced_by.to_owned(),
+            }),
+        }
+    }
+
+    fn check_directives(
+        &self,
+        directives: Option<&Directives>,
+        referenced_by: &str,
+        errors: &mut Vec<Error>,
+    ) {
+        directives
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .filter(|directive| !self.directive_definitions.contains_key(directive.name()))
+            .for_each(|directive| {
+                errors.push(Error::ReferencedDirectiveDoesNotExist {
+                    name: directive.name().to_owned(),
+                    referenced_by: referenced_by.to_owned(),
+                })
+            });
+    }
+}
+
+impl HasDirectives for SchemaDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
+
+impl definition::SchemaDefinition for SchemaDefinition {
+    type Directive = Directive;
+    type Directives = Directives;
+    type InputValueDefinition = InputValueDefinition;
+    type InputFieldsDefinition = InputFieldsDefinition;
+    type ArgumentsDefinition = ArgumentsDefinition;
+    type EnumValueDefinition = EnumValueDefinition;
+    type EnumValueDefinitions = EnumValueDefinitions;
+    type FieldDefinition = FieldDefinition;
+    type FieldsDefinition = FieldsDefinition;
+    type InterfaceImplementation = InterfaceImplementation;
+    type InterfaceImplementations = InterfaceImplementations;
+    type UnionMemberType = UnionMemberType;
+    type UnionMemberTypes = UnionMemberTypes;
+    type InputType = InputType;
+    type OutputType = OutputType;
+    type CustomScalarTypeDefinition = ScalarTypeDefinition;
+    type ObjectTypeDefinition = ObjectTypeDefinition;
+    type InterfaceTypeDefinition = InterfaceTypeDefinition;
+    type UnionTypeDefinition = UnionTypeDefinition;
+    type InputObjectTypeDefinition = InputObjectTypeDefinition;
+    type EnumTypeDefinition = EnumTypeDefinition;
+    type TypeDefinition = TypeDefinition;
+    type DirectiveDefinition = DirectiveDefinition;
+    type TypeDefinitions<'a> = std::iter::Map<
+        btree_map::Values<'a, String, TypeDefinition>,
+        fn(&'a TypeDefinition) -> TypeDefinitionReference<'a, TypeDefinition>,
+    >;
+    type DirectiveDefinitions<'a> = btree_map::Values<'a, String, DirectiveDefinition>;
+    type InterfaceImplementors<'a> = std::vec::IntoIter<&'a ObjectTypeDefinition>;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn query(&self) -> &Self::ObjectTypeDefinition {
+        self.get_type_definition(&self.query)
+            .and_then(|tdr| tdr.into_object().ok())
+            .unwrap()
+    }
+
+    fn mutation(&self) -> Option<&Self::ObjectTypeDefinition> {
+        self.mutation.as_ref().map(|name| {
+            self.get_type_definition(name)
+                .and_then(|tdr| tdr.into_object().ok())
+                .unwrap()
+        })
+    }
+
+    fn subscription(&self) -> Option<&Self::ObjectTypeDefinition> {
+        self.subscription.as_ref().map(|name| {
+            self.get_type_definition(name)
+                .and_then(|tdr| tdr.into_object().ok())
+                .unwrap()
+        })
+    }
+
+    fn get_type_definition(
+        &self,
+        name: &str,
+    ) -> Option<TypeDefinitionReference<'_, Self::TypeDefinition>> {
+        self.type_definitions
+            .get(name)
+            .map(definition::TypeDefinition::as_ref)
+    }
+
+    fn type_definitions(&self) -> Self::TypeDefinitions<'_> {
+        self.type_definitions
+            .values()
+            .map(definition::TypeDefinition::as_ref)
+    }
+
+    fn get_directive_definition(&self, name: &str) -> Option<&Self::DirectiveDefinition> {
+        self.directive_definitions.get(name)
+    }
+
+    fn directive_definitions(&self) -> Self::DirectiveDefinitions<'_> {
+        self.directive_definitions.values()
+    }
+
+    fn get_interface_implementors(
+        &self,
+        itd: &Self::InterfaceTypeDefinition,
+    ) -> Self::InterfaceImplementors<'_> {
+        self.interface_implementors
+            .get(itd.name())
+            .into_iter()
+            .flatten()
+            .filter_map(|name| {
+                self.get_type_definition(name)
+                    .and_then(|tdr| tdr.into_object().ok())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
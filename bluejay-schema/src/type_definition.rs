@@ -0,0 +1,44 @@
+use crate::{
+    EnumTypeDefinition, InputObjectTypeDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+    ScalarTypeDefinition, UnionTypeDefinition,
+};
+use bluejay_core::definition::{self, TypeDefinitionReference};
+use bluejay_core::BuiltinScalarDefinition;
+
+#[derive(Debug, Clone)]
+pub enum TypeDefinition {
+    BuiltinScalar(BuiltinScalarDefinition),
+    CustomScalar(ScalarTypeDefinition),
+    Object(ObjectTypeDefinition),
+    InputObject(InputObjectTypeDefinition),
+    Enum(EnumTypeDefinition),
+    Union(UnionTypeDefinition),
+    Interface(InterfaceTypeDefinition),
+}
+
+impl TypeDefinition {
+    pub(crate) fn name(&self) -> &str {
+        definition::TypeDefinition::as_ref(self).name()
+    }
+}
+
+impl definition::TypeDefinition for TypeDefinition {
+    type CustomScalarTypeDefinition = ScalarTypeDefinition;
+    type ObjectTypeDefinition = ObjectTypeDefinition;
+    type InputObjectTypeDefinition = InputObjectTypeDefinition;
+    type EnumTypeDefinition = EnumTypeDefinition;
+    type UnionTypeDefinition = UnionTypeDefinition;
+    type InterfaceTypeDefinition = InterfaceTypeDefinition;
+
+    fn as_ref(&self) -> TypeDefinitionReference<'_, Self> {
+        match self {
+            Self::BuiltinScalar(bstd) => TypeDefinitionReference::BuiltinScalar(*bstd),
+            Self::CustomScalar(cstd) => TypeDefinitionReference::CustomScalar(cstd),
+            Self::Object(otd) => TypeDefinitionReference::Object(otd),
+            Self::InputObject(iotd) => TypeDefinitionReference::InputObject(iotd),
+            Self::Enum(etd) => TypeDefinitionReference::Enum(etd),
+            Self::Union(utd) => TypeDefinitionReference::Union(utd),
+            Self::Interface(itd) => TypeDefinitionReference::Interface(itd),
+        }
+    }
+}
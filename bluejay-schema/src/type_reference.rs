@@ -0,0 +1,37 @@
+use crate::Error;
+
+/// Parses a type reference in SDL syntax, such as `[String!]!`
+pub(crate) fn parse<T>(
+    s: &str,
+    base: impl Fn(String, bool) -> T + Copy,
+    list: impl Fn(T, bool) -> T + Copy,
+) -> Result<T, Error> {
+    let invalid = || Error::InvalidTypeReference {
+        type_reference: s.to_owned(),
+    };
+    let trimmed = s.trim();
+    let (inner, required) = match trimmed.strip_suffix('!') {
+        Some(inner) => (inner.trim_end(), true),
+        None => (trimmed, false),
+    };
+    if let Some(inner) = inner
+        .strip_prefix('[')
+        .and_then(|inner| inner.strip_suffix(']'))
+    {
+        parse(inner, base, list)
+            .map(|inner| list(inner, required))
+            .map_err(|_| invalid())
+    } else if is_valid_name(inner) {
+        Ok(base(inner.to_owned(), required))
+    } else {
+        Err(invalid())
+    }
+}
+
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,32 @@
+use crate::ObjectTypeDefinition;
+use bluejay_core::definition::{self, SchemaDefinition};
+
+#[derive(Debug, Clone)]
+pub struct UnionMemberType {
+    name: String,
+}
+
+impl UnionMemberType {
+    pub(crate) fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl definition::UnionMemberType for UnionMemberType {
+    type ObjectTypeDefinition = ObjectTypeDefinition;
+
+    fn member_type<'a, S: SchemaDefinition<ObjectTypeDefinition = Self::ObjectTypeDefinition>>(
+        &'a self,
+        schema_definition: &'a S,
+    ) -> &'a Self::ObjectTypeDefinition {
+        schema_definition
+            .get_type_definition(&self.name)
+            .unwrap()
+            .as_object()
+            .unwrap()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,25 @@
+use crate::UnionMemberType;
+use bluejay_core::definition;
+use bluejay_core::AsIter;
+
+#[derive(Debug, Clone, Default)]
+pub struct UnionMemberTypes(Vec<UnionMemberType>);
+
+impl UnionMemberTypes {
+    pub(crate) fn new(items: Vec<UnionMemberType>) -> Self {
+        Self(items)
+    }
+}
+
+impl AsIter for UnionMemberTypes {
+    type Item = UnionMemberType;
+    type Iterator<'a> = std::slice::Iter<'a, UnionMemberType>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter()
+    }
+}
+
+impl definition::UnionMemberTypes for UnionMemberTypes {
+    type UnionMemberType = UnionMemberType;
+}
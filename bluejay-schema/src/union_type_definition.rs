@@ -0,0 +1,61 @@
+use crate::{Directives, FieldsDefinition, UnionMemberTypes};
+use bluejay_core::definition::{self, HasDirectives};
+
+#[derive(Debug, Clone)]
+pub struct UnionTypeDefinition {
+    description: Option<String>,
+    name: String,
+    union_member_types: UnionMemberTypes,
+    fields_definition: FieldsDefinition,
+    directives: Option<Directives>,
+}
+
+impl UnionTypeDefinition {
+    pub(crate) fn new(
+        description: Option<String>,
+        name: String,
+        union_member_types: UnionMemberTypes,
+        directives: Option<Directives>,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            union_member_types,
+            fields_definition: FieldsDefinition::default(),
+            directives,
+        }
+    }
+
+    pub(crate) fn fields_definition_mut(&mut self) -> &mut FieldsDefinition {
+        &mut self.fields_definition
+    }
+}
+
+impl definition::UnionTypeDefinition for UnionTypeDefinition {
+    type UnionMemberTypes = UnionMemberTypes;
+    type FieldsDefinition = FieldsDefinition;
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn union_member_types(&self) -> &Self::UnionMemberTypes {
+        &self.union_member_types
+    }
+
+    fn fields_definition(&self) -> &Self::FieldsDefinition {
+        &self.fields_definition
+    }
+}
+
+impl HasDirectives for UnionTypeDefinition {
+    type Directives = Directives;
+
+    fn directives(&self) -> Option<&Self::Directives> {
+        self.directives.as_ref()
+    }
+}
@@ -0,0 +1,53 @@
+use bluejay_core::{AsIter, ObjectValue, Value as CoreValue, ValueReference};
+
+/// An owned constant GraphQL value, used for default values and directive arguments
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Enum(String),
+    List(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl CoreValue<true> for Value {
+    type List = Vec<Value>;
+    type Object = Vec<(String, Value)>;
+    type Variable = String;
+
+    fn as_ref(&self) -> ValueReference<'_, true, Self> {
+        match self {
+            Self::Integer(i) => ValueReference::Integer(*i),
+            Self::Float(f) => ValueReference::Float(*f),
+            Self::String(s) => ValueReference::String(s),
+            Self::Boolean(b) => ValueReference::Boolean(*b),
+            Self::Null => ValueReference::Null,
+            Self::Enum(e) => ValueReference::Enum(e),
+            Self::List(l) => ValueReference::List(l),
+            Self::Object(o) => ValueReference::Object(o),
+        }
+    }
+}
+
+impl<V: CoreValue<true>> From<&V> for Value {
+    fn from(value: &V) -> Self {
+        match value.as_ref() {
+            ValueReference::Variable(_) => unreachable!("constant values cannot contain variables"),
+            ValueReference::Integer(i) => Self::Integer(i),
+            ValueReference::Float(f) => Self::Float(f),
+            ValueReference::String(s) => Self::String(s.to_owned()),
+            ValueReference::Boolean(b) => Self::Boolean(b),
+            ValueReference::Null => Self::Null,
+            ValueReference::Enum(e) => Self::Enum(e.to_owned()),
+            ValueReference::List(l) => Self::List(l.iter().map(Self::from).collect()),
+            ValueReference::Object(o) => Self::Object(
+                o.iter()
+                    .map(|(key, value)| (key.as_ref().to_owned(), Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
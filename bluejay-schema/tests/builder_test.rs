@@ -0,0 +1,164 @@
+#![cfg(feature = "parser")]
+
+use bluejay_core::definition::DirectiveLocation;
+use bluejay_parser::ast::{executable::ExecutableDocument, Parse};
+use bluejay_printer::definition::SchemaDefinitionPrinter;
+use bluejay_schema::{
+    Argument, Directive, DirectiveDefinitionBuilder, EnumTypeBuilder, EnumValueBuilder, Error,
+    FieldBuilder, InputObjectTypeBuilder, InputValueBuilder, InterfaceTypeBuilder,
+    ObjectTypeBuilder, ScalarTypeBuilder, SchemaBuilder, SchemaDefinition, UnionTypeBuilder, Value,
+};
+use bluejay_validator::{
+    definition::BuiltinRulesValidator as DefinitionValidator,
+    executable::{document::BuiltinRulesValidator as ExecutableValidator, Cache},
+};
+
+fn deprecated(reason: &str) -> Directive {
+    Directive::new(
+        "deprecated",
+        [Argument::new("reason", Value::String(reason.to_owned()))],
+    )
+}
+
+fn schema_builder() -> SchemaBuilder {
+    SchemaBuilder::new("Query")
+        .description("A schema built in code")
+        .directive_definition(
+            DirectiveDefinitionBuilder::new("tag")
+                .argument(InputValueBuilder::new("name", "String!"))
+                .repeatable()
+                .location(DirectiveLocation::FieldDefinition)
+                .location(DirectiveLocation::Object),
+        )
+        .scalar(ScalarTypeBuilder::new("DateTime"))
+        .interface(InterfaceTypeBuilder::new("Node").field(FieldBuilder::new("id", "ID!")))
+        .object(
+            ObjectTypeBuilder::new("Person")
+                .description("A person")
+                .implements("Node")
+                .directive(Directive::new(
+                    "tag",
+                    [Argument::new("name", Value::String("public".to_owned()))],
+                ))
+                .field(FieldBuilder::new("id", "ID!"))
+                .field(FieldBuilder::new("name", "String!"))
+                .field(FieldBuilder::new("nickname", "String").directive(deprecated("Use `name`")))
+                .field(FieldBuilder::new("friends", "[Person!]!").argument(
+                    InputValueBuilder::new("first", "Int").default_value(Value::Integer(10)),
+                ))
+                .field(FieldBuilder::new("birthday", "DateTime")),
+        )
+        .object(
+            ObjectTypeBuilder::new("Pet")
+                .implements("Node")
+                .field(FieldBuilder::new("id", "ID!"))
+                .field(FieldBuilder::new("species", "Species!")),
+        )
+        .union(
+            UnionTypeBuilder::new("SearchResult")
+                .member("Person")
+                .member("Pet"),
+        )
+        .r#enum(
+            EnumTypeBuilder::new("Species")
+                .value(EnumValueBuilder::new("DOG"))
+                .value(EnumValueBuilder::new("CAT"))
+                .value(EnumValueBuilder::new("PARROT").directive(deprecated("Not a mammal"))),
+        )
+        .input_object(
+            InputObjectTypeBuilder::new("PetFilter")
+                .directive(Directive::new("oneOf", []))
+                .field(InputValueBuilder::new("species", "Species"))
+                .field(InputValueBuilder::new("name", "String")),
+        )
+        .object(
+            ObjectTypeBuilder::new("Query")
+                .field(
+                    FieldBuilder::new("node", "Node").argument(InputValueBuilder::new("id", "ID!")),
+                )
+                .field(
+                    FieldBuilder::new("search", "[SearchResult!]!")
+                        .description("Searches people and pets")
+                        .argument(InputValueBuilder::new("term", "String!"))
+                        .argument(InputValueBuilder::new("petFilter", "PetFilter")),
+                ),
+        )
+}
+
+#[test]
+fn test_build() {
+    let schema_definition = schema_builder().build().unwrap();
+    let printed = SchemaDefinitionPrinter::to_string(&schema_definition);
+
+    insta::assert_snapshot!(printed);
+
+    let parsed = SchemaDefinition::parse(&printed).unwrap();
+    assert!(
+        bluejay_schema_comparator::compare(&parsed, &schema_definition)
+            .changes
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_validate() {
+    let schema_definition = schema_builder().build().unwrap();
+
+    assert_eq!(0, DefinitionValidator::validate(&schema_definition).count());
+
+    let executable_document = ExecutableDocument::parse(
+        "query { search(term: \"x\", petFilter: { species: DOG }) { ... on Pet { id species } } }",
+    )
+    .result
+    .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    assert_eq!(
+        0,
+        ExecutableValidator::validate(&executable_document, &schema_definition, &cache).count()
+    );
+
+    let executable_document = ExecutableDocument::parse("query { search { ... on Pet { name } } }")
+        .result
+        .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    assert_eq!(
+        2,
+        ExecutableValidator::validate(&executable_document, &schema_definition, &cache).count()
+    );
+}
+
+#[test]
+fn test_build_errors() {
+    let errors = SchemaBuilder::new("Query")
+        .object(
+            ObjectTypeBuilder::new("Query")
+                .field(FieldBuilder::new("value", "[String!"))
+                .field(FieldBuilder::new("other", "Missing")),
+        )
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        vec![Error::InvalidTypeReference {
+            type_reference: "[String!".to_owned()
+        }],
+        errors,
+    );
+
+    let errors = SchemaBuilder::new("Root")
+        .object(
+            ObjectTypeBuilder::new("Query")
+                .implements("Query")
+                .field(FieldBuilder::new("value", "Missing").directive(Directive::new("tag", [])))
+                .field(
+                    FieldBuilder::new("input", "String")
+                        .argument(InputValueBuilder::new("value", "Query")),
+                ),
+        )
+        .build()
+        .unwrap_err();
+    insta::assert_snapshot!(errors
+        .iter()
+        .map(Error::message)
+        .collect::<Vec<_>>()
+        .join("\n"));
+}
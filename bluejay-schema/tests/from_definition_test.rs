@@ -0,0 +1,52 @@
+#![cfg(feature = "parser")]
+
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
+    Parse,
+};
+use bluejay_printer::definition::SchemaDefinitionPrinter;
+use bluejay_schema::SchemaDefinition;
+use std::sync::Arc;
+
+#[test]
+fn test_parse_matches_parser_schema_definition() {
+    let s = std::fs::read_to_string("tests/test_data/schema.graphql").unwrap();
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(s.as_str())
+        .result
+        .expect("Schema had parse errors");
+    let parser_schema_definition =
+        ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+
+    let schema_definition = SchemaDefinition::parse(&s).unwrap();
+
+    assert_eq!(
+        SchemaDefinitionPrinter::to_string(&parser_schema_definition),
+        SchemaDefinitionPrinter::to_string(&schema_definition),
+    );
+}
+
+#[test]
+fn test_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<SchemaDefinition>();
+
+    let schema_definition =
+        Arc::new(SchemaDefinition::parse("type Query { value: String }").unwrap());
+    let printed = std::thread::spawn({
+        let schema_definition = Arc::clone(&schema_definition);
+        move || SchemaDefinitionPrinter::to_string(schema_definition.as_ref())
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!("type Query {\n  value: String\n}\n", printed);
+}
+
+#[test]
+fn test_parse_errors() {
+    let errors = SchemaDefinition::parse("type Query {").unwrap_err();
+    assert!(!errors.is_empty());
+
+    let errors = SchemaDefinition::parse("type Query { value: Missing }").unwrap_err();
+    assert_eq!(1, errors.len());
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "serde_json")]
+
+use bluejay_introspection::Executor;
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_printer::definition::SchemaDefinitionPrinter;
+use bluejay_schema::{IntrospectionError, SchemaDefinition};
+use serde_json::{json, Map, Value};
+
+fn introspect(schema_definition: &ParserSchemaDefinition) -> Value {
+    let query = std::fs::read_to_string("tests/test_data/introspection_query.graphql").unwrap();
+    let executable_document = ExecutableDocument::parse(query.as_str())
+        .result
+        .expect("Document had parse errors");
+    let data = Executor::execute(&executable_document, schema_definition, None, &Map::new())
+        .expect("Introspection failed");
+    json!({ "data": data })
+}
+
+#[test]
+fn test_round_trip() {
+    let s = std::fs::read_to_string("tests/test_data/schema.graphql").unwrap();
+    let definition_document = DefinitionDocument::parse(s.as_str())
+        .result
+        .expect("Schema had parse errors");
+    let parser_schema_definition =
+        ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+
+    let schema_definition =
+        SchemaDefinition::from_introspection(&introspect(&parser_schema_definition)).unwrap();
+
+    assert_eq!(
+        SchemaDefinitionPrinter::to_string(&parser_schema_definition),
+        SchemaDefinitionPrinter::to_string(&schema_definition),
+    );
+}
+
+#[test]
+fn test_accepts_schema_object() {
+    let schema_definition = SchemaDefinition::from_introspection(&json!({
+        "queryType": { "name": "Query" },
+        "types": [
+            {
+                "kind": "OBJECT",
+                "name": "Query",
+                "fields": [
+                    {
+                        "name": "value",
+                        "args": [],
+                        "type": { "kind": "SCALAR", "name": "String", "ofType": null },
+                        "isDeprecated": false,
+                    },
+                ],
+                "interfaces": [],
+            },
+        ],
+    }))
+    .unwrap();
+
+    insta::assert_snapshot!(SchemaDefinitionPrinter::to_string(&schema_definition));
+}
+
+#[test]
+fn test_errors() {
+    let error = |value: Value| SchemaDefinition::from_introspection(&value).unwrap_err();
+
+    assert_eq!(
+        IntrospectionError::MissingSchema,
+        error(json!({ "data": {} }))
+    );
+    assert_eq!(
+        "`Query` has unknown type kind `OTHER`",
+        error(json!({
+            "__schema": {
+                "queryType": { "name": "Query" },
+                "types": [{ "kind": "OTHER", "name": "Query" }],
+            },
+        }))
+        .message(),
+    );
+    assert_eq!(
+        "`Query.value` is missing field `type`",
+        error(json!({
+            "__schema": {
+                "queryType": { "name": "Query" },
+                "types": [{ "kind": "OBJECT", "name": "Query", "fields": [{ "name": "value" }] }],
+            },
+        }))
+        .message(),
+    );
+    assert_eq!(
+        "Type `Missing` referenced by `Query.value` does not exist",
+        error(json!({
+            "__schema": {
+                "queryType": { "name": "Query" },
+                "types": [
+                    {
+                        "kind": "OBJECT",
+                        "name": "Query",
+                        "fields": [
+                            {
+                                "name": "value",
+                                "type": { "kind": "OBJECT", "name": "Missing" },
+                            },
+                        ],
+                    },
+                ],
+            },
+        }))
+        .message(),
+    );
+}
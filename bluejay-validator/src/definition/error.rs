@@ -1,12 +1,23 @@
-use bluejay_core::definition::{InputObjectTypeDefinition, SchemaDefinition};
+use crate::value::input_coercion::Error as InputCoercionError;
+use bluejay_core::definition::{
+    DirectiveDefinition, DirectiveLocation, EnumValueDefinition, FieldDefinition,
+    InputObjectTypeDefinition, InputType, InputValueDefinition, InterfaceImplementation,
+    InterfaceTypeDefinition, OutputType, SchemaDefinition, TypeDefinitionReference,
+    UnionTypeDefinition,
+};
+use bluejay_core::{Argument, Arguments, AsIter, Directive};
+use itertools::Itertools;
 
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::definition::SchemaDefinition as ParserSchemaDefinition,
     error::{Annotation, Error as ParserError},
-    HasSpan,
+    HasSpan, Span,
 };
 
+pub(crate) type DirectiveArgument<S> = <<<S as SchemaDefinition>::Directive as Directive<true>>::Arguments as Arguments<true>>::Argument;
+pub(crate) type DirectiveArgumentValue<S> = <DirectiveArgument<S> as Argument<true>>::Value;
+
 pub enum Error<'a, S: SchemaDefinition> {
     NonUniqueInputValueDefinitionNames {
         name: &'a str,
@@ -20,17 +31,388 @@ pub enum Error<'a, S: SchemaDefinition> {
         input_object_type_definition: &'a S::InputObjectTypeDefinition,
         circular_references: Vec<&'a S::InputType>,
     },
+    NonUniqueFieldDefinitionNames {
+        name: &'a str,
+        field_definitions: Vec<&'a S::FieldDefinition>,
+    },
+    TypeDefinitionNameReserved {
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+    },
+    FieldDefinitionNameReserved {
+        field_definition: &'a S::FieldDefinition,
+    },
+    InputValueDefinitionNameReserved {
+        input_value_definition: &'a S::InputValueDefinition,
+    },
+    EnumValueDefinitionNameReserved {
+        enum_value_definition: &'a S::EnumValueDefinition,
+    },
+    DirectiveDefinitionNameReserved {
+        directive_definition: &'a S::DirectiveDefinition,
+    },
+    UnionMemberTypesEmpty {
+        union_type_definition: &'a S::UnionTypeDefinition,
+    },
+    InterfaceFieldNotImplemented {
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        interface_field_definition: &'a S::FieldDefinition,
+    },
+    InterfaceFieldTypeNotCovariant {
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        field_definition: &'a S::FieldDefinition,
+        interface_field_definition: &'a S::FieldDefinition,
+    },
+    InterfaceFieldArgumentNotImplemented {
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        field_definition: &'a S::FieldDefinition,
+        interface_argument_definition: &'a S::InputValueDefinition,
+    },
+    InterfaceFieldArgumentTypeMismatch {
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        field_definition: &'a S::FieldDefinition,
+        argument_definition: &'a S::InputValueDefinition,
+        interface_argument_definition: &'a S::InputValueDefinition,
+    },
+    InterfaceFieldAdditionalArgumentRequired {
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        field_definition: &'a S::FieldDefinition,
+        argument_definition: &'a S::InputValueDefinition,
+    },
+    TransitiveInterfaceNotImplemented {
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_implementation: &'a S::InterfaceImplementation,
+        transitive_interface_type_definition: &'a S::InterfaceTypeDefinition,
+    },
+    InterfaceImplementsItself {
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        interface_implementation: &'a S::InterfaceImplementation,
+    },
+    RequiredInputValueDefinitionDeprecated {
+        input_value_definition: &'a S::InputValueDefinition,
+        directive: &'a S::Directive,
+    },
+    OneOfInputFieldRequired {
+        input_object_type_definition: &'a S::InputObjectTypeDefinition,
+        input_field_definition: &'a S::InputValueDefinition,
+    },
+    OneOfInputFieldHasDefaultValue {
+        input_object_type_definition: &'a S::InputObjectTypeDefinition,
+        input_field_definition: &'a S::InputValueDefinition,
+    },
+    DirectiveInInvalidLocation {
+        directive: &'a S::Directive,
+        directive_definition: &'a S::DirectiveDefinition,
+        location: DirectiveLocation,
+    },
+    DirectivesNotUniquePerLocation {
+        directives: Vec<&'a S::Directive>,
+        directive_definition: &'a S::DirectiveDefinition,
+    },
+    NonUniqueDirectiveArgumentNames {
+        name: &'a str,
+        arguments: Vec<&'a DirectiveArgument<S>>,
+    },
+    ArgumentDoesNotExistOnDirective {
+        argument: &'a DirectiveArgument<S>,
+        directive_definition: &'a S::DirectiveDefinition,
+    },
+    DirectiveMissingRequiredArguments {
+        directive: &'a S::Directive,
+        directive_definition: &'a S::DirectiveDefinition,
+        missing_argument_definitions: Vec<&'a S::InputValueDefinition>,
+    },
+    InvalidConstValue(InputCoercionError<'a, true, DirectiveArgumentValue<S>>),
+}
+
+impl<'a, S: SchemaDefinition> From<InputCoercionError<'a, true, DirectiveArgumentValue<S>>>
+    for Error<'a, S>
+{
+    fn from(value: InputCoercionError<'a, true, DirectiveArgumentValue<S>>) -> Self {
+        Self::InvalidConstValue(value)
+    }
+}
+
+impl<S: SchemaDefinition> Error<'_, S> {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueInputValueDefinitionNames { .. } => {
+                "NON_UNIQUE_INPUT_VALUE_DEFINITION_NAMES"
+            }
+            Self::NonUniqueEnumValueDefinitionNames { .. } => {
+                "NON_UNIQUE_ENUM_VALUE_DEFINITION_NAMES"
+            }
+            Self::InputObjectTypeDefinitionCircularReferences { .. } => {
+                "INPUT_OBJECT_TYPE_DEFINITION_CIRCULAR_REFERENCES"
+            }
+            Self::NonUniqueFieldDefinitionNames { .. } => "NON_UNIQUE_FIELD_DEFINITION_NAMES",
+            Self::TypeDefinitionNameReserved { .. } => "TYPE_DEFINITION_NAME_RESERVED",
+            Self::FieldDefinitionNameReserved { .. } => "FIELD_DEFINITION_NAME_RESERVED",
+            Self::InputValueDefinitionNameReserved { .. } => "INPUT_VALUE_DEFINITION_NAME_RESERVED",
+            Self::EnumValueDefinitionNameReserved { .. } => "ENUM_VALUE_DEFINITION_NAME_RESERVED",
+            Self::DirectiveDefinitionNameReserved { .. } => "DIRECTIVE_DEFINITION_NAME_RESERVED",
+            Self::UnionMemberTypesEmpty { .. } => "UNION_MEMBER_TYPES_EMPTY",
+            Self::InterfaceFieldNotImplemented { .. } => "INTERFACE_FIELD_NOT_IMPLEMENTED",
+            Self::InterfaceFieldTypeNotCovariant { .. } => "INTERFACE_FIELD_TYPE_NOT_COVARIANT",
+            Self::InterfaceFieldArgumentNotImplemented { .. } => {
+                "INTERFACE_FIELD_ARGUMENT_NOT_IMPLEMENTED"
+            }
+            Self::InterfaceFieldArgumentTypeMismatch { .. } => {
+                "INTERFACE_FIELD_ARGUMENT_TYPE_MISMATCH"
+            }
+            Self::InterfaceFieldAdditionalArgumentRequired { .. } => {
+                "INTERFACE_FIELD_ADDITIONAL_ARGUMENT_REQUIRED"
+            }
+            Self::TransitiveInterfaceNotImplemented { .. } => {
+                "TRANSITIVE_INTERFACE_NOT_IMPLEMENTED"
+            }
+            Self::InterfaceImplementsItself { .. } => "INTERFACE_IMPLEMENTS_ITSELF",
+            Self::RequiredInputValueDefinitionDeprecated { .. } => {
+                "REQUIRED_INPUT_VALUE_DEFINITION_DEPRECATED"
+            }
+            Self::OneOfInputFieldRequired { .. } => "ONE_OF_INPUT_FIELD_REQUIRED",
+            Self::OneOfInputFieldHasDefaultValue { .. } => "ONE_OF_INPUT_FIELD_HAS_DEFAULT_VALUE",
+            Self::DirectiveInInvalidLocation { .. } => "DIRECTIVE_IN_INVALID_LOCATION",
+            Self::DirectivesNotUniquePerLocation { .. } => "DIRECTIVES_NOT_UNIQUE_PER_LOCATION",
+            Self::NonUniqueDirectiveArgumentNames { .. } => "NON_UNIQUE_DIRECTIVE_ARGUMENT_NAMES",
+            Self::ArgumentDoesNotExistOnDirective { .. } => "ARGUMENT_DOES_NOT_EXIST_ON_DIRECTIVE",
+            Self::DirectiveMissingRequiredArguments { .. } => {
+                "DIRECTIVE_MISSING_REQUIRED_ARGUMENTS"
+            }
+            Self::InvalidConstValue(error) => error.code(),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Self::NonUniqueInputValueDefinitionNames { name, .. } => {
+                format!("Multiple input value definitions named `{name}`")
+            }
+            Self::NonUniqueEnumValueDefinitionNames { name, .. } => {
+                format!("Multiple enum value definitions named `{name}`")
+            }
+            Self::InputObjectTypeDefinitionCircularReferences {
+                input_object_type_definition,
+                ..
+            } => format!(
+                "Input object type definition `{}` contains disallowed circular reference(s)",
+                input_object_type_definition.name()
+            ),
+            Self::NonUniqueFieldDefinitionNames { name, .. } => {
+                format!("Multiple field definitions named `{name}`")
+            }
+            Self::TypeDefinitionNameReserved { type_definition } => format!(
+                "Type name `{}` must not begin with `__`, which is reserved for introspection",
+                type_definition.name()
+            ),
+            Self::FieldDefinitionNameReserved { field_definition } => format!(
+                "Field name `{}` must not begin with `__`, which is reserved for introspection",
+                field_definition.name()
+            ),
+            Self::InputValueDefinitionNameReserved {
+                input_value_definition,
+            } => format!(
+                "Input value name `{}` must not begin with `__`, which is reserved for introspection",
+                input_value_definition.name()
+            ),
+            Self::EnumValueDefinitionNameReserved {
+                enum_value_definition,
+            } => format!(
+                "Enum value name `{}` must not begin with `__`, which is reserved for introspection",
+                enum_value_definition.name()
+            ),
+            Self::DirectiveDefinitionNameReserved {
+                directive_definition,
+            } => format!(
+                "Directive name `{}` must not begin with `__`, which is reserved for introspection",
+                directive_definition.name()
+            ),
+            Self::UnionMemberTypesEmpty {
+                union_type_definition,
+            } => format!(
+                "Union type definition `{}` must have at least one member type",
+                union_type_definition.name()
+            ),
+            Self::InterfaceFieldNotImplemented {
+                implementor,
+                interface_type_definition,
+                interface_field_definition,
+            } => format!(
+                "Type `{}` does not define field `{}` required by interface `{}`",
+                implementor.name(),
+                interface_field_definition.name(),
+                interface_type_definition.name(),
+            ),
+            Self::InterfaceFieldTypeNotCovariant {
+                implementor,
+                interface_type_definition,
+                field_definition,
+                interface_field_definition,
+            } => format!(
+                "Field `{}.{}` has type `{}`, which is not a valid subtype of `{}` as required by interface `{}`",
+                implementor.name(),
+                field_definition.name(),
+                field_definition.r#type().display_name(),
+                interface_field_definition.r#type().display_name(),
+                interface_type_definition.name(),
+            ),
+            Self::InterfaceFieldArgumentNotImplemented {
+                implementor,
+                interface_type_definition,
+                field_definition,
+                interface_argument_definition,
+            } => format!(
+                "Field `{}.{}` does not define argument `{}` required by interface `{}`",
+                implementor.name(),
+                field_definition.name(),
+                interface_argument_definition.name(),
+                interface_type_definition.name(),
+            ),
+            Self::InterfaceFieldArgumentTypeMismatch {
+                implementor,
+                interface_type_definition,
+                field_definition,
+                argument_definition,
+                interface_argument_definition,
+            } => format!(
+                "Argument `{}` of field `{}.{}` has type `{}`, but interface `{}` requires type `{}`",
+                argument_definition.name(),
+                implementor.name(),
+                field_definition.name(),
+                argument_definition.r#type().display_name(),
+                interface_type_definition.name(),
+                interface_argument_definition.r#type().display_name(),
+            ),
+            Self::InterfaceFieldAdditionalArgumentRequired {
+                implementor,
+                interface_type_definition,
+                field_definition,
+                argument_definition,
+            } => format!(
+                "Argument `{}` of field `{}.{}` must not be required because it is not defined by interface `{}`",
+                argument_definition.name(),
+                implementor.name(),
+                field_definition.name(),
+                interface_type_definition.name(),
+            ),
+            Self::TransitiveInterfaceNotImplemented {
+                implementor,
+                interface_implementation,
+                transitive_interface_type_definition,
+            } => format!(
+                "Type `{}` must implement `{}` because it is implemented by `{}`",
+                implementor.name(),
+                transitive_interface_type_definition.name(),
+                interface_implementation.name(),
+            ),
+            Self::InterfaceImplementsItself {
+                interface_type_definition,
+                ..
+            } => format!(
+                "Interface `{}` must not implement itself",
+                interface_type_definition.name()
+            ),
+            Self::RequiredInputValueDefinitionDeprecated {
+                input_value_definition,
+                ..
+            } => format!(
+                "Required input value `{}` must not be deprecated",
+                input_value_definition.name()
+            ),
+            Self::OneOfInputFieldRequired {
+                input_object_type_definition,
+                input_field_definition,
+            } => format!(
+                "Field `{}` of oneOf input object `{}` must be nullable",
+                input_field_definition.name(),
+                input_object_type_definition.name(),
+            ),
+            Self::OneOfInputFieldHasDefaultValue {
+                input_object_type_definition,
+                input_field_definition,
+            } => format!(
+                "Field `{}` of oneOf input object `{}` must not have a default value",
+                input_field_definition.name(),
+                input_object_type_definition.name(),
+            ),
+            Self::DirectiveInInvalidLocation {
+                directive,
+                directive_definition,
+                location,
+            } => format!(
+                "Directive @{} cannot be used at location {location}. It is only allowed at the following locations: {}",
+                directive.name(),
+                directive_definition.locations().iter().join(", "),
+            ),
+            Self::DirectivesNotUniquePerLocation {
+                directive_definition,
+                ..
+            } => format!(
+                "Directive @{} is not repeatable but was used multiple times in the same location",
+                directive_definition.name(),
+            ),
+            Self::NonUniqueDirectiveArgumentNames { name, .. } => {
+                format!("Multiple arguments with name `{name}`")
+            }
+            Self::ArgumentDoesNotExistOnDirective {
+                argument,
+                directive_definition,
+            } => format!(
+                "Directive `{}` does not define an argument named `{}`",
+                directive_definition.name(),
+                argument.name(),
+            ),
+            Self::DirectiveMissingRequiredArguments {
+                directive,
+                missing_argument_definitions,
+                ..
+            } => format!(
+                "Directive `{}` missing argument(s): {}",
+                directive.name(),
+                missing_argument_definitions
+                    .iter()
+                    .map(|ivd| ivd.name())
+                    .join(", "),
+            ),
+            Self::InvalidConstValue(error) => error.message().into_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+fn type_definition_name_span<'a>(
+    type_definition: TypeDefinitionReference<
+        'a,
+        <ParserSchemaDefinition<'a> as SchemaDefinition>::TypeDefinition,
+    >,
+) -> Option<Span> {
+    match type_definition {
+        TypeDefinitionReference::BuiltinScalar(_) => None,
+        TypeDefinitionReference::CustomScalar(cstd) => Some(*cstd.name_token().span()),
+        TypeDefinitionReference::Enum(etd) => Some(*etd.name_token().span()),
+        TypeDefinitionReference::InputObject(iotd) => Some(*iotd.name_token().span()),
+        TypeDefinitionReference::Interface(itd) => Some(*itd.name_token().span()),
+        TypeDefinitionReference::Object(otd) => Some(*otd.name_token().span()),
+        TypeDefinitionReference::Union(utd) => Some(*utd.name_token().span()),
+    }
 }
 
 #[cfg(feature = "parser-integration")]
 impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
     fn from(value: Error<'a, ParserSchemaDefinition<'a>>) -> Self {
-        match value {
+        let message = value.message();
+        let code = value.code();
+        let error = match value {
             Error::NonUniqueInputValueDefinitionNames {
                 name,
                 input_value_definitions,
             } => Self::new(
-                format!("Multiple input value definitions named `{name}`"),
+                message,
                 None,
                 input_value_definitions
                     .into_iter()
@@ -46,7 +428,7 @@ impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
                 name,
                 enum_value_definitions,
             } => Self::new(
-                format!("Multiple enum value definitions named `{name}`"),
+                message,
                 None,
                 enum_value_definitions
                     .into_iter()
@@ -63,10 +445,7 @@ impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
                 circular_references,
             } => {
                 Self::new(
-                format!(
-                    "Input object type definition `{}` contains disallowed circular reference(s)",
-                    input_object_type_definition.name()
-                ),
+                message,
                 Some(Annotation::new(
                     "Input object type definition contains circular reference(s) through an unbroken chain of non-null singular fields, which is disallowed",
                     *input_object_type_definition.name_token().span(),
@@ -82,6 +461,271 @@ impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
                     .collect(),
             )
             }
-        }
+            Error::NonUniqueFieldDefinitionNames {
+                name,
+                field_definitions,
+            } => Self::new(
+                message,
+                None,
+                field_definitions
+                    .into_iter()
+                    .map(|fd| {
+                        Annotation::new(
+                            format!("Field definition with name `{name}`"),
+                            *fd.name_token().span(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::TypeDefinitionNameReserved { type_definition } => Self::new(
+                message,
+                type_definition_name_span(type_definition)
+                    .map(|span| Annotation::new("Reserved name", span)),
+                Vec::new(),
+            ),
+            Error::FieldDefinitionNameReserved { field_definition } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Reserved name",
+                    *field_definition.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::InputValueDefinitionNameReserved {
+                input_value_definition,
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Reserved name",
+                    *input_value_definition.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::EnumValueDefinitionNameReserved {
+                enum_value_definition,
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Reserved name",
+                    *enum_value_definition.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::DirectiveDefinitionNameReserved {
+                directive_definition,
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Reserved name",
+                    *directive_definition.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::UnionMemberTypesEmpty {
+                union_type_definition,
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Union type definition without member types",
+                    *union_type_definition.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::InterfaceFieldNotImplemented {
+                implementor,
+                interface_field_definition,
+                ..
+            } => Self::new(
+                message,
+                type_definition_name_span(implementor).map(|span| {
+                    Annotation::new(
+                        format!("Missing field `{}`", interface_field_definition.name()),
+                        span,
+                    )
+                }),
+                vec![Annotation::new(
+                    "Interface field definition",
+                    *interface_field_definition.name_token().span(),
+                )],
+            ),
+            Error::InterfaceFieldTypeNotCovariant {
+                field_definition,
+                interface_field_definition,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Field type is not a valid subtype of the interface field type",
+                    *field_definition.name_token().span(),
+                )),
+                vec![Annotation::new(
+                    "Interface field definition",
+                    *interface_field_definition.name_token().span(),
+                )],
+            ),
+            Error::InterfaceFieldArgumentNotImplemented {
+                field_definition,
+                interface_argument_definition,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    format!("Missing argument `{}`", interface_argument_definition.name()),
+                    *field_definition.name_token().span(),
+                )),
+                vec![Annotation::new(
+                    "Interface argument definition",
+                    *interface_argument_definition.name_token().span(),
+                )],
+            ),
+            Error::InterfaceFieldArgumentTypeMismatch {
+                argument_definition,
+                interface_argument_definition,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Argument type does not match the interface argument type",
+                    *argument_definition.r#type().span(),
+                )),
+                vec![Annotation::new(
+                    "Interface argument type",
+                    *interface_argument_definition.r#type().span(),
+                )],
+            ),
+            Error::InterfaceFieldAdditionalArgumentRequired {
+                argument_definition,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Additional argument must not be required",
+                    *argument_definition.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::TransitiveInterfaceNotImplemented {
+                interface_implementation,
+                transitive_interface_type_definition,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    format!(
+                        "Implements `{}`",
+                        transitive_interface_type_definition.name()
+                    ),
+                    *interface_implementation.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::InterfaceImplementsItself {
+                interface_implementation,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Implementation of itself",
+                    *interface_implementation.name_token().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::RequiredInputValueDefinitionDeprecated {
+                input_value_definition,
+                directive,
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Deprecation of required input value",
+                    *directive.span(),
+                )),
+                vec![Annotation::new(
+                    "Required input value definition",
+                    *input_value_definition.name_token().span(),
+                )],
+            ),
+            Error::OneOfInputFieldRequired {
+                input_field_definition,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "Non-null type",
+                    *input_field_definition.r#type().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::OneOfInputFieldHasDefaultValue {
+                input_field_definition,
+                ..
+            } => Self::new(
+                message,
+                input_field_definition
+                    .default_value()
+                    .map(|default_value| Annotation::new("Default value", *default_value.span())),
+                Vec::new(),
+            ),
+            Error::DirectiveInInvalidLocation {
+                directive,
+                location,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    format!("Cannot be used at location {location}"),
+                    *directive.span(),
+                )),
+                Vec::new(),
+            ),
+            Error::DirectivesNotUniquePerLocation { directives, .. } => Self::new(
+                message,
+                None,
+                directives
+                    .into_iter()
+                    .map(|directive| Annotation::new("Usage of directive", *directive.span()))
+                    .collect(),
+            ),
+            Error::NonUniqueDirectiveArgumentNames { name, arguments } => Self::new(
+                message,
+                None,
+                arguments
+                    .into_iter()
+                    .map(|argument| {
+                        Annotation::new(
+                            format!("Argument with name `{name}`"),
+                            *argument.name().span(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::ArgumentDoesNotExistOnDirective { argument, .. } => Self::new(
+                message,
+                Some(Annotation::new(
+                    "No argument definition with this name",
+                    *argument.name().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::DirectiveMissingRequiredArguments {
+                directive,
+                missing_argument_definitions,
+                ..
+            } => Self::new(
+                message,
+                Some(Annotation::new(
+                    format!(
+                        "Missing argument(s): {}",
+                        missing_argument_definitions
+                            .into_iter()
+                            .map(InputValueDefinition::name)
+                            .join(", ")
+                    ),
+                    *directive.span(),
+                )),
+                Vec::new(),
+            ),
+            Error::InvalidConstValue(error) => Self::from(error),
+        };
+        error.with_code(code)
     }
 }
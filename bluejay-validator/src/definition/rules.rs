@@ -1,10 +1,32 @@
+mod argument_definition_uniqueness;
+mod directive_arguments_are_valid;
+mod directives_are_in_valid_locations;
+mod directives_are_unique_per_location;
 mod enum_value_definition_uniqueness;
+mod field_definition_uniqueness;
 mod input_field_definition_uniqueness;
 mod input_object_circular_references;
+mod interface_implementations_are_valid;
+mod names_are_not_reserved;
+mod one_of_input_fields;
+mod required_input_values_not_deprecated;
+mod transitive_interface_implementations;
+mod union_member_types_not_empty;
 
+pub use argument_definition_uniqueness::ArgumentDefinitionUniqueness;
+pub use directive_arguments_are_valid::DirectiveArgumentsAreValid;
+pub use directives_are_in_valid_locations::DirectivesAreInValidLocations;
+pub use directives_are_unique_per_location::DirectivesAreUniquePerLocation;
 pub use enum_value_definition_uniqueness::EnumValueDefinitionUniqueness;
+pub use field_definition_uniqueness::FieldDefinitionUniqueness;
 pub use input_field_definition_uniqueness::InputFieldDefinitionUniqueness;
 pub use input_object_circular_references::InputObjectCircularReferences;
+pub use interface_implementations_are_valid::InterfaceImplementationsAreValid;
+pub use names_are_not_reserved::NamesAreNotReserved;
+pub use one_of_input_fields::OneOfInputFields;
+pub use required_input_values_not_deprecated::RequiredInputValuesNotDeprecated;
+pub use transitive_interface_implementations::TransitiveInterfaceImplementations;
+pub use union_member_types_not_empty::UnionMemberTypesNotEmpty;
 
 #[macro_export]
 macro_rules! combine_definition_rules {
@@ -34,6 +56,22 @@ macro_rules! combine_definition_rules {
             }
 
             impl<'a, S: bluejay_core::definition::SchemaDefinition> $crate::definition::Visitor<'a, S> for $name<'a, S> {
+                fn visit_custom_scalar_type_definition(&mut self, custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_custom_scalar_type_definition(custom_scalar_type_definition);)*
+                }
+
+                fn visit_object_type_definition(&mut self, object_type_definition: &'a S::ObjectTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_object_type_definition(object_type_definition);)*
+                }
+
+                fn visit_interface_type_definition(&mut self, interface_type_definition: &'a S::InterfaceTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_interface_type_definition(interface_type_definition);)*
+                }
+
+                fn visit_union_type_definition(&mut self, union_type_definition: &'a S::UnionTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_union_type_definition(union_type_definition);)*
+                }
+
                 fn visit_input_object_type_definition(&mut self, input_object_type_definition: &'a S::InputObjectTypeDefinition) {
                     $(self.[<$rule:snake>].visit_input_object_type_definition(input_object_type_definition);)*
                 }
@@ -41,6 +79,22 @@ macro_rules! combine_definition_rules {
                 fn visit_enum_type_definition(&mut self, enum_type_definition: &'a S::EnumTypeDefinition) {
                     $(self.[<$rule:snake>].visit_enum_type_definition(enum_type_definition);)*
                 }
+
+                fn visit_field_definition(&mut self, field_definition: &'a S::FieldDefinition) {
+                    $(self.[<$rule:snake>].visit_field_definition(field_definition);)*
+                }
+
+                fn visit_argument_definition(&mut self, argument_definition: &'a S::InputValueDefinition) {
+                    $(self.[<$rule:snake>].visit_argument_definition(argument_definition);)*
+                }
+
+                fn visit_directive_definition(&mut self, directive_definition: &'a S::DirectiveDefinition) {
+                    $(self.[<$rule:snake>].visit_directive_definition(directive_definition);)*
+                }
+
+                fn visit_directives(&mut self, directives: &'a <S as bluejay_core::definition::SchemaDefinition>::Directives, location: bluejay_core::definition::DirectiveLocation) {
+                    $(self.[<$rule:snake>].visit_directives(directives, location);)*
+                }
             }
         }
     };
@@ -53,5 +107,16 @@ combine_definition_rules!(
         EnumValueDefinitionUniqueness,
         InputFieldDefinitionUniqueness,
         InputObjectCircularReferences,
+        FieldDefinitionUniqueness,
+        ArgumentDefinitionUniqueness,
+        NamesAreNotReserved,
+        UnionMemberTypesNotEmpty,
+        InterfaceImplementationsAreValid,
+        TransitiveInterfaceImplementations,
+        RequiredInputValuesNotDeprecated,
+        OneOfInputFields,
+        DirectivesAreInValidLocations,
+        DirectivesAreUniquePerLocation,
+        DirectiveArgumentsAreValid,
     ],
 );
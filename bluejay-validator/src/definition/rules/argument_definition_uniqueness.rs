@@ -0,0 +1,61 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use bluejay_core::definition::{
+    DirectiveDefinition, FieldDefinition, InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::AsIter;
+
+pub struct ArgumentDefinitionUniqueness<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> ArgumentDefinitionUniqueness<'a, S> {
+    fn visit_arguments_definition(
+        &mut self,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+    ) {
+        if let Some(arguments_definition) = arguments_definition {
+            self.errors.extend(
+                duplicates(arguments_definition.iter(), InputValueDefinition::name).map(
+                    |(name, input_value_definitions)| Error::NonUniqueInputValueDefinitionNames {
+                        name,
+                        input_value_definitions,
+                    },
+                ),
+            );
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for ArgumentDefinitionUniqueness<'a, S> {
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+    ) {
+        self.visit_arguments_definition(field_definition.arguments_definition());
+    }
+
+    fn visit_directive_definition(
+        &mut self,
+        directive_definition: &'a <S as SchemaDefinition>::DirectiveDefinition,
+    ) {
+        self.visit_arguments_definition(directive_definition.arguments_definition());
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for ArgumentDefinitionUniqueness<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for ArgumentDefinitionUniqueness<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
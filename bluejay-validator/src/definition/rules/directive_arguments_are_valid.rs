@@ -0,0 +1,111 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use crate::value::input_coercion::CoerceInput;
+use bluejay_core::definition::{
+    ArgumentsDefinition, DirectiveDefinition, DirectiveLocation, InputType, InputValueDefinition,
+    SchemaDefinition,
+};
+use bluejay_core::{Argument, AsIter, Directive};
+
+pub struct DirectiveArgumentsAreValid<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition + 'a> DirectiveArgumentsAreValid<'a, S> {
+    fn visit_directive(
+        &mut self,
+        directive: &'a S::Directive,
+        directive_definition: &'a S::DirectiveDefinition,
+    ) {
+        let arguments = directive.arguments();
+        let arguments_definition = directive_definition.arguments_definition();
+
+        if let Some(arguments) = arguments {
+            self.errors
+                .extend(
+                    duplicates(arguments.iter(), Argument::name).map(|(name, arguments)| {
+                        Error::NonUniqueDirectiveArgumentNames { name, arguments }
+                    }),
+                );
+
+            arguments.iter().for_each(|argument| {
+                match arguments_definition
+                    .and_then(|arguments_definition| arguments_definition.get(argument.name()))
+                {
+                    Some(argument_definition) => {
+                        if let Err(coercion_errors) = self.schema_definition.coerce_const_value(
+                            argument_definition.r#type(),
+                            argument.value(),
+                            Default::default(),
+                        ) {
+                            self.errors
+                                .extend(coercion_errors.into_iter().map(Error::InvalidConstValue));
+                        }
+                    }
+                    None => self.errors.push(Error::ArgumentDoesNotExistOnDirective {
+                        argument,
+                        directive_definition,
+                    }),
+                }
+            });
+        }
+
+        if let Some(arguments_definition) = arguments_definition {
+            let missing_argument_definitions: Vec<_> = arguments_definition
+                .iter()
+                .filter(|ivd| {
+                    ivd.r#type().is_required()
+                        && ivd.default_value().is_none()
+                        && !arguments
+                            .map(|args| args.iter().any(|arg| arg.name() == ivd.name()))
+                            .unwrap_or(false)
+                })
+                .collect();
+            if !missing_argument_definitions.is_empty() {
+                self.errors.push(Error::DirectiveMissingRequiredArguments {
+                    directive,
+                    directive_definition,
+                    missing_argument_definitions,
+                });
+            }
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Visitor<'a, S> for DirectiveArgumentsAreValid<'a, S> {
+    fn visit_directives(
+        &mut self,
+        directives: &'a <S as SchemaDefinition>::Directives,
+        _: DirectiveLocation,
+    ) {
+        directives.iter().for_each(|directive| {
+            if let Some(directive_definition) = self
+                .schema_definition
+                .get_directive_definition(directive.name())
+            {
+                self.visit_directive(directive, directive_definition);
+            }
+        });
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for DirectiveArgumentsAreValid<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Rule<'a, S> for DirectiveArgumentsAreValid<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
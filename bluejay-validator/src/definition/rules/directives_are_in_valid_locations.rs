@@ -0,0 +1,53 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{DirectiveDefinition, DirectiveLocation, SchemaDefinition};
+use bluejay_core::{AsIter, Directive};
+
+pub struct DirectivesAreInValidLocations<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition + 'a> Visitor<'a, S> for DirectivesAreInValidLocations<'a, S> {
+    fn visit_directives(
+        &mut self,
+        directives: &'a <S as SchemaDefinition>::Directives,
+        location: DirectiveLocation,
+    ) {
+        self.errors
+            .extend(directives.iter().filter_map(|directive| {
+                self.schema_definition
+                    .get_directive_definition(directive.name())
+                    .filter(|directive_definition| {
+                        directive_definition
+                            .locations()
+                            .iter()
+                            .all(|&definition_location| definition_location != location)
+                    })
+                    .map(|directive_definition| Error::DirectiveInInvalidLocation {
+                        directive,
+                        directive_definition,
+                        location,
+                    })
+            }));
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for DirectivesAreInValidLocations<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Rule<'a, S> for DirectivesAreInValidLocations<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
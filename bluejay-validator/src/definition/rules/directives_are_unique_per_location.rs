@@ -0,0 +1,54 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use bluejay_core::definition::{DirectiveDefinition, DirectiveLocation, SchemaDefinition};
+use bluejay_core::{AsIter, Directive};
+use std::ops::Not;
+
+pub struct DirectivesAreUniquePerLocation<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition + 'a> Visitor<'a, S> for DirectivesAreUniquePerLocation<'a, S> {
+    fn visit_directives(
+        &mut self,
+        directives: &'a <S as SchemaDefinition>::Directives,
+        _: DirectiveLocation,
+    ) {
+        self.errors
+            .extend(duplicates(directives.iter(), Directive::name).filter_map(
+                |(directive_name, directives)| {
+                    self.schema_definition
+                        .get_directive_definition(directive_name)
+                        .and_then(|directive_definition| {
+                            directive_definition.is_repeatable().not().then_some(
+                                Error::DirectivesNotUniquePerLocation {
+                                    directives,
+                                    directive_definition,
+                                },
+                            )
+                        })
+                },
+            ));
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for DirectivesAreUniquePerLocation<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Rule<'a, S> for DirectivesAreUniquePerLocation<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
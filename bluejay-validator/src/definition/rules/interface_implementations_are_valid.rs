@@ -0,0 +1,226 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{
+    ArgumentsDefinition, FieldDefinition, FieldsDefinition, InputType, InputValueDefinition,
+    InterfaceImplementation, InterfaceTypeDefinition, ObjectTypeDefinition, OutputType,
+    SchemaDefinition, ShallowOutputTypeReference, TypeDefinitionReference, UnionMemberTypes,
+    UnionTypeDefinition,
+};
+use bluejay_core::AsIter;
+
+pub struct InterfaceImplementationsAreValid<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition + 'a> InterfaceImplementationsAreValid<'a, S> {
+    fn visit_implementor(
+        &mut self,
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        fields_definition: &'a S::FieldsDefinition,
+        interface_implementations: Option<&'a S::InterfaceImplementations>,
+    ) {
+        interface_implementations
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|interface_implementation| {
+                let interface_type_definition =
+                    interface_implementation.interface(self.schema_definition);
+                interface_type_definition
+                    .fields_definition()
+                    .iter()
+                    .filter(|interface_field_definition| !interface_field_definition.is_builtin())
+                    .for_each(|interface_field_definition| {
+                        match fields_definition.get(interface_field_definition.name()) {
+                            Some(field_definition) => self.visit_implementation_field_definition(
+                                implementor,
+                                interface_type_definition,
+                                field_definition,
+                                interface_field_definition,
+                            ),
+                            None => self.errors.push(Error::InterfaceFieldNotImplemented {
+                                implementor,
+                                interface_type_definition,
+                                interface_field_definition,
+                            }),
+                        }
+                    });
+            });
+    }
+
+    fn visit_implementation_field_definition(
+        &mut self,
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        field_definition: &'a S::FieldDefinition,
+        interface_field_definition: &'a S::FieldDefinition,
+    ) {
+        if !self.is_valid_implementation_field_type(
+            field_definition.r#type(),
+            interface_field_definition.r#type(),
+        ) {
+            self.errors.push(Error::InterfaceFieldTypeNotCovariant {
+                implementor,
+                interface_type_definition,
+                field_definition,
+                interface_field_definition,
+            });
+        }
+
+        interface_field_definition
+            .arguments_definition()
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|interface_argument_definition| {
+                match field_definition
+                    .arguments_definition()
+                    .and_then(|arguments_definition| {
+                        arguments_definition.get(interface_argument_definition.name())
+                    }) {
+                    Some(argument_definition) => {
+                        if argument_definition.r#type().as_shallow_ref()
+                            != interface_argument_definition.r#type().as_shallow_ref()
+                        {
+                            self.errors.push(Error::InterfaceFieldArgumentTypeMismatch {
+                                implementor,
+                                interface_type_definition,
+                                field_definition,
+                                argument_definition,
+                                interface_argument_definition,
+                            });
+                        }
+                    }
+                    None => self
+                        .errors
+                        .push(Error::InterfaceFieldArgumentNotImplemented {
+                            implementor,
+                            interface_type_definition,
+                            field_definition,
+                            interface_argument_definition,
+                        }),
+                }
+            });
+
+        self.errors.extend(
+            field_definition
+                .arguments_definition()
+                .into_iter()
+                .flat_map(AsIter::iter)
+                .filter(|argument_definition| {
+                    argument_definition.is_required()
+                        && interface_field_definition
+                            .arguments_definition()
+                            .and_then(|arguments_definition| {
+                                arguments_definition.get(argument_definition.name())
+                            })
+                            .is_none()
+                })
+                .map(
+                    |argument_definition| Error::InterfaceFieldAdditionalArgumentRequired {
+                        implementor,
+                        interface_type_definition,
+                        field_definition,
+                        argument_definition,
+                    },
+                ),
+        );
+    }
+
+    /// Output types of fields are covariant: the implementing field may narrow the
+    /// interface field's type by making it non-null or by using a possible type of it
+    fn is_valid_implementation_field_type(
+        &self,
+        field_type: &'a S::OutputType,
+        interface_field_type: &'a S::OutputType,
+    ) -> bool {
+        let field_type = field_type.as_shallow_ref();
+        let interface_field_type = interface_field_type.as_shallow_ref();
+
+        if interface_field_type.is_required() && !field_type.is_required() {
+            return false;
+        }
+
+        match (field_type, interface_field_type) {
+            (
+                ShallowOutputTypeReference::List(inner, _),
+                ShallowOutputTypeReference::List(interface_inner, _),
+            ) => self.is_valid_implementation_field_type(inner, interface_inner),
+            (
+                ShallowOutputTypeReference::Base(name, _),
+                ShallowOutputTypeReference::Base(interface_name, _),
+            ) => name == interface_name || self.is_possible_type(name, interface_name),
+            _ => false,
+        }
+    }
+
+    fn is_possible_type(&self, name: &str, abstract_name: &str) -> bool {
+        match self.schema_definition.get_type_definition(abstract_name) {
+            Some(TypeDefinitionReference::Union(utd)) => {
+                utd.union_member_types().contains_type(name)
+            }
+            Some(TypeDefinitionReference::Interface(_)) => {
+                let interface_implementations = match self
+                    .schema_definition
+                    .get_type_definition(name)
+                {
+                    Some(TypeDefinitionReference::Object(otd)) => otd.interface_implementations(),
+                    Some(TypeDefinitionReference::Interface(itd)) => {
+                        itd.interface_implementations()
+                    }
+                    _ => None,
+                };
+                interface_implementations.is_some_and(|interface_implementations| {
+                    interface_implementations
+                        .iter()
+                        .any(|interface_implementation| {
+                            interface_implementation.name() == abstract_name
+                        })
+                })
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Visitor<'a, S> for InterfaceImplementationsAreValid<'a, S> {
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        self.visit_implementor(
+            TypeDefinitionReference::Object(object_type_definition),
+            object_type_definition.fields_definition(),
+            object_type_definition.interface_implementations(),
+        );
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.visit_implementor(
+            TypeDefinitionReference::Interface(interface_type_definition),
+            interface_type_definition.fields_definition(),
+            interface_type_definition.interface_implementations(),
+        );
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for InterfaceImplementationsAreValid<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Rule<'a, S> for InterfaceImplementationsAreValid<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
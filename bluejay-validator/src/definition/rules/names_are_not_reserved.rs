@@ -0,0 +1,150 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    InputObjectTypeDefinition, InputValueDefinition, SchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::AsIter;
+
+/// Names beginning with this prefix are reserved for use by the introspection system
+const RESERVED_PREFIX: &str = "__";
+
+pub struct NamesAreNotReserved<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> NamesAreNotReserved<'a, S> {
+    fn is_reserved(name: &str) -> bool {
+        name.starts_with(RESERVED_PREFIX)
+    }
+
+    fn visit_type_definition(
+        &mut self,
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if Self::is_reserved(type_definition.name()) {
+            self.errors
+                .push(Error::TypeDefinitionNameReserved { type_definition });
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for NamesAreNotReserved<'a, S> {
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        custom_scalar_type_definition: &'a <S as SchemaDefinition>::CustomScalarTypeDefinition,
+    ) {
+        self.visit_type_definition(TypeDefinitionReference::CustomScalar(
+            custom_scalar_type_definition,
+        ));
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        self.visit_type_definition(TypeDefinitionReference::Object(object_type_definition));
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.visit_type_definition(TypeDefinitionReference::Interface(
+            interface_type_definition,
+        ));
+    }
+
+    fn visit_union_type_definition(
+        &mut self,
+        union_type_definition: &'a <S as SchemaDefinition>::UnionTypeDefinition,
+    ) {
+        self.visit_type_definition(TypeDefinitionReference::Union(union_type_definition));
+    }
+
+    fn visit_input_object_type_definition(
+        &mut self,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        self.visit_type_definition(TypeDefinitionReference::InputObject(
+            input_object_type_definition,
+        ));
+        self.errors.extend(
+            input_object_type_definition
+                .input_field_definitions()
+                .iter()
+                .filter(|input_value_definition| Self::is_reserved(input_value_definition.name()))
+                .map(
+                    |input_value_definition| Error::InputValueDefinitionNameReserved {
+                        input_value_definition,
+                    },
+                ),
+        );
+    }
+
+    fn visit_enum_type_definition(
+        &mut self,
+        enum_type_definition: &'a <S as SchemaDefinition>::EnumTypeDefinition,
+    ) {
+        self.visit_type_definition(TypeDefinitionReference::Enum(enum_type_definition));
+        self.errors.extend(
+            enum_type_definition
+                .enum_value_definitions()
+                .iter()
+                .filter(|enum_value_definition| Self::is_reserved(enum_value_definition.name()))
+                .map(
+                    |enum_value_definition| Error::EnumValueDefinitionNameReserved {
+                        enum_value_definition,
+                    },
+                ),
+        );
+    }
+
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+    ) {
+        if Self::is_reserved(field_definition.name()) {
+            self.errors
+                .push(Error::FieldDefinitionNameReserved { field_definition });
+        }
+    }
+
+    fn visit_argument_definition(
+        &mut self,
+        input_value_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+    ) {
+        if Self::is_reserved(input_value_definition.name()) {
+            self.errors.push(Error::InputValueDefinitionNameReserved {
+                input_value_definition,
+            });
+        }
+    }
+
+    fn visit_directive_definition(
+        &mut self,
+        directive_definition: &'a <S as SchemaDefinition>::DirectiveDefinition,
+    ) {
+        if Self::is_reserved(directive_definition.name()) {
+            self.errors.push(Error::DirectiveDefinitionNameReserved {
+                directive_definition,
+            });
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for NamesAreNotReserved<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for NamesAreNotReserved<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
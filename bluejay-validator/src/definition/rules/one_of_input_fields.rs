@@ -0,0 +1,65 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{
+    HasDirectives, InputObjectTypeDefinition, InputType, InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::{AsIter, Directive};
+
+const ONE_OF_DIRECTIVE: &str = "oneOf";
+
+pub struct OneOfInputFields<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for OneOfInputFields<'a, S> {
+    fn visit_input_object_type_definition(
+        &mut self,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        let is_one_of = input_object_type_definition
+            .directives()
+            .is_some_and(|directives| {
+                directives
+                    .iter()
+                    .any(|directive| directive.name() == ONE_OF_DIRECTIVE)
+            });
+
+        if !is_one_of {
+            return;
+        }
+
+        input_object_type_definition
+            .input_field_definitions()
+            .iter()
+            .for_each(|input_field_definition| {
+                if input_field_definition.r#type().is_required() {
+                    self.errors.push(Error::OneOfInputFieldRequired {
+                        input_object_type_definition,
+                        input_field_definition,
+                    });
+                }
+                if input_field_definition.default_value().is_some() {
+                    self.errors.push(Error::OneOfInputFieldHasDefaultValue {
+                        input_object_type_definition,
+                        input_field_definition,
+                    });
+                }
+            });
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for OneOfInputFields<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for OneOfInputFields<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{
+    HasDirectives, InputObjectTypeDefinition, InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::{AsIter, Directive};
+
+const DEPRECATED_DIRECTIVE: &str = "deprecated";
+
+pub struct RequiredInputValuesNotDeprecated<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> RequiredInputValuesNotDeprecated<'a, S> {
+    fn visit_input_value_definition(
+        &mut self,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        if !input_value_definition.is_required() {
+            return;
+        }
+
+        if let Some(directive) = input_value_definition.directives().and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == DEPRECATED_DIRECTIVE)
+        }) {
+            self.errors
+                .push(Error::RequiredInputValueDefinitionDeprecated {
+                    input_value_definition,
+                    directive,
+                });
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for RequiredInputValuesNotDeprecated<'a, S> {
+    fn visit_input_object_type_definition(
+        &mut self,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        input_object_type_definition
+            .input_field_definitions()
+            .iter()
+            .for_each(|input_value_definition| {
+                self.visit_input_value_definition(input_value_definition)
+            });
+    }
+
+    fn visit_argument_definition(
+        &mut self,
+        argument_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+    ) {
+        self.visit_input_value_definition(argument_definition);
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for RequiredInputValuesNotDeprecated<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for RequiredInputValuesNotDeprecated<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
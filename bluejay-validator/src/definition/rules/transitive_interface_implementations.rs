@@ -0,0 +1,110 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{
+    InterfaceImplementation, InterfaceTypeDefinition, ObjectTypeDefinition, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::AsIter;
+
+pub struct TransitiveInterfaceImplementations<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition + 'a> TransitiveInterfaceImplementations<'a, S> {
+    fn visit_implementor(
+        &mut self,
+        implementor: TypeDefinitionReference<'a, S::TypeDefinition>,
+        interface_implementations: Option<&'a S::InterfaceImplementations>,
+    ) {
+        let Some(interface_implementations) = interface_implementations else {
+            return;
+        };
+
+        interface_implementations
+            .iter()
+            .for_each(|interface_implementation| {
+                let interface_type_definition =
+                    interface_implementation.interface(self.schema_definition);
+                self.errors.extend(
+                    interface_type_definition
+                        .interface_implementations()
+                        .into_iter()
+                        .flat_map(AsIter::iter)
+                        .filter(|transitive_interface_implementation| {
+                            !interface_implementations
+                                .iter()
+                                .any(|interface_implementation| {
+                                    interface_implementation.name()
+                                        == transitive_interface_implementation.name()
+                                })
+                        })
+                        .map(|transitive_interface_implementation| {
+                            Error::TransitiveInterfaceNotImplemented {
+                                implementor,
+                                interface_implementation,
+                                transitive_interface_type_definition:
+                                    transitive_interface_implementation
+                                        .interface(self.schema_definition),
+                            }
+                        }),
+                );
+            });
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Visitor<'a, S> for TransitiveInterfaceImplementations<'a, S> {
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        self.visit_implementor(
+            TypeDefinitionReference::Object(object_type_definition),
+            object_type_definition.interface_implementations(),
+        );
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.errors.extend(
+            interface_type_definition
+                .interface_implementations()
+                .into_iter()
+                .flat_map(AsIter::iter)
+                .filter(|interface_implementation| {
+                    interface_implementation.name() == interface_type_definition.name()
+                })
+                .map(
+                    |interface_implementation| Error::InterfaceImplementsItself {
+                        interface_type_definition,
+                        interface_implementation,
+                    },
+                ),
+        );
+        self.visit_implementor(
+            TypeDefinitionReference::Interface(interface_type_definition),
+            interface_type_definition.interface_implementations(),
+        );
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for TransitiveInterfaceImplementations<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> Rule<'a, S> for TransitiveInterfaceImplementations<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
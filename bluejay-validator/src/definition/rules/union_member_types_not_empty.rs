@@ -0,0 +1,37 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{SchemaDefinition, UnionTypeDefinition};
+use bluejay_core::AsIter;
+
+pub struct UnionMemberTypesNotEmpty<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for UnionMemberTypesNotEmpty<'a, S> {
+    fn visit_union_type_definition(
+        &mut self,
+        union_type_definition: &'a <S as SchemaDefinition>::UnionTypeDefinition,
+    ) {
+        if union_type_definition.union_member_types().is_empty() {
+            self.errors.push(Error::UnionMemberTypesEmpty {
+                union_type_definition,
+            });
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for UnionMemberTypesNotEmpty<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for UnionMemberTypesNotEmpty<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
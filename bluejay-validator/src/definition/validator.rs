@@ -1,5 +1,10 @@
 use crate::definition::{BuiltinRules, Rule};
-use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::definition::{
+    DirectiveDefinition, DirectiveLocation, EnumTypeDefinition, FieldDefinition, HasDirectives,
+    InputObjectTypeDefinition, InterfaceTypeDefinition, ObjectTypeDefinition, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::AsIter;
 
 pub struct Validator<'a, S: SchemaDefinition, R: Rule<'a, S>> {
     schema_definition: &'a S,
@@ -17,15 +22,75 @@ impl<'a, S: SchemaDefinition, R: Rule<'a, S>> Validator<'a, S, R> {
     }
 
     fn visit(&mut self) {
-        self.schema_definition.type_definitions().for_each(
-            |type_definition| match type_definition {
+        self.visit_directives(
+            self.schema_definition.directives(),
+            DirectiveLocation::Schema,
+        );
+        self.schema_definition
+            .type_definitions()
+            .filter(|type_definition| !type_definition.is_builtin())
+            .for_each(|type_definition| match type_definition {
+                TypeDefinitionReference::BuiltinScalar(_) => {}
+                TypeDefinitionReference::CustomScalar(cstd) => {
+                    self.visit_custom_scalar_type_definition(cstd)
+                }
+                TypeDefinitionReference::Object(otd) => self.visit_object_type_definition(otd),
+                TypeDefinitionReference::Interface(itd) => {
+                    self.visit_interface_type_definition(itd)
+                }
+                TypeDefinitionReference::Union(utd) => self.visit_union_type_definition(utd),
                 TypeDefinitionReference::InputObject(iotd) => {
                     self.visit_input_object_type_definition(iotd)
                 }
                 TypeDefinitionReference::Enum(etd) => self.visit_enum_type_definition(etd),
-                _ => {}
-            },
-        )
+            });
+        self.schema_definition
+            .directive_definitions()
+            .filter(|directive_definition| !directive_definition.is_builtin())
+            .for_each(|directive_definition| self.visit_directive_definition(directive_definition));
+    }
+
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition,
+    ) {
+        self.rule
+            .visit_custom_scalar_type_definition(custom_scalar_type_definition);
+        self.visit_directives(
+            custom_scalar_type_definition.directives(),
+            DirectiveLocation::Scalar,
+        );
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a S::ObjectTypeDefinition,
+    ) {
+        self.rule
+            .visit_object_type_definition(object_type_definition);
+        self.visit_directives(
+            object_type_definition.directives(),
+            DirectiveLocation::Object,
+        );
+        self.visit_fields_definition(object_type_definition.fields_definition());
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+    ) {
+        self.rule
+            .visit_interface_type_definition(interface_type_definition);
+        self.visit_directives(
+            interface_type_definition.directives(),
+            DirectiveLocation::Interface,
+        );
+        self.visit_fields_definition(interface_type_definition.fields_definition());
+    }
+
+    fn visit_union_type_definition(&mut self, union_type_definition: &'a S::UnionTypeDefinition) {
+        self.rule.visit_union_type_definition(union_type_definition);
+        self.visit_directives(union_type_definition.directives(), DirectiveLocation::Union);
     }
 
     fn visit_input_object_type_definition(
@@ -34,10 +99,78 @@ impl<'a, S: SchemaDefinition, R: Rule<'a, S>> Validator<'a, S, R> {
     ) {
         self.rule
             .visit_input_object_type_definition(input_object_type_definition);
+        self.visit_directives(
+            input_object_type_definition.directives(),
+            DirectiveLocation::InputObject,
+        );
+        input_object_type_definition
+            .input_field_definitions()
+            .iter()
+            .for_each(|input_field_definition| {
+                self.visit_directives(
+                    input_field_definition.directives(),
+                    DirectiveLocation::InputFieldDefinition,
+                )
+            });
     }
 
     fn visit_enum_type_definition(&mut self, enum_type_definition: &'a S::EnumTypeDefinition) {
         self.rule.visit_enum_type_definition(enum_type_definition);
+        self.visit_directives(enum_type_definition.directives(), DirectiveLocation::Enum);
+        enum_type_definition
+            .enum_value_definitions()
+            .iter()
+            .for_each(|enum_value_definition| {
+                self.visit_directives(
+                    enum_value_definition.directives(),
+                    DirectiveLocation::EnumValue,
+                )
+            });
+    }
+
+    fn visit_fields_definition(&mut self, fields_definition: &'a S::FieldsDefinition) {
+        fields_definition
+            .iter()
+            .filter(|field_definition| !field_definition.is_builtin())
+            .for_each(|field_definition| {
+                self.rule.visit_field_definition(field_definition);
+                self.visit_directives(
+                    field_definition.directives(),
+                    DirectiveLocation::FieldDefinition,
+                );
+                self.visit_arguments_definition(field_definition.arguments_definition());
+            });
+    }
+
+    fn visit_arguments_definition(
+        &mut self,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+    ) {
+        arguments_definition
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|argument_definition| {
+                self.rule.visit_argument_definition(argument_definition);
+                self.visit_directives(
+                    argument_definition.directives(),
+                    DirectiveLocation::ArgumentDefinition,
+                );
+            });
+    }
+
+    fn visit_directive_definition(&mut self, directive_definition: &'a S::DirectiveDefinition) {
+        self.rule.visit_directive_definition(directive_definition);
+        self.visit_arguments_definition(directive_definition.arguments_definition());
+    }
+
+    fn visit_directives(
+        &mut self,
+        directives: Option<&'a <S as SchemaDefinition>::Directives>,
+        location: DirectiveLocation,
+    ) {
+        if let Some(directives) = directives {
+            self.rule.visit_directives(directives, location);
+        }
     }
 
     pub fn validate(schema_definition: &'a S) -> <Self as IntoIterator>::IntoIter {
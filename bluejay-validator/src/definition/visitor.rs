@@ -1,6 +1,26 @@
-use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition};
 
 pub trait Visitor<'a, S: SchemaDefinition> {
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        _custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition,
+    ) {
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        _object_type_definition: &'a S::ObjectTypeDefinition,
+    ) {
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        _interface_type_definition: &'a S::InterfaceTypeDefinition,
+    ) {
+    }
+
+    fn visit_union_type_definition(&mut self, _union_type_definition: &'a S::UnionTypeDefinition) {}
+
     fn visit_input_object_type_definition(
         &mut self,
         _input_object_type_definition: &'a S::InputObjectTypeDefinition,
@@ -8,4 +28,19 @@ pub trait Visitor<'a, S: SchemaDefinition> {
     }
 
     fn visit_enum_type_definition(&mut self, _enum_type_definition: &'a S::EnumTypeDefinition) {}
+
+    fn visit_field_definition(&mut self, _field_definition: &'a S::FieldDefinition) {}
+
+    /// Visits the arguments of both field definitions and directive definitions
+    fn visit_argument_definition(&mut self, _argument_definition: &'a S::InputValueDefinition) {}
+
+    fn visit_directive_definition(&mut self, _directive_definition: &'a S::DirectiveDefinition) {}
+
+    /// Visits the directives applied at a type system location, such as a type or field definition
+    fn visit_directives(
+        &mut self,
+        _directives: &'a <S as SchemaDefinition>::Directives,
+        _location: DirectiveLocation,
+    ) {
+    }
 }
@@ -1,5 +1,7 @@
 mod cache;
 pub mod document;
 pub mod operation;
+mod validation_cache;
 
 pub use cache::Cache;
+pub use validation_cache::ValidationCache;
@@ -1,3 +1,5 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use crate::value::input_coercion::Error as InputCoercionError;
 use bluejay_core::definition::{
     FieldDefinition, InputType, OutputType, SchemaDefinition, TypeDefinitionReference,
@@ -32,6 +34,7 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     FieldDoesNotExistOnType {
         field: &'a E::Field,
         r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        suggestions: Vec<&'a str>,
     },
     OperationTypeNotDefined {
         operation: &'a E::ExplicitOperationDefinition,
@@ -65,6 +68,7 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     },
     FragmentSpreadTargetUndefined {
         fragment_spread: &'a E::FragmentSpread,
+        suggestions: Vec<&'a str>,
     },
     FragmentSpreadCycle {
         fragment_definition: &'a E::FragmentDefinition,
@@ -127,10 +131,67 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     },
 }
 
+impl<E: ExecutableDocument, S: SchemaDefinition> Error<'_, E, S> {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueOperationNames { .. } => "NON_UNIQUE_OPERATION_NAMES",
+            Self::NotLoneAnonymousOperation { .. } => "NOT_LONE_ANONYMOUS_OPERATION",
+            Self::SubscriptionRootNotSingleField { .. } => "SUBSCRIPTION_ROOT_NOT_SINGLE_FIELD",
+            Self::FieldDoesNotExistOnType { .. } => "FIELD_DOES_NOT_EXIST_ON_TYPE",
+            Self::OperationTypeNotDefined { .. } => "OPERATION_TYPE_NOT_DEFINED",
+            Self::LeafFieldSelectionNotEmpty { .. } => "LEAF_FIELD_SELECTION_NOT_EMPTY",
+            Self::NonLeafFieldSelectionEmpty { .. } => "NON_LEAF_FIELD_SELECTION_EMPTY",
+            Self::NonUniqueFragmentDefinitionNames { .. } => "NON_UNIQUE_FRAGMENT_DEFINITION_NAMES",
+            Self::FragmentDefinitionTargetTypeDoesNotExist { .. } => {
+                "FRAGMENT_DEFINITION_TARGET_TYPE_DOES_NOT_EXIST"
+            }
+            Self::InlineFragmentTargetTypeDoesNotExist { .. } => {
+                "INLINE_FRAGMENT_TARGET_TYPE_DOES_NOT_EXIST"
+            }
+            Self::FragmentDefinitionTargetTypeNotComposite { .. } => {
+                "FRAGMENT_DEFINITION_TARGET_TYPE_NOT_COMPOSITE"
+            }
+            Self::InlineFragmentTargetTypeNotComposite { .. } => {
+                "INLINE_FRAGMENT_TARGET_TYPE_NOT_COMPOSITE"
+            }
+            Self::FragmentDefinitionUnused { .. } => "FRAGMENT_DEFINITION_UNUSED",
+            Self::FragmentSpreadTargetUndefined { .. } => "FRAGMENT_SPREAD_TARGET_UNDEFINED",
+            Self::FragmentSpreadCycle { .. } => "FRAGMENT_SPREAD_CYCLE",
+            Self::FieldSelectionsDoNotMergeIncompatibleTypes { .. } => {
+                "FIELD_SELECTIONS_DO_NOT_MERGE_INCOMPATIBLE_TYPES"
+            }
+            Self::FieldSelectionsDoNotMergeDifferingNames { .. } => {
+                "FIELD_SELECTIONS_DO_NOT_MERGE_DIFFERING_NAMES"
+            }
+            Self::FieldSelectionsDoNotMergeDifferingArguments { .. } => {
+                "FIELD_SELECTIONS_DO_NOT_MERGE_DIFFERING_ARGUMENTS"
+            }
+            Self::FragmentSpreadIsNotPossible { .. } => "FRAGMENT_SPREAD_IS_NOT_POSSIBLE",
+            Self::InlineFragmentSpreadIsNotPossible { .. } => {
+                "INLINE_FRAGMENT_SPREAD_IS_NOT_POSSIBLE"
+            }
+            Self::InvalidConstValue(error) => error.code(),
+            Self::InvalidVariableValue(error) => error.code(),
+            Self::InvalidConstDirective(error) => error.code(),
+            Self::InvalidVariableDirective(error) => error.code(),
+            Self::InvalidConstArgument(error) => error.code(),
+            Self::InvalidVariableArgument(error) => error.code(),
+            Self::NonUniqueVariableDefinitionNames { .. } => "NON_UNIQUE_VARIABLE_DEFINITION_NAMES",
+            Self::VariableDefinitionTypeNotInput { .. } => "VARIABLE_DEFINITION_TYPE_NOT_INPUT",
+            Self::VariableNotDefined { .. } => "VARIABLE_NOT_DEFINED",
+            Self::VariableDefinitionUnused { .. } => "VARIABLE_DEFINITION_UNUSED",
+            Self::InvalidVariableUsage { .. } => "INVALID_VARIABLE_USAGE",
+            Self::InvalidOneOfVariableUsage { .. } => "INVALID_ONE_OF_VARIABLE_USAGE",
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> for ParserError {
     fn from(value: Error<'a, ParserExecutableDocument<'a>, S>) -> Self {
-        match value {
+        let code = value.code();
+        let error = match value {
             Error::NonUniqueOperationNames { name, operations } => Self::new(
                 format!("Multiple operation definitions named `{name}`"),
                 None,
@@ -169,14 +230,21 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::FieldDoesNotExistOnType { field, r#type } => Self::new(
+            Error::FieldDoesNotExistOnType {
+                field,
+                r#type,
+                suggestions,
+            } => Self::new(
                 format!(
                     "Field `{}` does not exist on type `{}`",
                     field.name().as_ref(),
                     r#type.name()
                 ),
                 Some(Annotation::new(
-                    format!("Field does not exist on type `{}`", r#type.name()),
+                    with_did_you_mean(
+                        format!("Field does not exist on type `{}`", r#type.name()),
+                        &suggestions,
+                    ),
                     *field.name().span(),
                 )),
                 Vec::new(),
@@ -310,13 +378,16 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::FragmentSpreadTargetUndefined { fragment_spread } => Self::new(
+            Error::FragmentSpreadTargetUndefined {
+                fragment_spread,
+                suggestions,
+            } => Self::new(
                 format!(
                     "No fragment defined with name `{}`",
                     fragment_spread.name().as_ref()
                 ),
                 Some(Annotation::new(
-                    "No fragment defined with this name",
+                    with_did_you_mean("No fragment defined with this name", &suggestions),
                     *fragment_spread.name().span(),
                 )),
                 Vec::new(),
@@ -544,6 +615,7 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-        }
+        };
+        error.with_code(code)
     }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use bluejay_core::definition::{
     DirectiveDefinition, FieldDefinition, InputValueDefinition, SchemaDefinition,
 };
@@ -18,10 +20,12 @@ pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDe
     ArgumentDoesNotExistOnField {
         argument: &'a E::Argument<CONST>,
         field_definition: &'a S::FieldDefinition,
+        suggestions: Vec<&'a str>,
     },
     ArgumentDoesNotExistOnDirective {
         argument: &'a E::Argument<CONST>,
         directive_definition: &'a S::DirectiveDefinition,
+        suggestions: Vec<&'a str>,
     },
     DirectiveMissingRequiredArguments {
         directive: &'a E::Directive<CONST>,
@@ -35,12 +39,28 @@ pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDe
     },
 }
 
+impl<const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> ArgumentError<'_, CONST, E, S> {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueArgumentNames { .. } => "NON_UNIQUE_ARGUMENT_NAMES",
+            Self::ArgumentDoesNotExistOnField { .. } => "ARGUMENT_DOES_NOT_EXIST_ON_FIELD",
+            Self::ArgumentDoesNotExistOnDirective { .. } => "ARGUMENT_DOES_NOT_EXIST_ON_DIRECTIVE",
+            Self::DirectiveMissingRequiredArguments { .. } => {
+                "DIRECTIVE_MISSING_REQUIRED_ARGUMENTS"
+            }
+            Self::FieldMissingRequiredArguments { .. } => "FIELD_MISSING_REQUIRED_ARGUMENTS",
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a, const CONST: bool, S: SchemaDefinition>
     From<ArgumentError<'a, CONST, ParserExecutableDocument<'a>, S>> for ParserError
 {
     fn from(value: ArgumentError<'a, CONST, ParserExecutableDocument<'a>, S>) -> Self {
-        match value {
+        let code = value.code();
+        let error = match value {
             ArgumentError::NonUniqueArgumentNames { arguments, name } => Self::new(
                 format!("Multiple arguments with name `{name}`"),
                 None,
@@ -57,6 +77,7 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
             ArgumentError::ArgumentDoesNotExistOnField {
                 argument,
                 field_definition,
+                suggestions,
             } => Self::new(
                 format!(
                     "Field `{}` does not define an argument named `{}`",
@@ -64,7 +85,7 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     argument.name().as_ref(),
                 ),
                 Some(Annotation::new(
-                    "No argument definition with this name",
+                    with_did_you_mean("No argument definition with this name", &suggestions),
                     *argument.name().span(),
                 )),
                 Vec::new(),
@@ -72,6 +93,7 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
             ArgumentError::ArgumentDoesNotExistOnDirective {
                 argument,
                 directive_definition,
+                suggestions,
             } => Self::new(
                 format!(
                     "Directive `{}` does not define an argument named `{}`",
@@ -79,7 +101,7 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     argument.name().as_ref(),
                 ),
                 Some(Annotation::new(
-                    "No argument definition with this name",
+                    with_did_you_mean("No argument definition with this name", &suggestions),
                     *argument.name().span(),
                 )),
                 Vec::new(),
@@ -130,6 +152,7 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     Vec::new(),
                 )
             }
-        }
+        };
+        error.with_code(code)
     }
 }
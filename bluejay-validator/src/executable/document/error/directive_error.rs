@@ -1,3 +1,5 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use bluejay_core::definition::{DirectiveDefinition, DirectiveLocation, SchemaDefinition};
 use bluejay_core::executable::ExecutableDocument;
 use bluejay_core::AsIter;
@@ -12,6 +14,7 @@ use itertools::Itertools;
 pub enum DirectiveError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> {
     DirectiveDoesNotExist {
         directive: &'a E::Directive<CONST>,
+        suggestions: Vec<&'a str>,
     },
     DirectiveInInvalidLocation {
         directive: &'a E::Directive<CONST>,
@@ -24,19 +27,36 @@ pub enum DirectiveError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaD
     },
 }
 
+impl<const CONST: bool, E: ExecutableDocument, S: SchemaDefinition>
+    DirectiveError<'_, CONST, E, S>
+{
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DirectiveDoesNotExist { .. } => "DIRECTIVE_DOES_NOT_EXIST",
+            Self::DirectiveInInvalidLocation { .. } => "DIRECTIVE_IN_INVALID_LOCATION",
+            Self::DirectivesNotUniquePerLocation { .. } => "DIRECTIVES_NOT_UNIQUE_PER_LOCATION",
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a, const CONST: bool, S: SchemaDefinition>
     From<DirectiveError<'a, CONST, ParserExecutableDocument<'a>, S>> for ParserError
 {
     fn from(value: DirectiveError<'a, CONST, ParserExecutableDocument<'a>, S>) -> Self {
-        match value {
-            DirectiveError::DirectiveDoesNotExist { directive } => Self::new(
+        let code = value.code();
+        let error = match value {
+            DirectiveError::DirectiveDoesNotExist {
+                directive,
+                suggestions,
+            } => Self::new(
                 format!(
                     "No directive definition with name `@{}`",
                     directive.name().as_ref()
                 ),
                 Some(Annotation::new(
-                    "No directive definition with this name",
+                    with_did_you_mean("No directive definition with this name", &suggestions),
                     *directive.name().span(),
                 )),
                 Vec::new(),
@@ -68,6 +88,7 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     *directive.span(),
                 )).collect(),
             ),
-        }
+        };
+        error.with_code(code)
     }
 }
@@ -2,6 +2,7 @@ use crate::executable::{
     document::{ArgumentError, Error, Path, Rule, Visitor},
     Cache,
 };
+use crate::utils::suggestion_list;
 use bluejay_core::definition::{
     DirectiveDefinition, FieldDefinition, InputValueDefinition, SchemaDefinition,
 };
@@ -29,17 +30,21 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> ArgumentNames<'a,
             self.visit_arguments(
                 Some(arguments),
                 directive_definition.arguments_definition(),
-                |argument| {
+                |argument, suggestions| {
                     build_error(ArgumentError::ArgumentDoesNotExistOnDirective {
                         argument,
                         directive_definition,
+                        suggestions,
                     })
                 },
             )
         }
     }
 
-    fn visit_arguments<const CONST: bool, F: Fn(&'a E::Argument<CONST>) -> Error<'a, E, S>>(
+    fn visit_arguments<
+        const CONST: bool,
+        F: Fn(&'a E::Argument<CONST>, Vec<&'a str>) -> Error<'a, E, S>,
+    >(
         &mut self,
         arguments: Option<&'a E::Arguments<CONST>>,
         arguments_definition: Option<&'a S::ArgumentsDefinition>,
@@ -52,7 +57,16 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> ArgumentNames<'a,
                         .iter()
                         .find(|ivd| ivd.name() == argument.name())
                 });
-                argument_definition.is_none().then(|| build_error(argument))
+                argument_definition.is_none().then(|| {
+                    let suggestions = suggestion_list(
+                        argument.name(),
+                        arguments_definition
+                            .into_iter()
+                            .flat_map(|arguments_definition| arguments_definition.iter())
+                            .map(InputValueDefinition::name),
+                    );
+                    build_error(argument, suggestions)
+                })
             }))
         }
     }
@@ -77,10 +91,11 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         self.visit_arguments(
             field.arguments(),
             field_definition.arguments_definition(),
-            |argument| {
+            |argument, suggestions| {
                 Error::InvalidVariableArgument(ArgumentError::ArgumentDoesNotExistOnField {
                     argument,
                     field_definition,
+                    suggestions,
                 })
             },
         )
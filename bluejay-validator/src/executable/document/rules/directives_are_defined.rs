@@ -2,7 +2,8 @@ use crate::executable::{
     document::{DirectiveError, Error, Rule, Visitor},
     Cache,
 };
-use bluejay_core::definition::SchemaDefinition;
+use crate::utils::suggestion_list;
+use bluejay_core::definition::{DirectiveDefinition, SchemaDefinition};
 use bluejay_core::executable::ExecutableDocument;
 use bluejay_core::Directive;
 
@@ -28,6 +29,12 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> DirectivesAreDefi
             self.errors
                 .push(build_error(DirectiveError::DirectiveDoesNotExist {
                     directive,
+                    suggestions: suggestion_list(
+                        directive.name(),
+                        self.schema_definition
+                            .directive_definitions()
+                            .map(DirectiveDefinition::name),
+                    ),
                 }));
         }
     }
@@ -2,10 +2,12 @@ use crate::executable::{
     document::{Error, Path, Rule, Visitor},
     Cache,
 };
+use crate::utils::suggestion_list;
 use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
-use bluejay_core::executable::{ExecutableDocument, FragmentSpread};
+use bluejay_core::executable::{ExecutableDocument, FragmentDefinition, FragmentSpread};
 
 pub struct FragmentSpreadTargetDefined<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    executable_document: &'a E,
     errors: Vec<Error<'a, E, S>>,
     cache: &'a Cache<'a, E, S>,
 }
@@ -13,8 +15,9 @@ pub struct FragmentSpreadTargetDefined<'a, E: ExecutableDocument, S: SchemaDefin
 impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
     for FragmentSpreadTargetDefined<'a, E, S>
 {
-    fn new(_: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
+    fn new(executable_document: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
         Self {
+            executable_document,
             errors: Vec::new(),
             cache,
         }
@@ -31,8 +34,15 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
             .fragment_definition(fragment_spread.name())
             .is_none()
         {
-            self.errors
-                .push(Error::FragmentSpreadTargetUndefined { fragment_spread });
+            self.errors.push(Error::FragmentSpreadTargetUndefined {
+                fragment_spread,
+                suggestions: suggestion_list(
+                    fragment_spread.name(),
+                    self.executable_document
+                        .fragment_definitions()
+                        .map(FragmentDefinition::name),
+                ),
+            });
         }
     }
 }
@@ -1,10 +1,16 @@
 mod analyzer;
 pub mod analyzers;
+mod collect_fields;
 mod orchestrator;
+#[cfg(feature = "serde_json")]
+mod response_validator;
 mod variable_values;
 mod visitor;
 
 pub use analyzer::Analyzer;
+pub use collect_fields::{CollectedField, FieldCollector, GroupedFieldSet, ObjectSelection};
 pub use orchestrator::{OperationResolutionError, Orchestrator};
+#[cfg(feature = "serde_json")]
+pub use response_validator::{ResponseError, ResponseValidator};
 pub use variable_values::{OperationDefinitionValueEvaluationExt, VariableValues};
 pub use visitor::Visitor;
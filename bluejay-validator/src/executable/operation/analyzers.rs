@@ -1,11 +1,17 @@
+mod coerce_values;
 pub mod complexity_cost;
-mod deprecation;
-mod input_size;
+pub mod deprecation;
+mod executable_element;
+pub mod input_size;
+mod limits;
 mod query_depth;
 mod variable_values_are_valid;
 
-pub use complexity_cost::ComplexityCost;
+pub use coerce_values::{CoerceValues, CoercedValues, ValueCoercionError};
+pub use complexity_cost::{ComplexityCost, ExplainComplexityCost};
 pub use deprecation::Deprecation;
+pub use executable_element::ExecutableElement;
 pub use input_size::InputSize;
+pub use limits::{Limit, LimitViolation, Limits, LimitsConfig};
 pub use query_depth::QueryDepth;
 pub use variable_values_are_valid::{VariableValueError, VariableValuesAreValid};
@@ -0,0 +1,567 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    value::input_coercion::{
+        CoerceInput, CoercedArgumentValues, CoercedVariableValues, Error as CoerceInputError,
+    },
+};
+use bluejay_core::definition::{FieldDefinition, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, OperationDefinition, VariableDefinition,
+};
+use bluejay_core::{AsIter, Value};
+
+/// The [CoerceValues] analyzer coerces the variable values of the operation and the arguments
+/// of every field included in the response, following the CoerceVariableValues and
+/// CoerceArgumentValues algorithms from the spec.
+/// Unlike [VariableValuesAreValid](super::VariableValuesAreValid), provided values without a
+/// corresponding variable definition are ignored rather than reported.
+pub struct CoerceValues<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    schema_definition: &'a S,
+    variable_values: CoercedVariableValues,
+    argument_values: HashMap<FieldKey<'a, E>, CoercedArgumentValues>,
+    errors: Vec<ValueCoercionError<'a, E, VV>>,
+}
+
+/// Identifies a field of the executable document by its address, as distinct fields
+/// can be structurally equal
+struct FieldKey<'a, E: ExecutableDocument>(&'a E::Field);
+
+impl<E: ExecutableDocument> PartialEq for FieldKey<'_, E> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl<E: ExecutableDocument> Eq for FieldKey<'_, E> {}
+
+impl<E: ExecutableDocument> Hash for FieldKey<'_, E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.0, state)
+    }
+}
+
+impl<E: ExecutableDocument> std::fmt::Debug for FieldKey<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FieldKey")
+            .field(&self.0.response_name())
+            .finish()
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for CoerceValues<'a, E, S, VV>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        // variables need to be coerced before any arguments referencing them, and the
+        // orchestrator visits variable definitions after the selection set
+        let mut coerced_variable_values = CoercedVariableValues::new();
+        let mut errors = Vec::new();
+
+        operation_definition
+            .as_ref()
+            .variable_definitions()
+            .into_iter()
+            .flat_map(|variable_definitions| variable_definitions.iter())
+            .for_each(|variable_definition| {
+                let Some(input_type) =
+                    cache.variable_definition_input_type(variable_definition.r#type())
+                else {
+                    return;
+                };
+                let name = variable_definition.variable();
+
+                let coerced_value = match (
+                    variable_values.get(name),
+                    variable_definition.default_value(),
+                ) {
+                    (Some(value), _) => schema_definition
+                        .coerce_const_value_to_owned(input_type, value, Default::default())
+                        .map_err(|errors| ValueCoercionError::InvalidVariableValue {
+                            variable_definition,
+                            value,
+                            errors,
+                        }),
+                    (None, Some(default_value)) => schema_definition
+                        .coerce_const_value_to_owned(input_type, default_value, Default::default())
+                        .map_err(|errors| ValueCoercionError::InvalidDefaultValue {
+                            variable_definition,
+                            errors,
+                        }),
+                    (None, None) if variable_definition.is_required() => {
+                        Err(ValueCoercionError::MissingVariableValue {
+                            variable_definition,
+                        })
+                    }
+                    (None, None) => return,
+                };
+
+                match coerced_value {
+                    Ok(coerced_value) => {
+                        coerced_variable_values.insert(name.to_owned(), coerced_value);
+                    }
+                    Err(error) => errors.push(error),
+                }
+            });
+
+        Self {
+            schema_definition,
+            variable_values: coerced_variable_values,
+            argument_values: HashMap::new(),
+            errors,
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        _: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included
+            || self
+                .errors
+                .iter()
+                .any(|error| !matches!(error, ValueCoercionError::InvalidArgumentValues { .. }))
+            || self.argument_values.contains_key(&FieldKey(field))
+        {
+            return;
+        }
+
+        match self.schema_definition.coerce_argument_values(
+            field_definition.arguments_definition(),
+            field.arguments(),
+            &self.variable_values,
+        ) {
+            Ok(argument_values) => {
+                self.argument_values
+                    .insert(FieldKey(field), argument_values);
+            }
+            Err(errors) => self
+                .errors
+                .push(ValueCoercionError::InvalidArgumentValues { field, errors }),
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for CoerceValues<'a, E, S, VV>
+{
+    type Output = Result<CoercedValues<'a, E>, Vec<ValueCoercionError<'a, E, VV>>>;
+
+    fn into_output(self) -> Self::Output {
+        if self.errors.is_empty() {
+            Ok(CoercedValues {
+                variable_values: self.variable_values,
+                argument_values: self.argument_values,
+            })
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// The output of the [CoerceValues] analyzer
+#[derive(Debug)]
+pub struct CoercedValues<'a, E: ExecutableDocument> {
+    variable_values: CoercedVariableValues,
+    argument_values: HashMap<FieldKey<'a, E>, CoercedArgumentValues>,
+}
+
+impl<'a, E: ExecutableDocument> CoercedValues<'a, E> {
+    pub fn variable_values(&self) -> &CoercedVariableValues {
+        &self.variable_values
+    }
+
+    /// Returns the coerced arguments of `field`, or `None` if the field was not
+    /// included in the response
+    pub fn argument_values(&self, field: &'a E::Field) -> Option<&CoercedArgumentValues> {
+        self.argument_values.get(&FieldKey(field))
+    }
+
+    pub fn into_variable_values(self) -> CoercedVariableValues {
+        self.variable_values
+    }
+}
+
+#[derive(Debug)]
+pub enum ValueCoercionError<'a, E: ExecutableDocument, VV: VariableValues> {
+    MissingVariableValue {
+        variable_definition: &'a E::VariableDefinition,
+    },
+    InvalidVariableValue {
+        variable_definition: &'a E::VariableDefinition,
+        value: &'a VV::Value,
+        errors: Vec<CoerceInputError<'a, true, <VV as VariableValues>::Value>>,
+    },
+    InvalidDefaultValue {
+        variable_definition: &'a E::VariableDefinition,
+        errors: Vec<CoerceInputError<'a, true, E::Value<true>>>,
+    },
+    InvalidArgumentValues {
+        field: &'a E::Field,
+        errors: Vec<CoerceInputError<'a, false, E::Value<false>>>,
+    },
+}
+
+impl<E: ExecutableDocument, VV: VariableValues> ValueCoercionError<'_, E, VV> {
+    pub fn message(&self) -> String {
+        match self {
+            Self::MissingVariableValue {
+                variable_definition,
+            } => format!(
+                "Missing value for required variable ${}",
+                variable_definition.variable()
+            ),
+            Self::InvalidVariableValue {
+                variable_definition,
+                errors,
+                ..
+            } => format!(
+                "Invalid value for variable ${}:\n- {}",
+                variable_definition.variable(),
+                Self::join_messages(errors),
+            ),
+            Self::InvalidDefaultValue {
+                variable_definition,
+                errors,
+            } => format!(
+                "Invalid default value for variable ${}:\n- {}",
+                variable_definition.variable(),
+                Self::join_messages(errors),
+            ),
+            Self::InvalidArgumentValues { field, errors } => format!(
+                "Invalid arguments for field `{}`:\n- {}",
+                field.response_name(),
+                Self::join_messages(errors),
+            ),
+        }
+    }
+
+    fn join_messages<const CONST: bool, V: Value<CONST>>(
+        errors: &[CoerceInputError<'_, CONST, V>],
+    ) -> String {
+        errors
+            .iter()
+            .map(|error| error.message())
+            .collect::<Vec<_>>()
+            .join("\n- ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::executable::{operation::Orchestrator, Cache};
+    use crate::value::input_coercion::{
+        CoercedArgumentValues, CoercedValue, CoercedVariableValues,
+    };
+    use bluejay_core::executable::{Field, OperationDefinition, Selection, SelectionReference};
+    use bluejay_core::AsIter;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+
+    use super::CoerceValues;
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        enum Species {
+            CAT
+            DOG
+        }
+
+        input PetFilter {
+            species: Species! = CAT
+            limit: Int = 10
+            name: String
+        }
+
+        input LegacyPetFilter {
+            limit: Int = "ten"
+        }
+
+        type Query {
+            pets(species: [Species!], filter: PetFilter, first: Int = 5): String!
+            pet(id: ID!): String!
+            price(amount: Float!): String!
+            legacyPets(first: Int = 2147483648, filter: LegacyPetFilter): String!
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    type FieldArgumentValues = Vec<(String, Option<CoercedArgumentValues>)>;
+
+    /// Coerces the values for the first operation in `source`, returning the coerced variable
+    /// values and the coerced argument values of each root field
+    fn coerce_values(
+        source: &str,
+        variable_values: &serde_json::Value,
+    ) -> Result<(CoercedVariableValues, FieldArgumentValues), Vec<String>> {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let coerced_values = Orchestrator::<_, _, _, CoerceValues<_, _, _>>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values
+                .as_object()
+                .expect("Variables must be an object"),
+            &cache,
+            (),
+        )
+        .unwrap()
+        .map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|err| err.message())
+                .collect::<Vec<_>>()
+        })?;
+
+        let field_argument_values = executable_document
+            .operation_definitions()
+            .first()
+            .unwrap()
+            .as_ref()
+            .selection_set()
+            .iter()
+            .filter_map(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => Some((
+                    field.response_name().to_owned(),
+                    coerced_values.argument_values(field).cloned(),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        Ok((coerced_values.into_variable_values(), field_argument_values))
+    }
+
+    fn object<const N: usize>(entries: [(&str, CoercedValue); N]) -> CoercedValue {
+        CoercedValue::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    fn arguments<const N: usize>(entries: [(&str, CoercedValue); N]) -> CoercedArgumentValues {
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect()
+    }
+
+    #[test]
+    fn test_variable_defaults_and_enum_strings() {
+        let (variable_values, field_argument_values) = coerce_values(
+            r#"
+                query($species: [Species!] = [DOG], $id: ID!) {
+                    pets(species: $species)
+                    pet(id: $id)
+                }
+            "#,
+            &serde_json::json!({ "id": 1 }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            variable_values,
+            arguments([
+                (
+                    "species",
+                    CoercedValue::List(vec![CoercedValue::Enum("DOG".to_owned())])
+                ),
+                ("id", CoercedValue::String("1".to_owned())),
+            ]),
+        );
+        assert_eq!(
+            field_argument_values,
+            vec![
+                (
+                    "pets".to_owned(),
+                    Some(arguments([
+                        (
+                            "species",
+                            CoercedValue::List(vec![CoercedValue::Enum("DOG".to_owned())])
+                        ),
+                        ("first", CoercedValue::Integer(5)),
+                    ])),
+                ),
+                (
+                    "pet".to_owned(),
+                    Some(arguments([("id", CoercedValue::String("1".to_owned()))])),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_list_wrapping_and_input_field_defaults() {
+        let (variable_values, field_argument_values) = coerce_values(
+            r#"
+                query($species: [Species!], $first: Int) {
+                    pets(species: $species, filter: { name: "Rex" }, first: $first)
+                    price(amount: 1)
+                }
+            "#,
+            &serde_json::json!({ "species": "CAT" }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            variable_values,
+            arguments([(
+                "species",
+                CoercedValue::List(vec![CoercedValue::Enum("CAT".to_owned())])
+            )]),
+        );
+        assert_eq!(
+            field_argument_values,
+            vec![
+                (
+                    "pets".to_owned(),
+                    Some(arguments([
+                        (
+                            "species",
+                            CoercedValue::List(vec![CoercedValue::Enum("CAT".to_owned())])
+                        ),
+                        (
+                            "filter",
+                            object([
+                                ("species", CoercedValue::Enum("CAT".to_owned())),
+                                ("limit", CoercedValue::Integer(10)),
+                                ("name", CoercedValue::String("Rex".to_owned())),
+                            ])
+                        ),
+                        ("first", CoercedValue::Integer(5)),
+                    ])),
+                ),
+                (
+                    "price".to_owned(),
+                    Some(arguments([("amount", CoercedValue::Float(1.0))])),
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_excluded_fields() {
+        let (_, field_argument_values) = coerce_values(
+            r#"
+                query($include: Boolean!) {
+                    pet(id: "1") @include(if: $include)
+                }
+            "#,
+            &serde_json::json!({ "include": false }),
+        )
+        .unwrap();
+
+        assert_eq!(field_argument_values, vec![("pet".to_owned(), None)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            coerce_values(
+                r#"
+                    query($id: ID!) {
+                        pet(id: $id)
+                    }
+                "#,
+                &serde_json::json!({}),
+            ),
+            Err(vec!["Missing value for required variable $id".to_owned()]),
+        );
+        assert_eq!(
+            coerce_values(
+                r#"
+                    query($amount: Float!) {
+                        price(amount: $amount)
+                    }
+                "#,
+                &serde_json::json!({ "amount": "1.0" }),
+            ),
+            Err(vec![
+                "Invalid value for variable $amount:\n- No implicit conversion of string to Float!"
+                    .to_owned()
+            ]),
+        );
+        assert_eq!(
+            coerce_values(
+                r#"
+                    query($first: Int = "five") {
+                        pets(first: $first)
+                    }
+                "#,
+                &serde_json::json!({}),
+            ),
+            Err(vec![
+                "Invalid default value for variable $first:\n- No implicit conversion of string to Int".to_owned()
+            ]),
+        );
+        assert_eq!(
+            coerce_values(
+                r#"
+                    query($id: ID) {
+                        pet(id: $id)
+                    }
+                "#,
+                &serde_json::json!({ "id": null }),
+            ),
+            Err(vec![
+                "Invalid arguments for field `pet`:\n- Got null when non-null value of type ID! was expected"
+                    .to_owned()
+            ]),
+        );
+        assert_eq!(
+            coerce_values(
+                r#"
+                    query {
+                        legacyPets
+                    }
+                "#,
+                &serde_json::json!({}),
+            ),
+            Err(vec![
+                "Invalid arguments for field `legacyPets`:\n- Invalid default value for first: Int cannot represent non 32-bit signed integer value 2147483648"
+                    .to_owned()
+            ]),
+        );
+        assert_eq!(
+            coerce_values(
+                r#"
+                    query {
+                        legacyPets(first: 1, filter: {})
+                    }
+                "#,
+                &serde_json::json!({}),
+            ),
+            Err(vec![
+                "Invalid arguments for field `legacyPets`:\n- Invalid default value for limit: No implicit conversion of string to Int"
+                    .to_owned()
+            ]),
+        );
+    }
+}
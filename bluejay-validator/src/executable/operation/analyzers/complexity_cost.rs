@@ -18,6 +18,14 @@ use arena::{Arena, NodeId};
 mod cost_computer;
 pub use cost_computer::{CostComputer, DefaultCostComputer, FieldMultipliers};
 
+mod cost_spec_cost_computer;
+pub use cost_spec_cost_computer::{
+    CostSpecCostComputer, CostSpecFieldMultipliers, RequestCostComputer, TypeCostComputer,
+};
+
+mod explanation;
+pub use explanation::{ComplexityCostExplanation, ExplainComplexityCost, FieldCostExplanation};
+
 mod relay_cost_computer;
 pub use relay_cost_computer::RelayCostComputer;
 
@@ -70,9 +78,7 @@ impl<
         if !included {
             return;
         }
-        let cost = self
-            .cost_computer
-            .cost_for_field_definition(field_definition);
+        let cost = self.cost_computer.cost_for_field(field_definition, field);
 
         // Don't grow the costing tree for leaf fields without cost,
         // just hold their position in the traversal stack with a None scope
@@ -182,14 +188,16 @@ impl<
     > ComplexityCost<'a, E, S, V, C>
 {
     fn result(&mut self) -> usize {
-        let root_scope = self
-            .scopes_stack
+        self.merged_max_complexity_for_scopes(&[self.root_scope()])
+    }
+
+    fn root_scope(&self) -> &ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers> {
+        self.scopes_stack
             .first()
             .copied()
             .flatten()
             .and_then(|index| self.scopes_arena.get(index))
-            .unwrap();
-        self.merged_max_complexity_for_scopes(&[root_scope])
+            .unwrap()
     }
 
     fn merged_max_complexity_for_scopes(
@@ -277,9 +285,12 @@ impl<
 
                 let children_cost = self.merged_max_complexity_for_scopes(&composite_scopes);
 
-                (base_cost + children_cost) * multiplier
+                // sizes from slicing arguments are user input, so saturate rather than overflow
+                base_cost
+                    .saturating_add(children_cost)
+                    .saturating_mul(multiplier)
             })
-            .sum()
+            .fold(0, usize::saturating_add)
     }
 
     fn possible_type_names(
@@ -1210,4 +1221,142 @@ mod tests {
             1,
         );
     }
+
+    fn explain_complexity(source: &str) -> ComplexityCostExplanation {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        Orchestrator::<
+            _,
+            _,
+            _,
+            ExplainComplexityCost<'_, _, _, _, RelayCostComputer<'_, _, _, _>>,
+        >::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &serde_json::Map::new(),
+            &cache,
+            (),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn explanation_breaks_down_cost_per_field() {
+        let explanation = explain_complexity(
+            r#"{
+            zeroScalar
+            oneObjectConnection(first: 7) {
+              nodes { zeroScalar twoScalar }
+            }
+        }"#,
+        );
+
+        assert_eq!(
+            explanation,
+            ComplexityCostExplanation {
+                total: 10,
+                fields: vec![FieldCostExplanation {
+                    response_name: "oneObjectConnection".to_owned(),
+                    type_name: "Query".to_owned(),
+                    base_cost: 1,
+                    multiplier: 1,
+                    subtotal: 10,
+                    children: vec![FieldCostExplanation {
+                        response_name: "nodes".to_owned(),
+                        type_name: "BasicObjectConnection".to_owned(),
+                        base_cost: 1,
+                        multiplier: 3,
+                        subtotal: 9,
+                        children: vec![FieldCostExplanation {
+                            response_name: "twoScalar".to_owned(),
+                            type_name: "BasicObject".to_owned(),
+                            base_cost: 2,
+                            multiplier: 1,
+                            subtotal: 2,
+                            children: Vec::new(),
+                        }],
+                    }],
+                }],
+            },
+        );
+    }
+
+    #[test]
+    fn explanation_total_matches_complexity() {
+        let source = r#"{
+          node(id: "r2d2c3p0") {
+            id
+            ...on Product {
+              featuredImage: one { zeroScalar }
+              featuredMedia: one { zeroScalar }
+            }
+            ...on User {
+              companyContactProfiles: one { zeroScalar }
+            }
+          }
+        }"#;
+        let explanation = explain_complexity(source);
+
+        check_complexity(source, explanation.total);
+        assert_eq!(
+            explanation
+                .fields
+                .iter()
+                .map(|field| (field.response_name.as_str(), field.subtotal))
+                .collect::<Vec<_>>(),
+            vec![("node", 3)],
+        );
+        assert_eq!(
+            explanation.fields[0]
+                .children
+                .iter()
+                .map(|field| (
+                    field.type_name.as_str(),
+                    field.response_name.as_str(),
+                    field.subtotal
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Product", "featuredImage", 1),
+                ("Product", "featuredMedia", 1),
+                ("User", "companyContactProfiles", 1),
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn explanation_serializes_to_json() {
+        let explanation = explain_complexity(r#"{ oneObject { twoScalar } }"#);
+
+        assert_eq!(
+            serde_json::to_value(&explanation).unwrap(),
+            serde_json::json!({
+                "total": 3,
+                "fields": [{
+                    "response_name": "oneObject",
+                    "type_name": "Query",
+                    "base_cost": 1,
+                    "multiplier": 1,
+                    "subtotal": 3,
+                    "children": [{
+                        "response_name": "twoScalar",
+                        "type_name": "BasicObject",
+                        "base_cost": 2,
+                        "multiplier": 1,
+                        "subtotal": 2,
+                    }],
+                }],
+            }),
+        );
+    }
 }
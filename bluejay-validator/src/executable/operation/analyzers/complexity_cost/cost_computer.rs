@@ -13,6 +13,12 @@ pub trait CostComputer<'a, E: ExecutableDocument, S: SchemaDefinition, V: Variab
 
     fn cost_for_field_definition(&self, field_definition: &S::FieldDefinition) -> usize;
 
+    /// The cost of a selection of `field`, which defaults to the cost of its definition.
+    /// Override this when the cost depends on the arguments given to the field.
+    fn cost_for_field(&self, field_definition: &S::FieldDefinition, _field: &E::Field) -> usize {
+        self.cost_for_field_definition(field_definition)
+    }
+
     fn field_multipliers(
         &self,
         field_definition: &S::FieldDefinition,
@@ -0,0 +1,587 @@
+use crate::executable::operation::{
+    analyzers::complexity_cost::{CostComputer, FieldMultipliers},
+    OperationDefinitionValueEvaluationExt, VariableValues,
+};
+use bluejay_core::definition::{
+    prelude::*, BaseInputTypeReference, BaseOutputTypeReference, InputTypeReference,
+    OutputTypeReference, SchemaDefinition,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{Argument, AsIter, Directive, ObjectValue, Value, ValueReference, Variable};
+use std::marker::PhantomData;
+
+const COST_DIRECTIVE: &str = "cost";
+const WEIGHT_ARGUMENT: &str = "weight";
+const LIST_SIZE_DIRECTIVE: &str = "listSize";
+const ASSUMED_SIZE_ARGUMENT: &str = "assumedSize";
+const SLICING_ARGUMENTS_ARGUMENT: &str = "slicingArguments";
+const SIZED_FIELDS_ARGUMENT: &str = "sizedFields";
+const SLICING_ARGUMENT_PATH_SEPARATOR: char = '.';
+
+/// Computes the type cost of an operation, as defined by the
+/// [GraphQL Cost Directive specification](https://ibm.github.io/graphql-specs/cost-spec.html).
+///
+/// The type cost is a measure of the size of the response. Each field costs the weight of its
+/// type, given by `@cost(weight:)` on object, scalar and enum types, or defaulting to 1 for
+/// composite types and 0 for scalars and enums.
+pub type TypeCostComputer<'a, E, S, V> = CostSpecCostComputer<'a, E, S, V, false>;
+
+/// Computes the request cost of an operation, as defined by the
+/// [GraphQL Cost Directive specification](https://ibm.github.io/graphql-specs/cost-spec.html).
+///
+/// The request cost is a measure of the work needed to resolve the operation. Each field costs
+/// its `@cost(weight:)`, or the weight of its type when it has none, plus the weights of the
+/// arguments and input fields given to it.
+pub type RequestCostComputer<'a, E, S, V> = CostSpecCostComputer<'a, E, S, V, true>;
+
+/// A [CostComputer] following the
+/// [GraphQL Cost Directive specification](https://ibm.github.io/graphql-specs/cost-spec.html),
+/// using [TypeCostComputer] or [RequestCostComputer] depending on `REQUEST_COST`.
+///
+/// Lists are sized using `@listSize` on the field definition: the largest value given for one of
+/// its `slicingArguments` (which may be a path into an input object, ie. `"page.first"`), or else
+/// its `assumedSize`. When `sizedFields` is given, the size applies to those child fields instead
+/// of the field itself. Lists without a size count as a single item. `requireOneSlicingArgument`
+/// does not affect the cost.
+pub struct CostSpecCostComputer<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    const REQUEST_COST: bool,
+> {
+    operation_definition: &'a E::OperationDefinition,
+    schema_definition: &'a S,
+    variable_values: &'a V,
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        const REQUEST_COST: bool,
+    > CostComputer<'a, E, S, V> for CostSpecCostComputer<'a, E, S, V, REQUEST_COST>
+{
+    type FieldMultipliers = CostSpecFieldMultipliers<E>;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+    ) -> Self {
+        Self {
+            operation_definition,
+            schema_definition,
+            variable_values,
+        }
+    }
+
+    fn cost_for_field_definition(
+        &self,
+        field_definition: &<S as SchemaDefinition>::FieldDefinition,
+    ) -> usize {
+        let type_weight = self.type_weight(field_definition);
+
+        if REQUEST_COST {
+            Self::weight(field_definition.directives()).unwrap_or(type_weight)
+        } else {
+            type_weight
+        }
+    }
+
+    fn cost_for_field(
+        &self,
+        field_definition: &<S as SchemaDefinition>::FieldDefinition,
+        field: &<E as ExecutableDocument>::Field,
+    ) -> usize {
+        let mut cost = self.cost_for_field_definition(field_definition);
+
+        if REQUEST_COST {
+            cost = cost.saturating_add(self.arguments_weight(field_definition, field));
+        }
+
+        match self.list_size(field_definition, field) {
+            Some(ListSize {
+                size,
+                sized_fields: None,
+            }) => cost.saturating_mul(size),
+            _ => cost,
+        }
+    }
+
+    fn field_multipliers(
+        &self,
+        field_definition: &<S as SchemaDefinition>::FieldDefinition,
+        field: &<E as ExecutableDocument>::Field,
+    ) -> CostSpecFieldMultipliers<E> {
+        CostSpecFieldMultipliers {
+            list_size: self.list_size(field_definition, field),
+            executable_document: PhantomData,
+        }
+    }
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition, V: VariableValues, const REQUEST_COST: bool>
+    CostSpecCostComputer<'_, E, S, V, REQUEST_COST>
+{
+    fn type_weight(&self, field_definition: &<S as SchemaDefinition>::FieldDefinition) -> usize {
+        let base_type = field_definition.r#type().base(self.schema_definition);
+
+        let directives = match base_type {
+            BaseOutputTypeReference::Object(otd) => otd.directives(),
+            BaseOutputTypeReference::CustomScalar(cstd) => cstd.directives(),
+            BaseOutputTypeReference::Enum(etd) => etd.directives(),
+            BaseOutputTypeReference::BuiltinScalar(_)
+            | BaseOutputTypeReference::Interface(_)
+            | BaseOutputTypeReference::Union(_) => None,
+        };
+
+        Self::weight(directives).unwrap_or_else(|| if base_type.is_composite() { 1 } else { 0 })
+    }
+
+    fn arguments_weight(
+        &self,
+        field_definition: &<S as SchemaDefinition>::FieldDefinition,
+        field: &<E as ExecutableDocument>::Field,
+    ) -> usize {
+        let (Some(arguments), Some(arguments_definition)) =
+            (field.arguments(), field_definition.arguments_definition())
+        else {
+            return 0;
+        };
+
+        arguments
+            .iter()
+            .filter_map(|argument| {
+                arguments_definition
+                    .get(argument.name())
+                    .map(|argument_definition| {
+                        Self::weight(argument_definition.directives()).unwrap_or(0)
+                            + self
+                                .input_value_weight(argument.value(), argument_definition.r#type())
+                    })
+            })
+            .sum()
+    }
+
+    fn input_value_weight<const CONST: bool, T: Value<CONST>>(
+        &self,
+        value: &T,
+        input_type: &S::InputType,
+    ) -> usize {
+        match value.as_ref() {
+            ValueReference::List(list) => {
+                let item_type = match input_type.as_ref(self.schema_definition) {
+                    InputTypeReference::List(item_type, _) => item_type,
+                    InputTypeReference::Base(_, _) => input_type,
+                };
+                list.iter()
+                    .map(|item| self.input_value_weight(item, item_type))
+                    .sum()
+            }
+            ValueReference::Object(object) => {
+                let BaseInputTypeReference::InputObject(iotd) =
+                    input_type.base(self.schema_definition)
+                else {
+                    return 0;
+                };
+                object
+                    .iter()
+                    .filter_map(|(key, value)| {
+                        iotd.input_field_definitions().get(key.as_ref()).map(
+                            |input_field_definition| {
+                                Self::weight(input_field_definition.directives()).unwrap_or(0)
+                                    + self
+                                        .input_value_weight(value, input_field_definition.r#type())
+                            },
+                        )
+                    })
+                    .sum()
+            }
+            _ => 0,
+        }
+    }
+
+    fn list_size(
+        &self,
+        field_definition: &<S as SchemaDefinition>::FieldDefinition,
+        field: &<E as ExecutableDocument>::Field,
+    ) -> Option<ListSize> {
+        let list_size_arguments = field_definition.directives().and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == LIST_SIZE_DIRECTIVE)
+                .and_then(|directive| directive.arguments())
+        });
+
+        let Some(list_size_arguments) = list_size_arguments else {
+            return matches!(
+                field_definition.r#type().as_ref(self.schema_definition),
+                OutputTypeReference::List(_, _)
+            )
+            .then_some(ListSize {
+                size: 1,
+                sized_fields: None,
+            });
+        };
+
+        let argument_value = |name: &str| {
+            list_size_arguments
+                .iter()
+                .find(|argument| argument.name() == name)
+                .map(|argument| argument.value())
+        };
+
+        let slicing_size = argument_value(SLICING_ARGUMENTS_ARGUMENT)
+            .into_iter()
+            .flat_map(|value| Self::string_list(value))
+            .filter_map(|slicing_argument| {
+                self.slicing_argument_size(field_definition, field, slicing_argument)
+            })
+            .max();
+
+        let assumed_size = argument_value(ASSUMED_SIZE_ARGUMENT).and_then(|value| {
+            if let ValueReference::Integer(int) = value.as_ref() {
+                Some(int.max(0) as usize)
+            } else {
+                None
+            }
+        });
+
+        let sized_fields = argument_value(SIZED_FIELDS_ARGUMENT)
+            .map(|value| Self::string_list(value).map(ToOwned::to_owned).collect());
+
+        Some(ListSize {
+            size: slicing_size.or(assumed_size).unwrap_or(1),
+            sized_fields,
+        })
+    }
+
+    fn slicing_argument_size(
+        &self,
+        field_definition: &<S as SchemaDefinition>::FieldDefinition,
+        field: &<E as ExecutableDocument>::Field,
+        slicing_argument: &str,
+    ) -> Option<usize> {
+        let mut path = slicing_argument.split(SLICING_ARGUMENT_PATH_SEPARATOR);
+        let argument_name = path.next()?;
+        let path: Vec<&str> = path.collect();
+
+        let argument = field
+            .arguments()
+            .and_then(|arguments| arguments.iter().find(|arg| arg.name() == argument_name));
+
+        match argument {
+            Some(argument) => self.integer_at_path(argument.value(), &path),
+            None => field_definition
+                .arguments_definition()
+                .and_then(|arguments_definition| arguments_definition.get(argument_name))
+                .and_then(|argument_definition| argument_definition.default_value())
+                .and_then(|default_value| self.integer_at_path(default_value, &path)),
+        }
+    }
+
+    fn integer_at_path<const CONST: bool, T: Value<CONST>>(
+        &self,
+        value: &T,
+        path: &[&str],
+    ) -> Option<usize> {
+        match (value.as_ref(), path.split_first()) {
+            (ValueReference::Integer(int), None) => Some(int.max(0) as usize),
+            (ValueReference::Variable(var), None) => self
+                .operation_definition
+                .evaluate_int(var, self.variable_values)
+                .map(|int| int.max(0) as usize),
+            (ValueReference::Variable(var), Some(_)) => self
+                .variable_values
+                .get(var.name())
+                .and_then(|value| self.integer_at_path(value, path)),
+            (ValueReference::Object(object), Some((key, rest))) => object
+                .iter()
+                .find(|(k, _)| k.as_ref() == *key)
+                .and_then(|(_, value)| self.integer_at_path(value, rest)),
+            _ => None,
+        }
+    }
+
+    fn string_list<const CONST: bool, T: Value<CONST>>(value: &T) -> impl Iterator<Item = &str> {
+        let list = if let ValueReference::List(list) = value.as_ref() {
+            Some(list)
+        } else {
+            None
+        };
+
+        list.into_iter().flat_map(|list| {
+            list.iter().filter_map(|item| {
+                if let ValueReference::String(str) = item.as_ref() {
+                    Some(str)
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    fn weight(directives: Option<&<S as SchemaDefinition>::Directives>) -> Option<usize> {
+        directives.and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == COST_DIRECTIVE)
+                .and_then(|directive| directive.arguments())
+                .and_then(|arguments| {
+                    arguments
+                        .iter()
+                        .find(|argument| argument.name() == WEIGHT_ARGUMENT)
+                        .and_then(|argument| match argument.value().as_ref() {
+                            ValueReference::String(str) => str
+                                .parse::<f32>()
+                                .ok()
+                                .map(|weight| weight.max(0f32) as usize),
+                            ValueReference::Integer(int) => Some(int.max(0) as usize),
+                            ValueReference::Float(float) => Some(float.max(0f64) as usize),
+                            _ => None,
+                        })
+                })
+        })
+    }
+}
+
+struct ListSize {
+    size: usize,
+    sized_fields: Option<Vec<String>>,
+}
+
+pub struct CostSpecFieldMultipliers<E: ExecutableDocument> {
+    list_size: Option<ListSize>,
+    executable_document: PhantomData<E>,
+}
+
+impl<E: ExecutableDocument> Default for CostSpecFieldMultipliers<E> {
+    fn default() -> Self {
+        Self {
+            list_size: None,
+            executable_document: PhantomData,
+        }
+    }
+}
+
+impl<E: ExecutableDocument> FieldMultipliers<E> for CostSpecFieldMultipliers<E> {
+    fn multiplier_for_field(&self, field: &E::Field) -> usize {
+        match &self.list_size {
+            Some(ListSize {
+                size,
+                sized_fields: None,
+            }) => *size,
+            Some(ListSize {
+                size,
+                sized_fields: Some(sized_fields),
+            }) if sized_fields.iter().any(|name| name == field.name()) => *size,
+            _ => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestCostComputer, TypeCostComputer};
+    use crate::executable::{
+        operation::{analyzers::ComplexityCost, Orchestrator},
+        Cache,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefaultContext, DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use serde_json::Value as JsonValue;
+
+    type TypeCostAnalyzer<'a, E, S, V> =
+        Orchestrator<'a, E, S, V, ComplexityCost<'a, E, S, V, TypeCostComputer<'a, E, S, V>>>;
+    type RequestCostAnalyzer<'a, E, S, V> =
+        Orchestrator<'a, E, S, V, ComplexityCost<'a, E, S, V, RequestCostComputer<'a, E, S, V>>>;
+
+    const TEST_SCHEMA: &str = r#"
+        directive @cost(weight: String!) on ARGUMENT_DEFINITION | ENUM | FIELD_DEFINITION | INPUT_FIELD_DEFINITION | OBJECT | SCALAR
+        directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!], requireOneSlicingArgument: Boolean = true) on FIELD_DEFINITION
+
+        scalar Money @cost(weight: "2")
+
+        type Shop @cost(weight: "4") {
+          name: String!
+        }
+
+        type Product {
+          id: ID!
+          title: String!
+          price: Money!
+          related(first: Int): [Product!]! @listSize(slicingArguments: ["first"])
+        }
+
+        type ProductEdge {
+          node: Product!
+        }
+
+        type PageInfo {
+          hasNextPage: Boolean!
+        }
+
+        type ProductConnection {
+          edges: [ProductEdge!]!
+          nodes: [Product!]!
+          pageInfo: PageInfo!
+        }
+
+        input PageInput {
+          first: Int
+        }
+
+        input ProductFilter {
+          tag: String @cost(weight: "2")
+          tags: [String!]
+        }
+
+        type Query {
+          shop: Shop!
+          tags: [String!]!
+          products(first: Int, last: Int = 5): [Product!]! @listSize(slicingArguments: ["first", "last"])
+          featuredProducts: [Product!]! @listSize(assumedSize: 5)
+          productConnection(page: PageInput): ProductConnection @listSize(slicingArguments: ["page.first"], sizedFields: ["edges", "nodes"])
+          search(query: String! @cost(weight: "10"), filters: [ProductFilter!]): [Product!]! @cost(weight: "3") @listSize(assumedSize: 2)
+        }
+    "#;
+
+    fn check_cost(
+        source: &str,
+        variables: JsonValue,
+        expected_type_cost: usize,
+        expected_request_cost: usize,
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+
+        let type_cost = TypeCostAnalyzer::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+        let request_cost = RequestCostAnalyzer::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        assert_eq!(type_cost, expected_type_cost, "type cost");
+        assert_eq!(request_cost, expected_request_cost, "request cost");
+    }
+
+    #[test]
+    fn type_weights() {
+        check_cost(r#"{ shop { name } }"#, serde_json::json!({}), 4, 4);
+        check_cost(r#"{ tags }"#, serde_json::json!({}), 0, 0);
+        check_cost(
+            r#"{ search(query: "shirt") { price } }"#,
+            serde_json::json!({}),
+            2 * (1 + 2),
+            2 * (3 + 10 + 2),
+        );
+    }
+
+    #[test]
+    fn slicing_arguments() {
+        check_cost(
+            r#"{ products(first: 10) { id } }"#,
+            serde_json::json!({}),
+            10,
+            10,
+        );
+        check_cost(
+            r#"{ products(first: 2, last: 3) { price } }"#,
+            serde_json::json!({}),
+            3 * (1 + 2),
+            3 * (1 + 2),
+        );
+        check_cost(r#"{ products { id } }"#, serde_json::json!({}), 5, 5);
+        check_cost(
+            r#"query($first: Int) { products(first: $first) { id } }"#,
+            serde_json::json!({ "first": 7 }),
+            7,
+            7,
+        );
+    }
+
+    #[test]
+    fn assumed_size() {
+        check_cost(
+            r#"{ featuredProducts { title price } }"#,
+            serde_json::json!({}),
+            5 * (1 + 2),
+            5 * (1 + 2),
+        );
+    }
+
+    #[test]
+    fn sized_fields_with_nested_slicing_arguments() {
+        check_cost(
+            r#"{
+              productConnection(page: { first: 4 }) {
+                edges { node { id } }
+                pageInfo { hasNextPage }
+              }
+            }"#,
+            serde_json::json!({}),
+            1 + 4 * (1 + 1) + 1,
+            1 + 4 * (1 + 1) + 1,
+        );
+        check_cost(
+            r#"query($page: PageInput) { productConnection(page: $page) { nodes { id } } }"#,
+            serde_json::json!({ "page": { "first": 3 } }),
+            1 + 3,
+            1 + 3,
+        );
+    }
+
+    #[test]
+    fn argument_and_input_field_weights() {
+        check_cost(
+            r#"{ search(query: "shirt", filters: [{ tag: "a" }, { tag: "b", tags: ["c"] }]) { id } }"#,
+            serde_json::json!({}),
+            2,
+            2 * (3 + 10 + 2 + 2),
+        );
+    }
+
+    #[test]
+    fn nested_slicing_arguments_saturate() {
+        // slicing argument sizes come from the request, so their product must not overflow
+        check_cost(
+            r#"{
+              products(first: 2147483647) {
+                related(first: 2147483647) {
+                  related(first: 2147483647) { id }
+                }
+              }
+            }"#,
+            serde_json::json!({}),
+            usize::MAX,
+            usize::MAX,
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::executable::{
+    operation::{
+        analyzers::complexity_cost::{
+            ComplexityCost, ComplexityScope, CostComputer, DefaultCostComputer,
+        },
+        Analyzer, VariableValues, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::ExecutableDocument;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Explains the cost computed by [ComplexityCost] as a tree of the fields that contributed to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ComplexityCostExplanation {
+    /// The total cost of the operation, equal to the output of [ComplexityCost]
+    pub total: usize,
+    /// The root fields of the operation that contributed to the cost, in document order.
+    /// Leaf fields without a cost are omitted.
+    pub fields: Vec<FieldCostExplanation>,
+}
+
+/// The cost of a field selected within the type named `type_name`. Fields selected with the same
+/// response name within the same type are merged, as they are in [ComplexityCost].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct FieldCostExplanation {
+    pub response_name: String,
+    pub type_name: String,
+    /// The cost of the field itself, from [CostComputer::cost_for_field]
+    pub base_cost: usize,
+    /// The multiplier applied to the field by its parent field, from
+    /// [FieldMultipliers](super::FieldMultipliers)
+    pub multiplier: usize,
+    /// The cost of the field and its children with the multiplier applied. When selections on
+    /// different possible types are merged, only the most expensive one counts towards the
+    /// subtotal of the parent field.
+    pub subtotal: usize,
+    /// The child fields that contributed to the cost, in document order
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub children: Vec<FieldCostExplanation>,
+}
+
+/// The [ExplainComplexityCost] analyzer computes the same cost as [ComplexityCost], but outputs
+/// a [ComplexityCostExplanation] breaking the cost down per field.
+pub struct ExplainComplexityCost<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    C: CostComputer<'a, E, S, V> = DefaultCostComputer,
+>(ComplexityCost<'a, E, S, V, C>);
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Visitor<'a, E, S, V> for ExplainComplexityCost<'a, E, S, V, C>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+        cache: &'a Cache<'a, E, S>,
+        extra_info: Self::ExtraInfo,
+    ) -> Self {
+        Self(ComplexityCost::new(
+            operation_definition,
+            schema_definition,
+            variable_values,
+            cache,
+            extra_info,
+        ))
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        self.0
+            .visit_field(field, field_definition, scoped_type, included)
+    }
+
+    fn leave_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        self.0
+            .leave_field(field, field_definition, scoped_type, included)
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Analyzer<'a, E, S, V> for ExplainComplexityCost<'a, E, S, V, C>
+{
+    type Output = ComplexityCostExplanation;
+
+    fn into_output(self) -> Self::Output {
+        let root_scope = self.0.root_scope();
+        ComplexityCostExplanation {
+            total: self.0.merged_max_complexity_for_scopes(&[root_scope]),
+            fields: self.explain_scope(root_scope),
+        }
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > ExplainComplexityCost<'a, E, S, V, C>
+{
+    fn explain_scope(
+        &self,
+        scope: &ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>,
+    ) -> Vec<FieldCostExplanation> {
+        let mut field_scopes: Vec<_> = scope
+            .typed_selections
+            .values()
+            .flat_map(|typed_selection| {
+                typed_selection
+                    .inner_selection
+                    .iter()
+                    .map(|(field_key, scope_index)| {
+                        (
+                            typed_selection.type_definition.name(),
+                            *field_key,
+                            *scope_index,
+                        )
+                    })
+            })
+            .collect();
+        // scopes are added to the arena in document order
+        field_scopes.sort_by_key(|(_, _, scope_index)| *scope_index);
+
+        field_scopes
+            .into_iter()
+            .filter_map(|(type_name, field_key, scope_index)| {
+                let child_scope = self.0.scopes_arena.get(scope_index)?;
+                let children_cost = self.0.merged_max_complexity_for_scopes(&[child_scope]);
+                Some(FieldCostExplanation {
+                    response_name: field_key.to_owned(),
+                    type_name: type_name.to_owned(),
+                    base_cost: child_scope.cost,
+                    multiplier: child_scope.multiplier,
+                    subtotal: child_scope
+                        .cost
+                        .saturating_add(children_cost)
+                        .saturating_mul(child_scope.multiplier),
+                    children: self.explain_scope(child_scope),
+                })
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
+    operation::{analyzers::ExecutableElement, Analyzer, VariableValues, Visitor},
     Cache,
 };
 use bluejay_core::definition::{
@@ -9,22 +9,96 @@ use bluejay_core::definition::{
 };
 use bluejay_core::executable::{ExecutableDocument, Field, VariableDefinition};
 use bluejay_core::{Argument, AsIter, Directive, ObjectValue, Value, ValueReference};
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::executable::ExecutableDocument as ParserExecutableDocument,
+    error::{Annotation, Error as ParserError},
+    HasSpan,
+};
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 /// The deprecated usage we encountered.
-pub enum UsageType {
+pub enum UsageType<'a> {
     Argument,
-    EnumValue,
-    InputField,
+    /// A deprecated enum `value`
+    EnumValue {
+        value: &'a str,
+    },
+    /// A deprecated field of `input_object_type`
+    InputField {
+        input_object_type: &'a str,
+    },
     Field,
     Variable,
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct Offender<'a> {
+pub struct Offender<'a, E: ExecutableDocument> {
     pub reason: &'a str,
-    pub offense_type: UsageType,
+    pub offense_type: UsageType<'a>,
     pub name: &'a str,
+    /// The field, argument or variable definition containing the deprecated usage
+    pub element: ExecutableElement<'a, E>,
+}
+
+impl<E: ExecutableDocument> Offender<'_, E> {
+    /// A description of the deprecated usage, e.g. ``Enum value `DEPRECATED` is deprecated``
+    pub fn message(&self) -> String {
+        match self.offense_type {
+            UsageType::Argument => format!("Argument `{}` is deprecated", self.name),
+            UsageType::EnumValue { value } => format!("Enum value `{value}` is deprecated"),
+            UsageType::InputField { input_object_type } => format!(
+                "Input field `{}.{}` is deprecated",
+                input_object_type, self.name,
+            ),
+            UsageType::Field => format!("Field `{}` is deprecated", self.name),
+            UsageType::Variable => format!("Variable `{}` is deprecated", self.name),
+        }
+    }
+}
+
+impl<E: ExecutableDocument> Clone for Offender<'_, E> {
+    fn clone(&self) -> Self {
+        Self {
+            reason: self.reason,
+            offense_type: self.offense_type,
+            name: self.name,
+            element: self.element,
+        }
+    }
+}
+
+impl<E: ExecutableDocument> std::fmt::Debug for Offender<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Offender")
+            .field("reason", &self.reason)
+            .field("offense_type", &self.offense_type)
+            .field("name", &self.name)
+            .field("element", &self.element)
+            .finish()
+    }
+}
+
+impl<E: ExecutableDocument> PartialEq for Offender<'_, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.reason == other.reason
+            && self.offense_type == other.offense_type
+            && self.name == other.name
+            && self.element == other.element
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+impl<'a> From<Offender<'a, ParserExecutableDocument<'a>>> for ParserError {
+    fn from(value: Offender<'a, ParserExecutableDocument<'a>>) -> Self {
+        Self::new(
+            value.message(),
+            Some(Annotation::new(
+                format!("Deprecated: {}", value.reason),
+                *value.element.span(),
+            )),
+            Vec::new(),
+        )
+    }
 }
 
 /// The [Deprecation] analyzer will go over all ast-nodes of type Field, EnumValue, Argument and InputField
@@ -32,7 +106,7 @@ pub struct Offender<'a> {
 /// it will be added ot the list of [Offender].
 /// This method will output the list of [Offender].
 pub struct Deprecation<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
-    offenders: Vec<Offender<'a>>,
+    offenders: Vec<Offender<'a, E>>,
     schema_definition: &'a S,
     cache: &'a Cache<'a, E, S>,
     variable_values: &'a VV,
@@ -72,13 +146,19 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 .variable_values
                 .get(variable_definition.variable().as_ref())
             {
-                self.find_deprecations_for_value(input_type, value, variable_definition.variable());
+                self.find_deprecations_for_value(
+                    input_type,
+                    value,
+                    variable_definition.variable(),
+                    ExecutableElement::VariableDefinition(variable_definition),
+                );
             }
             if let Some(default_value) = variable_definition.default_value() {
                 self.find_deprecations_for_value(
                     input_type,
                     default_value,
                     variable_definition.variable(),
+                    ExecutableElement::VariableDefinition(variable_definition),
                 );
             }
         }
@@ -105,6 +185,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 name: field.name(),
                 offense_type: UsageType::Field,
                 reason,
+                element: ExecutableElement::Field(field),
             });
         }
     }
@@ -121,6 +202,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 name: argument.name(),
                 offense_type: UsageType::Argument,
                 reason,
+                element: ExecutableElement::Argument(argument),
             });
         }
 
@@ -128,6 +210,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             input_value_definition.r#type(),
             argument.value(),
             argument.name(),
+            ExecutableElement::Argument(argument),
         );
     }
 }
@@ -172,13 +255,14 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
         input_type: &'a I,
         value: &'a V,
         name: &'a str,
+        element: ExecutableElement<'a, E>,
     ) {
         match input_type.as_ref(self.schema_definition) {
             InputTypeReference::List(inner_list_type, _) => match value.as_ref() {
                 ValueReference::List(list_value) => list_value.iter().for_each(|list_item| {
-                    self.find_deprecations_for_value(inner_list_type, list_item, name)
+                    self.find_deprecations_for_value(inner_list_type, list_item, name, element)
                 }),
-                _ => self.find_deprecations_for_value(inner_list_type, value, name),
+                _ => self.find_deprecations_for_value(inner_list_type, value, name, element),
             },
             InputTypeReference::Base(base_input_type, _) => match base_input_type {
                 BaseInputTypeReference::Enum(etd) => {
@@ -192,18 +276,22 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                         _ => None,
                     };
                     if let Some(enum_value) = enum_value {
-                        if let Some(deprecation_reason) = etd
+                        if let Some((enum_value_definition, deprecation_reason)) = etd
                             .enum_value_definitions()
                             .iter()
                             .find(|evd| evd.name() == enum_value)
                             .and_then(|found_enum_value| {
                                 get_deprecation_reason::<S::EnumValueDefinition>(found_enum_value)
+                                    .map(|reason| (found_enum_value, reason))
                             })
                         {
                             self.offenders.push(Offender {
                                 name,
-                                offense_type: UsageType::EnumValue,
+                                offense_type: UsageType::EnumValue {
+                                    value: enum_value_definition.name(),
+                                },
                                 reason: deprecation_reason,
+                                element,
                             });
                         }
                     }
@@ -225,8 +313,11 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                     {
                                         self.offenders.push(Offender {
                                             name: input_field_definition.name(),
-                                            offense_type: UsageType::InputField,
+                                            offense_type: UsageType::InputField {
+                                                input_object_type: iotd.name(),
+                                            },
                                             reason,
+                                            element,
                                         });
                                     }
 
@@ -234,6 +325,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                         input_field_definition.r#type(),
                                         value,
                                         name,
+                                        element,
                                     )
                                 }
                             });
@@ -248,7 +340,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
 impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
     for Deprecation<'a, E, S, VV>
 {
-    type Output = Vec<Offender<'a>>;
+    type Output = Vec<Offender<'a, E>>;
 
     fn into_output(self) -> Self::Output {
         self.offenders
@@ -257,16 +349,19 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyze
 
 #[cfg(test)]
 mod tests {
-    use super::{Deprecation, Offender};
+    use super::{Deprecation, UsageType};
     use crate::executable::{
-        operation::{analyzers::deprecation::UsageType, Orchestrator},
+        operation::{analyzers::ExecutableElement, Orchestrator},
         Cache,
     };
+    use bluejay_core::executable::{OperationDefinition, Selection, SelectionReference};
+    use bluejay_core::AsIter;
     use bluejay_parser::ast::{
         definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
         executable::ExecutableDocument as ParserExecutableDocument,
         Parse,
     };
+    use bluejay_parser::{error::Error as ParserError, HasSpan};
     use once_cell::sync::Lazy;
     use serde_json::{Map as JsonMap, Value as JsonValue};
 
@@ -315,6 +410,14 @@ mod tests {
     static TEST_SCHEMA_DEFINITION: Lazy<ParserSchemaDefinition<'static>> =
         Lazy::new(|| ParserSchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
 
+    /// An [Offender](super::Offender) without its element
+    #[derive(Debug, PartialEq)]
+    struct Offender<'a> {
+        reason: &'a str,
+        offense_type: UsageType<'a>,
+        name: &'a str,
+    }
+
     fn validate_deprecations(query: &str, variables: serde_json::Value, expected: Vec<Offender>) {
         let executable_document = ParserExecutableDocument::parse(query)
             .result
@@ -330,9 +433,121 @@ mod tests {
             (),
         )
         .unwrap();
+        let deprecations: Vec<Offender> = deprecations
+            .into_iter()
+            .map(|offender| Offender {
+                reason: offender.reason,
+                offense_type: offender.offense_type,
+                name: offender.name,
+            })
+            .collect();
         assert_eq!(deprecations, expected);
     }
 
+    fn deprecation_elements_and_errors(
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Vec<(String, String)> {
+        let executable_document = ParserExecutableDocument::parse(query)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = variables.as_object().expect("Variables must be an object");
+        DeprecationAnalyzer::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variables,
+            &cache,
+            (),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|offender| {
+            let element = format!("{:?}", offender.element);
+            let error = ParserError::from(offender);
+            (element, error.message().to_owned())
+        })
+        .collect()
+    }
+
+    #[test]
+    fn offender_elements() {
+        assert_eq!(
+            deprecation_elements_and_errors(
+                r#"query($test: TestEnum) {
+                  renamed: test_field
+                  test_arg(deprecated_arg: "x")
+                  test_enum(deprecated_enum: $test)
+                }"#,
+                serde_json::json!({}),
+            ),
+            vec![
+                (
+                    "Field(\"renamed\")".to_owned(),
+                    "Field `test_field` is deprecated".to_owned()
+                ),
+                (
+                    "Argument(\"deprecated_arg\")".to_owned(),
+                    "Argument `deprecated_arg` is deprecated".to_owned()
+                ),
+            ],
+        );
+        assert_eq!(
+            deprecation_elements_and_errors(
+                r#"query($test: TestEnum = DEPRECATED) { test_enum(deprecated_enum: $test) }"#,
+                serde_json::json!({}),
+            ),
+            vec![(
+                "VariableDefinition(\"test\")".to_owned(),
+                "Enum value `DEPRECATED` is deprecated".to_owned()
+            )],
+        );
+        assert_eq!(
+            deprecation_elements_and_errors(
+                r#"query { test_input(input: { deprecated_input_field: "x" }) }"#,
+                serde_json::json!({}),
+            ),
+            vec![(
+                "Argument(\"input\")".to_owned(),
+                "Input field `TestInput.deprecated_input_field` is deprecated".to_owned()
+            )],
+        );
+    }
+
+    #[test]
+    fn offender_element_matches_document() {
+        let executable_document =
+            ParserExecutableDocument::parse(r#"query { valid_field test_field }"#)
+                .result
+                .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let deprecations = DeprecationAnalyzer::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        let SelectionReference::Field(field) = executable_document.operation_definitions()[0]
+            .as_ref()
+            .selection_set()
+            .iter()
+            .nth(1)
+            .unwrap()
+            .as_ref()
+        else {
+            panic!("expected a field");
+        };
+        assert_eq!(deprecations.len(), 1);
+        assert_eq!(deprecations[0].element, ExecutableElement::Field(field));
+        assert_eq!(deprecations[0].element.span(), field.name().span(),);
+    }
+
     #[test]
     fn field_deprecation() {
         validate_deprecations(
@@ -359,7 +574,9 @@ mod tests {
             vec![Offender {
                 name: "test",
                 reason: "enum_value",
-                offense_type: UsageType::EnumValue,
+                offense_type: UsageType::EnumValue {
+                    value: "DEPRECATED",
+                },
             }],
         );
     }
@@ -372,7 +589,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_enum",
                 reason: "enum_value",
-                offense_type: UsageType::EnumValue,
+                offense_type: UsageType::EnumValue {
+                    value: "DEPRECATED",
+                },
             }],
         );
     }
@@ -411,7 +630,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_input_field",
                 reason: "input_field",
-                offense_type: UsageType::InputField,
+                offense_type: UsageType::InputField {
+                    input_object_type: "TestInput",
+                },
             }],
         );
     }
@@ -424,7 +645,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_input_field",
                 reason: "input_field",
-                offense_type: UsageType::InputField,
+                offense_type: UsageType::InputField {
+                    input_object_type: "TestInput",
+                },
             }],
         );
     }
@@ -439,7 +662,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_input_field",
                 reason: "input_field",
-                offense_type: UsageType::InputField,
+                offense_type: UsageType::InputField {
+                    input_object_type: "TestInput",
+                },
             }],
         );
     }
@@ -452,7 +677,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_input_field",
                 reason: "input_field",
-                offense_type: UsageType::InputField,
+                offense_type: UsageType::InputField {
+                    input_object_type: "TestInput",
+                },
             }],
         );
     }
@@ -465,7 +692,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_input_field",
                 reason: "input_field",
-                offense_type: UsageType::InputField,
+                offense_type: UsageType::InputField {
+                    input_object_type: "TestInput",
+                },
             }],
         );
     }
@@ -478,7 +707,9 @@ mod tests {
             vec![Offender {
                 name: "deprecated_input_field",
                 reason: "input_field",
-                offense_type: UsageType::InputField,
+                offense_type: UsageType::InputField {
+                    input_object_type: "TestInput",
+                },
             }],
         );
     }
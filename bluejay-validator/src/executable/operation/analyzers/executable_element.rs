@@ -0,0 +1,65 @@
+use bluejay_core::executable::{ExecutableDocument, Field, VariableDefinition};
+use bluejay_core::Argument;
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::executable::ExecutableDocument as ParserExecutableDocument, HasSpan, Span,
+};
+
+/// An element of an executable document that an analyzer result refers to
+pub enum ExecutableElement<'a, E: ExecutableDocument> {
+    Field(&'a E::Field),
+    Argument(&'a E::Argument<false>),
+    VariableDefinition(&'a E::VariableDefinition),
+}
+
+impl<'a, E: ExecutableDocument> ExecutableElement<'a, E> {
+    /// The response name of the field, or the name of the argument or variable
+    pub fn name(&self) -> &'a str {
+        match self {
+            Self::Field(field) => field.response_name(),
+            Self::Argument(argument) => argument.name(),
+            Self::VariableDefinition(variable_definition) => variable_definition.variable(),
+        }
+    }
+}
+
+impl<E: ExecutableDocument> Clone for ExecutableElement<'_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: ExecutableDocument> Copy for ExecutableElement<'_, E> {}
+
+impl<E: ExecutableDocument> PartialEq for ExecutableElement<'_, E> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Field(a), Self::Field(b)) => std::ptr::eq(*a, *b),
+            (Self::Argument(a), Self::Argument(b)) => std::ptr::eq(*a, *b),
+            (Self::VariableDefinition(a), Self::VariableDefinition(b)) => std::ptr::eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+impl<E: ExecutableDocument> std::fmt::Debug for ExecutableElement<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            Self::Field(_) => "Field",
+            Self::Argument(_) => "Argument",
+            Self::VariableDefinition(_) => "VariableDefinition",
+        };
+        f.debug_tuple(variant).field(&self.name()).finish()
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+impl HasSpan for ExecutableElement<'_, ParserExecutableDocument<'_>> {
+    fn span(&self) -> &Span {
+        match self {
+            Self::Field(field) => field.name().span(),
+            Self::Argument(argument) => argument.span(),
+            Self::VariableDefinition(variable_definition) => variable_definition.variable().span(),
+        }
+    }
+}
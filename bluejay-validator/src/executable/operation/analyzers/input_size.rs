@@ -1,5 +1,5 @@
 use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
+    operation::{analyzers::ExecutableElement, Analyzer, VariableValues, Visitor},
     Cache,
 };
 use bluejay_core::{
@@ -7,13 +7,47 @@ use bluejay_core::{
     executable::{ExecutableDocument, OperationDefinition, VariableDefinition},
     Argument, AsIter, ObjectValue, Value, ValueReference, Variable,
 };
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::executable::ExecutableDocument as ParserExecutableDocument,
+    error::{Annotation, Error as ParserError},
+    HasSpan,
+};
 
-#[derive(Clone)]
 /// Represents an argument or input-field that exceeds
 /// the maximum allowed list-size.
-pub struct Offender {
+pub struct Offender<'a, E: ExecutableDocument> {
     pub size: usize,
     pub name: String,
+    /// The argument whose value contains the list
+    pub element: ExecutableElement<'a, E>,
+}
+
+impl<E: ExecutableDocument> Clone for Offender<'_, E> {
+    fn clone(&self) -> Self {
+        Self {
+            size: self.size,
+            name: self.name.clone(),
+            element: self.element,
+        }
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+impl<'a> From<Offender<'a, ParserExecutableDocument<'a>>> for ParserError {
+    fn from(value: Offender<'a, ParserExecutableDocument<'a>>) -> Self {
+        Self::new(
+            format!(
+                "List value of `{}` with {} items exceeds the maximum list size",
+                value.name, value.size,
+            ),
+            Some(Annotation::new(
+                format!("Contains a list of {} items", value.size),
+                *value.element.span(),
+            )),
+            Vec::new(),
+        )
+    }
 }
 
 #[derive(Clone)]
@@ -22,7 +56,7 @@ pub struct Offender {
 /// allowed list-size we will add it to the list of offenders.
 /// As output we'll return an array of [Offender].
 pub struct InputSize<'a, E: ExecutableDocument, VV: VariableValues> {
-    offenders: Vec<Offender>,
+    offenders: Vec<Offender<'a, E>>,
     max_length: usize,
     variable_values: &'a VV,
     variable_definitions: Option<&'a E::VariableDefinitions>,
@@ -58,6 +92,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             &mut self.offenders,
             self.variable_values,
             self.variable_definitions,
+            ExecutableElement::Argument(argument),
             argument.name().to_string(),
             argument.value(),
         );
@@ -70,14 +105,16 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
 /// deeper to find potential Objects contained within the list. When we enconter an object
 /// we'll traverse deeper to find object-fields that contain lists as a value.
 fn find_input_size_offenders_arguments<
+    'a,
     E: ExecutableDocument,
     VV: VariableValues,
     const CONST: bool,
 >(
     max_length: usize,
-    offenders: &mut Vec<Offender>,
+    offenders: &mut Vec<Offender<'a, E>>,
     variable_values: &VV,
     variable_definitions: Option<&E::VariableDefinitions>,
+    element: ExecutableElement<'a, E>,
     argument_name: String,
     argument_value: &<E as bluejay_core::executable::ExecutableDocument>::Value<CONST>,
 ) {
@@ -88,6 +125,7 @@ fn find_input_size_offenders_arguments<
                 offenders.push(Offender {
                     size: list_length,
                     name: argument_name,
+                    element,
                 })
             } else {
                 list.iter().enumerate().for_each(|(index, item)| {
@@ -96,6 +134,7 @@ fn find_input_size_offenders_arguments<
                         offenders,
                         variable_values,
                         variable_definitions,
+                        element,
                         format!("{argument_name}.{index}"),
                         item,
                     );
@@ -109,6 +148,7 @@ fn find_input_size_offenders_arguments<
                     offenders,
                     variable_values,
                     variable_definitions,
+                    element,
                     format!("{}.{}", argument_name, key.as_ref()),
                     value,
                 );
@@ -121,6 +161,7 @@ fn find_input_size_offenders_arguments<
                 find_input_size_offenders_variables::<E, VV>(
                     max_length,
                     offenders,
+                    element,
                     argument_name,
                     value,
                 );
@@ -138,6 +179,7 @@ fn find_input_size_offenders_arguments<
                             offenders,
                             variable_values,
                             variable_definitions,
+                            element,
                             argument_name,
                             default_value,
                         );
@@ -151,9 +193,10 @@ fn find_input_size_offenders_arguments<
 
 /// Similar to [find_input_size_offenders_arguments] however, it is specialised to traversing
 /// variable-values.
-fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues>(
+fn find_input_size_offenders_variables<'a, E: ExecutableDocument, VV: VariableValues>(
     max_length: usize,
-    offenders: &mut Vec<Offender>,
+    offenders: &mut Vec<Offender<'a, E>>,
+    element: ExecutableElement<'a, E>,
     argument_name: String,
     argument_value: &VV::Value,
 ) {
@@ -164,12 +207,14 @@ fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues
                 offenders.push(Offender {
                     size: list_length,
                     name: argument_name,
+                    element,
                 })
             } else {
                 list.iter().enumerate().for_each(|(index, item)| {
                     find_input_size_offenders_variables::<E, VV>(
                         max_length,
                         offenders,
+                        element,
                         format!("{argument_name}.{index}"),
                         item,
                     );
@@ -181,6 +226,7 @@ fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues
                 find_input_size_offenders_variables::<E, VV>(
                     max_length,
                     offenders,
+                    element,
                     format!("{}.{}", argument_name, key.as_ref()),
                     value,
                 );
@@ -193,7 +239,7 @@ fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues
 impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
     for InputSize<'a, E, VV>
 {
-    type Output = Vec<Offender>;
+    type Output = Vec<Offender<'a, E>>;
 
     fn into_output(self) -> Self::Output {
         self.offenders
@@ -202,8 +248,11 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyze
 
 #[cfg(test)]
 mod tests {
-    use super::{InputSize, Offender};
-    use crate::executable::{operation::Orchestrator, Cache};
+    use super::InputSize;
+    use crate::executable::{
+        operation::{analyzers::ExecutableElement, Orchestrator},
+        Cache,
+    };
     use bluejay_parser::ast::{
         definition::{
             DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
@@ -211,6 +260,7 @@ mod tests {
         executable::ExecutableDocument as ParserExecutableDocument,
         Parse,
     };
+    use bluejay_parser::{error::Error as ParserError, HasSpan};
     use serde_json::{Map as JsonMap, Value as JsonValue};
 
     const TEST_SCHEMA: &str = r#"
@@ -229,6 +279,12 @@ mod tests {
         }
     "#;
 
+    /// An [Offender](super::Offender) without its element
+    struct Offender {
+        size: usize,
+        name: String,
+    }
+
     fn analyze_input_size(query: &str, variables: serde_json::Value) -> Vec<Offender> {
         let definition_document: DefinitionDocument<'_, DefaultContext> =
             DefinitionDocument::parse(TEST_SCHEMA)
@@ -250,6 +306,49 @@ mod tests {
             1,
         )
         .unwrap()
+        .into_iter()
+        .map(|offender| Offender {
+            size: offender.size,
+            name: offender.name,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn offender_element_and_error() {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let source = r#"query { simple(x: ["x", "y"]) }"#;
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = JsonMap::new();
+        let offenders = Orchestrator::<_, _, JsonMap<String, JsonValue>, InputSize<_, _>>::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variables,
+            &cache,
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(offenders.len(), 1);
+        let offender = offenders[0].clone();
+        assert!(matches!(offender.element, ExecutableElement::Argument(_)));
+        assert_eq!(
+            &source[offender.element.span().byte_range()],
+            r#"x: ["x", "y"]"#
+        );
+        assert_eq!(
+            ParserError::from(offender).message(),
+            "List value of `x` with 2 items exceeds the maximum list size",
+        );
     }
 
     #[test]
@@ -0,0 +1,583 @@
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{Argument, AsIter, ObjectValue, Value, ValueReference, Variable};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// The caps enforced by the [Limits] analyzer. Caps that are `None` are not enforced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LimitsConfig {
+    /// The maximum number of aliased fields in the operation
+    pub max_aliases: Option<usize>,
+    /// The maximum number of fields in the operation, counting fields of a fragment once per
+    /// spread of the fragment
+    pub max_fields: Option<usize>,
+    /// The maximum number of times a response key can be selected within a single selection set,
+    /// including the selections of the fragments spread into it
+    pub max_field_repetitions: Option<usize>,
+    /// The maximum number of fields selected on the root operation type
+    pub max_root_fields: Option<usize>,
+    /// The maximum number of nested fragment spreads
+    pub max_fragment_depth: Option<usize>,
+    /// The maximum number of directives used at a single location
+    pub max_directives_per_location: Option<usize>,
+    /// The maximum total number of bytes of the string values passed as arguments, including
+    /// string values passed through variables
+    pub max_argument_string_bytes: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Aliases,
+    Fields,
+    FieldRepetitions,
+    RootFields,
+    FragmentDepth,
+    DirectivesPerLocation(DirectiveLocation),
+    ArgumentStringBytes,
+}
+
+/// A cap of [LimitsConfig] exceeded by the operation. `path` is the response path at which
+/// the cap was first exceeded, and `actual` is the final value of the capped measure there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitViolation {
+    pub limit: Limit,
+    pub max: usize,
+    pub actual: usize,
+    pub path: Vec<String>,
+}
+
+impl LimitViolation {
+    pub fn message(&self) -> String {
+        let Self {
+            limit, max, actual, ..
+        } = self;
+        match limit {
+            Limit::Aliases => {
+                format!("Operation uses {actual} aliases, exceeding the limit of {max}")
+            }
+            Limit::Fields => {
+                format!("Operation selects {actual} fields, exceeding the limit of {max}")
+            }
+            Limit::FieldRepetitions => format!(
+                "Field `{}` is selected {actual} times, exceeding the limit of {max}",
+                self.path.join("."),
+            ),
+            Limit::RootFields => {
+                format!("Operation selects {actual} root fields, exceeding the limit of {max}")
+            }
+            Limit::FragmentDepth => format!(
+                "Fragment spreads are nested {actual} levels deep, exceeding the limit of {max}"
+            ),
+            Limit::DirectivesPerLocation(location) => {
+                format!("{actual} directives are used on {location}, exceeding the limit of {max}")
+            }
+            Limit::ArgumentStringBytes => {
+                format!("Arguments contain {actual} bytes of strings, exceeding the limit of {max}")
+            }
+        }
+    }
+}
+
+/// The [Limits] analyzer checks an operation against the caps of a [LimitsConfig], and
+/// outputs a [LimitViolation] for each cap exceeded. Only fields known to be included in the
+/// response are counted.
+pub struct Limits<'a, E: ExecutableDocument, VV: VariableValues> {
+    config: LimitsConfig,
+    variable_values: &'a VV,
+    path: Vec<&'a str>,
+    response_key_counts: Vec<HashMap<&'a str, ResponseKeyCount>>,
+    fragment_depth: usize,
+    aliases: usize,
+    fields: usize,
+    root_fields: usize,
+    argument_string_bytes: usize,
+    violations: Vec<LimitViolation>,
+    fragment_depth_violation: Option<usize>,
+    executable_document: PhantomData<E>,
+}
+
+#[derive(Default)]
+struct ResponseKeyCount {
+    count: usize,
+    violation: Option<usize>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for Limits<'a, E, VV>
+{
+    type ExtraInfo = LimitsConfig;
+
+    fn new(
+        _: &'a E::OperationDefinition,
+        _: &'a S,
+        variable_values: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        config: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            config,
+            variable_values,
+            path: Vec::new(),
+            response_key_counts: vec![HashMap::new()],
+            fragment_depth: 0,
+            aliases: 0,
+            fields: 0,
+            root_fields: 0,
+            argument_string_bytes: 0,
+            violations: Vec::new(),
+            fragment_depth_violation: None,
+            executable_document: PhantomData,
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        let response_key = field.response_name();
+
+        if included {
+            self.fields += 1;
+            self.check_total(
+                Limit::Fields,
+                self.config.max_fields,
+                self.fields,
+                1,
+                response_key,
+            );
+
+            if field.alias().is_some() {
+                self.aliases += 1;
+                self.check_total(
+                    Limit::Aliases,
+                    self.config.max_aliases,
+                    self.aliases,
+                    1,
+                    response_key,
+                );
+            }
+
+            if self.path.is_empty() {
+                self.root_fields += 1;
+                self.check_total(
+                    Limit::RootFields,
+                    self.config.max_root_fields,
+                    self.root_fields,
+                    1,
+                    response_key,
+                );
+            }
+
+            self.count_response_key(response_key);
+        }
+
+        self.path.push(response_key);
+        self.response_key_counts.push(HashMap::new());
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _included: bool,
+    ) {
+        self.path.pop();
+        self.response_key_counts.pop();
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        _fragment_spread: &'a <E as ExecutableDocument>::FragmentSpread,
+        included: bool,
+    ) {
+        self.fragment_depth += 1;
+
+        let Some(max) = self.config.max_fragment_depth else {
+            return;
+        };
+        if !included || self.fragment_depth <= max {
+            return;
+        }
+
+        match self.fragment_depth_violation {
+            Some(index) => {
+                let violation = &mut self.violations[index];
+                violation.actual = violation.actual.max(self.fragment_depth);
+            }
+            None => {
+                self.fragment_depth_violation = Some(self.violations.len());
+                self.push_violation(Limit::FragmentDepth, max, self.fragment_depth, None);
+            }
+        }
+    }
+
+    fn leave_fragment_spread(
+        &mut self,
+        _fragment_spread: &'a <E as ExecutableDocument>::FragmentSpread,
+        _included: bool,
+    ) {
+        self.fragment_depth -= 1;
+
+        if self
+            .config
+            .max_fragment_depth
+            .is_some_and(|max| self.fragment_depth <= max)
+        {
+            self.fragment_depth_violation = None;
+        }
+    }
+
+    fn visit_variable_directives(
+        &mut self,
+        directives: &'a <E as ExecutableDocument>::Directives<false>,
+        location: DirectiveLocation,
+    ) {
+        let Some(max) = self.config.max_directives_per_location else {
+            return;
+        };
+        let count = directives.len();
+        if count > max {
+            self.push_violation(Limit::DirectivesPerLocation(location), max, count, None);
+        }
+    }
+
+    fn visit_variable_argument(
+        &mut self,
+        argument: &'a <E as ExecutableDocument>::Argument<false>,
+        _input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        let string_bytes = self.string_bytes(argument.value());
+        self.argument_string_bytes += string_bytes;
+        self.check_total(
+            Limit::ArgumentStringBytes,
+            self.config.max_argument_string_bytes,
+            self.argument_string_bytes,
+            string_bytes,
+            argument.name(),
+        );
+    }
+}
+
+impl<'a, E: ExecutableDocument, VV: VariableValues> Limits<'a, E, VV> {
+    /// Reports a violation of a cap on a total when adding `added` to it made it exceed the cap
+    fn check_total(
+        &mut self,
+        limit: Limit,
+        max: Option<usize>,
+        total: usize,
+        added: usize,
+        name: &str,
+    ) {
+        if let Some(max) = max.filter(|max| total > *max && total - added <= *max) {
+            self.push_violation(limit, max, total, Some(name));
+        }
+    }
+
+    fn count_response_key(&mut self, response_key: &'a str) {
+        let Some(max) = self.config.max_field_repetitions else {
+            return;
+        };
+        let response_key_count = self
+            .response_key_counts
+            .last_mut()
+            .expect("expected a response key scope")
+            .entry(response_key)
+            .or_default();
+        response_key_count.count += 1;
+
+        let count = response_key_count.count;
+        if count <= max {
+            return;
+        }
+
+        match response_key_count.violation {
+            Some(index) => self.violations[index].actual = count,
+            None => {
+                response_key_count.violation = Some(self.violations.len());
+                self.push_violation(Limit::FieldRepetitions, max, count, Some(response_key));
+            }
+        }
+    }
+
+    fn push_violation(&mut self, limit: Limit, max: usize, actual: usize, name: Option<&str>) {
+        self.violations.push(LimitViolation {
+            limit,
+            max,
+            actual,
+            path: self
+                .path
+                .iter()
+                .copied()
+                .chain(name)
+                .map(ToOwned::to_owned)
+                .collect(),
+        });
+    }
+
+    fn string_bytes<const CONST: bool, V: Value<CONST>>(&self, value: &V) -> usize {
+        match value.as_ref() {
+            ValueReference::String(s) => s.len(),
+            ValueReference::List(list) => list.iter().map(|item| self.string_bytes(item)).sum(),
+            ValueReference::Object(object) => object
+                .iter()
+                .map(|(_, value)| self.string_bytes(value))
+                .sum(),
+            ValueReference::Variable(variable) => self
+                .variable_values
+                .get(variable.name())
+                .map_or(0, |value| self.string_bytes(value)),
+            _ => 0,
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for Limits<'a, E, VV>
+{
+    type Output = Vec<LimitViolation>;
+
+    fn into_output(mut self) -> Self::Output {
+        // totals can keep growing after the violation is reported
+        self.violations
+            .iter_mut()
+            .for_each(|violation| match violation.limit {
+                Limit::Aliases => violation.actual = self.aliases,
+                Limit::Fields => violation.actual = self.fields,
+                Limit::RootFields => violation.actual = self.root_fields,
+                Limit::ArgumentStringBytes => violation.actual = self.argument_string_bytes,
+                Limit::FieldRepetitions
+                | Limit::FragmentDepth
+                | Limit::DirectivesPerLocation(_) => {}
+            });
+        self.violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Limit, LimitViolation, Limits, LimitsConfig};
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_core::definition::DirectiveLocation;
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    type LimitsAnalyzer<'a, E, S> = Orchestrator<
+        'a,
+        E,
+        S,
+        JsonMap<String, JsonValue>,
+        Limits<'a, E, JsonMap<String, JsonValue>>,
+    >;
+
+    const TEST_SCHEMA: &str = r#"
+        directive @tag(name: String!) repeatable on FIELD | FRAGMENT_SPREAD
+        type Query {
+          thing(title: String): Thing!
+          things(titles: [String!]): [Thing!]!
+          ping: String!
+        }
+        type Thing {
+          id: ID!
+          title: String!
+          parent: Thing!
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    fn check_limits(
+        source: &str,
+        variables: JsonValue,
+        config: LimitsConfig,
+        expected_violations: Vec<LimitViolation>,
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let violations = LimitsAnalyzer::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+            config,
+        )
+        .unwrap();
+
+        assert_eq!(violations, expected_violations);
+    }
+
+    fn violation(limit: Limit, max: usize, actual: usize, path: &[&str]) -> LimitViolation {
+        LimitViolation {
+            limit,
+            max,
+            actual,
+            path: path.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn within_limits() {
+        check_limits(
+            r#"{ a: thing { id } ping }"#,
+            serde_json::json!({}),
+            LimitsConfig {
+                max_aliases: Some(1),
+                max_fields: Some(3),
+                max_field_repetitions: Some(1),
+                max_root_fields: Some(2),
+                max_fragment_depth: Some(0),
+                max_directives_per_location: Some(0),
+                max_argument_string_bytes: Some(0),
+            },
+            vec![],
+        );
+    }
+
+    #[test]
+    fn field_counts() {
+        check_limits(
+            r#"{
+                a: thing { id }
+                b: thing { parent { id } }
+                ping
+                skipped: ping @skip(if: true)
+            }"#,
+            serde_json::json!({}),
+            LimitsConfig {
+                max_aliases: Some(1),
+                max_fields: Some(4),
+                max_root_fields: Some(2),
+                ..Default::default()
+            },
+            vec![
+                violation(Limit::Aliases, 1, 2, &["b"]),
+                violation(Limit::Fields, 4, 6, &["b", "parent", "id"]),
+                violation(Limit::RootFields, 2, 3, &["ping"]),
+            ],
+        );
+    }
+
+    #[test]
+    fn field_repetitions() {
+        check_limits(
+            r#"{
+                thing { id id ...ThingId }
+                ping
+                ping
+            }
+            fragment ThingId on Thing { id }"#,
+            serde_json::json!({}),
+            LimitsConfig {
+                max_field_repetitions: Some(2),
+                ..Default::default()
+            },
+            vec![violation(Limit::FieldRepetitions, 2, 3, &["thing", "id"])],
+        );
+    }
+
+    #[test]
+    fn fragment_depth() {
+        check_limits(
+            r#"{
+                thing { ...A }
+                ping
+            }
+            fragment A on Thing { parent { ...B } }
+            fragment B on Thing { parent { ...C } }
+            fragment C on Thing { id }"#,
+            serde_json::json!({}),
+            LimitsConfig {
+                max_fragment_depth: Some(1),
+                ..Default::default()
+            },
+            vec![violation(Limit::FragmentDepth, 1, 3, &["thing", "parent"])],
+        );
+    }
+
+    #[test]
+    fn directives_per_location() {
+        check_limits(
+            r#"{
+                thing @tag(name: "a") @tag(name: "b") {
+                    ...ThingId @tag(name: "a") @tag(name: "b") @tag(name: "c")
+                }
+            }
+            fragment ThingId on Thing { id }"#,
+            serde_json::json!({}),
+            LimitsConfig {
+                max_directives_per_location: Some(2),
+                ..Default::default()
+            },
+            vec![violation(
+                Limit::DirectivesPerLocation(DirectiveLocation::FragmentSpread),
+                2,
+                3,
+                &["thing"],
+            )],
+        );
+    }
+
+    #[test]
+    fn argument_string_bytes_through_variables() {
+        check_limits(
+            r#"query($titles: [String!]) {
+                thing(title: "abcd") { id }
+                things(titles: $titles) { id }
+            }"#,
+            serde_json::json!({ "titles": ["abc", "defgh"] }),
+            LimitsConfig {
+                max_argument_string_bytes: Some(10),
+                ..Default::default()
+            },
+            vec![violation(
+                Limit::ArgumentStringBytes,
+                10,
+                12,
+                &["things", "titles"],
+            )],
+        );
+    }
+
+    #[test]
+    fn violation_messages() {
+        assert_eq!(
+            violation(Limit::FieldRepetitions, 2, 3, &["thing", "id"]).message(),
+            "Field `thing.id` is selected 3 times, exceeding the limit of 2",
+        );
+        assert_eq!(
+            violation(
+                Limit::DirectivesPerLocation(DirectiveLocation::Field),
+                1,
+                2,
+                &[]
+            )
+            .message(),
+            "2 directives are used on FIELD, exceeding the limit of 1",
+        );
+    }
+}
@@ -0,0 +1,667 @@
+use crate::executable::{
+    operation::{
+        orchestrator::{evaluate_selection_inclusion, resolve_operation_definition},
+        OperationResolutionError, VariableValues,
+    },
+    Cache,
+};
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, InterfaceImplementation, InterfaceTypeDefinition,
+    ObjectTypeDefinition, OutputType, SchemaDefinition, TypeDefinitionReference, UnionMemberType,
+    UnionMemberTypes, UnionTypeDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference,
+};
+use bluejay_core::{AsIter, OperationType};
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+
+/// Collects the fields of an operation following the CollectFields algorithm from the spec.
+/// Fragments are expanded for each possible runtime type, `@skip` and `@include` are evaluated
+/// against the variable values, and fields are merged by response name.
+///
+/// Like ExecuteField in the spec, the subfields of a composite field are only collected for a
+/// possible type when they are accessed, so the work done is proportional to the parts of the
+/// result that are visited rather than to every possible type at every depth.
+pub struct FieldCollector<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    schema_definition: &'a S,
+    operation_definition: &'a E::OperationDefinition,
+    variable_values: &'a VV,
+    cache: &'a Cache<'a, E, S>,
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Clone
+    for FieldCollector<'_, E, S, VV>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Copy
+    for FieldCollector<'_, E, S, VV>
+{
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+    FieldCollector<'a, E, S, VV>
+{
+    pub fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Self {
+        Self {
+            schema_definition,
+            operation_definition,
+            variable_values,
+            cache,
+        }
+    }
+
+    /// Creates a collector for the operation with name `operation_name`, or the only operation
+    /// in the document if `operation_name` is `None`
+    pub fn for_operation<'b>(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_name: Option<&'b str>,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Result<Self, OperationResolutionError<'b>> {
+        resolve_operation_definition(executable_document, operation_name).map(
+            |operation_definition| {
+                Self::new(
+                    operation_definition,
+                    schema_definition,
+                    variable_values,
+                    cache,
+                )
+            },
+        )
+    }
+
+    /// Collects the fields of the root selection set of the operation. Returns `None` if the
+    /// schema does not define a root type for the operation type.
+    pub fn collect_operation_fields(&self) -> Option<ObjectSelection<'a, E, S, VV>> {
+        let core_operation_definition = self.operation_definition.as_ref();
+
+        let root_operation_type_definition = match core_operation_definition.operation_type() {
+            OperationType::Query => Some(self.schema_definition.query()),
+            OperationType::Mutation => self.schema_definition.mutation(),
+            OperationType::Subscription => self.schema_definition.subscription(),
+        }?;
+
+        Some(ObjectSelection::new(
+            root_operation_type_definition,
+            vec![core_operation_definition.selection_set()],
+            *self,
+        ))
+    }
+
+    /// Collects the fields of `selection_sets` for an object of type `object_type_definition`.
+    /// The subfields of composite fields are collected lazily, see
+    /// [CollectedField::possible_types].
+    pub fn collect_fields(
+        &self,
+        object_type_definition: &'a S::ObjectTypeDefinition,
+        selection_sets: &[&'a E::SelectionSet],
+    ) -> GroupedFieldSet<'a, E, S, VV> {
+        let mut fields: Vec<CollectedField<'a, E, S, VV>> = Vec::new();
+        let mut index: HashMap<&'a str, usize> = HashMap::new();
+
+        // like CollectSubfields in the spec, each of the merged selection sets is collected
+        // with its own set of visited fragments
+        selection_sets.iter().for_each(|selection_set| {
+            self.collect_fields_into(
+                object_type_definition,
+                selection_set,
+                &mut |field, field_definition| {
+                    let response_name = field.response_name();
+                    match index.get(response_name) {
+                        Some(&idx) => fields[idx].push(field),
+                        None => {
+                            index.insert(response_name, fields.len());
+                            fields.push(CollectedField {
+                                response_name,
+                                fields: Vec::new(),
+                                field_definition,
+                                selection_sets: Vec::new(),
+                                field_collector: *self,
+                                possible_types: OnceCell::new(),
+                            });
+                            fields.last_mut().unwrap().push(field);
+                        }
+                    }
+                },
+                &mut HashSet::new(),
+            )
+        });
+
+        GroupedFieldSet { fields }
+    }
+
+    fn collect_fields_into(
+        &self,
+        object_type_definition: &'a S::ObjectTypeDefinition,
+        selection_set: &'a E::SelectionSet,
+        push_field: &mut impl FnMut(&'a E::Field, &'a S::FieldDefinition),
+        visited_fragments: &mut HashSet<&'a str>,
+    ) {
+        selection_set.iter().for_each(|selection| {
+            let selection = selection.as_ref();
+            if !selection.directives().is_none_or(|directives| {
+                evaluate_selection_inclusion::<E, VV>(
+                    self.operation_definition,
+                    directives,
+                    self.variable_values,
+                )
+            }) {
+                return;
+            }
+
+            match selection {
+                SelectionReference::Field(field) => {
+                    if let Some(field_definition) =
+                        object_type_definition.fields_definition().get(field.name())
+                    {
+                        push_field(field, field_definition);
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    if !visited_fragments.insert(fragment_spread.name()) {
+                        return;
+                    }
+                    let Some(fragment_definition) =
+                        self.cache.fragment_definition(fragment_spread.name())
+                    else {
+                        return;
+                    };
+                    if self.does_fragment_type_apply(
+                        object_type_definition,
+                        fragment_definition.type_condition(),
+                    ) {
+                        self.collect_fields_into(
+                            object_type_definition,
+                            fragment_definition.selection_set(),
+                            push_field,
+                            visited_fragments,
+                        );
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    if inline_fragment
+                        .type_condition()
+                        .is_none_or(|type_condition| {
+                            self.does_fragment_type_apply(object_type_definition, type_condition)
+                        })
+                    {
+                        self.collect_fields_into(
+                            object_type_definition,
+                            inline_fragment.selection_set(),
+                            push_field,
+                            visited_fragments,
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    fn does_fragment_type_apply(
+        &self,
+        object_type_definition: &'a S::ObjectTypeDefinition,
+        fragment_type_name: &str,
+    ) -> bool {
+        match self
+            .schema_definition
+            .get_type_definition(fragment_type_name)
+        {
+            Some(TypeDefinitionReference::Object(otd)) => {
+                otd.name() == object_type_definition.name()
+            }
+            Some(TypeDefinitionReference::Interface(itd)) => object_type_definition
+                .interface_implementations()
+                .is_some_and(|interface_implementations| {
+                    interface_implementations
+                        .iter()
+                        .any(|interface_implementation| {
+                            interface_implementation.name() == itd.name()
+                        })
+                }),
+            Some(TypeDefinitionReference::Union(utd)) => utd
+                .union_member_types()
+                .contains_type(object_type_definition.name()),
+            _ => false,
+        }
+    }
+
+    fn possible_types(&self, type_name: &str) -> Vec<&'a S::ObjectTypeDefinition> {
+        match self.schema_definition.get_type_definition(type_name) {
+            Some(TypeDefinitionReference::Object(otd)) => vec![otd],
+            Some(TypeDefinitionReference::Interface(itd)) => self
+                .schema_definition
+                .get_interface_implementors(itd)
+                .collect(),
+            Some(TypeDefinitionReference::Union(utd)) => utd
+                .union_member_types()
+                .iter()
+                .map(|union_member_type| union_member_type.member_type(self.schema_definition))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The fields collected for a selection set, keyed by response name in the order in which
+/// they were first selected
+pub struct GroupedFieldSet<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    fields: Vec<CollectedField<'a, E, S, VV>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+    GroupedFieldSet<'a, E, S, VV>
+{
+    pub fn iter(&self) -> std::slice::Iter<'_, CollectedField<'a, E, S, VV>> {
+        self.fields.iter()
+    }
+
+    pub fn get(&self, response_name: &str) -> Option<&CollectedField<'a, E, S, VV>> {
+        self.fields
+            .iter()
+            .find(|collected_field| collected_field.response_name == response_name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl<'a, 'b, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> IntoIterator
+    for &'b GroupedFieldSet<'a, E, S, VV>
+{
+    type Item = &'b CollectedField<'a, E, S, VV>;
+    type IntoIter = std::slice::Iter<'b, CollectedField<'a, E, S, VV>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// All of the fields selected with the same response name on an object type
+pub struct CollectedField<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    response_name: &'a str,
+    fields: Vec<&'a E::Field>,
+    field_definition: &'a S::FieldDefinition,
+    selection_sets: Vec<&'a E::SelectionSet>,
+    field_collector: FieldCollector<'a, E, S, VV>,
+    possible_types: OnceCell<Vec<ObjectSelection<'a, E, S, VV>>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+    CollectedField<'a, E, S, VV>
+{
+    fn push(&mut self, field: &'a E::Field) {
+        self.fields.push(field);
+        self.selection_sets.extend(field.selection_set());
+    }
+
+    pub fn response_name(&self) -> &'a str {
+        self.response_name
+    }
+
+    pub fn fields(&self) -> &[&'a E::Field] {
+        &self.fields
+    }
+
+    pub fn field_definition(&self) -> &'a S::FieldDefinition {
+        self.field_definition
+    }
+
+    /// The merged selection sets of the fields
+    pub fn selection_sets(&self) -> &[&'a E::SelectionSet] {
+        &self.selection_sets
+    }
+
+    /// The merged selections of the fields for each possible runtime type of the field's type.
+    /// Empty if the field is of a scalar or enum type. The fields of each possible type are
+    /// only collected when its [ObjectSelection::grouped_field_set] is accessed.
+    pub fn possible_types(&self) -> &[ObjectSelection<'a, E, S, VV>] {
+        self.possible_types.get_or_init(|| {
+            if self.selection_sets.is_empty() {
+                return Vec::new();
+            }
+            self.field_collector
+                .possible_types(self.field_definition.r#type().base_name())
+                .into_iter()
+                .map(|object_type_definition| {
+                    ObjectSelection::new(
+                        object_type_definition,
+                        self.selection_sets.clone(),
+                        self.field_collector,
+                    )
+                })
+                .collect()
+        })
+    }
+
+    pub fn possible_type(&self, name: &str) -> Option<&ObjectSelection<'a, E, S, VV>> {
+        self.possible_types()
+            .iter()
+            .find(|object_selection| object_selection.object_type_definition.name() == name)
+    }
+}
+
+/// The fields collected for an object type
+pub struct ObjectSelection<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    object_type_definition: &'a S::ObjectTypeDefinition,
+    selection_sets: Vec<&'a E::SelectionSet>,
+    field_collector: FieldCollector<'a, E, S, VV>,
+    grouped_field_set: OnceCell<GroupedFieldSet<'a, E, S, VV>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+    ObjectSelection<'a, E, S, VV>
+{
+    fn new(
+        object_type_definition: &'a S::ObjectTypeDefinition,
+        selection_sets: Vec<&'a E::SelectionSet>,
+        field_collector: FieldCollector<'a, E, S, VV>,
+    ) -> Self {
+        Self {
+            object_type_definition,
+            selection_sets,
+            field_collector,
+            grouped_field_set: OnceCell::new(),
+        }
+    }
+
+    pub fn object_type_definition(&self) -> &'a S::ObjectTypeDefinition {
+        self.object_type_definition
+    }
+
+    /// The fields collected for the object type, computed on first access
+    pub fn grouped_field_set(&self) -> &GroupedFieldSet<'a, E, S, VV> {
+        self.grouped_field_set.get_or_init(|| {
+            self.field_collector
+                .collect_fields(self.object_type_definition, &self.selection_sets)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldCollector, GroupedFieldSet, ObjectSelection};
+    use crate::executable::{operation::VariableValues, Cache};
+    use bluejay_core::definition::{FieldDefinition, ObjectTypeDefinition};
+    use bluejay_core::executable::ExecutableDocument as CoreExecutableDocument;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        interface Node {
+            id: ID!
+        }
+
+        type Cat implements Node {
+            id: ID!
+            name: String!
+            lives: Int!
+        }
+
+        type Dog implements Node {
+            id: ID!
+            name: String!
+            owner: Person
+        }
+
+        type Person {
+            name: String!
+        }
+
+        union Pet = Cat | Dog
+
+        type Query {
+            pets: [Pet!]!
+            node(id: ID!): Node
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn render<E: CoreExecutableDocument, VV: VariableValues>(
+        grouped_field_set: &GroupedFieldSet<'_, E, SchemaDefinition<'static>, VV>,
+        depth: usize,
+        lines: &mut Vec<String>,
+    ) {
+        grouped_field_set.iter().for_each(|collected_field| {
+            lines.push(format!(
+                "{}{}: {} ({} fields)",
+                "  ".repeat(depth),
+                collected_field.response_name(),
+                collected_field.field_definition().name(),
+                collected_field.fields().len(),
+            ));
+            collected_field
+                .possible_types()
+                .iter()
+                .for_each(|object_selection| {
+                    lines.push(format!(
+                        "{}... on {}",
+                        "  ".repeat(depth + 1),
+                        object_selection.object_type_definition().name(),
+                    ));
+                    render(object_selection.grouped_field_set(), depth + 2, lines);
+                });
+        });
+    }
+
+    fn collect_fields(source: &str, variable_values: &serde_json::Value) -> Vec<String> {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variable_values = variable_values
+            .as_object()
+            .expect("Variables must be an object");
+        let root_selection = FieldCollector::for_operation(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values,
+            &cache,
+        )
+        .unwrap()
+        .collect_operation_fields()
+        .unwrap();
+        let mut lines = Vec::new();
+        render(root_selection.grouped_field_set(), 0, &mut lines);
+        lines
+    }
+
+    #[test]
+    fn test_fragments_expanded_per_possible_type() {
+        assert_eq!(
+            collect_fields(
+                r#"
+                    query {
+                        pets {
+                            ... on Node { id }
+                            ... on Cat { name lives }
+                            ...DogFields
+                        }
+                    }
+
+                    fragment DogFields on Dog {
+                        petName: name
+                        owner { name }
+                    }
+                "#,
+                &json!({}),
+            ),
+            vec![
+                "pets: pets (1 fields)",
+                "  ... on Cat",
+                "    id: id (1 fields)",
+                "    name: name (1 fields)",
+                "    lives: lives (1 fields)",
+                "  ... on Dog",
+                "    id: id (1 fields)",
+                "    petName: name (1 fields)",
+                "    owner: owner (1 fields)",
+                "      ... on Person",
+                "        name: name (1 fields)",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_fields_merged_by_response_name() {
+        assert_eq!(
+            collect_fields(
+                r#"
+                    query {
+                        node(id: "1") { id }
+                        node(id: "1") { ... on Dog { owner { name } } }
+                        ... on Query { node(id: "1") { ... on Dog { owner { n: name } } } }
+                    }
+                "#,
+                &json!({}),
+            ),
+            vec![
+                "node: node (3 fields)",
+                "  ... on Cat",
+                "    id: id (1 fields)",
+                "  ... on Dog",
+                "    id: id (1 fields)",
+                "    owner: owner (2 fields)",
+                "      ... on Person",
+                "        name: name (1 fields)",
+                "        n: name (1 fields)",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_fragment_spread_under_each_merged_field() {
+        assert_eq!(
+            collect_fields(
+                r#"
+                    query {
+                        pet: node(id: "1") { ...DogOwner }
+                        pet: node(id: "1") { ...DogOwner }
+                    }
+
+                    fragment DogOwner on Dog {
+                        owner { name }
+                    }
+                "#,
+                &json!({}),
+            ),
+            vec![
+                "pet: node (2 fields)",
+                "  ... on Cat",
+                "  ... on Dog",
+                "    owner: owner (2 fields)",
+                "      ... on Person",
+                "        name: name (2 fields)",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_skip_and_include() {
+        let source = r#"
+            query($withId: Boolean!) {
+                node(id: "1") {
+                    id @include(if: $withId)
+                    ... on Cat @skip(if: true) { name }
+                }
+            }
+        "#;
+        assert_eq!(
+            collect_fields(source, &json!({ "withId": false })),
+            vec!["node: node (1 fields)", "  ... on Cat", "  ... on Dog"],
+        );
+        assert_eq!(
+            collect_fields(source, &json!({ "withId": true })),
+            vec![
+                "node: node (1 fields)",
+                "  ... on Cat",
+                "    id: id (1 fields)",
+                "  ... on Dog",
+                "    id: id (1 fields)",
+            ],
+        );
+    }
+
+    /// Counts the grouped field sets that have been collected, including nested ones
+    fn collected_count<E: CoreExecutableDocument, VV: VariableValues>(
+        object_selection: &ObjectSelection<'_, E, SchemaDefinition<'_>, VV>,
+    ) -> usize {
+        object_selection
+            .grouped_field_set
+            .get()
+            .map_or(0, |grouped_field_set| {
+                1 + grouped_field_set
+                    .iter()
+                    .filter_map(|collected_field| collected_field.possible_types.get())
+                    .flatten()
+                    .map(collected_count)
+                    .sum::<usize>()
+            })
+    }
+
+    #[test]
+    fn test_nested_abstract_fields_collected_lazily() {
+        const IMPLEMENTOR_COUNT: usize = 8;
+        const DEPTH: usize = 24;
+
+        let schema_sdl = (0..IMPLEMENTOR_COUNT).fold(
+            "interface Node { id: ID! node: Node }\ntype Query { node: Node }\n".to_string(),
+            |sdl, idx| sdl + &format!("type T{idx} implements Node {{ id: ID! node: Node }}\n"),
+        );
+        let definition_document: DefinitionDocument =
+            DefinitionDocument::parse(&schema_sdl).result.unwrap();
+        let schema_definition = SchemaDefinition::try_from(&definition_document).unwrap();
+
+        let source = format!("{{ {}id{} }}", "node { ".repeat(DEPTH), " }".repeat(DEPTH));
+        let executable_document = ExecutableDocument::parse(&source).result.unwrap();
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variable_values = serde_json::Map::new();
+        let root_selection = FieldCollector::for_operation(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variable_values,
+            &cache,
+        )
+        .unwrap()
+        .collect_operation_fields()
+        .unwrap();
+
+        let mut object_selection = &root_selection;
+        for _ in 0..DEPTH {
+            let node = object_selection.grouped_field_set().get("node").unwrap();
+            assert_eq!(IMPLEMENTOR_COUNT, node.possible_types().len());
+            object_selection = node.possible_type("T0").unwrap();
+        }
+        assert!(object_selection.grouped_field_set().get("id").is_some());
+
+        // only the grouped field sets along the visited path were collected
+        assert_eq!(DEPTH + 1, collected_count(&root_selection));
+    }
+}
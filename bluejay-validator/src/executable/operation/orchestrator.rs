@@ -43,10 +43,6 @@ impl<
         V: Visitor<'a, E, S, VV>,
     > Orchestrator<'a, E, S, VV, V>
 {
-    const SKIP_DIRECTIVE_NAME: &'static str = "skip";
-    const INCLUDE_DIRECTIVE_NAME: &'static str = "include";
-    const SKIP_INCLUDE_CONDITION_ARGUMENT: &'static str = "if";
-
     fn new(
         operation_definition: &'a E::OperationDefinition,
         schema_definition: &'a S,
@@ -79,6 +75,7 @@ impl<
         directives: &'a E::Directives<false>,
         location: DirectiveLocation,
     ) {
+        self.visitor.visit_variable_directives(directives, location);
         directives
             .iter()
             .for_each(|directive| self.visit_variable_directive(directive, location));
@@ -178,9 +175,6 @@ impl<
         owner_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         included: bool,
     ) {
-        if let Some(directives) = field.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::Field);
-        }
         let included = included
             && field
                 .directives()
@@ -189,6 +183,10 @@ impl<
         self.visitor
             .visit_field(field, field_definition, owner_type, included);
 
+        if let Some(directives) = field.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::Field);
+        }
+
         if let Some(arguments) = field.arguments() {
             if let Some(arguments_definition) = field_definition.arguments_definition() {
                 arguments.iter().for_each(|argument| {
@@ -260,14 +258,18 @@ impl<
     }
 
     fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
-        if let Some(directives) = fragment_spread.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread);
-        }
-
         let included = included
             && fragment_spread
                 .directives()
                 .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+
+        self.visitor
+            .visit_fragment_spread(fragment_spread, included);
+
+        if let Some(directives) = fragment_spread.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread);
+        }
+
         if self
             .currently_spread_fragments
             .insert(fragment_spread.name())
@@ -289,50 +291,19 @@ impl<
             self.currently_spread_fragments
                 .remove(fragment_spread.name());
         }
+
+        self.visitor
+            .leave_fragment_spread(fragment_spread, included);
     }
 
     fn evaluate_selection_inclusion(&mut self, directives: &'a E::Directives<false>) -> bool {
-        let skip_directive_value = self.evaluate_boolean_directive_argument_value(
-            directives,
-            Self::SKIP_DIRECTIVE_NAME,
-            Self::SKIP_INCLUDE_CONDITION_ARGUMENT,
-        );
-
-        let include_directive_value = self.evaluate_boolean_directive_argument_value(
+        evaluate_selection_inclusion::<E, VV>(
+            self.operation_definition,
             directives,
-            Self::INCLUDE_DIRECTIVE_NAME,
-            Self::SKIP_INCLUDE_CONDITION_ARGUMENT,
-        );
-
-        !matches!(
-            (skip_directive_value, include_directive_value),
-            (Some(true), _) | (_, Some(false))
+            self.variable_values,
         )
     }
 
-    fn evaluate_boolean_directive_argument_value(
-        &self,
-        directives: &'a E::Directives<false>,
-        directive_name: &str,
-        arg_name: &str,
-    ) -> Option<bool> {
-        directives
-            .iter()
-            .find(|directive| directive.name() == directive_name)
-            .and_then(|directive| {
-                directive
-                    .arguments()
-                    .and_then(|arguments| arguments.iter().find(|arg| arg.name() == arg_name))
-                    .and_then(|argument| match argument.value().as_ref() {
-                        ValueReference::Boolean(val) => Some(val),
-                        ValueReference::Variable(v) => self
-                            .operation_definition
-                            .evaluate_bool(v, self.variable_values),
-                        _ => None,
-                    })
-            })
-    }
-
     pub fn analyze<'b>(
         executable_document: &'a E,
         schema_definition: &'a S,
@@ -344,25 +315,8 @@ impl<
     where
         V: Analyzer<'a, E, S, VV>,
     {
-        let operation_definition = match operation_name {
-            Some(operation_name) => executable_document
-                .operation_definitions()
-                .find(|operation_definition| {
-                    operation_definition.as_ref().name() == Some(operation_name)
-                })
-                .ok_or(OperationResolutionError::NoOperationWithName {
-                    name: operation_name,
-                })?,
-            None => {
-                let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
-                    .operation_definitions()
-                    .collect::<Vec<_>>()
-                    .as_slice()
-                    .try_into()
-                    .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
-                operation_definition
-            }
-        };
+        let operation_definition =
+            resolve_operation_definition(executable_document, operation_name)?;
         let mut instance = Self::new(
             operation_definition,
             schema_definition,
@@ -375,6 +329,88 @@ impl<
     }
 }
 
+const SKIP_DIRECTIVE_NAME: &str = "skip";
+const INCLUDE_DIRECTIVE_NAME: &str = "include";
+const SKIP_INCLUDE_CONDITION_ARGUMENT: &str = "if";
+
+/// Finds the operation definition to execute, following the GetOperation algorithm from the spec
+pub(crate) fn resolve_operation_definition<'a, 'b, E: ExecutableDocument>(
+    executable_document: &'a E,
+    operation_name: Option<&'b str>,
+) -> Result<&'a E::OperationDefinition, OperationResolutionError<'b>> {
+    match operation_name {
+        Some(operation_name) => executable_document
+            .operation_definitions()
+            .find(|operation_definition| {
+                operation_definition.as_ref().name() == Some(operation_name)
+            })
+            .ok_or(OperationResolutionError::NoOperationWithName {
+                name: operation_name,
+            }),
+        None => {
+            let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
+                .operation_definitions()
+                .collect::<Vec<_>>()
+                .as_slice()
+                .try_into()
+                .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
+            Ok(operation_definition)
+        }
+    }
+}
+
+/// Returns false if the `@skip` or `@include` directives in `directives` exclude the selection
+pub(crate) fn evaluate_selection_inclusion<E: ExecutableDocument, VV: VariableValues>(
+    operation_definition: &E::OperationDefinition,
+    directives: &E::Directives<false>,
+    variable_values: &VV,
+) -> bool {
+    let skip_directive_value = evaluate_boolean_directive_argument_value::<E, VV>(
+        operation_definition,
+        directives,
+        SKIP_DIRECTIVE_NAME,
+        SKIP_INCLUDE_CONDITION_ARGUMENT,
+        variable_values,
+    );
+
+    let include_directive_value = evaluate_boolean_directive_argument_value::<E, VV>(
+        operation_definition,
+        directives,
+        INCLUDE_DIRECTIVE_NAME,
+        SKIP_INCLUDE_CONDITION_ARGUMENT,
+        variable_values,
+    );
+
+    !matches!(
+        (skip_directive_value, include_directive_value),
+        (Some(true), _) | (_, Some(false))
+    )
+}
+
+fn evaluate_boolean_directive_argument_value<E: ExecutableDocument, VV: VariableValues>(
+    operation_definition: &E::OperationDefinition,
+    directives: &E::Directives<false>,
+    directive_name: &str,
+    arg_name: &str,
+    variable_values: &VV,
+) -> Option<bool> {
+    directives
+        .iter()
+        .find(|directive| directive.name() == directive_name)
+        .and_then(|directive| {
+            directive
+                .arguments()
+                .and_then(|arguments| arguments.iter().find(|arg| arg.name() == arg_name))
+                .and_then(|argument| match argument.value().as_ref() {
+                    ValueReference::Boolean(val) => Some(val),
+                    ValueReference::Variable(v) => {
+                        operation_definition.evaluate_bool(v, variable_values)
+                    }
+                    _ => None,
+                })
+        })
+}
+
 #[derive(Debug)]
 pub enum OperationResolutionError<'a> {
     NoOperationWithName { name: &'a str },
@@ -0,0 +1,602 @@
+use crate::executable::{
+    operation::{
+        CollectedField, FieldCollector, GroupedFieldSet, ObjectSelection, OperationResolutionError,
+        VariableValues,
+    },
+    Cache,
+};
+use crate::Path;
+use bluejay_core::definition::{
+    BaseOutputTypeReference, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    ObjectTypeDefinition, OutputType, OutputTypeReference, SchemaDefinition,
+};
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::{AsIter, BuiltinScalarDefinition};
+use serde_json::{Map, Value as JsonValue};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+const TYPENAME_FIELD_NAME: &str = "__typename";
+
+/// Checks that the `data` of a response matches the shape requested by an operation.
+/// Fields are collected with [FieldCollector], and the response keys, nullability, list shape,
+/// scalar and enum values, and `__typename` of every object in the response are validated
+/// against the collected fields.
+///
+/// When an object of an abstract type does not include `__typename`, its runtime type is
+/// inferred from its keys. If no possible type has exactly those keys, the object is
+/// validated against all of the possible types: keys selected for any of them are allowed,
+/// and keys selected for all of them are required.
+pub struct ResponseValidator<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    errors: Vec<ResponseError<'a>>,
+}
+
+impl<'a, S: SchemaDefinition> ResponseValidator<'a, S> {
+    pub fn validate<'b, E: ExecutableDocument, VV: VariableValues>(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_name: Option<&'b str>,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+        data: &'a JsonValue,
+    ) -> Result<Vec<ResponseError<'a>>, OperationResolutionError<'b>> {
+        let field_collector = FieldCollector::for_operation(
+            executable_document,
+            schema_definition,
+            operation_name,
+            variable_values,
+            cache,
+        )?;
+        let mut instance = Self {
+            schema_definition,
+            errors: Vec::new(),
+        };
+
+        // `data` is null when an error was raised for a non-null root field
+        if let Some(root_selection) = field_collector.collect_operation_fields() {
+            if !data.is_null() {
+                instance.validate_object(
+                    std::slice::from_ref(&root_selection),
+                    root_selection.object_type_definition().name(),
+                    data,
+                    Path::default(),
+                );
+            }
+        }
+
+        Ok(instance.errors)
+    }
+
+    fn validate_value<E: ExecutableDocument, VV: VariableValues>(
+        &mut self,
+        output_type: &'a S::OutputType,
+        collected_field: &CollectedField<'a, E, S, VV>,
+        value: &'a JsonValue,
+        path: Path<'a>,
+    ) {
+        let core_type = output_type.as_ref(self.schema_definition);
+
+        if value.is_null() {
+            if core_type.is_required() {
+                self.errors.push(ResponseError::NullValueForRequiredType {
+                    type_name: output_type.display_name(),
+                    path,
+                });
+            }
+            return;
+        }
+
+        match core_type {
+            OutputTypeReference::List(inner, _) => {
+                if let JsonValue::Array(items) = value {
+                    items.iter().enumerate().for_each(|(idx, item)| {
+                        self.validate_value(inner, collected_field, item, path.push(idx))
+                    });
+                } else {
+                    self.push_invalid_value(value, output_type.display_name(), path);
+                }
+            }
+            OutputTypeReference::Base(base, _) => match base {
+                BaseOutputTypeReference::BuiltinScalar(bstd) => {
+                    if !Self::is_valid_builtin_scalar_value(bstd, value) {
+                        self.push_invalid_value(value, output_type.display_name(), path);
+                    }
+                }
+                // custom scalars have no serialization rules to validate against
+                BaseOutputTypeReference::CustomScalar(_) => {}
+                BaseOutputTypeReference::Enum(etd) => {
+                    let is_member = value.as_str().is_some_and(|name| {
+                        etd.enum_value_definitions()
+                            .iter()
+                            .any(|evd| evd.name() == name)
+                    });
+                    if !is_member {
+                        self.errors.push(ResponseError::NoEnumMemberWithName {
+                            value,
+                            enum_type_name: etd.name(),
+                            path,
+                        });
+                    }
+                }
+                BaseOutputTypeReference::Object(_)
+                | BaseOutputTypeReference::Interface(_)
+                | BaseOutputTypeReference::Union(_) => {
+                    self.validate_object(collected_field.possible_types(), base.name(), value, path)
+                }
+            },
+        }
+    }
+
+    fn is_valid_builtin_scalar_value(bstd: BuiltinScalarDefinition, value: &JsonValue) -> bool {
+        match bstd {
+            BuiltinScalarDefinition::Boolean => value.is_boolean(),
+            BuiltinScalarDefinition::Float => value.is_number(),
+            BuiltinScalarDefinition::Int => {
+                value.as_i64().is_some_and(|int| i32::try_from(int).is_ok())
+            }
+            BuiltinScalarDefinition::ID | BuiltinScalarDefinition::String => value.is_string(),
+        }
+    }
+
+    fn push_invalid_value(&mut self, value: &'a JsonValue, type_name: String, path: Path<'a>) {
+        self.errors.push(ResponseError::InvalidValue {
+            value,
+            type_name,
+            path,
+        });
+    }
+
+    fn validate_object<E: ExecutableDocument, VV: VariableValues>(
+        &mut self,
+        possible_types: &[ObjectSelection<'a, E, S, VV>],
+        type_name: &'a str,
+        value: &'a JsonValue,
+        path: Path<'a>,
+    ) {
+        let JsonValue::Object(object) = value else {
+            self.push_invalid_value(value, type_name.to_owned(), path);
+            return;
+        };
+
+        match self.runtime_type(possible_types, type_name, object, &path) {
+            Some(object_selection) => {
+                self.validate_grouped_field_sets(
+                    &[object_selection.grouped_field_set()],
+                    object,
+                    path,
+                );
+            }
+            None => {
+                let grouped_field_sets: Vec<_> = possible_types
+                    .iter()
+                    .map(ObjectSelection::grouped_field_set)
+                    .collect();
+                self.validate_grouped_field_sets(&grouped_field_sets, object, path);
+            }
+        }
+    }
+
+    /// Determines the possible type of `object` using the value of `__typename`, if selected,
+    /// or otherwise the keys of the object
+    fn runtime_type<'c, E: ExecutableDocument, VV: VariableValues>(
+        &mut self,
+        possible_types: &'c [ObjectSelection<'a, E, S, VV>],
+        type_name: &'a str,
+        object: &'a Map<String, JsonValue>,
+        path: &Path<'a>,
+    ) -> Option<&'c ObjectSelection<'a, E, S, VV>> {
+        let typename = possible_types
+            .iter()
+            .flat_map(|object_selection| object_selection.grouped_field_set())
+            .filter(|collected_field| {
+                collected_field.field_definition().name() == TYPENAME_FIELD_NAME
+            })
+            .find_map(|collected_field| {
+                object
+                    .get(collected_field.response_name())
+                    .and_then(JsonValue::as_str)
+            });
+
+        match typename {
+            Some(typename) => {
+                let object_selection = possible_types.iter().find(|object_selection| {
+                    object_selection.object_type_definition().name() == typename
+                });
+                if object_selection.is_none() {
+                    self.errors.push(ResponseError::InvalidTypename {
+                        typename,
+                        type_name,
+                        path: path.clone(),
+                    });
+                }
+                object_selection
+            }
+            None => possible_types.iter().find(|object_selection| {
+                let grouped_field_set = object_selection.grouped_field_set();
+                grouped_field_set.len() == object.len()
+                    && object
+                        .keys()
+                        .all(|key| grouped_field_set.get(key).is_some())
+            }),
+        }
+    }
+
+    /// Validates `object` against the union of `grouped_field_sets`. Keys must be selected in
+    /// at least one of the field sets, and are required if they are selected in all of them.
+    /// Errors are reported in the order of the selections rather than of the response keys.
+    fn validate_grouped_field_sets<E: ExecutableDocument, VV: VariableValues>(
+        &mut self,
+        grouped_field_sets: &[&GroupedFieldSet<'a, E, S, VV>],
+        object: &'a Map<String, JsonValue>,
+        path: Path<'a>,
+    ) {
+        object
+            .keys()
+            .filter(|key| {
+                grouped_field_sets
+                    .iter()
+                    .all(|grouped_field_set| grouped_field_set.get(key).is_none())
+            })
+            .for_each(|key| {
+                self.errors.push(ResponseError::UnexpectedField {
+                    response_name: key,
+                    path: path.push(key.as_str()),
+                })
+            });
+
+        let mut visited_response_names = HashSet::new();
+        grouped_field_sets
+            .iter()
+            .flat_map(|grouped_field_set| grouped_field_set.iter())
+            .filter(|collected_field| {
+                visited_response_names.insert(collected_field.response_name())
+            })
+            .for_each(|collected_field| {
+                let response_name = collected_field.response_name();
+                match object.get(response_name) {
+                    Some(value) => self.validate_value(
+                        collected_field.field_definition().r#type(),
+                        collected_field,
+                        value,
+                        path.push(response_name),
+                    ),
+                    None => {
+                        if grouped_field_sets
+                            .iter()
+                            .all(|grouped_field_set| grouped_field_set.get(response_name).is_some())
+                        {
+                            self.errors.push(ResponseError::MissingField {
+                                response_name,
+                                path: path.push(response_name),
+                            })
+                        }
+                    }
+                }
+            });
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ResponseError<'a> {
+    MissingField {
+        response_name: &'a str,
+        path: Path<'a>,
+    },
+    UnexpectedField {
+        response_name: &'a str,
+        path: Path<'a>,
+    },
+    NullValueForRequiredType {
+        type_name: String,
+        path: Path<'a>,
+    },
+    InvalidValue {
+        value: &'a JsonValue,
+        type_name: String,
+        path: Path<'a>,
+    },
+    NoEnumMemberWithName {
+        value: &'a JsonValue,
+        enum_type_name: &'a str,
+        path: Path<'a>,
+    },
+    InvalidTypename {
+        typename: &'a str,
+        type_name: &'a str,
+        path: Path<'a>,
+    },
+}
+
+impl<'a> ResponseError<'a> {
+    pub fn message(&self) -> Cow<'static, str> {
+        match self {
+            Self::MissingField { response_name, .. } => {
+                format!("Missing value for response key `{response_name}`").into()
+            }
+            Self::UnexpectedField { response_name, .. } => {
+                format!("No selection for response key `{response_name}`").into()
+            }
+            Self::NullValueForRequiredType { type_name, .. } => {
+                format!("Got null when non-null value of type {type_name} was expected").into()
+            }
+            Self::InvalidValue {
+                value, type_name, ..
+            } => format!(
+                "Expected value of type {type_name}, got {}",
+                json_value_kind(value)
+            )
+            .into(),
+            Self::NoEnumMemberWithName {
+                value,
+                enum_type_name,
+                ..
+            } => format!("No member {value} on enum {enum_type_name}").into(),
+            Self::InvalidTypename {
+                typename,
+                type_name,
+                ..
+            } => format!("`{typename}` is not a possible type of {type_name}").into(),
+        }
+    }
+
+    pub fn path(&self) -> &Path<'a> {
+        match self {
+            Self::MissingField { path, .. }
+            | Self::UnexpectedField { path, .. }
+            | Self::NullValueForRequiredType { path, .. }
+            | Self::InvalidValue { path, .. }
+            | Self::NoEnumMemberWithName { path, .. }
+            | Self::InvalidTypename { path, .. } => path,
+        }
+    }
+}
+
+fn json_value_kind(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "list",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseValidator;
+    use crate::executable::Cache;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        enum Species {
+            CAT
+            DOG
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type Cat implements Node {
+            id: ID!
+            name: String!
+            lives: Int!
+        }
+
+        type Dog implements Node {
+            id: ID!
+            name: String!
+            goodBoy: Boolean!
+        }
+
+        union Pet = Cat | Dog
+
+        type Query {
+            pets: [Pet!]!
+            node(id: ID!): Node
+            species: Species
+            weights: [Float]
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn validate_response(
+        source: &str,
+        variable_values: &serde_json::Value,
+        data: &serde_json::Value,
+    ) -> Vec<(String, Vec<String>)> {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        ResponseValidator::validate(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values
+                .as_object()
+                .expect("Variables must be an object"),
+            &cache,
+            data,
+        )
+        .unwrap()
+        .into_iter()
+        .map(|error| (error.message().into_owned(), error.path().to_vec()))
+        .collect()
+    }
+
+    const PETS_QUERY: &str = r#"
+        query {
+            pets {
+                kind: __typename
+                ... on Node { id }
+                ... on Cat { name lives }
+                ...DogFields
+            }
+            species
+        }
+
+        fragment DogFields on Dog {
+            name
+            goodBoy
+        }
+    "#;
+
+    #[test]
+    fn test_valid_response() {
+        let errors = validate_response(
+            PETS_QUERY,
+            &json!({}),
+            &json!({
+                "pets": [
+                    { "kind": "Cat", "id": "1", "name": "Tom", "lives": 9 },
+                    { "kind": "Dog", "id": "2", "name": "Rex", "goodBoy": true },
+                ],
+                "species": null,
+            }),
+        );
+        assert!(errors.is_empty(), "Expected errors to be empty: {errors:?}");
+    }
+
+    #[test]
+    fn test_invalid_response() {
+        let errors = validate_response(
+            PETS_QUERY,
+            &json!({}),
+            &json!({
+                "pets": [
+                    { "kind": "Cat", "id": "1", "name": null, "lives": 9.5 },
+                    { "kind": "Dog", "id": "2", "name": "Rex", "lives": 9 },
+                    { "kind": "Query" },
+                ],
+                "species": "BIRD",
+            }),
+        );
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "Got null when non-null value of type String! was expected".to_owned(),
+                    vec!["pets".to_owned(), "0".to_owned(), "name".to_owned()],
+                ),
+                (
+                    "Expected value of type Int!, got number".to_owned(),
+                    vec!["pets".to_owned(), "0".to_owned(), "lives".to_owned()],
+                ),
+                (
+                    "No selection for response key `lives`".to_owned(),
+                    vec!["pets".to_owned(), "1".to_owned(), "lives".to_owned()],
+                ),
+                (
+                    "Missing value for response key `goodBoy`".to_owned(),
+                    vec!["pets".to_owned(), "1".to_owned(), "goodBoy".to_owned()],
+                ),
+                (
+                    "`Query` is not a possible type of Pet".to_owned(),
+                    vec!["pets".to_owned(), "2".to_owned()],
+                ),
+                (
+                    "Missing value for response key `id`".to_owned(),
+                    vec!["pets".to_owned(), "2".to_owned(), "id".to_owned()],
+                ),
+                (
+                    "Missing value for response key `name`".to_owned(),
+                    vec!["pets".to_owned(), "2".to_owned(), "name".to_owned()],
+                ),
+                (
+                    "No member \"BIRD\" on enum Species".to_owned(),
+                    vec!["species".to_owned()],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_list_shape() {
+        let errors = validate_response(
+            "{ pets { __typename } weights }",
+            &json!({}),
+            &json!({ "pets": null, "weights": 1.5 }),
+        );
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "Got null when non-null value of type [Pet!]! was expected".to_owned(),
+                    vec!["pets".to_owned()],
+                ),
+                (
+                    "Expected value of type [Float], got number".to_owned(),
+                    vec!["weights".to_owned()],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_skip_and_include() {
+        let source = r#"
+            query($withSpecies: Boolean!) {
+                species @include(if: $withSpecies)
+                weights @skip(if: true)
+            }
+        "#;
+        assert_eq!(
+            validate_response(
+                source,
+                &json!({ "withSpecies": false }),
+                &json!({ "weights": [] }),
+            ),
+            vec![(
+                "No selection for response key `weights`".to_owned(),
+                vec!["weights".to_owned()],
+            )],
+        );
+        assert_eq!(
+            validate_response(source, &json!({ "withSpecies": true }), &json!({})),
+            vec![(
+                "Missing value for response key `species`".to_owned(),
+                vec!["species".to_owned()],
+            )],
+        );
+    }
+
+    #[test]
+    fn test_abstract_type_without_typename() {
+        let source = r#"
+            {
+                node(id: "1") {
+                    id
+                    ... on Cat { lives }
+                }
+            }
+        "#;
+        assert!(
+            validate_response(source, &json!({}), &json!({ "node": { "id": "1" } })).is_empty()
+        );
+        assert!(validate_response(
+            source,
+            &json!({}),
+            &json!({ "node": { "id": "1", "lives": 9 } })
+        )
+        .is_empty());
+        assert_eq!(
+            validate_response(source, &json!({}), &json!({ "node": { "lives": 9 } })),
+            vec![(
+                "Missing value for response key `id`".to_owned(),
+                vec!["node".to_owned(), "id".to_owned()],
+            )],
+        );
+    }
+}
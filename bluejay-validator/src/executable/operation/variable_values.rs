@@ -1,3 +1,4 @@
+use crate::value::input_coercion::{CoercedValue, CoercedVariableValues};
 use bluejay_core::executable::{
     OperationDefinition, VariableDefinition, VariableType, VariableTypeReference,
 };
@@ -86,6 +87,7 @@ impl<T: OperationDefinition> OperationDefinitionValueEvaluationExt for T {
                 .default_value()
                 .and_then(|value| value.as_ref().as_integer().copied())
         }
+        .and_then(|int| i32::try_from(int).ok())
     }
 }
 
@@ -99,3 +101,17 @@ impl VariableValues for serde_json::Map<String, serde_json::Value> {
         self.iter()
     }
 }
+
+impl VariableValues for CoercedVariableValues {
+    type Key = String;
+    type Value = CoercedValue;
+    type Iterator<'a> = std::collections::btree_map::Iter<'a, String, CoercedValue>;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.iter()
+    }
+
+    fn get(&self, key: &str) -> Option<&Self::Value> {
+        self.get(key)
+    }
+}
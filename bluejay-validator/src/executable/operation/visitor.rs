@@ -1,5 +1,5 @@
 use crate::executable::{operation::VariableValues, Cache};
-use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::ExecutableDocument;
 
 pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> {
@@ -51,6 +51,29 @@ pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableVal
     ) {
     }
 
+    /// Visits the directives used at a location, before their arguments are visited.
+    /// # Variables
+    /// - `directives` are the directives being visited
+    /// - `location` is the location the directives are used in
+    #[allow(unused_variables)]
+    fn visit_variable_directives(
+        &mut self,
+        directives: &'a E::Directives<false>,
+        location: DirectiveLocation,
+    ) {
+    }
+
+    /// Visits the fragment spread, before the selections of the fragment definition are
+    /// visited. Spreads of a fragment within its own definition are visited but not traversed.
+    /// See `visit_field` for more information about `included`.
+    #[allow(unused_variables)]
+    fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {}
+
+    /// Called after the selections of the fragment definition have been visited.
+    /// See `visit_fragment_spread` for more information about the variables.
+    #[allow(unused_variables)]
+    fn leave_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {}
+
     /// Visits the variable definition.
     /// # Variables
     /// - `variable_definition` is the variable definition being visited
@@ -104,6 +127,18 @@ macro_rules! impl_visitor {
                     #(self.N.leave_field(field, field_definition, owner_type, included);)*
                 }
 
+                fn visit_variable_directives(&mut self, directives: &'a E::Directives<false>, location: DirectiveLocation) {
+                    #(self.N.visit_variable_directives(directives, location);)*
+                }
+
+                fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
+                    #(self.N.visit_fragment_spread(fragment_spread, included);)*
+                }
+
+                fn leave_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
+                    #(self.N.leave_fragment_spread(fragment_spread, included);)*
+                }
+
                 fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {
                     #(self.N.visit_variable_definition(variable_definition);)*
                 }
@@ -0,0 +1,237 @@
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use std::sync::Mutex;
+
+/// A thread-safe cache of validation results, for validating the same documents against the
+/// same schema repeatedly.
+///
+/// Unlike [Cache](super::Cache), which borrows from a single document and schema, entries are
+/// owned and looked up by a key of type `K` that must identify both the document and the schema,
+/// e.g. a tuple of the raw text of the document as an `Arc<str>` and an identifier of the schema
+/// version. The value `V` is the owned outcome of validation, such as a list of `GraphQLError`s,
+/// optionally alongside analyzer outputs.
+///
+/// Do not key by a fixed, non-cryptographic hash of the document, such as one from
+/// `DefaultHasher`: collisions can be crafted, and a document colliding with a valid one would be
+/// served its cached result without ever being validated. Use the text itself or a cryptographic
+/// digest of it.
+///
+/// Entries are spread over shards, each behind its own lock, so that concurrent lookups of
+/// different documents rarely contend. When a shard is full, its least recently used entry is
+/// evicted.
+pub struct ValidationCache<K, V> {
+    capacity: usize,
+    shards: Vec<Mutex<Entries<K, V>>>,
+    hash_builder: RandomState,
+}
+
+struct Entries<K, V> {
+    capacity: usize,
+    values: HashMap<K, (V, u64)>,
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> ValidationCache<K, V> {
+    const DEFAULT_SHARDS: usize = 16;
+
+    /// Creates a cache holding at most `capacity` entries. A cache with a capacity of zero never
+    /// stores anything.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_shards(capacity, Self::DEFAULT_SHARDS)
+    }
+
+    /// Creates a cache holding at most `capacity` entries, split evenly over at most `shards`
+    /// shards. A single shard evicts in exact least recently used order.
+    pub fn with_shards(capacity: usize, shards: usize) -> Self {
+        let shards = shards.clamp(1, capacity.max(1));
+        Self {
+            capacity,
+            shards: (0..shards)
+                .map(|index| {
+                    Mutex::new(Entries {
+                        capacity: capacity / shards + usize::from(index < capacity % shards),
+                        values: HashMap::new(),
+                        recency: BTreeMap::new(),
+                        tick: 0,
+                    })
+                })
+                .collect(),
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| lock(shard).values.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the cached value for `key`, marking it as the most recently used entry
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.shard(key);
+        let tick = entries.next_tick();
+        let Entries {
+            values, recency, ..
+        } = &mut *entries;
+        let (value, last_used) = values.get_mut(key)?;
+        let key = recency
+            .remove(last_used)
+            .expect("Recency is out of sync with values");
+        *last_used = tick;
+        recency.insert(tick, key);
+        Some(value.clone())
+    }
+
+    /// Caches `value` for `key`, evicting the least recently used entry of its shard if the shard
+    /// is full
+    pub fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.shard(&key);
+        let tick = entries.next_tick();
+        if let Some((_, last_used)) = entries.values.remove(&key) {
+            entries.recency.remove(&last_used);
+        } else if entries.values.len() >= entries.capacity {
+            if let Some((_, evicted)) = entries.recency.pop_first() {
+                entries.values.remove(&evicted);
+            }
+        }
+        entries.recency.insert(tick, key.clone());
+        entries.values.insert(key, (value, tick));
+    }
+
+    /// Returns the cached value for `key`, or computes it with `f` and caches it.
+    /// The lock is not held while `f` runs, so concurrent misses for the same key may each
+    /// compute the value.
+    pub fn get_or_insert_with(&self, key: K, f: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f();
+        self.insert(key, value.clone());
+        value
+    }
+
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let mut entries = self.shard(key);
+        let (value, last_used) = entries.values.remove(key)?;
+        entries.recency.remove(&last_used);
+        Some(value)
+    }
+
+    pub fn clear(&self) {
+        self.shards.iter().for_each(|shard| {
+            let mut entries = lock(shard);
+            entries.values.clear();
+            entries.recency.clear();
+        });
+    }
+
+    fn shard(&self, key: &K) -> std::sync::MutexGuard<'_, Entries<K, V>> {
+        let index = self.hash_builder.hash_one(key) as usize % self.shards.len();
+        lock(&self.shards[index])
+    }
+}
+
+fn lock<K, V>(shard: &Mutex<Entries<K, V>>) -> std::sync::MutexGuard<'_, Entries<K, V>> {
+    // the entries are always left consistent, so a panic in another thread can be ignored
+    shard
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl<K, V> Entries<K, V> {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidationCache;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = ValidationCache::with_shards(2, 1);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(Some(1), cache.get(&"a"));
+
+        cache.insert("c", 3);
+        assert_eq!(2, cache.len());
+        assert_eq!(None, cache.get(&"b"));
+        assert_eq!(Some(1), cache.get(&"a"));
+        assert_eq!(Some(3), cache.get(&"c"));
+
+        cache.insert("c", 4);
+        assert_eq!(2, cache.len());
+        assert_eq!(Some(4), cache.get(&"c"));
+
+        assert_eq!(Some(1), cache.remove(&"a"));
+        assert_eq!(1, cache.len());
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_sharded_capacity() {
+        let cache = ValidationCache::with_shards(10, 4);
+        (0..100).for_each(|key| cache.insert(key, key));
+        assert_eq!(10, cache.len());
+        assert_eq!(Some(99), cache.get(&99));
+
+        // never more shards than entries
+        let cache = ValidationCache::with_shards(2, 16);
+        (0..100).for_each(|key| cache.insert(key, key));
+        assert_eq!(2, cache.len());
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let cache = ValidationCache::new(0);
+        assert_eq!(1, cache.get_or_insert_with("a", || 1));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_get_or_insert_with_across_threads() {
+        let cache: ValidationCache<(Arc<str>, u64), Arc<Vec<String>>> = ValidationCache::new(16);
+        let document: Arc<str> = Arc::from("query { dog { name } }");
+        let computations = AtomicUsize::new(0);
+
+        cache.get_or_insert_with((document.clone(), 1), || {
+            computations.fetch_add(1, Ordering::SeqCst);
+            Arc::new(Vec::new())
+        });
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let errors = cache.get_or_insert_with((document.clone(), 1), || {
+                        computations.fetch_add(1, Ordering::SeqCst);
+                        Arc::new(vec!["error".to_string()])
+                    });
+                    assert!(errors.is_empty());
+                });
+            }
+        });
+
+        assert_eq!(1, computations.load(Ordering::SeqCst));
+        assert_eq!(1, cache.len());
+    }
+}
@@ -36,3 +36,165 @@ pub fn duplicates<T: Copy, I: Iterator<Item = T>, K: Hash + Ord + Eq + Copy>(
         .collect::<Vec<_>>()
         .into_iter()
 }
+
+/// Returns the options that are close to `input`, ranked by their edit distance to it, to be
+/// used as "did you mean" suggestions. Options that only differ from `input` in casing are
+/// ranked first.
+pub fn suggestion_list<'a>(
+    input: &str,
+    options: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let input_lowercase: Vec<char> = input.to_lowercase().chars().collect();
+    let threshold = input.chars().count() * 2 / 5 + 1;
+
+    let mut suggestions: Vec<(usize, &'a str)> = options
+        .into_iter()
+        .filter(|option| *option != input)
+        .filter_map(|option| {
+            let distance = if option.eq_ignore_ascii_case(input) {
+                1
+            } else {
+                let option_lowercase: Vec<char> = option.to_lowercase().chars().collect();
+                lexical_distance(&input_lowercase, &option_lowercase, threshold)?
+            };
+            Some((distance, option))
+        })
+        .collect();
+
+    suggestions.sort_unstable();
+    suggestions.dedup();
+    suggestions.into_iter().map(|(_, option)| option).collect()
+}
+
+/// Formats a "did you mean" hint for up to the first five suggestions, or `None` if there are no
+/// suggestions
+pub fn did_you_mean(suggestions: &[&str]) -> Option<String> {
+    const MAX_SUGGESTIONS: usize = 5;
+
+    let quoted: Vec<String> = suggestions
+        .iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|suggestion| format!("`{suggestion}`"))
+        .collect();
+
+    match quoted.as_slice() {
+        [] => None,
+        [only] => Some(format!("Did you mean {only}?")),
+        [first, second] => Some(format!("Did you mean {first} or {second}?")),
+        [rest @ .., last] => Some(format!("Did you mean {}, or {last}?", rest.join(", "))),
+    }
+}
+
+/// Appends the [did_you_mean] hint for `suggestions` to `message`, if there are any suggestions
+pub fn with_did_you_mean(message: impl Into<String>, suggestions: &[&str]) -> String {
+    let mut message = message.into();
+    if let Some(hint) = did_you_mean(suggestions) {
+        message.push_str(". ");
+        message.push_str(&hint);
+    }
+    message
+}
+
+/// Optimal string alignment distance, i.e. the Levenshtein distance that also counts the
+/// transposition of two adjacent characters as a single edit. Returns `None` as soon as the
+/// distance is known to exceed `threshold`, so that comparing against a very long input is cheap.
+fn lexical_distance(a: &[char], b: &[char], threshold: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > threshold {
+        return None;
+    }
+
+    // only the current row and the two before it are needed, for substitutions and transpositions
+    let mut rows: [Vec<usize>; 3] = [
+        (0..=b.len()).collect(),
+        vec![0; b.len() + 1],
+        vec![0; b.len() + 1],
+    ];
+
+    for i in 1..=a.len() {
+        let [before_previous, previous, current] = rotate(&mut rows, i);
+        current[0] = i;
+        let mut smallest = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before_previous[j - 2] + 1);
+            }
+            smallest = smallest.min(current[j]);
+        }
+
+        // distances never decrease from one row to the next
+        if smallest > threshold {
+            return None;
+        }
+    }
+
+    let distance = rows[a.len() % 3][b.len()];
+    (distance <= threshold).then_some(distance)
+}
+
+/// Borrows the rows for iteration `i` as the one before the previous, the previous and the
+/// current row, which is stored at `i % 3`
+fn rotate(rows: &mut [Vec<usize>; 3], i: usize) -> [&mut Vec<usize>; 3] {
+    let [r0, r1, r2] = rows;
+    match i % 3 {
+        0 => [r1, r2, r0],
+        1 => [r2, r0, r1],
+        _ => [r0, r1, r2],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{did_you_mean, lexical_distance, suggestion_list};
+
+    #[test]
+    fn test_suggestion_list() {
+        assert_eq!(
+            vec!["name", "names"],
+            suggestion_list("nam", ["names", "name", "nickname", "owner"]),
+        );
+        assert_eq!(vec!["FIRST"], suggestion_list("first", ["FIRST", "SECOND"]));
+        assert_eq!(
+            vec!["isHouseTrained"],
+            suggestion_list("isHouesTrained", ["isHouseTrained"])
+        );
+        assert!(suggestion_list("barkVolume", ["name", "owner"]).is_empty());
+    }
+
+    #[test]
+    fn test_lexical_distance_threshold() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(Some(0), lexical_distance(&chars("name"), &chars("name"), 2));
+        assert_eq!(Some(1), lexical_distance(&chars("ab"), &chars("ba"), 2));
+        assert_eq!(
+            Some(3),
+            lexical_distance(&chars("kitten"), &chars("sitting"), 3)
+        );
+        assert_eq!(
+            None,
+            lexical_distance(&chars("kitten"), &chars("sitting"), 2)
+        );
+        assert_eq!(None, lexical_distance(&chars("name"), &chars("n"), 2));
+
+        // rejected by the length difference without computing any rows
+        let long_input = "a".repeat(1 << 20);
+        assert!(suggestion_list(&long_input, ["name", "owner"]).is_empty());
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(None, did_you_mean(&[]));
+        assert_eq!(Some("Did you mean `a`?".to_string()), did_you_mean(&["a"]));
+        assert_eq!(
+            Some("Did you mean `a` or `b`?".to_string()),
+            did_you_mean(&["a", "b"]),
+        );
+        assert_eq!(
+            Some("Did you mean `a`, `b`, `c`, `d`, or `e`?".to_string()),
+            did_you_mean(&["a", "b", "c", "d", "e", "f"]),
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::suggestion_list;
 use crate::Path;
 use bluejay_core::definition::{
     BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition, InputFieldsDefinition,
@@ -5,14 +6,20 @@ use bluejay_core::definition::{
     ScalarTypeDefinition, SchemaDefinition,
 };
 use bluejay_core::{
-    AsIter, BuiltinScalarDefinition, Directive, ObjectValue, Value, ValueReference,
+    Argument, Arguments, AsIter, BuiltinScalarDefinition, Directive, ObjectValue, Value,
+    ValueReference, Variable,
 };
 use std::collections::BTreeMap;
 
+mod coerced_value;
 mod error;
 
+pub use coerced_value::{CoercedArgumentValues, CoercedValue, CoercedVariableValues};
 pub use error::Error;
 
+type ArgumentError<'a, const CONST: bool, A> =
+    Error<'a, CONST, <<A as Arguments<CONST>>::Argument as Argument<CONST>>::Value>;
+
 pub trait CoerceInput: SchemaDefinition {
     fn coerce_value<
         'a,
@@ -46,6 +53,54 @@ pub trait CoerceInput: SchemaDefinition {
     ) -> Result<(), Vec<Error<'a, true, V>>> {
         self.coerce_value(input_type, value, path)
     }
+
+    /// Coerces `value` to `input_type` and returns the resulting owned value.
+    /// Variables within `value` are replaced by their value in `variable_values`, which are
+    /// expected to have already been coerced.
+    fn coerce_value_to_owned<
+        'a,
+        const CONST: bool,
+        I: InputType<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = Self::InputObjectTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+        >,
+        V: Value<CONST>,
+    >(
+        &'a self,
+        input_type: &'a I,
+        value: &'a V,
+        variable_values: &CoercedVariableValues,
+        path: Path<'a>,
+    ) -> Result<CoercedValue, Vec<Error<'a, CONST, V>>>;
+
+    fn coerce_const_value_to_owned<
+        'a,
+        I: InputType<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = Self::InputObjectTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+        >,
+        V: Value<true>,
+    >(
+        &'a self,
+        input_type: &'a I,
+        value: &'a V,
+        path: Path<'a>,
+    ) -> Result<CoercedValue, Vec<Error<'a, true, V>>> {
+        self.coerce_value_to_owned(input_type, value, &CoercedVariableValues::new(), path)
+    }
+
+    /// Coerces `arguments` against `arguments_definition`, following the CoerceArgumentValues
+    /// algorithm from the spec. Arguments that are neither provided nor have a default value
+    /// are omitted from the result, so the arguments are expected to have already been
+    /// validated to include all required arguments.
+    fn coerce_argument_values<'a, const CONST: bool, A: Arguments<CONST>>(
+        &'a self,
+        arguments_definition: Option<&'a Self::ArgumentsDefinition>,
+        arguments: Option<&'a A>,
+        variable_values: &CoercedVariableValues,
+    ) -> Result<CoercedArgumentValues, Vec<ArgumentError<'a, CONST, A>>>;
 }
 
 impl<S: SchemaDefinition> CoerceInput for S {
@@ -66,6 +121,71 @@ impl<S: SchemaDefinition> CoerceInput for S {
     ) -> Result<(), Vec<Error<'a, CONST, V>>> {
         coerce_value_for_input_type(self, input_type, value, path, true)
     }
+
+    fn coerce_value_to_owned<
+        'a,
+        const CONST: bool,
+        I: InputType<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = Self::InputObjectTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+        >,
+        V: Value<CONST>,
+    >(
+        &'a self,
+        input_type: &'a I,
+        value: &'a V,
+        variable_values: &CoercedVariableValues,
+        path: Path<'a>,
+    ) -> Result<CoercedValue, Vec<Error<'a, CONST, V>>> {
+        self.coerce_value(input_type, value, path.clone())?;
+        coerced_value_for_input_type(self, input_type, value, variable_values, path)
+    }
+
+    fn coerce_argument_values<'a, const CONST: bool, A: Arguments<CONST>>(
+        &'a self,
+        arguments_definition: Option<&'a Self::ArgumentsDefinition>,
+        arguments: Option<&'a A>,
+        variable_values: &CoercedVariableValues,
+    ) -> Result<CoercedArgumentValues, Vec<ArgumentError<'a, CONST, A>>> {
+        let mut errors = Vec::new();
+        let mut argument_values = CoercedArgumentValues::new();
+
+        arguments_definition
+            .into_iter()
+            .flat_map(|arguments_definition| arguments_definition.iter())
+            .for_each(|ivd| {
+                let value = arguments
+                    .and_then(|arguments| {
+                        arguments
+                            .iter()
+                            .find(|argument| argument.name() == ivd.name())
+                    })
+                    .map(Argument::value);
+                let path = Path::new(ivd.name());
+
+                if let Some(value) = value {
+                    if let Err(errs) = self.coerce_value(ivd.r#type(), value, path.clone()) {
+                        errors.extend(errs);
+                        return;
+                    }
+                }
+
+                match coerced_input_value(self, ivd, value, variable_values, path) {
+                    Ok(Some(coerced_value)) => {
+                        argument_values.insert(ivd.name().to_owned(), coerced_value);
+                    }
+                    Ok(None) => {}
+                    Err(errs) => errors.extend(errs),
+                }
+            });
+
+        if errors.is_empty() {
+            Ok(argument_values)
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 fn coerce_value_for_input_type<
@@ -179,7 +299,19 @@ fn coerce_builtin_scalar_value<'a, const CONST: bool, V: Value<CONST>, T: InputT
             BuiltinScalarDefinition::ID | BuiltinScalarDefinition::String,
             ValueReference::String(_),
         ) => Ok(()),
-        (BuiltinScalarDefinition::Int, ValueReference::Integer(_)) => Ok(()),
+        (BuiltinScalarDefinition::Int, ValueReference::Integer(i)) => {
+            // integer literals are 64-bit so that custom scalars can accept larger values,
+            // but the built-in Int is a 32-bit signed integer
+            if i32::try_from(i).is_ok() {
+                Ok(())
+            } else {
+                Err(vec![Error::IntValueOutOfRange {
+                    value,
+                    input_type_name: input_type.display_name(),
+                    path,
+                }])
+            }
+        }
         _ => Err(vec![Error::NoImplicitConversion {
             value,
             input_type_name: input_type.display_name(),
@@ -241,6 +373,13 @@ fn coerce_enum_value_from_name<'a, const CONST: bool, V: Value<CONST>>(
             name,
             value,
             enum_type_name: enum_type_definition.name(),
+            suggestions: suggestion_list(
+                name,
+                enum_type_definition
+                    .enum_value_definitions()
+                    .iter()
+                    .map(EnumValueDefinition::name),
+            ),
             path,
         }])
     }
@@ -369,6 +508,193 @@ fn coerce_input_object_value<
     }
 }
 
+fn coerced_value_for_input_type<
+    'a,
+    const CONST: bool,
+    S: SchemaDefinition,
+    T: InputType<
+        CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+        InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+        EnumTypeDefinition = S::EnumTypeDefinition,
+    >,
+    V: Value<CONST>,
+>(
+    schema_definition: &'a S,
+    input_type: &'a T,
+    value: &'a V,
+    variable_values: &CoercedVariableValues,
+    path: Path<'a>,
+) -> Result<CoercedValue, Vec<Error<'a, CONST, V>>> {
+    let core_type = input_type.as_ref(schema_definition);
+    match value.as_ref() {
+        ValueReference::Variable(variable) => match variable_values.get(variable.name()) {
+            Some(CoercedValue::Null) | None if core_type.is_required() => {
+                Err(vec![Error::NullValueForRequiredType {
+                    value,
+                    input_type_name: input_type.display_name(),
+                    path,
+                }])
+            }
+            Some(coerced_value) => Ok(coerced_value.clone()),
+            None => Ok(CoercedValue::Null),
+        },
+        ValueReference::Null => Ok(CoercedValue::Null),
+        core_value => match core_type {
+            InputTypeReference::Base(_, _) => coerced_value_for_base_input_type(
+                schema_definition,
+                input_type,
+                value,
+                variable_values,
+                path,
+            ),
+            InputTypeReference::List(inner, _) => {
+                if let ValueReference::List(values) = core_value {
+                    let mut errors = Vec::new();
+                    let coerced_values = values
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, value)| {
+                            coerced_value_for_input_type(
+                                schema_definition,
+                                inner,
+                                value,
+                                variable_values,
+                                path.push(idx),
+                            )
+                            .map_err(|errs| errors.extend(errs))
+                            .ok()
+                        })
+                        .collect();
+
+                    if errors.is_empty() {
+                        Ok(CoercedValue::List(coerced_values))
+                    } else {
+                        Err(errors)
+                    }
+                } else {
+                    coerced_value_for_input_type(
+                        schema_definition,
+                        inner,
+                        value,
+                        variable_values,
+                        path,
+                    )
+                    .map(|coerced_value| CoercedValue::List(vec![coerced_value]))
+                }
+            }
+        },
+    }
+}
+
+fn coerced_value_for_base_input_type<
+    'a,
+    const CONST: bool,
+    S: SchemaDefinition,
+    T: InputType<
+        CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+        InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+        EnumTypeDefinition = S::EnumTypeDefinition,
+    >,
+    V: Value<CONST>,
+>(
+    schema_definition: &'a S,
+    input_type: &'a T,
+    value: &'a V,
+    variable_values: &CoercedVariableValues,
+    path: Path<'a>,
+) -> Result<CoercedValue, Vec<Error<'a, CONST, V>>> {
+    match (input_type.base(schema_definition), value.as_ref()) {
+        (
+            BaseInputTypeReference::BuiltinScalar(BuiltinScalarDefinition::Float),
+            ValueReference::Integer(i),
+        ) => Ok(CoercedValue::Float(i as f64)),
+        (
+            BaseInputTypeReference::BuiltinScalar(BuiltinScalarDefinition::ID),
+            ValueReference::Integer(i),
+        ) => Ok(CoercedValue::String(i.to_string())),
+        (
+            BaseInputTypeReference::Enum(_),
+            ValueReference::Enum(name) | ValueReference::String(name),
+        ) => Ok(CoercedValue::Enum(name.to_owned())),
+        (BaseInputTypeReference::InputObject(iotd), ValueReference::Object(object)) => {
+            let mut errors = Vec::new();
+            let fields = iotd
+                .input_field_definitions()
+                .iter()
+                .filter_map(|ivd| {
+                    let value_for_field = object
+                        .iter()
+                        .find(|(key, _)| key.as_ref() == ivd.name())
+                        .map(|(_, value)| value);
+                    coerced_input_value(
+                        schema_definition,
+                        ivd,
+                        value_for_field,
+                        variable_values,
+                        path.push(ivd.name()),
+                    )
+                    .map_err(|errs| errors.extend(errs))
+                    .ok()
+                    .flatten()
+                    .map(|coerced_value| (ivd.name().to_owned(), coerced_value))
+                })
+                .collect();
+
+            if errors.is_empty() {
+                Ok(CoercedValue::Object(fields))
+            } else {
+                Err(errors)
+            }
+        }
+        _ => Ok(CoercedValue::from_value(value, variable_values)),
+    }
+}
+
+/// Coerces the value for an argument or input object field, returning `None` if the value
+/// should be omitted because it was not provided and has no default value
+fn coerced_input_value<'a, const CONST: bool, S: SchemaDefinition, V: Value<CONST>>(
+    schema_definition: &'a S,
+    input_value_definition: &'a S::InputValueDefinition,
+    value: Option<&'a V>,
+    variable_values: &CoercedVariableValues,
+    path: Path<'a>,
+) -> Result<Option<CoercedValue>, Vec<Error<'a, CONST, V>>> {
+    let provided_value = value.filter(|value| match value.as_ref() {
+        ValueReference::Variable(variable) => variable_values.contains_key(variable.name()),
+        _ => true,
+    });
+    let input_type = input_value_definition.r#type();
+
+    match (provided_value, input_value_definition.default_value()) {
+        (Some(value), _) => coerced_value_for_input_type(
+            schema_definition,
+            input_type,
+            value,
+            variable_values,
+            path,
+        )
+        .map(Some),
+        (None, Some(default_value)) => schema_definition
+            .coerce_const_value_to_owned(input_type, default_value, path.clone())
+            .map(Some)
+            .map_err(|errors| {
+                vec![Error::InvalidDefaultValue {
+                    input_value_name: input_value_definition.name(),
+                    messages: errors.iter().map(Error::message).collect(),
+                    path,
+                }]
+            }),
+        (None, None) => match value {
+            Some(value) if input_type.is_required() => Err(vec![Error::NullValueForRequiredType {
+                value,
+                input_type_name: input_type.display_name(),
+                path,
+            }]),
+            _ => Ok(None),
+        },
+    }
+}
+
 #[cfg(feature = "one-of-input-objects")]
 fn validate_one_of_input_object_value<'a, const CONST: bool, V: Value<CONST>>(
     input_object_type_definition: &'a impl InputObjectTypeDefinition,
@@ -421,7 +747,7 @@ fn validate_one_of_input_object_value<'a, const CONST: bool, V: Value<CONST>>(
 
 #[cfg(test)]
 mod tests {
-    use super::{CoerceInput, Error};
+    use super::{CoerceInput, CoercedValue, Error};
     use crate::Path;
     use bluejay_core::definition::{
         ArgumentsDefinition, FieldDefinition, FieldsDefinition, InputType, InputValueDefinition,
@@ -467,6 +793,13 @@ mod tests {
                         )))
                     }
                 }
+                "BigInt" => match value {
+                    ValueReference::Integer(_) => Ok(()),
+                    _ => Err(Cow::Owned(format!(
+                        "Cannot coerce {} to BigInt",
+                        value.variant()
+                    ))),
+                },
                 _ => Ok(()),
             }
         }
@@ -486,6 +819,7 @@ mod tests {
         enumArg: Choices!
         inputObjectArg: CustomInput!
         decimalArg: Decimal!
+        bigIntArg: BigInt!
         oneOfInputObjectArg: InputUnion!
       ): Boolean!
     }
@@ -503,6 +837,8 @@ mod tests {
 
     scalar Decimal
 
+    scalar BigInt
+
     input InputUnion @oneOf {
         first: String
         second: Int
@@ -578,6 +914,18 @@ mod tests {
             }]),
             SCHEMA_DEFINITION.coerce_const_value(it, &json!(123.4), Default::default()),
         );
+        assert_eq!(
+            Err(vec![Error::IntValueOutOfRange {
+                value: &json!(2147483648i64),
+                input_type_name: it.display_name(),
+                path: Default::default(),
+            }]),
+            SCHEMA_DEFINITION.coerce_const_value(it, &json!(2147483648i64), Default::default()),
+        );
+        assert_eq!(
+            Ok(()),
+            SCHEMA_DEFINITION.coerce_const_value(it, &json!(i32::MIN), Default::default())
+        );
     }
 
     #[test]
@@ -768,6 +1116,7 @@ mod tests {
                 name: "first",
                 value: &json!("first"),
                 enum_type_name: "Choices",
+                suggestions: vec!["FIRST"],
                 path: Default::default(),
             }]),
             SCHEMA_DEFINITION.coerce_const_value(it, &json!("first"), Default::default()),
@@ -853,6 +1202,16 @@ mod tests {
             }]),
             SCHEMA_DEFINITION.coerce_const_value(it, &json!(123.456), Default::default()),
         );
+
+        let it = input_type("Query", "field", "bigIntArg");
+        assert_eq!(
+            Ok(CoercedValue::Integer(9007199254740993)),
+            SCHEMA_DEFINITION.coerce_const_value_to_owned(
+                it,
+                &json!(9007199254740993i64),
+                Default::default()
+            )
+        );
     }
 
     #[test]
@@ -897,4 +1256,60 @@ mod tests {
             ),
         )
     }
+
+    #[test]
+    fn test_coerce_to_owned() {
+        assert_eq!(
+            Ok(CoercedValue::List(vec![CoercedValue::List(vec![
+                CoercedValue::Integer(1)
+            ])])),
+            SCHEMA_DEFINITION.coerce_const_value_to_owned(
+                input_type("Query", "field", "optionalListOfListArg"),
+                &json!(1),
+                Default::default()
+            ),
+        );
+        assert_eq!(
+            Ok(CoercedValue::Float(1.0)),
+            SCHEMA_DEFINITION.coerce_const_value_to_owned(
+                input_type("Query", "field", "floatArg"),
+                &json!(1),
+                Default::default()
+            ),
+        );
+        assert_eq!(
+            Ok(CoercedValue::Enum("FIRST".to_owned())),
+            SCHEMA_DEFINITION.coerce_const_value_to_owned(
+                input_type("Query", "field", "enumArg"),
+                &json!("FIRST"),
+                Default::default()
+            ),
+        );
+        assert_eq!(
+            Ok(CoercedValue::Object(vec![
+                ("stringArg".to_owned(), CoercedValue::String("s".to_owned())),
+                (
+                    "stringArgWithDefault".to_owned(),
+                    CoercedValue::String("".to_owned())
+                ),
+            ])),
+            SCHEMA_DEFINITION.coerce_const_value_to_owned(
+                input_type("Query", "field", "inputObjectArg"),
+                &json!({ "stringArg": "s" }),
+                Default::default()
+            ),
+        );
+        assert_eq!(
+            Err(vec![Error::NoImplicitConversion {
+                value: &json!("1"),
+                input_type_name: "Int".to_owned(),
+                path: Path::new(0),
+            }]),
+            SCHEMA_DEFINITION.coerce_const_value_to_owned(
+                input_type("Query", "field", "optionalListArg"),
+                &json!(["1"]),
+                Default::default()
+            ),
+        );
+    }
 }
@@ -0,0 +1,79 @@
+use bluejay_core::{AsIter, ObjectValue, Value, ValueReference, Variable};
+use std::collections::BTreeMap;
+
+/// An owned input value that has been through input coercion, so it is of the shape
+/// described by its input type: defaults have been applied, single values have been
+/// wrapped in lists, and enum values are always [`CoercedValue::Enum`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoercedValue {
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Enum(String),
+    List(Vec<CoercedValue>),
+    Object(Vec<(String, CoercedValue)>),
+}
+
+/// The coerced values of the variables of an operation, keyed by variable name
+pub type CoercedVariableValues = BTreeMap<String, CoercedValue>;
+
+/// The coerced values of the arguments of a field, keyed by argument name
+pub type CoercedArgumentValues = BTreeMap<String, CoercedValue>;
+
+impl Value<true> for CoercedValue {
+    type List = Vec<CoercedValue>;
+    type Object = Vec<(String, CoercedValue)>;
+    type Variable = String;
+
+    fn as_ref(&self) -> ValueReference<'_, true, Self> {
+        match self {
+            Self::Integer(i) => ValueReference::Integer(*i),
+            Self::Float(f) => ValueReference::Float(*f),
+            Self::String(s) => ValueReference::String(s),
+            Self::Boolean(b) => ValueReference::Boolean(*b),
+            Self::Null => ValueReference::Null,
+            Self::Enum(e) => ValueReference::Enum(e),
+            Self::List(l) => ValueReference::List(l),
+            Self::Object(o) => ValueReference::Object(o),
+        }
+    }
+}
+
+impl CoercedValue {
+    /// Copies `value` without coercing it, replacing variables with their value in
+    /// `variable_values` or null if they were not provided
+    pub(super) fn from_value<const CONST: bool, V: Value<CONST>>(
+        value: &V,
+        variable_values: &CoercedVariableValues,
+    ) -> Self {
+        match value.as_ref() {
+            ValueReference::Variable(variable) => variable_values
+                .get(variable.name())
+                .cloned()
+                .unwrap_or(Self::Null),
+            ValueReference::Integer(i) => Self::Integer(i),
+            ValueReference::Float(f) => Self::Float(f),
+            ValueReference::String(s) => Self::String(s.to_owned()),
+            ValueReference::Boolean(b) => Self::Boolean(b),
+            ValueReference::Null => Self::Null,
+            ValueReference::Enum(e) => Self::Enum(e.to_owned()),
+            ValueReference::List(l) => Self::List(
+                l.iter()
+                    .map(|value| Self::from_value(value, variable_values))
+                    .collect(),
+            ),
+            ValueReference::Object(o) => Self::Object(
+                o.iter()
+                    .map(|(key, value)| {
+                        (
+                            key.as_ref().to_owned(),
+                            Self::from_value(value, variable_values),
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use crate::Path;
 use bluejay_core::{ObjectValue, Value};
 #[cfg(feature = "parser-integration")]
@@ -22,10 +24,16 @@ pub enum Error<'a, const CONST: bool, V: Value<CONST>> {
         input_type_name: String,
         path: Path<'a>,
     },
+    IntValueOutOfRange {
+        value: &'a V,
+        input_type_name: String,
+        path: Path<'a>,
+    },
     NoEnumMemberWithName {
         name: &'a str,
         value: &'a V,
         enum_type_name: &'a str,
+        suggestions: Vec<&'a str>,
         path: Path<'a>,
     },
     NoValueForRequiredFields {
@@ -51,6 +59,12 @@ pub enum Error<'a, const CONST: bool, V: Value<CONST>> {
         message: Cow<'static, str>,
         path: Path<'a>,
     },
+    /// The schema default value of an omitted argument or input field does not coerce to its type
+    InvalidDefaultValue {
+        input_value_name: &'a str,
+        messages: Vec<Cow<'static, str>>,
+        path: Path<'a>,
+    },
     #[cfg(feature = "one-of-input-objects")]
     OneOfInputNullValues {
         value: &'a V,
@@ -68,6 +82,27 @@ pub enum Error<'a, const CONST: bool, V: Value<CONST>> {
 }
 
 impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
+    /// A stable, machine-readable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NullValueForRequiredType { .. } => "NULL_VALUE_FOR_REQUIRED_TYPE",
+            Self::NoImplicitConversion { .. } => "NO_IMPLICIT_CONVERSION",
+            Self::IntValueOutOfRange { .. } => "INT_VALUE_OUT_OF_RANGE",
+            Self::NoEnumMemberWithName { .. } => "NO_ENUM_MEMBER_WITH_NAME",
+            Self::NoValueForRequiredFields { .. } => "NO_VALUE_FOR_REQUIRED_FIELDS",
+            Self::NonUniqueFieldNames { .. } => "NON_UNIQUE_FIELD_NAMES",
+            Self::NoInputFieldWithName { .. } => "NO_INPUT_FIELD_WITH_NAME",
+            Self::CustomScalarInvalidValue { .. } => "CUSTOM_SCALAR_INVALID_VALUE",
+            Self::InvalidDefaultValue { .. } => "INVALID_DEFAULT_VALUE",
+            #[cfg(feature = "one-of-input-objects")]
+            Self::OneOfInputNullValues { .. } => "ONE_OF_INPUT_NULL_VALUES",
+            #[cfg(feature = "one-of-input-objects")]
+            Self::OneOfInputNotSingleNonNullValue { .. } => {
+                "ONE_OF_INPUT_NOT_SINGLE_NON_NULL_VALUE"
+            }
+        }
+    }
+
     pub fn message(&self) -> Cow<'static, str> {
         match self {
             Self::NullValueForRequiredType { input_type_name, .. } => {
@@ -77,6 +112,10 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
             Self::NoImplicitConversion { input_type_name, value, .. } => {
                 format!("No implicit conversion of {} to {input_type_name}", value.as_ref().variant()).into()
             }
+            Self::IntValueOutOfRange { input_type_name, value, .. } => {
+                let integer = value.as_ref().as_integer().copied().unwrap_or_default();
+                format!("{input_type_name} cannot represent non 32-bit signed integer value {integer}").into()
+            }
             Self::NoEnumMemberWithName { name, enum_type_name, .. } => {
                 format!("No member `{name}` on enum {enum_type_name}").into()
             }
@@ -100,6 +139,13 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
                 .into()
             }
             Self::CustomScalarInvalidValue { message, .. } => message.clone(),
+            Self::InvalidDefaultValue { input_value_name, messages, .. } => {
+                format!(
+                    "Invalid default value for {input_value_name}: {}",
+                    messages.join("; ")
+                )
+                .into()
+            }
             #[cfg(feature = "one-of-input-objects")]
             Self::OneOfInputNullValues { input_object_type_name, .. } => {
                 format!("Multiple entries with null values for oneOf input object {input_object_type_name}")
@@ -120,7 +166,8 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
 #[cfg(feature = "parser-integration")]
 impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for ParserError {
     fn from(error: Error<'a, CONST, ParserValue<'a, CONST>>) -> Self {
-        match &error {
+        let code = error.code();
+        let parser_error = match &error {
             Error::NullValueForRequiredType { value, .. } => Self::new(
                 error.message(),
                 Some(Annotation::new("Expected non-null value", *value.span())),
@@ -138,14 +185,26 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
                 )),
                 Vec::new(),
             ),
+            Error::IntValueOutOfRange { value, .. } => Self::new(
+                error.message(),
+                Some(Annotation::new(
+                    "Outside of the 32-bit signed integer range",
+                    *value.span(),
+                )),
+                Vec::new(),
+            ),
             Error::NoEnumMemberWithName {
                 value,
                 enum_type_name,
+                suggestions,
                 ..
             } => Self::new(
                 error.message(),
                 Some(Annotation::new(
-                    format!("No such member on enum {enum_type_name}"),
+                    with_did_you_mean(
+                        format!("No such member on enum {enum_type_name}"),
+                        suggestions,
+                    ),
                     *value.span(),
                 )),
                 Vec::new(),
@@ -188,6 +247,7 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
                 Some(Annotation::new(message.clone(), *value.span())),
                 Vec::new(),
             ),
+            Error::InvalidDefaultValue { .. } => Self::new(error.message(), None, Vec::new()),
             #[cfg(feature = "one-of-input-objects")]
             Error::OneOfInputNullValues {
                 value,
@@ -224,6 +284,7 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
                     })
                     .collect(),
             ),
-        }
+        };
+        parser_error.with_code(code)
     }
 }
@@ -6,7 +6,8 @@ use bluejay_parser::{
     },
     Error,
 };
-use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache};
+use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache, ValidationCache};
+use std::sync::Arc;
 
 #[test]
 fn test_error() {
@@ -55,6 +56,63 @@ fn test_valid() {
     });
 }
 
+#[test]
+fn test_graphql_error_codes() {
+    with_schema(|schema_definition| {
+        let input = "{ dog { doesNotExist doesKnowCommand(dogCommand: SIT, dogCommand: DOWN) } }";
+        let executable_document = ExecutableDocument::parse(input)
+            .result
+            .expect("Document had parse errors");
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let errors =
+            BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache);
+        let codes: Vec<_> = Error::into_graphql_errors(input, errors)
+            .into_iter()
+            .map(|error| {
+                assert!(error.path.is_none());
+                error.extensions.expect("Error had no extensions").code
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                "FIELD_DOES_NOT_EXIST_ON_TYPE",
+                "NON_UNIQUE_ARGUMENT_NAMES",
+                "NON_UNIQUE_ARGUMENT_NAMES",
+            ],
+            codes,
+        );
+    });
+}
+
+#[test]
+fn test_validation_cache() {
+    const SCHEMA_VERSION: u64 = 1;
+
+    with_schema(|schema_definition| {
+        let validation_cache = ValidationCache::new(8);
+        let validate = |input: &str| {
+            validation_cache.get_or_insert_with((Arc::<str>::from(input), SCHEMA_VERSION), || {
+                let executable_document = ExecutableDocument::parse(input)
+                    .result
+                    .expect("Document had parse errors");
+                let cache = Cache::new(&executable_document, &schema_definition);
+                let errors = BuiltinRulesValidator::validate(
+                    &executable_document,
+                    &schema_definition,
+                    &cache,
+                );
+                Arc::new(Error::into_graphql_errors(input, errors))
+            })
+        };
+
+        let errors = validate("{ dog { doesNotExist } }");
+        assert_eq!(1, errors.len());
+        assert!(Arc::ptr_eq(&errors, &validate("{ dog { doesNotExist } }")));
+        assert!(validate("{ dog { name } }").is_empty());
+        assert_eq!(2, validation_cache.len());
+    });
+}
+
 fn with_schema(f: fn(SchemaDefinition) -> ()) {
     let s = std::fs::read_to_string("tests/test_data/executable/schema.graphql").unwrap();
     let definition_document = DefinitionDocument::parse(s.as_str())
@@ -1,6 +1,7 @@
 use crate::{DirectiveDefinition, Warden};
 use bluejay_core::definition::{Directive as CoreDefinitionDirective, SchemaDefinition};
 use bluejay_core::Directive as CoreDirective;
+use bluejay_core::Location;
 
 pub struct Directive<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
     inner: &'a S::Directive,
@@ -36,6 +37,10 @@ impl<S: SchemaDefinition, W: Warden<SchemaDefinition = S>> CoreDirective<true>
     fn arguments(&self) -> Option<&Self::Arguments> {
         self.definition.cache().warden().directive_arguments(self)
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> CoreDefinitionDirective
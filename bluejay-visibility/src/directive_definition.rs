@@ -1,5 +1,6 @@
 use crate::{ArgumentsDefinition, Cache, Warden};
 use bluejay_core::definition::{self, SchemaDefinition};
+use bluejay_core::Location;
 use once_cell::unsync::OnceCell;
 
 pub struct DirectiveDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
@@ -65,4 +66,8 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> definition::
     fn locations(&self) -> &Self::DirectiveLocations {
         self.inner.locations()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{Cache, Directives, EnumValueDefinitions, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
+use bluejay_core::Location;
 use once_cell::unsync::OnceCell;
 
 pub struct EnumTypeDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
@@ -46,6 +47,10 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> definition::
     fn is_builtin(&self) -> bool {
         self.inner.is_builtin()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> HasDirectives
@@ -1,5 +1,6 @@
 use crate::{Cache, Directives, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
+use bluejay_core::Location;
 use std::marker::PhantomData;
 
 pub struct EnumValueDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
@@ -38,6 +39,10 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> definition::
     fn name(&self) -> &str {
         self.inner.name()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> HasDirectives
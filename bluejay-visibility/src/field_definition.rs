@@ -1,6 +1,7 @@
 use crate::{ArgumentsDefinition, Cache, Directives, OutputType, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
 
+use bluejay_core::Location;
 use once_cell::unsync::OnceCell;
 
 pub struct FieldDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
@@ -66,6 +67,10 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> definition::
             })
             .as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> HasDirectives
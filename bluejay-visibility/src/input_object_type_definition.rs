@@ -1,5 +1,6 @@
 use crate::{Cache, Directives, InputFieldsDefinition, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
+use bluejay_core::Location;
 use once_cell::unsync::OnceCell;
 
 pub struct InputObjectTypeDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
@@ -44,6 +45,10 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>>
             InputFieldsDefinition::new(self.inner.input_field_definitions(), self.cache)
         })
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> HasDirectives
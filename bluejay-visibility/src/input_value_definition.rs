@@ -1,5 +1,6 @@
 use crate::{Cache, Directives, InputType, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
+use bluejay_core::Location;
 
 pub struct InputValueDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
     inner: &'a S::InputValueDefinition,
@@ -61,6 +62,10 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> definition::
     fn r#type(&self) -> &Self::InputType {
         &self.r#type
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> HasDirectives
@@ -1,5 +1,6 @@
 use crate::{Cache, Directives, FieldsDefinition, InterfaceImplementations, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
+use bluejay_core::Location;
 use once_cell::unsync::OnceCell;
 
 pub struct InterfaceTypeDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
@@ -56,6 +57,10 @@ impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>>
             })
             .as_ref()
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition + 'a, W: Warden<SchemaDefinition = S>> HasDirectives
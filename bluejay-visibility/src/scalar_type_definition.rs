@@ -1,5 +1,6 @@
 use crate::{Cache, Directives, Warden};
 use bluejay_core::definition::{self, HasDirectives, SchemaDefinition};
+use bluejay_core::Location;
 
 pub struct ScalarTypeDefinition<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> {
     inner: &'a S::CustomScalarTypeDefinition,
@@ -43,6 +44,10 @@ impl<S: SchemaDefinition, W: Warden<SchemaDefinition = S>> definition::ScalarTyp
             .warden()
             .custom_scalar_definition_coerce_input(self.inner, value)
     }
+
+    fn location(&self) -> Option<Location> {
+        self.inner.location()
+    }
 }
 
 impl<'a, S: SchemaDefinition, W: Warden<SchemaDefinition = S>> HasDirectives
@@ -207,3 +207,47 @@ fn test_fields_definition_get() {
         "Expected field to be visible",
     );
 }
+
+#[test]
+fn test_forwards_location() {
+    let schema = "
+        directive @visible on FIELD_DEFINITION | ENUM_VALUE | INPUT_FIELD_DEFINITION | ARGUMENT_DEFINITION | SCALAR | OBJECT | INTERFACE | UNION | ENUM
+
+        type Query @visible {
+            field(arg: String @visible): String @visible
+        }
+    ";
+
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(schema)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+
+    let cache = Cache::new(DirectiveWarden::default(), &schema_definition);
+    let visibility_scoped_schema_definition = SchemaDefinition::new(&cache).unwrap();
+
+    let query = visibility_scoped_schema_definition.query();
+    assert_eq!(schema_definition.query().location(), query.location());
+    assert_eq!(
+        Some("Query"),
+        query
+            .location()
+            .map(|location| &schema[location.byte_range()]),
+    );
+
+    let field = query.fields_definition().get("field").unwrap();
+    assert_eq!(
+        Some("field"),
+        field
+            .location()
+            .map(|location| &schema[location.byte_range()]),
+    );
+    let argument = field.arguments_definition().unwrap().get("arg").unwrap();
+    assert_eq!(
+        Some("arg"),
+        argument
+            .location()
+            .map(|location| &schema[location.byte_range()]),
+    );
+}